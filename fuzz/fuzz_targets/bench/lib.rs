@@ -24,7 +24,7 @@ const FLATTEN_CONFLICT_MSG: &str = "ron::fuzz::FlattenFieldConflict";
 
 pub fn roundtrip_arbitrary_typed_ron_or_panic(data: &[u8]) -> Option<TypedSerdeData> {
     if let Ok(typed_value) = TypedSerdeData::arbitrary(&mut Unstructured::new(data)) {
-        let options = ron::Options::default().with_recursion_limit(RECURSION_LIMIT);
+        let options = ron::Options::default().with_depth_limit(RECURSION_LIMIT);
 
         let ron = match options.to_string_pretty(&typed_value, typed_value.pretty_config()) {
             Ok(ron) => ron,
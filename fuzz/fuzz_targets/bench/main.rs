@@ -66,7 +66,7 @@ fn main() -> anyhow::Result<()> {
         let data = fs::read(&entry).context("could not read corpus entry")?;
 
         if let Some(typed_data) = typed_data::roundtrip_arbitrary_typed_ron_or_panic(&data) {
-            let options = ron::Options::default().without_recursion_limit();
+            let options = ron::Options::default().without_depth_limit();
             let ty = options
                 .to_string_pretty(&typed_data.ty(), PrettyConfig::default().struct_names(true))
                 .unwrap();
@@ -0,0 +1,43 @@
+use serde::de::{self, IntoDeserializer, Visitor};
+
+use super::{Error, Result};
+
+/// Deserializer for a single byte of a byte string that is deserialized as
+///  a sequence, e.g. a `Vec<u8>` written with
+///  [`crate::ser::PrettyConfig::utf8_bytes_as_string`].
+///
+/// Only `u8` elements can be deserialized from a byte, so that a byte string
+///  is still rejected as a sequence of any other type, e.g. a `Vec<u32>`.
+pub struct Deserializer(pub u8);
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::ExpectedArray)
+    }
+
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u8(self.0)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl IntoDeserializer<'_, Error> for Deserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
@@ -0,0 +1,132 @@
+use alloc::{format, string::String};
+use core::fmt;
+
+use serde::de::{self, Deserialize, Error as _, IgnoredAny, Unexpected, Visitor};
+
+use super::{Error, Result, FOUND_SNIPPET_MAX_LEN};
+
+/// Visitor that describes the value of any type that was found where a
+///  value of a different, `expected`, type was expected
+pub struct FoundVisitor<'a> {
+    expected: &'a dyn de::Expected,
+    // identifier at the start of the found value, which names a unit struct
+    ident: Option<&'a str>,
+}
+
+impl<'a> FoundVisitor<'a> {
+    pub fn new(expected: &'a dyn de::Expected, ident: Option<&'a str>) -> Self {
+        FoundVisitor { expected, ident }
+    }
+}
+
+impl<'de> Visitor<'de> for FoundVisitor<'_> {
+    type Value = Error;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Error::invalid_type(Unexpected::Bool(v), self.expected))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Error::invalid_type(Unexpected::Signed(v), self.expected))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Error::invalid_type(Unexpected::Unsigned(v), self.expected))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Error::invalid_type(Unexpected::Float(v), self.expected))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Error::invalid_type(Unexpected::Char(v), self.expected))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        // only the start of a long string is included in the error
+        let found = match v.char_indices().nth(FOUND_SNIPPET_MAX_LEN) {
+            Some((end, _)) => Error::invalid_type(
+                Unexpected::Other(&format!("the string starting with {:?}", &v[..end])),
+                self.expected,
+            ),
+            None => Error::invalid_type(Unexpected::Str(v), self.expected),
+        };
+
+        Ok(found)
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.visit_str(&v)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() <= FOUND_SNIPPET_MAX_LEN {
+            return Ok(Error::invalid_type(Unexpected::Bytes(v), self.expected));
+        }
+
+        // only the start of a long byte string is included in the error
+        let snippet = v[..FOUND_SNIPPET_MAX_LEN]
+            .iter()
+            .flat_map(|c| core::ascii::escape_default(*c))
+            .map(char::from)
+            .collect::<String>();
+
+        Ok(Error::invalid_type(
+            Unexpected::Other(&format!("the byte string starting with b\"{snippet}\"")),
+            self.expected,
+        ))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        let found = match self.ident {
+            Some(ident) => Error::invalid_type(
+                Unexpected::Other(&format!("the identifier `{ident}`")),
+                self.expected,
+            ),
+            None => Error::invalid_type(Unexpected::Unit, self.expected),
+        };
+
+        Ok(found)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Error::invalid_type(Unexpected::Option, self.expected))
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        IgnoredAny::deserialize(deserializer)?;
+
+        Ok(Error::invalid_type(Unexpected::Option, self.expected))
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        IgnoredAny::deserialize(deserializer)?;
+
+        Ok(Error::invalid_type(
+            Unexpected::NewtypeStruct,
+            self.expected,
+        ))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+        Ok(Error::invalid_type(Unexpected::Seq, self.expected))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+
+        Ok(Error::invalid_type(Unexpected::Map, self.expected))
+    }
+}
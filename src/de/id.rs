@@ -1,3 +1,6 @@
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
+
 use serde::de::{self, Visitor};
 
 use super::{Error, Result};
@@ -20,8 +23,17 @@ impl<'a, 'b: 'a, 'c> de::Deserializer<'b> for &'c mut Deserializer<'a, 'b> {
     where
         V: Visitor<'b>,
     {
-        if self.map_as_struct {
-            // We only allow string keys in flattened structs and maps
+        if let Some(rename) = self.de.field_rename {
+            let visitor = RenamedField { visitor, rename };
+
+            if self.map_as_struct || self.de.parser.check_char('"') {
+                self.de.deserialize_str(visitor)
+            } else {
+                self.de.deserialize_identifier(visitor)
+            }
+        } else if self.map_as_struct || self.de.parser.check_char('"') {
+            // We only allow string keys in flattened structs and maps,
+            // but struct fields may also be quoted, e.g. `"a": 1`
             self.de.deserialize_str(visitor)
         } else {
             self.de.deserialize_identifier(visitor)
@@ -250,3 +262,40 @@ impl<'a, 'b: 'a, 'c> de::Deserializer<'b> for &'c mut Deserializer<'a, 'b> {
         self.deserialize_any(visitor)
     }
 }
+
+/// Visitor that renames a struct field name with
+/// [`Options::field_rename`](crate::Options::field_rename) before passing
+/// it on to the inner `visitor`.
+struct RenamedField<V> {
+    visitor: V,
+    rename: fn(&str) -> Cow<'_, str>,
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for RenamedField<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        match (self.rename)(v) {
+            Cow::Borrowed(field) => self.visitor.visit_borrowed_str(field),
+            Cow::Owned(field) => self.visitor.visit_string(field),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match (self.rename)(v) {
+            Cow::Borrowed(field) => self.visitor.visit_str(field),
+            Cow::Owned(field) => self.visitor.visit_string(field),
+        }
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        match (self.rename)(&v) {
+            Cow::Borrowed(field) => self.visitor.visit_str(field),
+            Cow::Owned(field) => self.visitor.visit_string(field),
+        }
+    }
+}
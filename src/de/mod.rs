@@ -1,61 +1,138 @@
 /// Deserialization module.
-use std::{
-    io::{self, Write},
-    str,
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
 };
+use core::{fmt::Write, marker::PhantomData, str};
+#[cfg(feature = "std")]
+use std::io;
 
 use serde::{
-    de::{self, DeserializeSeed, Deserializer as _, Visitor},
+    de::{self, DeserializeSeed, Deserializer as _, IntoDeserializer, Visitor},
     Deserialize,
 };
 
 pub use crate::error::{Error, Position, SpannedError};
 use crate::{
-    error::{Result, SpannedResult},
+    error::{Result, SpannedResult, Warning, WarningKind},
     extensions::Extensions,
-    options::Options,
-    parse::{NewtypeMode, ParsedByteStr, ParsedStr, Parser, StructType, TupleMode},
+    options::{Case, Options},
+    parse::{
+        NewtypeMode, ParsedByteStr, ParsedStr, Parser, ParserCursor, StructType, TupleMode,
+        RESERVED_IDENTIFIERS,
+    },
+    value::{Map, Value},
 };
 
+mod byte;
+mod found;
 mod id;
+mod parens;
 mod tag;
 #[cfg(test)]
 mod tests;
 mod value;
 
+pub(crate) use self::parens::RedundantParens;
+
 const SERDE_CONTENT_CANARY: &str = "serde::__private::de::content::Content";
 const SERDE_TAG_KEY_CANARY: &str = "serde::__private::de::content::TagOrContent";
 
+/// Returns whether `T` is the type at `path`, ignoring any generic arguments
+/// (including lifetimes) that [`core::any::type_name`] may include.
+fn is_type_at_path<T: ?Sized>(path: &str) -> bool {
+    let name = core::any::type_name::<T>();
+
+    name.split_once('<').map_or(name, |(name, _)| name) == path
+}
+
+/// Maximum number of characters of trailing input that are included in a
+/// [`Error::TrailingCharacters`] error.
+const TRAILING_SNIPPET_MAX_CHARS: usize = 16;
+
+/// Maximum number of characters or bytes of a found string or byte string
+/// that are included in an [`Error::InvalidValueForType`] error.
+const FOUND_SNIPPET_MAX_LEN: usize = 32;
+
 /// The RON deserializer.
 ///
 /// If you just want to simply deserialize a value,
 /// you can use the [`from_str`] convenience function.
+///
+/// Constructing a deserializer manually, e.g. with
+/// [`Deserializer::from_str`], allows driving it with your own
+/// [`Deserialize`] or [`DeserializeSeed`]
+/// implementations. Afterwards, call [`Deserializer::end`] to check that the
+/// entire input has been consumed, which [`from_str`] does automatically.
+#[allow(clippy::struct_excessive_bools)]
 pub struct Deserializer<'de> {
     pub(crate) parser: Parser<'de>,
     newtype_variant: bool,
     serde_content_newtype: bool,
     last_identifier: Option<&'de str>,
     recursion_limit: Option<usize>,
+    lenient_unit: bool,
+    trailing_ignored: Option<fn(&str) -> bool>,
+    precount_collections: bool,
+    bool_aliases: Vec<(String, bool)>,
+    numeric_enum_variants: bool,
+    field_rename: Option<fn(&str) -> Cow<'_, str>>,
+    variant_case_conversion: Option<Case>,
+    unit_as_none: bool,
+    // the defaults for the next struct or map, see `Options::field_defaults`
+    field_defaults: Option<Map>,
+    pub(crate) warnings: Option<Vec<Warning>>,
+    // whether a found value is currently being described, see
+    // `Deserializer::found_instead`, which must not recurse
+    describing_found: bool,
 }
 
 impl<'de> Deserializer<'de> {
+    /// Creates a new deserializer for the RON `input` string.
+    ///
+    /// Any `#![enable(...)]` extension attributes at the start of the
+    /// `input` are parsed eagerly.
     // Cannot implement trait here since output is tied to input lifetime 'de.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(input: &'de str) -> SpannedResult<Self> {
         Self::from_str_with_options(input, &Options::default())
     }
 
+    /// Creates a new deserializer for the RON `input` bytes, which must be
+    /// valid UTF-8.
+    ///
+    /// Any `#![enable(...)]` extension attributes at the start of the
+    /// `input` are parsed eagerly.
     pub fn from_bytes(input: &'de [u8]) -> SpannedResult<Self> {
         Self::from_bytes_with_options(input, &Options::default())
     }
 
+    /// Creates a new deserializer for the RON `input` string that is
+    /// configured with the given [`Options`].
+    ///
+    /// Unlike [`Options::from_str`], the deserializer can then be driven
+    /// by hand, see [`Deserializer::span_error`] for an example. The
+    /// [`Options::unwrap_redundant_parens`] are only applied by the
+    /// [`Options`] methods and not here.
     pub fn from_str_with_options(input: &'de str, options: &Options) -> SpannedResult<Self> {
         let mut deserializer = Deserializer {
-            parser: Parser::new(input)?,
-            newtype_variant: false,
-            serde_content_newtype: false,
-            last_identifier: None,
-            recursion_limit: options.recursion_limit,
+            recursion_limit: options.depth_limit,
+            lenient_unit: options.lenient_unit,
+            trailing_ignored: options.trailing_ignored,
+            precount_collections: options.precount_collections,
+            bool_aliases: options.bool_aliases.clone(),
+            numeric_enum_variants: options.numeric_enum_variants,
+            field_rename: options.field_rename,
+            variant_case_conversion: options.variant_case_conversion,
+            unit_as_none: options.unit_as_none,
+            field_defaults: options.field_defaults.clone().and_then(into_field_defaults),
+            warnings: if options.warnings {
+                Some(Vec::new())
+            } else {
+                None
+            },
+            ..Deserializer::with_parser(Parser::new_with_options(input, options)?)
         };
 
         deserializer.parser.exts |= options.default_extensions;
@@ -63,6 +140,44 @@ impl<'de> Deserializer<'de> {
         Ok(deserializer)
     }
 
+    /// Creates a deserializer for `parser` without any of the [`Options`]
+    ///  that are not already applied by the parser itself.
+    fn with_parser(parser: Parser<'de>) -> Self {
+        Deserializer {
+            parser,
+            newtype_variant: false,
+            serde_content_newtype: false,
+            last_identifier: None,
+            recursion_limit: None,
+            lenient_unit: false,
+            trailing_ignored: None,
+            precount_collections: false,
+            bool_aliases: Vec::new(),
+            numeric_enum_variants: false,
+            field_rename: None,
+            variant_case_conversion: None,
+            unit_as_none: false,
+            field_defaults: None,
+            warnings: None,
+            describing_found: false,
+        }
+    }
+
+    /// Creates a detached deserializer that continues from a copy of the
+    ///  parser, e.g. to look ahead, which leaves the state of this
+    ///  deserializer untouched. Only the recursion limit is kept, and the
+    ///  detached deserializer never describes a found value.
+    pub(crate) fn detached(&self) -> Self {
+        Deserializer {
+            recursion_limit: self.recursion_limit,
+            describing_found: true,
+            ..Deserializer::with_parser(self.parser.clone())
+        }
+    }
+
+    /// Creates a new deserializer for the RON `input` bytes, which must be
+    /// valid UTF-8, that is configured with the given [`Options`], see
+    /// [`Deserializer::from_str_with_options`].
     // FIXME: panic is not actually possible, remove once utf8_chunks is stabilized
     #[allow(clippy::missing_panics_doc)]
     pub fn from_bytes_with_options(input: &'de [u8], options: &Options) -> SpannedResult<Self> {
@@ -82,24 +197,96 @@ impl<'de> Deserializer<'de> {
         })
     }
 
+    /// Returns the input that has not yet been consumed.
     #[must_use]
     pub fn remainder(&self) -> &'de str {
         self.parser.src()
     }
 
+    /// Attaches the current position of the deserializer to an [`Error`],
+    /// e.g. one returned by a custom [`Deserialize`] or [`Visitor`]
+    /// implementation that drives this deserializer, to convert it into a
+    /// [`SpannedError`] like those returned by [`from_str`].
+    ///
+    /// Since the position is taken from the deserializer at the time of
+    /// the call, the error should be spanned right after it was returned.
+    ///
+    /// ```
+    /// use ron::{de::Deserializer, extensions::Extensions, Error, Options};
+    /// use serde::de::{Deserializer as _, Visitor};
+    ///
+    /// struct Even;
+    ///
+    /// impl<'de> Visitor<'de> for Even {
+    ///     type Value = u8;
+    ///
+    ///     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    ///         formatter.write_str("an even number")
+    ///     }
+    ///
+    ///     fn visit_u8<E: serde::de::Error>(self, v: u8) -> Result<u8, E> {
+    ///         if v % 2 == 0 {
+    ///             Ok(v)
+    ///         } else {
+    ///             Err(E::custom("odd number"))
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let options = Options::default().with_default_extension(Extensions::UNWRAP_NEWTYPES);
+    ///
+    /// let mut deserializer = Deserializer::from_str_with_options("42 // answer", &options).unwrap();
+    /// assert_eq!((&mut deserializer).deserialize_u8(Even), Ok(42));
+    /// assert_eq!(deserializer.end(), Ok(()));
+    ///
+    /// let mut deserializer = Deserializer::from_str_with_options("\n  7", &options).unwrap();
+    /// let err = (&mut deserializer).deserialize_u8(Even).unwrap_err();
+    /// let err = deserializer.span_error(err);
+    ///
+    /// assert_eq!(err.code, Error::Message(String::from("odd number")));
+    /// assert_eq!(err.position.line, 2);
+    /// assert_eq!(err.position.col, 4);
+    /// assert_eq!(err.to_string(), "2:4: odd number");
+    /// ```
     #[must_use]
     pub fn span_error(&self, code: Error) -> SpannedError {
         self.parser.span_error(code)
     }
 
+    /// Returns the [`Extensions`] that are enabled for this deserializer.
+    ///
+    /// These combine the extensions enabled by the `#![enable(...)]`
+    /// attributes at the start of the document with the default extensions
+    /// of the [`Options`] the deserializer was constructed with, e.g. with
+    /// [`Deserializer::from_str_with_options`].
     #[must_use]
     pub fn extensions(&self) -> Extensions {
         self.parser.exts
     }
+
+    /// Returns the [`Warning`]s about lenient but discouraged constructs
+    /// that have been accepted so far.
+    ///
+    /// Warnings are only collected if [`Options::warnings`] was enabled
+    /// when the deserializer was constructed, e.g. with
+    /// [`Deserializer::from_str_with_options`].
+    #[must_use]
+    pub fn warnings(&self) -> &[Warning] {
+        self.warnings.as_deref().unwrap_or(&[])
+    }
+
+    fn warn(&mut self, kind: WarningKind, cursor: ParserCursor) {
+        if let Some(ref mut warnings) = self.warnings {
+            warnings.push(self.parser.span_warning(kind, cursor));
+        }
+    }
 }
 
 /// A convenience function for building a deserializer
 /// and deserializing a value of type `T` from a reader.
+///
+/// This function is only available with the `std` feature.
+#[cfg(feature = "std")]
 pub fn from_reader<R, T>(rdr: R) -> SpannedResult<T>
 where
     R: io::Read,
@@ -149,16 +336,123 @@ macro_rules! guard_recursion {
 impl<'de> Deserializer<'de> {
     /// Check if the remaining bytes are whitespace only,
     /// otherwise return an error.
+    ///
+    /// This should be called after deserializing a value from a manually
+    /// constructed [`Deserializer`] to detect trailing characters that would
+    /// otherwise be silently ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TrailingCharacters`] if any non-whitespace and
+    /// non-comment characters remain in the input, unless they are ignored
+    /// by [`Options::trailing_ignored`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ron::{de::Deserializer, error::Error};
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// let mut deserializer = Deserializer::from_str("[1, 2, 3] // comment").unwrap();
+    /// let value = core::marker::PhantomData::<Vec<u8>>
+    ///     .deserialize(&mut deserializer)
+    ///     .unwrap();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(deserializer.end(), Ok(()));
+    ///
+    /// let mut deserializer = Deserializer::from_str("[1, 2, 3] [4]").unwrap();
+    /// let value = core::marker::PhantomData::<Vec<u8>>
+    ///     .deserialize(&mut deserializer)
+    ///     .unwrap();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(deserializer.remainder(), " [4]");
+    ///
+    /// let err = deserializer.end().unwrap_err();
+    /// assert_eq!(
+    ///     err,
+    ///     Error::TrailingCharacters {
+    ///         found: String::from("[4]")
+    ///     }
+    /// );
+    /// assert_eq!(deserializer.span_error(err).position.col, 11);
+    /// ```
     pub fn end(&mut self) -> Result<()> {
         self.parser.skip_ws()?;
 
-        if self.parser.src().is_empty() {
+        let src = self.parser.src();
+
+        if src.is_empty() {
+            Ok(())
+        } else if self.trailing_ignored.map_or(false, |ignored| ignored(src)) {
+            self.parser.advance_bytes(src.len());
+
+            Ok(())
+        } else {
+            Err(Error::TrailingCharacters {
+                found: trailing_snippet(src),
+            })
+        }
+    }
+
+    /// Consumes the opening `[` of a top-level sequence whose elements are
+    /// then deserialized one by one.
+    fn start_seq(&mut self) -> Result<()> {
+        if self.parser.consume_char('[') {
             Ok(())
         } else {
-            Err(Error::TrailingCharacters)
+            Err(Error::ExpectedArray)
         }
     }
 
+    /// Consumes the closing `]` of a top-level sequence and checks that
+    /// only whitespace follows it.
+    fn end_seq(&mut self) -> Result<()> {
+        self.parser.skip_ws()?;
+
+        if !self.parser.consume_char(']') {
+            return Err(Error::ExpectedArrayEnd);
+        }
+
+        self.end()
+    }
+
+    /// Replaces the error of a typed `deserialize_*` method that found a
+    /// value of a different type at `cursor`, e.g. [`Error::ExpectedString`]
+    /// for `42`, with an [`Error::InvalidValueForType`] that also describes
+    /// the value that was found, e.g. as "the unsigned integer `42`".
+    ///
+    /// Any other error, an error after part of the value has already been
+    /// consumed, or a value that cannot be parsed at all, is returned
+    /// unchanged.
+    #[cold]
+    fn found_instead(
+        &mut self,
+        err: Error,
+        cursor: ParserCursor,
+        expected: &dyn de::Expected,
+    ) -> Error {
+        match err {
+            Error::ExpectedBoolean
+            | Error::ExpectedInteger
+            | Error::ExpectedFloat
+            | Error::ExpectedChar
+            | Error::ExpectedString
+                if !self.describing_found && self.parser.cursor() == cursor => {}
+            err => return err,
+        }
+
+        // describe the value with a detached deserializer, which leaves
+        // the state of this deserializer untouched
+        let mut found = self.detached();
+
+        // a unit struct is described by its name
+        let ident = found.parser.clone().identifier().ok();
+
+        found
+            .deserialize_any(found::FoundVisitor::new(expected, ident))
+            .unwrap_or(err)
+    }
+
     /// Called from [`deserialize_any`][serde::Deserializer::deserialize_any]
     /// when a struct was detected. Decides if there is a unit, tuple or usual
     /// struct and deserializes it accordingly.
@@ -170,8 +464,8 @@ impl<'de> Deserializer<'de> {
     {
         // HACK: switch to JSON enum semantics for JSON content
         // Robust impl blocked on https://github.com/serde-rs/serde/pull/2420
-        let is_serde_content = std::any::type_name::<V::Value>() == SERDE_CONTENT_CANARY
-            || std::any::type_name::<V::Value>() == SERDE_TAG_KEY_CANARY;
+        let is_serde_content = is_type_at_path::<V::Value>(SERDE_CONTENT_CANARY)
+            || is_type_at_path::<V::Value>(SERDE_TAG_KEY_CANARY);
 
         let old_serde_content_newtype = self.serde_content_newtype;
         self.serde_content_newtype = false;
@@ -245,20 +539,18 @@ impl<'de> Deserializer<'de> {
             let old_newtype_variant = self.newtype_variant;
             self.newtype_variant = false;
 
-            let value = guard_recursion! { self =>
-                visitor
-                    .visit_map(CommaSeparated::new(Terminator::Struct, self))
-                    .map_err(|err| {
-                        struct_error_name(
-                            err,
-                            if !old_newtype_variant && !name_for_pretty_errors_only.is_empty() {
-                                Some(name_for_pretty_errors_only)
-                            } else {
-                                None
-                            },
-                        )
-                    })?
-            };
+            let value = visitor
+                .visit_map(CommaSeparated::new(Terminator::Struct, self))
+                .map_err(|err| {
+                    struct_error_name(
+                        err,
+                        if !old_newtype_variant && !name_for_pretty_errors_only.is_empty() {
+                            Some(name_for_pretty_errors_only)
+                        } else {
+                            None
+                        },
+                    )
+                })?;
 
             self.parser.skip_ws()?;
 
@@ -267,6 +559,8 @@ impl<'de> Deserializer<'de> {
             } else {
                 Err(Error::ExpectedStructLikeEnd)
             }
+        } else if self.parser.check_json_object() {
+            Err(Error::ExpectedStructGotMap(name_for_pretty_errors_only))
         } else if name_for_pretty_errors_only.is_empty() {
             Err(Error::ExpectedStructLike)
         } else {
@@ -312,6 +606,8 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
             }
         }
 
+        // the kind of an untyped value is inferred from its syntax alone,
+        //  following the rules that are documented on `Value`
         if self.parser.consume_ident("true") {
             return visitor.visit_bool(true);
         } else if self.parser.consume_ident("false") {
@@ -323,17 +619,23 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
         } else if self.parser.consume_str("()") {
             return visitor.visit_unit();
         } else if self.parser.consume_ident("inf") || self.parser.consume_ident("inff32") {
-            return visitor.visit_f32(std::f32::INFINITY);
+            return visitor.visit_f32(core::f32::INFINITY);
         } else if self.parser.consume_ident("inff64") {
-            return visitor.visit_f64(std::f64::INFINITY);
+            return visitor.visit_f64(core::f64::INFINITY);
         } else if self.parser.consume_ident("NaN") || self.parser.consume_ident("NaNf32") {
-            return visitor.visit_f32(std::f32::NAN);
+            return visitor.visit_f32(core::f32::NAN);
         } else if self.parser.consume_ident("NaNf64") {
-            return visitor.visit_f64(std::f64::NAN);
+            return visitor.visit_f64(core::f64::NAN);
         }
 
         // `skip_identifier` does not change state if it fails
+        let cursor = self.parser.cursor();
         if let Some(ident) = self.parser.skip_identifier() {
+            if let Err(err) = self.parser.check_string_length(ident.len()) {
+                self.parser.set_cursor(cursor);
+                return Err(err);
+            }
+
             self.parser.skip_ws()?;
 
             return self.handle_any_struct(visitor, Some(ident));
@@ -356,35 +658,80 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        visitor.visit_bool(self.parser.bool()?)
+        let cursor = self.parser.cursor();
+
+        match self.parser.bool() {
+            Err(Error::ExpectedBoolean) => {
+                let parser = &mut self.parser;
+
+                match self
+                    .bool_aliases
+                    .iter()
+                    .find(|(alias, _)| parser.consume_ident(alias))
+                    .map(|(alias, value)| (alias.clone(), *value))
+                {
+                    Some((alias, value)) => {
+                        self.warn(WarningKind::BoolAlias { alias, value }, cursor);
+
+                        visitor.visit_bool(value)
+                    }
+                    None => Err(self.found_instead(Error::ExpectedBoolean, cursor, &visitor)),
+                }
+            }
+            result => visitor.visit_bool(result?),
+        }
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_i8(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_i8(value)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_i16(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_i16(value)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_i32(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_i32(value)
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_i64(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_i64(value)
     }
 
     #[cfg(feature = "integer128")]
@@ -392,35 +739,65 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        visitor.visit_i128(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_i128(value)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_u8(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_u8(value)
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_u16(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_u16(value)
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_u32(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_u32(value)
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_u64(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_u64(value)
     }
 
     #[cfg(feature = "integer128")]
@@ -428,35 +805,65 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        visitor.visit_u128(self.parser.integer()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .integer()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_u128(value)
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_f32(self.parser.float()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .float()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_f32(value)
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_f64(self.parser.float()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .float()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_f64(value)
     }
 
     fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        visitor.visit_char(self.parser.char()?)
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .char()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        visitor.visit_char(value)
     }
 
     fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        match self.parser.string()? {
+        let cursor = self.parser.cursor();
+        let value = self
+            .parser
+            .string()
+            .map_err(|err| self.found_instead(err, cursor, &visitor))?;
+
+        match value {
             ParsedStr::Allocated(s) => visitor.visit_string(s),
             ParsedStr::Slice(s) => visitor.visit_borrowed_str(s),
         }
@@ -519,6 +926,8 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
             } else {
                 Err(Error::ExpectedOptionEnd)
             }
+        } else if self.unit_as_none && self.parser.consume_str("()") {
+            visitor.visit_none()
         } else if self.parser.exts.contains(Extensions::IMPLICIT_SOME) {
             guard_recursion! { self => visitor.visit_some(&mut *self) }
         } else {
@@ -531,10 +940,28 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        // The unit inside an unwrapped newtype variant may still be written
+        //  explicitly, e.g. `Ok(())` instead of `Ok()`
+        if self.newtype_variant && self.parser.check_str("()") {
+            self.newtype_variant = false;
+        }
+
         if self.newtype_variant || self.parser.consume_str("()") {
             self.newtype_variant = false;
 
             visitor.visit_unit()
+        } else if self.lenient_unit
+            && !self.parser.check_ident("")
+            && !RESERVED_IDENTIFIERS
+                .iter()
+                .any(|ident| self.parser.check_ident(ident))
+        {
+            // Leniently accept a unit struct name in place of `()`, but not
+            //  a literal like `true` or `None`
+            match self.parser.identifier() {
+                Ok(_) => visitor.visit_unit(),
+                Err(_) => Err(Error::ExpectedUnit),
+            }
         } else {
             Err(Error::ExpectedUnit)
         }
@@ -544,6 +971,12 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        // The unit struct inside an unwrapped newtype variant may still be
+        //  written explicitly, e.g. `Ok(Unit)` or `Ok(())` instead of `Ok()`
+        if self.newtype_variant && (self.parser.check_str("()") || !self.parser.check_ident("")) {
+            self.newtype_variant = false;
+        }
+
         if self.newtype_variant || self.parser.consume_struct_name(name)? {
             self.newtype_variant = false;
 
@@ -608,10 +1041,36 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     {
         self.newtype_variant = false;
 
-        if self.parser.consume_char('[') {
-            let value = guard_recursion! { self =>
-                visitor.visit_seq(CommaSeparated::new(Terminator::Seq, self))?
+        // A sequence of bytes that was written as a byte string, e.g. with
+        //  `PrettyConfig::utf8_bytes_as_string`, whose elements must be `u8`s
+        if self.parser.check_str("b\"")
+            || self.parser.check_str("br\"")
+            || self.parser.check_str("br#")
+        {
+            let cursor = self.parser.cursor();
+
+            let bytes = self.parser.byte_string()?;
+            let bytes = match bytes {
+                ParsedByteStr::Allocated(ref byte_buf) => byte_buf.as_slice(),
+                ParsedByteStr::Slice(bytes) => bytes,
             };
+
+            let mut seq = de::value::SeqDeserializer::<_, Error>::new(
+                bytes.iter().copied().map(byte::Deserializer),
+            );
+
+            return visitor
+                .visit_seq(&mut seq)
+                .and_then(|value| seq.end().map(|()| value))
+                .map_err(|err| {
+                    // report the error at the start of the byte string
+                    self.parser.set_cursor(cursor);
+                    err
+                });
+        }
+
+        if self.parser.consume_char('[') {
+            let value = visitor.visit_seq(CommaSeparated::new_precounted(Terminator::Seq, self))?;
             self.parser.skip_ws()?;
 
             if self.parser.consume_char(']') {
@@ -632,9 +1091,7 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
             let old_newtype_variant = self.newtype_variant;
             self.newtype_variant = false;
 
-            let value = guard_recursion! { self =>
-                visitor.visit_seq(CommaSeparated::new(Terminator::Tuple, self))?
-            };
+            let value = visitor.visit_seq(CommaSeparated::new(Terminator::Tuple, self))?;
             self.parser.skip_ws()?;
 
             if old_newtype_variant || self.parser.consume_char(')') {
@@ -662,6 +1119,7 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
         self.deserialize_tuple(len, visitor).map_err(|e| match e {
             Error::ExpectedStructLike if !name.is_empty() => Error::ExpectedNamedStructLike(name),
+            e if !name.is_empty() => tuple_error_name(e, Some(name)),
             e => e,
         })
     }
@@ -674,20 +1132,38 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
         const SERDE_FLATTEN_CANARY: &[u8] = b"struct ";
 
         struct VisitorExpecting<V>(V);
-        impl<'de, V: Visitor<'de>> std::fmt::Display for VisitorExpecting<&'_ V> {
-            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl<'de, V: Visitor<'de>> core::fmt::Display for VisitorExpecting<&'_ V> {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
                 self.0.expecting(fmt)
             }
         }
 
+        /// Writes as much of the formatted output as fits into the buffer
+        struct TruncatingWriter<'a>(&'a mut [u8]);
+        impl core::fmt::Write for TruncatingWriter<'_> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let len = s.len().min(self.0.len());
+                let (head, tail) = core::mem::take(&mut self.0).split_at_mut(len);
+                head.copy_from_slice(&s.as_bytes()[..len]);
+                self.0 = tail;
+
+                if len < s.len() {
+                    Err(core::fmt::Error)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
         self.newtype_variant = false;
 
         let mut canary_buffer = [0u8; SERDE_FLATTEN_CANARY.len()];
-        std::mem::drop(write!(
-            canary_buffer.as_mut(),
+        // Only the truncated prefix is needed, so the error is ignored
+        let _ = write!(
+            TruncatingWriter(&mut canary_buffer),
             "{}",
             VisitorExpecting(&visitor)
-        ));
+        );
         let terminator = if canary_buffer == SERDE_FLATTEN_CANARY {
             Terminator::MapAsStruct
         } else {
@@ -695,9 +1171,7 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
         };
 
         if self.parser.consume_char('{') {
-            let value = guard_recursion! { self =>
-                visitor.visit_map(CommaSeparated::new(terminator, self))?
-            };
+            let value = visitor.visit_map(CommaSeparated::new_precounted(terminator, self))?;
             self.parser.skip_ws()?;
 
             if self.parser.consume_char('}') {
@@ -719,6 +1193,12 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        // The struct inside an unwrapped newtype variant may still be written
+        //  explicitly, e.g. `Ok((a: 1))` or `Ok(S(a: 1))` instead of `Ok(a: 1)`
+        if self.newtype_variant && self.parser.check_wrapped_struct()? {
+            self.newtype_variant = false;
+        }
+
         if !self.newtype_variant {
             self.parser.consume_struct_name(name)?;
         }
@@ -731,15 +1211,17 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     fn deserialize_enum<V>(
         self,
         name: &'static str,
-        _variants: &'static [&'static str],
+        variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
         self.newtype_variant = false;
+        // the fields of struct variants are not filled in from defaults
+        self.field_defaults = None;
 
-        match guard_recursion! { self => visitor.visit_enum(Enum::new(self)) } {
+        match visitor.visit_enum(Enum::new(self, variants)) {
             Ok(value) => Ok(value),
             Err(Error::NoSuchEnumVariant {
                 expected,
@@ -750,6 +1232,24 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
                 found,
                 outer: Some(String::from(name)),
             }),
+            Err(Error::NoSuchEnumVariantIndex {
+                expected,
+                found,
+                outer: None,
+            }) if !name.is_empty() => Err(Error::NoSuchEnumVariantIndex {
+                expected,
+                found,
+                outer: Some(String::from(name)),
+            }),
+            Err(Error::AmbiguousEnumVariant {
+                found,
+                candidates,
+                outer: None,
+            }) if !name.is_empty() => Err(Error::AmbiguousEnumVariant {
+                found,
+                candidates,
+                outer: Some(String::from(name)),
+            }),
             Err(e) => Err(e),
         }
     }
@@ -796,16 +1296,148 @@ struct CommaSeparated<'a, 'de: 'a> {
     terminator: Terminator,
     had_comma: bool,
     inside_internally_tagged_enum: bool,
+    remaining: Option<usize>,
+    // the defaults for the entries that have not been parsed (yet), or all
+    //  defaults of a map, which are only used to look up nested defaults
+    field_defaults: Option<Map>,
+    // the defaults of a map, which are yielded before its parsed entries so
+    //  that parsed entries with an equal typed key replace them
+    leading_defaults: Vec<(Value, Value)>,
+    // the defaults for the value of the parsed entry
+    value_defaults: Option<Map>,
+    // the value of an entry that is filled in from the defaults
+    default_value: Option<Value>,
 }
 
 impl<'a, 'de> CommaSeparated<'a, 'de> {
     fn new(terminator: Terminator, de: &'a mut Deserializer<'de>) -> Self {
+        // only structs and maps take the defaults, which are discarded by
+        //  any other container so that they never reach a nested struct
+        let field_defaults = match terminator {
+            Terminator::Map | Terminator::MapAsStruct | Terminator::Struct => {
+                de.field_defaults.take()
+            }
+            Terminator::Tuple | Terminator::Seq => {
+                de.field_defaults = None;
+                None
+            }
+        };
+
+        // the keys of a map may be written differently from their defaults,
+        //  e.g. `1u32` or a bool alias, so its defaults are not matched up
+        let leading_defaults = match (&terminator, &field_defaults) {
+            (Terminator::Map, Some(defaults)) => defaults
+                .iter()
+                .rev()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            _ => Vec::new(),
+        };
+
         CommaSeparated {
             de,
             terminator,
             had_comma: true,
             inside_internally_tagged_enum: false,
+            remaining: None,
+            field_defaults,
+            leading_defaults,
+            value_defaults: None,
+            default_value: None,
+        }
+    }
+
+    /// Like [`CommaSeparated::new`], but counts the remaining elements
+    ///  up front if [`Options::precount_collections`] is enabled
+    fn new_precounted(terminator: Terminator, de: &'a mut Deserializer<'de>) -> Self {
+        let remaining = if de.precount_collections {
+            Some(de.parser.count_elements())
+        } else {
+            None
+        };
+
+        CommaSeparated {
+            remaining,
+            ..Self::new(terminator, de)
+        }
+    }
+
+    fn consume_element(&mut self) {
+        if let Some(remaining) = &mut self.remaining {
+            *remaining = remaining.saturating_sub(1);
+        }
+    }
+
+    /// Consumes a `=>` key-value separator, which is only accepted inside
+    ///  maps with the [`Extensions::ARROW_MAPS`] extension
+    fn consume_map_arrow(&mut self) -> bool {
+        matches!(self.terminator, Terminator::Map)
+            && self.de.parser.exts.contains(Extensions::ARROW_MAPS)
+            && self.de.parser.consume_str("=>")
+    }
+
+    /// Consumes a `=` field-value separator, which is only accepted inside
+    ///  structs with the [`Extensions::EQUALS_FIELDS`] extension
+    fn consume_field_equals(&mut self) -> bool {
+        matches!(self.terminator, Terminator::Struct) && self.de.parser.consume_field_equals()
+    }
+
+    /// Parses the key of the next entry without consuming it, e.g. to look
+    ///  up its defaults, or returns `None` if it is not a valid key
+    fn peek_key(&mut self) -> Option<Value> {
+        let cursor = self.de.parser.cursor();
+
+        let key = match self.terminator {
+            Terminator::Struct if !self.de.parser.check_char('"') => self
+                .de
+                .parser
+                .identifier()
+                .ok()
+                .map(|key| Value::String(String::from(key))),
+            _ => Value::deserialize(&mut *self.de).ok(),
+        };
+
+        self.de.parser.set_cursor(cursor);
+
+        key
+    }
+
+    /// Takes the defaults for the value of the parsed entry with `key`
+    fn value_defaults(&mut self, key: &Value) -> Option<Map> {
+        let defaults = self.field_defaults.as_mut()?;
+
+        let value = match self.terminator {
+            // a unit key, e.g. an enum variant, is ambiguous
+            Terminator::Map if *key == Value::Unit => None,
+            Terminator::Map => defaults
+                .iter()
+                .find(|(default_key, _)| default_key.semantic_eq(key))
+                .map(|(_, value)| value.clone()),
+            _ => defaults.remove(key),
+        };
+
+        value.and_then(into_field_defaults)
+    }
+
+    /// Removes the next entry that was not parsed from the defaults of a
+    ///  struct
+    fn next_default(&mut self) -> Option<(Value, Value)> {
+        if matches!(self.terminator, Terminator::Map) {
+            return None;
         }
+
+        let defaults = self.field_defaults.as_mut()?;
+        let key = defaults.keys().next()?.clone();
+        let value = defaults.remove(&key)?;
+
+        let key = match (&self.terminator, self.de.field_rename, key) {
+            (Terminator::Struct, Some(rename), Value::String(key)) => {
+                Value::String(rename(&key).into_owned())
+            }
+            (_, _, key) => key,
+        };
+
+        Some((key, value))
     }
 
     fn has_element(&mut self) -> Result<bool> {
@@ -833,6 +1465,12 @@ impl<'de, 'a> de::SeqAccess<'de> for CommaSeparated<'a, 'de> {
         T: DeserializeSeed<'de>,
     {
         if self.has_element()? {
+            if matches!(self.terminator, Terminator::Tuple) && self.de.parser.check_named_field() {
+                return Err(Error::ExpectedPositionalFields { outer: None });
+            }
+
+            self.consume_element();
+
             let res = guard_recursion! { self.de => seed.deserialize(&mut *self.de)? };
 
             self.had_comma = self.de.parser.comma()?;
@@ -842,6 +1480,10 @@ impl<'de, 'a> de::SeqAccess<'de> for CommaSeparated<'a, 'de> {
             Ok(None)
         }
     }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
 }
 
 impl<'de, 'a> de::MapAccess<'de> for CommaSeparated<'a, 'de> {
@@ -851,9 +1493,26 @@ impl<'de, 'a> de::MapAccess<'de> for CommaSeparated<'a, 'de> {
     where
         K: DeserializeSeed<'de>,
     {
+        if let Some((key, value)) = self.leading_defaults.pop() {
+            self.default_value = Some(value);
+
+            return seed.deserialize(key).map(Some);
+        }
+
         if self.has_element()? {
-            self.inside_internally_tagged_enum =
-                std::any::type_name::<K::Value>() == SERDE_TAG_KEY_CANARY;
+            if matches!(self.terminator, Terminator::Struct)
+                && self.de.parser.check_positional_field()
+            {
+                return Err(Error::ExpectedNamedFields { outer: None });
+            }
+
+            self.consume_element();
+
+            if self.field_defaults.is_some() {
+                self.value_defaults = self.peek_key().and_then(|key| self.value_defaults(&key));
+            }
+
+            self.inside_internally_tagged_enum = is_type_at_path::<K::Value>(SERDE_TAG_KEY_CANARY);
 
             match self.terminator {
                 Terminator::Struct => guard_recursion! { self.de =>
@@ -864,6 +1523,11 @@ impl<'de, 'a> de::MapAccess<'de> for CommaSeparated<'a, 'de> {
                 },
                 _ => guard_recursion! { self.de => seed.deserialize(&mut *self.de).map(Some) },
             }
+        } else if let Some((key, value)) = self.next_default() {
+            // fill in the entries that were missing after the parsed ones
+            self.default_value = Some(value);
+
+            seed.deserialize(key).map(Some)
         } else {
             Ok(None)
         }
@@ -873,13 +1537,22 @@ impl<'de, 'a> de::MapAccess<'de> for CommaSeparated<'a, 'de> {
     where
         V: DeserializeSeed<'de>,
     {
+        if let Some(value) = self.default_value.take() {
+            return seed.deserialize(value);
+        }
+
         self.de.parser.skip_ws()?;
 
-        if self.de.parser.consume_char(':') {
+        if self.de.parser.consume_char(':')
+            || self.consume_map_arrow()
+            || self.consume_field_equals()
+        {
             self.de.parser.skip_ws()?;
 
+            self.de.field_defaults = self.value_defaults.take();
+
             let res = if self.inside_internally_tagged_enum
-                && std::any::type_name::<V::Value>() != SERDE_CONTENT_CANARY
+                && !is_type_at_path::<V::Value>(SERDE_CONTENT_CANARY)
             {
                 guard_recursion! { self.de =>
                     seed.deserialize(&mut tag::Deserializer::new(&mut *self.de))?
@@ -890,6 +1563,9 @@ impl<'de, 'a> de::MapAccess<'de> for CommaSeparated<'a, 'de> {
                 }
             };
 
+            // the defaults are discarded if the value was not a struct or map
+            self.de.field_defaults = None;
+
             self.had_comma = self.de.parser.comma()?;
 
             Ok(res)
@@ -897,15 +1573,95 @@ impl<'de, 'a> de::MapAccess<'de> for CommaSeparated<'a, 'de> {
             Err(Error::ExpectedMapColon)
         }
     }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+/// Iterator over the lazily deserialized elements of a top-level sequence,
+/// see [`Options::deserialize_seq_iter`].
+pub(crate) struct SeqIter<'de, T> {
+    // `None` once the sequence has ended or an error has occurred
+    de: Option<Deserializer<'de>>,
+    error: Option<SpannedError>,
+    had_comma: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> SeqIter<'de, T> {
+    pub(crate) fn new(input: &'de str, options: &Options) -> Self {
+        let mut iter = SeqIter {
+            de: None,
+            error: None,
+            had_comma: true,
+            marker: PhantomData,
+        };
+
+        let mut de = match Deserializer::from_str_with_options(input, options) {
+            Ok(de) => de,
+            Err(err) => {
+                iter.error = Some(err);
+                return iter;
+            }
+        };
+
+        match de.start_seq() {
+            Ok(()) => iter.de = Some(de),
+            Err(err) => iter.error = Some(de.span_error(err)),
+        }
+
+        iter
+    }
+
+    fn next_element(de: &mut Deserializer<'de>, had_comma: &mut bool) -> Result<Option<T>> {
+        let mut seq = CommaSeparated::new(Terminator::Seq, de);
+        seq.had_comma = *had_comma;
+
+        let element = de::SeqAccess::next_element(&mut seq)?;
+        *had_comma = seq.had_comma;
+
+        if element.is_none() {
+            de.end_seq()?;
+        }
+
+        Ok(element)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Iterator for SeqIter<'de, T> {
+    type Item = SpannedResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            return Some(Err(err));
+        }
+
+        let de = self.de.as_mut()?;
+
+        match Self::next_element(de, &mut self.had_comma) {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.de = None;
+                None
+            }
+            Err(err) => {
+                let err = de.span_error(err);
+                self.de = None;
+                Some(Err(err))
+            }
+        }
+    }
 }
 
 struct Enum<'a, 'de: 'a> {
     de: &'a mut Deserializer<'de>,
+    variants: &'static [&'static str],
 }
 
 impl<'a, 'de> Enum<'a, 'de> {
-    fn new(de: &'a mut Deserializer<'de>) -> Self {
-        Enum { de }
+    fn new(de: &'a mut Deserializer<'de>, variants: &'static [&'static str]) -> Self {
+        Enum { de, variants }
     }
 }
 
@@ -919,7 +1675,77 @@ impl<'de, 'a> de::EnumAccess<'de> for Enum<'a, 'de> {
     {
         self.de.parser.skip_ws()?;
 
-        let value = guard_recursion! { self.de => seed.deserialize(&mut *self.de)? };
+        let cursor = self.de.parser.cursor();
+
+        if self.de.numeric_enum_variants
+            && self
+                .de
+                .parser
+                .peek_char()
+                .map_or(false, |c| c.is_ascii_digit())
+        {
+            // The variant is given by its index, e.g. `1` for the second one
+            let index = self.de.parser.integer::<u64>()?;
+
+            let variant = usize::try_from(index)
+                .ok()
+                .and_then(|index| self.variants.get(index))
+                .ok_or(Error::NoSuchEnumVariantIndex {
+                    expected: self.variants,
+                    found: index,
+                    outer: None,
+                })?;
+
+            self.de.last_identifier = Some(variant);
+            self.de
+                .warn(WarningKind::NumericEnumVariant { index, variant }, cursor);
+
+            let value =
+                seed.deserialize(de::value::BorrowedStrDeserializer::<Error>::new(variant))?;
+
+            return Ok((value, self));
+        }
+
+        if let Some(case) = self.de.variant_case_conversion {
+            // The variant may be written in another case convention, which
+            // only applies if it does not exactly match any variant
+            let mut parser = self.de.parser.clone();
+
+            if let Ok(found) = parser.identifier() {
+                if !self.variants.contains(&found) {
+                    let candidates = self
+                        .variants
+                        .iter()
+                        .copied()
+                        .filter(|variant| case.convert(variant) == found)
+                        .collect::<Vec<_>>();
+
+                    match candidates[..] {
+                        [] => (),
+                        [variant] => {
+                            self.de.parser = parser;
+                            self.de.last_identifier = Some(variant);
+
+                            let value =
+                                seed.deserialize(
+                                    de::value::BorrowedStrDeserializer::<Error>::new(variant),
+                                )?;
+
+                            return Ok((value, self));
+                        }
+                        _ => {
+                            return Err(Error::AmbiguousEnumVariant {
+                                found: String::from(found),
+                                candidates,
+                                outer: None,
+                            })
+                        }
+                    }
+                }
+            }
+        }
+
+        let value = seed.deserialize(&mut *self.de)?;
 
         Ok((value, self))
     }
@@ -929,6 +1755,19 @@ impl<'de, 'a> de::VariantAccess<'de> for Enum<'a, 'de> {
     type Error = Error;
 
     fn unit_variant(self) -> Result<()> {
+        if self.de.lenient_unit {
+            self.de.parser.skip_ws()?;
+
+            // Leniently accept an empty tuple after a unit variant, e.g. `A()`
+            if self.de.parser.consume_char('(') {
+                self.de.parser.skip_ws()?;
+
+                if !self.de.parser.consume_char(')') {
+                    return Err(Error::ExpectedStructLikeEnd);
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -964,6 +1803,10 @@ impl<'de, 'a> de::VariantAccess<'de> for Enum<'a, 'de> {
             } else {
                 Err(Error::ExpectedStructLikeEnd)
             }
+        } else if self.de.lenient_unit {
+            // Leniently accept a bare newtype variant whose content is a unit
+            seed.deserialize(().into_deserializer())
+                .map_err(|_: Error| Error::ExpectedStructLike)
         } else {
             Err(Error::ExpectedStructLike)
         }
@@ -973,9 +1816,13 @@ impl<'de, 'a> de::VariantAccess<'de> for Enum<'a, 'de> {
     where
         V: Visitor<'de>,
     {
+        let tuple_variant = self.de.last_identifier;
+
         self.de.parser.skip_ws()?;
 
-        self.de.deserialize_tuple(len, visitor)
+        self.de
+            .deserialize_tuple(len, visitor)
+            .map_err(|err| tuple_error_name(err, tuple_variant))
     }
 
     fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value>
@@ -992,6 +1839,16 @@ impl<'de, 'a> de::VariantAccess<'de> for Enum<'a, 'de> {
     }
 }
 
+/// Returns the entries of `defaults` if they are a map, which may be wrapped
+///  in a `Some(...)`, see [`Options::field_defaults`].
+fn into_field_defaults(defaults: Value) -> Option<Map> {
+    match defaults {
+        Value::Option(Some(defaults)) => into_field_defaults(*defaults),
+        Value::Map(defaults) => Some(defaults),
+        _ => None,
+    }
+}
+
 fn struct_error_name(error: Error, name: Option<&str>) -> Error {
     match error {
         Error::NoSuchStructField {
@@ -1011,10 +1868,33 @@ fn struct_error_name(error: Error, name: Option<&str>) -> Error {
             field,
             outer: name.map(ToOwned::to_owned),
         },
+        Error::ExpectedNamedFields { outer: None } => Error::ExpectedNamedFields {
+            outer: name.map(ToOwned::to_owned),
+        },
         e => e,
     }
 }
 
+fn tuple_error_name(error: Error, name: Option<&str>) -> Error {
+    match error {
+        Error::ExpectedPositionalFields { outer: None } => Error::ExpectedPositionalFields {
+            outer: name.map(ToOwned::to_owned),
+        },
+        e => e,
+    }
+}
+
+/// Extracts a short snippet from the start of the first line of the trailing
+/// input `src` to include in a [`Error::TrailingCharacters`] error.
+pub(crate) fn trailing_snippet(src: &str) -> String {
+    src.lines()
+        .next()
+        .unwrap_or(src)
+        .chars()
+        .take(TRAILING_SNIPPET_MAX_CHARS)
+        .collect()
+}
+
 struct SerdeEnumContent<'a, 'de: 'a> {
     de: &'a mut Deserializer<'de>,
     ident: Option<&'a str>,
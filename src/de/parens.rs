@@ -0,0 +1,178 @@
+use serde::de::{self, Deserializer as _, IgnoredAny, Visitor};
+
+use super::{Error, Result};
+use crate::extensions::Extensions;
+
+/// Deserializer for a top-level value that may be wrapped in a single pair
+///  of redundant parentheses, e.g. `(42)` or `((1, 2))`, see
+///  [`Options::unwrap_redundant_parens`](crate::Options::unwrap_redundant_parens)
+pub struct RedundantParens<'a, 'de> {
+    de: &'a mut super::Deserializer<'de>,
+}
+
+impl<'a, 'de> RedundantParens<'a, 'de> {
+    pub fn new(de: &'a mut super::Deserializer<'de>) -> Self {
+        RedundantParens { de }
+    }
+
+    /// Checks if the remaining input starts with `(`, followed by exactly
+    ///  one value and a closing `)`, without consuming any of it.
+    fn is_wrapped(&self) -> bool {
+        // skip the wrapped value with a detached deserializer, which leaves
+        // the state of the wrapped deserializer untouched
+        let mut probe = self.de.detached();
+
+        probe.parser.consume_char('(')
+            && probe.parser.skip_ws().is_ok()
+            && !probe.parser.check_char(')')
+            && probe.deserialize_any(IgnoredAny).is_ok()
+            && probe.parser.skip_ws().is_ok()
+            && probe.parser.consume_char(')')
+    }
+
+    /// Deserializes the value with `deserialize`, after peeling off its
+    ///  redundant parentheses if there are any.
+    fn peel<T>(
+        self,
+        deserialize: impl FnOnce(&mut super::Deserializer<'de>) -> Result<T>,
+    ) -> Result<T> {
+        if !self.is_wrapped() {
+            return deserialize(self.de);
+        }
+
+        self.de.parser.consume_char('(');
+        self.de.parser.skip_ws()?;
+
+        let value = deserialize(&mut *self.de)?;
+
+        self.de.parser.skip_ws()?;
+        self.de
+            .parser
+            .expect_char(')', Error::ExpectedStructLikeEnd)?;
+
+        Ok(value)
+    }
+}
+
+macro_rules! peel_redundant_parens {
+    ($($method:ident)*) => {$(
+        fn $method<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            self.peel(|de| de.$method(visitor))
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for RedundantParens<'_, 'de> {
+    type Error = Error;
+
+    peel_redundant_parens! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16
+        deserialize_u32 deserialize_u64 deserialize_u128 deserialize_f32
+        deserialize_f64 deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf deserialize_unit deserialize_seq
+        deserialize_map deserialize_identifier
+    }
+
+    // Without a type hint, `(42)` could just as well be a tuple
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_any(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_ignored_any(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        // `(...)` may be the content of an implicit `Some`
+        if self.de.parser.exts.contains(Extensions::IMPLICIT_SOME) {
+            self.de.deserialize_option(visitor)
+        } else {
+            self.peel(|de| de.deserialize_option(visitor))
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.peel(|de| de.deserialize_unit_struct(name, visitor))
+    }
+
+    // `(...)` is how the newtype itself is written
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        // `(...)` is how a tuple with a single element is written
+        if len == 1 {
+            self.de.deserialize_tuple(len, visitor)
+        } else {
+            self.peel(|de| de.deserialize_tuple(len, visitor))
+        }
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        // `(...)` is how a tuple struct with a single field is written
+        if len == 1 {
+            self.de.deserialize_tuple_struct(name, len, visitor)
+        } else {
+            self.peel(|de| de.deserialize_tuple_struct(name, len, visitor))
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.peel(|de| de.deserialize_struct(name, fields, visitor))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.peel(|de| de.deserialize_enum(name, variants, visitor))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.de.is_human_readable()
+    }
+}
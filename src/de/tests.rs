@@ -68,14 +68,22 @@ fn test_struct() {
         "NewType",
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike("NewType"),
-            position: Position { line: 1, col: 8 },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7,
+            },
         }),
     );
     check_from_str_bytes_reader::<UnnamedNewType>(
         "",
         Err(SpannedError {
             code: Error::ExpectedStructLike,
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
         }),
     );
     check_from_str_bytes_reader("(33)", Ok(UnnamedNewType(33)));
@@ -83,7 +91,11 @@ fn test_struct() {
         "Newtype",
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike(""),
-            position: Position { line: 1, col: 8 },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7,
+            },
         }),
     );
 
@@ -93,14 +105,22 @@ fn test_struct() {
         "",
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike("TupleStruct"),
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
         }),
     );
     check_from_str_bytes_reader::<UnnamedTupleStruct>(
         "TupleStruct(2,5,)",
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike(""),
-            position: Position { line: 1, col: 12 },
+            position: Position {
+                line: 1,
+                col: 12,
+                offset: 11,
+            },
         }),
     );
     check_from_str_bytes_reader("(3,4)", Ok(UnnamedTupleStruct(3.0, 4.0)));
@@ -108,7 +128,11 @@ fn test_struct() {
         "",
         Err(SpannedError {
             code: Error::ExpectedStructLike,
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
         }),
     );
 }
@@ -147,7 +171,11 @@ fn test_unclosed_limited_seq_struct() {
         "(",
         Err(SpannedError {
             code: Error::ExpectedStructLikeEnd,
-            position: Position { line: 1, col: 2 },
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
         }),
     )
 }
@@ -186,7 +214,11 @@ fn test_unclosed_limited_seq() {
         "[",
         Err(SpannedError {
             code: Error::ExpectedArrayEnd,
-            position: Position { line: 1, col: 2 },
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
         }),
     );
 
@@ -233,7 +265,11 @@ fn test_unclosed_limited_map() {
         "{",
         Err(SpannedError {
             code: Error::ExpectedMapEnd,
-            position: Position { line: 1, col: 2 },
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
         }),
     );
 
@@ -260,7 +296,11 @@ fn test_enum() {
         "B",
         Err(SpannedError {
             code: Error::ExpectedStructLike,
-            position: Position { line: 1, col: 2 },
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
         }),
     );
     check_from_str_bytes_reader("C(true,3.5,)", Ok(MyEnum::C(true, 3.5)));
@@ -342,7 +382,11 @@ y: 2.0 // 2!
 fn err<T>(kind: Error, line: usize, col: usize) -> SpannedResult<T> {
     Err(SpannedError {
         code: kind,
-        position: Position { line, col },
+        position: Position {
+            line,
+            col,
+            offset: col - 1,
+        },
     })
 }
 
@@ -350,8 +394,28 @@ fn err<T>(kind: Error, line: usize, col: usize) -> SpannedResult<T> {
 fn test_err_wrong_value() {
     use std::collections::HashMap;
 
-    check_from_str_bytes_reader::<f32>("'c'", err(Error::ExpectedFloat, 1, 1));
-    check_from_str_bytes_reader::<String>("'c'", err(Error::ExpectedString, 1, 1));
+    check_from_str_bytes_reader::<f32>(
+        "'c'",
+        err(
+            Error::InvalidValueForType {
+                expected: String::from("f32"),
+                found: String::from("the UTF-8 character `c`"),
+            },
+            1,
+            1,
+        ),
+    );
+    check_from_str_bytes_reader::<String>(
+        "'c'",
+        err(
+            Error::InvalidValueForType {
+                expected: String::from("a string"),
+                found: String::from("the UTF-8 character `c`"),
+            },
+            1,
+            1,
+        ),
+    );
     check_from_str_bytes_reader::<HashMap<u32, u32>>("'c'", err(Error::ExpectedMap, 1, 1));
     check_from_str_bytes_reader::<[u8; 5]>("'c'", err(Error::ExpectedStructLike, 1, 1));
     check_from_str_bytes_reader::<Vec<u32>>("'c'", err(Error::ExpectedArray, 1, 1));
@@ -372,15 +436,42 @@ fn test_err_wrong_value() {
         ),
     );
     check_from_str_bytes_reader::<(u8, bool)>("'c'", err(Error::ExpectedStructLike, 1, 1));
-    check_from_str_bytes_reader::<bool>("notabool", err(Error::ExpectedBoolean, 1, 1));
+    check_from_str_bytes_reader::<bool>(
+        "notabool",
+        err(
+            Error::InvalidValueForType {
+                expected: String::from("a boolean"),
+                found: String::from("the identifier `notabool`"),
+            },
+            1,
+            1,
+        ),
+    );
 
     check_from_str_bytes_reader::<MyStruct>(
         "MyStruct(\n    x: true)",
-        err(Error::ExpectedFloat, 2, 8),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("f32"),
+                found: String::from("the boolean `true`"),
+            },
+            position: Position {
+                line: 2,
+                col: 8,
+                offset: 17,
+            },
+        }),
     );
     check_from_str_bytes_reader::<MyStruct>(
         "MyStruct(\n    x: 3.5, \n    y:)",
-        err(Error::ExpectedFloat, 3, 7),
+        Err(SpannedError {
+            code: Error::ExpectedFloat,
+            position: Position {
+                line: 3,
+                col: 7,
+                offset: 29,
+            },
+        }),
     );
 }
 
@@ -414,7 +505,11 @@ fn untagged() {
         "Value(()",
         Err(crate::error::SpannedError {
             code: crate::Error::Eof,
-            position: crate::error::Position { line: 1, col: 9 },
+            position: crate::error::Position {
+                line: 1,
+                col: 9,
+                offset: 8,
+            },
         }),
     );
 }
@@ -439,7 +534,11 @@ fn forgot_apostrophes() {
         "(4, \"Hello)",
         Err(SpannedError {
             code: Error::ExpectedStringEnd,
-            position: Position { line: 1, col: 6 },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5,
+            },
         }),
     );
 }
@@ -636,8 +735,11 @@ fn check_from_str_bytes_reader<T: serde::de::DeserializeOwned + PartialEq + std:
     let res_bytes = super::from_bytes::<T>(ron.as_bytes());
     assert_eq!(res_bytes, check);
 
-    let res_reader = super::from_reader::<&[u8], T>(ron.as_bytes());
-    assert_eq!(res_reader, check);
+    #[cfg(feature = "std")]
+    {
+        let res_reader = super::from_reader::<&[u8], T>(ron.as_bytes());
+        assert_eq!(res_reader, check);
+    }
 }
 
 #[test]
@@ -656,7 +758,42 @@ fn test_remainder() {
         42
     );
     assert_eq!(deserializer.remainder(), " 37 ");
-    assert_eq!(deserializer.end(), Err(Error::TrailingCharacters));
+    assert_eq!(
+        deserializer.end(),
+        Err(Error::TrailingCharacters {
+            found: String::from("37 ")
+        })
+    );
+}
+
+#[test]
+fn trailing_characters_snippet() {
+    assert_eq!(
+        super::from_str::<u8>("42 // comment\n  37, 38\n39"),
+        Err(SpannedError {
+            code: Error::TrailingCharacters {
+                found: String::from("37, 38")
+            },
+            position: Position {
+                line: 2,
+                col: 3,
+                offset: 16
+            },
+        })
+    );
+    assert_eq!(
+        super::from_str::<u8>("42 abcdefghijklmnopqrstuvwxyz"),
+        Err(SpannedError {
+            code: Error::TrailingCharacters {
+                found: String::from("abcdefghijklmnop")
+            },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
+        })
+    );
 }
 
 #[test]
@@ -664,15 +801,29 @@ fn boolean_struct_name() {
     check_from_str_bytes_reader::<bool>(
         "true_",
         Err(SpannedError {
-            code: Error::ExpectedBoolean,
-            position: Position { line: 1, col: 1 },
+            code: Error::InvalidValueForType {
+                expected: String::from("a boolean"),
+                found: String::from("the identifier `true_`"),
+            },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
         }),
     );
     check_from_str_bytes_reader::<bool>(
         "false_",
         Err(SpannedError {
-            code: Error::ExpectedBoolean,
-            position: Position { line: 1, col: 1 },
+            code: Error::InvalidValueForType {
+                expected: String::from("a boolean"),
+                found: String::from("the identifier `false_`"),
+            },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
         }),
     );
 }
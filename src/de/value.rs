@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use core::fmt;
 
 use serde::{
     de::{Error, MapAccess, SeqAccess, Visitor},
@@ -10,7 +11,7 @@ use crate::{
     value::{Map, Number, Value},
 };
 
-impl std::str::FromStr for Value {
+impl core::str::FromStr for Value {
     type Err = crate::error::SpannedError;
 
     /// Creates a value from a string reference.
@@ -237,7 +238,7 @@ impl<'de> Visitor<'de> for ValueVisitor {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use core::str::FromStr;
 
     use super::*;
 
@@ -291,8 +292,14 @@ mod tests {
         assert_eq!(
             Value::from_str("Foo:").unwrap_err(),
             SpannedError {
-                code: Error::TrailingCharacters,
-                position: Position { col: 4, line: 1 }
+                code: Error::TrailingCharacters {
+                    found: String::from(":"),
+                },
+                position: Position {
+                    line: 1,
+                    col: 4,
+                    offset: 3
+                }
             },
         );
     }
@@ -302,9 +309,9 @@ mod tests {
         assert_eq!(
             eval("(inf, -inf, NaN)"),
             Value::Seq(vec![
-                Value::Number(Number::new(std::f32::INFINITY)),
-                Value::Number(Number::new(std::f32::NEG_INFINITY)),
-                Value::Number(Number::new(std::f32::NAN)),
+                Value::Number(Number::new(core::f32::INFINITY)),
+                Value::Number(Number::new(core::f32::NEG_INFINITY)),
+                Value::Number(Number::new(core::f32::NAN)),
             ]),
         );
     }
@@ -418,7 +425,11 @@ mod tests {
             "(r#:42)".parse::<Value>().unwrap_err(),
             crate::error::SpannedError {
                 code: crate::Error::ExpectedString,
-                position: crate::error::Position { line: 1, col: 4 },
+                position: crate::error::Position {
+                    line: 1,
+                    col: 4,
+                    offset: 3
+                },
             },
         );
 
@@ -429,7 +440,11 @@ mod tests {
             "( /*".parse::<Value>().unwrap_err(),
             crate::error::SpannedError {
                 code: crate::Error::UnclosedBlockComment,
-                position: crate::error::Position { line: 1, col: 5 },
+                position: crate::error::Position {
+                    line: 1,
+                    col: 5,
+                    offset: 4
+                },
             },
         );
     }
@@ -1,10 +1,15 @@
-use std::{
-    error::Error as StdError,
-    fmt, io,
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt,
     str::{self, Utf8Error},
 };
+#[cfg(feature = "std")]
+use std::io;
 
-use serde::{de, ser};
+use serde::{de, de::StdError, ser};
 use unicode_ident::is_xid_continue;
 
 use crate::parse::{is_ident_first_char, is_ident_raw_char};
@@ -18,8 +23,51 @@ pub struct SpannedError {
     pub position: Position,
 }
 
-pub type Result<T, E = Error> = std::result::Result<T, E>;
-pub type SpannedResult<T> = std::result::Result<T, SpannedError>;
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+pub type SpannedResult<T> = core::result::Result<T, SpannedError>;
+
+/// A [`SpannedError`] together with the path through the deserialized
+/// value at which it occurred, e.g. `config.servers[2].port`.
+///
+/// This type is only available with the `serde_path_to_error` feature and
+/// is returned by [`Options::from_str_traced`](crate::Options::from_str_traced).
+#[cfg(feature = "serde_path_to_error")]
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TracedError {
+    pub error: SpannedError,
+    /// The dotted path to the erroneous value, or `.` for the root value
+    pub path: String,
+}
+
+/// A non-fatal warning about a lenient but discouraged construct that was
+/// accepted during deserialization, together with its position.
+///
+/// Warnings are only collected if [`Options::warnings`] is enabled, see
+/// [`Deserializer::warnings`], or with [`Options::from_str_with_warnings`].
+///
+/// [`Options::warnings`]: crate::Options::warnings
+/// [`Options::from_str_with_warnings`]: crate::Options::from_str_with_warnings
+/// [`Deserializer::warnings`]: crate::Deserializer::warnings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    pub kind: WarningKind,
+    pub position: Position,
+}
+
+/// The lenient but discouraged constructs that are reported as a [`Warning`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WarningKind {
+    /// A boolean was given by one of the
+    /// [`Options::bool_aliases`](crate::Options::bool_aliases),
+    /// e.g. `yes` instead of `true`
+    BoolAlias { alias: String, value: bool },
+    /// An enum variant was given by its index with
+    /// [`Options::numeric_enum_variants`](crate::Options::numeric_enum_variants),
+    /// e.g. `1` instead of `B`
+    NumericEnumVariant { index: u64, variant: &'static str },
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -37,6 +85,7 @@ pub enum Error {
     ExpectedArrayEnd,
     ExpectedAttribute,
     ExpectedAttributeEnd,
+    UnexpectedAttribute,
     ExpectedBoolean,
     ExpectedComma,
     ExpectedChar,
@@ -55,6 +104,7 @@ pub enum Error {
     },
     ExpectedStructLike,
     ExpectedNamedStructLike(&'static str),
+    ExpectedStructGotMap(&'static str),
     ExpectedStructLikeEnd,
     ExpectedUnit,
     ExpectedString,
@@ -71,6 +121,7 @@ pub enum Error {
     },
 
     NoSuchExtension(String),
+    DuplicateExtension(String),
 
     UnclosedBlockComment,
     UnclosedLineComment,
@@ -78,7 +129,9 @@ pub enum Error {
     UnexpectedChar(char),
 
     Utf8Error(Utf8Error),
-    TrailingCharacters,
+    TrailingCharacters {
+        found: String,
+    },
 
     InvalidValueForType {
         expected: String,
@@ -93,6 +146,22 @@ pub enum Error {
         found: String,
         outer: Option<String>,
     },
+    /// An enum variant name that is written in the
+    ///  [`Options::variant_case_conversion`](crate::Options::variant_case_conversion)
+    ///  case convention matches more than one variant.
+    AmbiguousEnumVariant {
+        found: String,
+        candidates: Vec<&'static str>,
+        outer: Option<String>,
+    },
+    /// An enum variant was given by an index, see
+    ///  [`Options::numeric_enum_variants`](crate::Options::numeric_enum_variants),
+    ///  but the enum has no variant at that index.
+    NoSuchEnumVariantIndex {
+        expected: &'static [&'static str],
+        found: u64,
+        outer: Option<String>,
+    },
     NoSuchStructField {
         expected: &'static [&'static str],
         found: String,
@@ -106,11 +175,230 @@ pub enum Error {
         field: &'static str,
         outer: Option<String>,
     },
+    /// A struct with named fields, e.g. `(a: 1)`, was given a positional
+    ///  field, e.g. `(1)`.
+    ExpectedNamedFields {
+        outer: Option<String>,
+    },
+    /// A tuple or tuple struct with positional fields, e.g. `(1)`, was given
+    ///  a named field, e.g. `(a: 1)`.
+    ExpectedPositionalFields {
+        outer: Option<String>,
+    },
     InvalidIdentifier(String),
     SuggestRawIdentifier(String),
     ExpectedRawValue,
     ExceededRecursionLimit,
     ExpectedStructName(String),
+    /// A string, byte string, or identifier is longer than the
+    ///  [`Options::max_string_length`](crate::Options::max_string_length)
+    ///  allows.
+    ExceededStringLengthLimit,
+    /// A non-finite float, e.g. `NaN`, was serialized with
+    ///  [`PrettyConfig::non_finite_floats`](crate::ser::PrettyConfig::non_finite_floats)
+    ///  set to [`NonFinitePolicy::Error`](crate::ser::NonFinitePolicy::Error).
+    NonFiniteFloat(String),
+}
+
+/// The broad category of an [`Error`], see [`Error::category`].
+///
+/// The categories allow bucketing errors, e.g. for telemetry, without
+/// matching on their messages. The category of an existing [`Error`]
+/// variant does not change, but new categories may be added.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCategory {
+    /// The input is not well-formed RON, e.g. it has an unclosed string, a
+    ///  missing comma, an invalid escape, or trailing characters.
+    Syntax,
+    /// The input is well-formed RON, but its shape does not match the type
+    ///  it is deserialized into, e.g. a map where a struct is expected, an
+    ///  unknown enum variant, or a missing struct field.
+    Schema,
+    /// The input has the right shape, but a value is invalid, e.g. an
+    ///  integer that is out of bounds or a string that is not valid UTF-8.
+    ///  Custom errors of [`serde::de::Error::custom`] and
+    ///  [`serde::ser::Error::custom`] also belong to this category.
+    Data,
+    /// The input ended unexpectedly.
+    Eof,
+    /// The input, or the value to serialize, is nested more deeply than the
+    ///  [`Options::depth_limit`](crate::Options::depth_limit) allows.
+    Recursion,
+    /// Reading the input or writing the output failed.
+    Io,
+}
+
+impl Error {
+    /// Returns the broad [`ErrorCategory`] of this error.
+    ///
+    /// ```
+    /// use ron::error::ErrorCategory;
+    ///
+    /// let err = ron::from_str::<Vec<u8>>("[1 2]").unwrap_err();
+    /// assert_eq!(err.code.category(), ErrorCategory::Syntax);
+    ///
+    /// let err = ron::from_str::<Vec<u8>>("(a: 1)").unwrap_err();
+    /// assert_eq!(err.code.category(), ErrorCategory::Schema);
+    ///
+    /// let err = ron::from_str::<Vec<u8>>("[1, 256]").unwrap_err();
+    /// assert_eq!(err.code.category(), ErrorCategory::Data);
+    /// ```
+    #[must_use]
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Error::ExpectedArrayEnd
+            | Error::ExpectedAttribute
+            | Error::ExpectedAttributeEnd
+            | Error::UnexpectedAttribute
+            | Error::ExpectedComma
+            | Error::ExpectedOptionEnd
+            | Error::ExpectedMapColon
+            | Error::ExpectedMapEnd
+            | Error::ExpectedStructLikeEnd
+            | Error::ExpectedStringEnd
+            | Error::FloatUnderscore
+            | Error::InvalidEscape(_)
+            | Error::InvalidIntegerDigit { .. }
+            | Error::NoSuchExtension(_)
+            | Error::DuplicateExtension(_)
+            | Error::UnclosedBlockComment
+            | Error::UnclosedLineComment
+            | Error::UnderscoreAtBeginning
+            | Error::UnexpectedChar(_)
+            | Error::TrailingCharacters { .. }
+            | Error::SuggestRawIdentifier(_) => ErrorCategory::Syntax,
+            Error::ExpectedArray
+            | Error::ExpectedBoolean
+            | Error::ExpectedChar
+            | Error::ExpectedByteLiteral
+            | Error::ExpectedFloat
+            | Error::ExpectedInteger
+            | Error::ExpectedOption
+            | Error::ExpectedMap
+            | Error::ExpectedDifferentStructName { .. }
+            | Error::ExpectedStructLike
+            | Error::ExpectedNamedStructLike(_)
+            | Error::ExpectedStructGotMap(_)
+            | Error::ExpectedUnit
+            | Error::ExpectedString
+            | Error::ExpectedByteString
+            | Error::ExpectedIdentifier
+            | Error::ExpectedDifferentLength { .. }
+            | Error::NoSuchEnumVariant { .. }
+            | Error::NoSuchEnumVariantIndex { .. }
+            | Error::AmbiguousEnumVariant { .. }
+            | Error::NoSuchStructField { .. }
+            | Error::MissingStructField { .. }
+            | Error::DuplicateStructField { .. }
+            | Error::ExpectedNamedFields { .. }
+            | Error::ExpectedPositionalFields { .. }
+            | Error::ExpectedRawValue
+            | Error::ExpectedStructName(_) => ErrorCategory::Schema,
+            #[allow(deprecated)]
+            Error::Message(_)
+            | Error::Base64Error(_)
+            | Error::IntegerOutOfBounds
+            | Error::Utf8Error(_)
+            | Error::InvalidValueForType { .. }
+            | Error::InvalidIdentifier(_)
+            | Error::ExceededStringLengthLimit
+            | Error::NonFiniteFloat(_) => ErrorCategory::Data,
+            Error::Eof => ErrorCategory::Eof,
+            Error::ExceededRecursionLimit => ErrorCategory::Recursion,
+            Error::Fmt | Error::Io(_) => ErrorCategory::Io,
+        }
+    }
+
+    /// Returns `true` if the error itself says that the input ended
+    /// unexpectedly, i.e. for an [`Error::Eof`], e.g. for `[1, ` or `(a: `,
+    /// an unterminated string ([`Error::ExpectedStringEnd`]), or an
+    /// unclosed block comment ([`Error::UnclosedBlockComment`]).
+    ///
+    /// An input that ends right after a complete token, e.g. `[1, 2` or
+    /// `'a`, instead reports the token that is missing, e.g. an
+    /// [`Error::ExpectedComma`], at the end of the input. Use
+    /// [`SpannedError::is_eof`] to also detect these truncated inputs.
+    ///
+    /// ```
+    /// let err = ron::from_str::<Vec<u8>>("[1, ").unwrap_err();
+    /// assert!(err.code.is_eof());
+    ///
+    /// let err = ron::from_str::<Vec<u8>>("[1 2]").unwrap_err();
+    /// assert!(!err.code.is_eof());
+    /// ```
+    #[must_use]
+    pub fn is_eof(&self) -> bool {
+        matches!(
+            self,
+            Error::Eof | Error::ExpectedStringEnd | Error::UnclosedBlockComment
+        )
+    }
+}
+
+impl SpannedError {
+    /// Returns `true` if deserializing `src` failed because the input
+    /// ended unexpectedly, e.g. because it is read incrementally and has
+    /// been truncated in the middle of a value, such that it may become
+    /// valid once more input is available.
+    ///
+    /// This is the case if [`Error::is_eof`] is `true`, or if a missing
+    /// token was reported at the end of `src`, e.g. a comma after `[1, 2`,
+    /// the rest of a number after `-`, `0x`, or `1e`, or the closing quote
+    /// after `'a`. Errors that are not about the syntax, e.g. an
+    /// [`Error::MissingStructField`] after a complete struct, are never
+    /// caused by truncated input.
+    ///
+    /// ```
+    /// let src = "[1, 2";
+    /// let err = ron::from_str::<Vec<u8>>(src).unwrap_err();
+    /// assert!(err.is_eof(src));
+    ///
+    /// let src = "[1 2]";
+    /// let err = ron::from_str::<Vec<u8>>(src).unwrap_err();
+    /// assert!(!err.is_eof(src));
+    /// ```
+    #[must_use]
+    pub fn is_eof(&self, src: &str) -> bool {
+        if self.code.is_eof() {
+            return true;
+        }
+
+        // a number that was cut off in its exponent, e.g. `1e`, is reported
+        //  at its start or at the `e`
+        if matches!(
+            self.code,
+            Error::ExpectedFloat | Error::ExpectedInteger | Error::InvalidIntegerDigit { .. }
+        ) && crate::parse::ends_with_truncated_exponent(src)
+            && src[self.position.offset.min(src.len())..]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '-'))
+        {
+            return true;
+        }
+
+        let at_end = self.position.offset >= src.len();
+
+        at_end
+            && match self.code {
+                Error::ExpectedArray
+                | Error::ExpectedBoolean
+                | Error::ExpectedChar
+                | Error::ExpectedByteLiteral
+                | Error::ExpectedFloat
+                | Error::ExpectedInteger
+                | Error::ExpectedOption
+                | Error::ExpectedMap
+                | Error::ExpectedStructLike
+                | Error::ExpectedNamedStructLike(_)
+                | Error::ExpectedUnit
+                | Error::ExpectedString
+                | Error::ExpectedByteString
+                | Error::ExpectedIdentifier
+                | Error::ExpectedStructName(_) => true,
+                ref code => code.category() == ErrorCategory::Syntax,
+            }
+    }
 }
 
 impl fmt::Display for SpannedError {
@@ -119,6 +407,40 @@ impl fmt::Display for SpannedError {
     }
 }
 
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.position, self.kind)
+    }
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            WarningKind::BoolAlias { ref alias, value } => write!(
+                f,
+                "The boolean alias `{alias}` is discouraged, write `{value}` instead"
+            ),
+            WarningKind::NumericEnumVariant { index, variant } => write!(
+                f,
+                "The enum variant index `{}` is discouraged, write {} instead",
+                index,
+                Identifier(variant)
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde_path_to_error")]
+impl fmt::Display for TracedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at `{}`",
+            self.error.position, self.error.code, self.path
+        )
+    }
+}
+
 impl fmt::Display for Error {
     #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -134,6 +456,9 @@ impl fmt::Display for Error {
             Error::ExpectedAttributeEnd => {
                 f.write_str("Expected closing `)]` after the enable attribute")
             }
+            Error::UnexpectedAttribute => f.write_str(
+                "Unexpected `#![...]` attribute while the extension header scan is disabled",
+            ),
             Error::ExpectedBoolean => f.write_str("Expected boolean"),
             Error::ExpectedComma => f.write_str("Expected comma"),
             Error::ExpectedChar => f.write_str("Expected char"),
@@ -165,6 +490,18 @@ impl fmt::Display for Error {
                     write!(f, "Expected opening `(` for struct {}", Identifier(name))
                 }
             }
+            Error::ExpectedStructGotMap(name) => {
+                f.write_str("Expected opening `(`")?;
+
+                if !name.is_empty() {
+                    write!(f, " for struct {}", Identifier(name))?;
+                }
+
+                f.write_str(
+                    " but found a map with string keys, which looks like a JSON object: \
+                    RON structs are written as `(field: value)` with unquoted field names",
+                )
+            }
             Error::ExpectedUnit => f.write_str("Expected unit"),
             Error::ExpectedString => f.write_str("Expected string"),
             Error::ExpectedByteString => f.write_str("Expected byte string"),
@@ -178,6 +515,9 @@ impl fmt::Display for Error {
             Error::NoSuchExtension(ref name) => {
                 write!(f, "No RON extension named {}", Identifier(name))
             }
+            Error::DuplicateExtension(ref name) => {
+                write!(f, "Duplicate RON extension named {}", Identifier(name))
+            }
             Error::Utf8Error(ref e) => fmt::Display::fmt(e, f),
             Error::UnclosedBlockComment => f.write_str("Unclosed block comment"),
             Error::UnclosedLineComment => f.write_str(
@@ -188,7 +528,10 @@ impl fmt::Display for Error {
                 f.write_str("Unexpected leading underscore in a number")
             }
             Error::UnexpectedChar(c) => write!(f, "Unexpected char {:?}", c),
-            Error::TrailingCharacters => f.write_str("Non-whitespace trailing characters"),
+            Error::TrailingCharacters { ref found } => write!(
+                f,
+                "Unexpected trailing characters {found:?} after value; expected end of input"
+            ),
             Error::InvalidValueForType {
                 ref expected,
                 ref found,
@@ -235,6 +578,55 @@ impl fmt::Display for Error {
                     }
                 )
             }
+            Error::AmbiguousEnumVariant {
+                ref found,
+                ref candidates,
+                ref outer,
+            } => {
+                write!(f, "Ambiguous enum variant named {}", Identifier(found))?;
+
+                if let Some(outer) = outer {
+                    write!(f, " in enum {}", Identifier(outer))?;
+                }
+
+                f.write_str(", which matches ")?;
+
+                for (i, candidate) in candidates.iter().enumerate() {
+                    match i {
+                        0 => {}
+                        i if i + 1 == candidates.len() && i == 1 => f.write_str(" and ")?,
+                        i if i + 1 == candidates.len() => f.write_str(", and ")?,
+                        _ => f.write_str(", ")?,
+                    }
+
+                    write!(f, "{}", Identifier(candidate))?;
+                }
+
+                Ok(())
+            }
+            Error::NoSuchEnumVariantIndex {
+                expected,
+                found,
+                ref outer,
+            } => {
+                f.write_str("Unexpected ")?;
+
+                if outer.is_none() {
+                    f.write_str("enum ")?;
+                }
+
+                write!(f, "variant index {found}")?;
+
+                if let Some(outer) = outer {
+                    write!(f, " in enum {}", Identifier(outer))?;
+                }
+
+                match expected.len() {
+                    0 => f.write_str(", there are no variants"),
+                    1 => f.write_str(", expected the index 0 instead"),
+                    n => write!(f, ", expected an index from 0 to {} instead", n - 1),
+                }
+            }
             Error::NoSuchStructField {
                 expected,
                 ref found,
@@ -271,6 +663,24 @@ impl fmt::Display for Error {
                     None => Ok(()),
                 }
             }
+            Error::ExpectedNamedFields { ref outer } => {
+                f.write_str("Expected named fields")?;
+
+                if let Some(outer) = outer {
+                    write!(f, " in {}", Identifier(outer))?;
+                }
+
+                f.write_str(", e.g. `(a: 1)`, but found a positional field instead")
+            }
+            Error::ExpectedPositionalFields { ref outer } => {
+                f.write_str("Expected positional fields")?;
+
+                if let Some(outer) = outer {
+                    write!(f, " in {}", Identifier(outer))?;
+                }
+
+                f.write_str(", e.g. `(1)`, but found a named field instead")
+            }
             Error::InvalidIdentifier(ref invalid) => write!(f, "Invalid identifier {:?}", invalid),
             Error::SuggestRawIdentifier(ref identifier) => write!(
                 f,
@@ -279,14 +689,22 @@ impl fmt::Display for Error {
             ),
             Error::ExpectedRawValue => f.write_str("Expected a `ron::value::RawValue`"),
             Error::ExceededRecursionLimit => f.write_str(
-                "Exceeded recursion limit, try increasing `ron::Options::recursion_limit` \
+                "Exceeded recursion limit, try increasing `ron::Options::depth_limit` \
                 and using `serde_stacker` to protect against a stack overflow",
             ),
+            Error::ExceededStringLengthLimit => f.write_str(
+                "Exceeded string length limit, try increasing `ron::Options::max_string_length`",
+            ),
             Error::ExpectedStructName(ref name) => write!(
                 f,
                 "Expected the explicit struct name {}, but none was found",
                 Identifier(name)
             ),
+            Error::NonFiniteFloat(ref float) => write!(
+                f,
+                "Refused to serialize the non-finite float `{float}`, \
+                see `ron::ser::PrettyConfig::non_finite_floats`"
+            ),
         }
     }
 }
@@ -295,14 +713,37 @@ impl fmt::Display for Error {
 pub struct Position {
     pub line: usize,
     pub col: usize,
+    /// The byte offset into the source string
+    pub offset: usize,
 }
 
 impl Position {
+    /// Computes the [`Position`] of the byte `offset` in `src`.
+    ///
+    /// An `offset` past the end of `src` is clamped to its length, and an
+    /// `offset` inside a multi-byte character is moved back to the start
+    /// of that character.
+    #[must_use]
+    pub fn from_offset(src: &str, offset: usize) -> Position {
+        let mut offset = offset.min(src.len());
+
+        while !src.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        Self::from_src_end(&src[..offset])
+    }
+
     pub(crate) fn from_src_end(src: &str) -> Position {
+        let offset = src.len();
+
+        // A leading byte order mark is not counted as a column
+        let src = src.strip_prefix('\u{FEFF}').unwrap_or(src);
+
         let line = 1 + src.chars().filter(|&c| c == '\n').count();
         let col = 1 + src.chars().rev().take_while(|&c| c != '\n').count();
 
-        Self { line, col }
+        Self { line, col, offset }
     }
 }
 
@@ -346,7 +787,7 @@ impl de::Error for Error {
                     de::Unexpected::Str(s) => write!(f, "the string {:?}", s),
                     de::Unexpected::Bytes(b) => write!(f, "the byte string b\"{}\"", {
                         b.iter()
-                            .flat_map(|c| std::ascii::escape_default(*c))
+                            .flat_map(|c| core::ascii::escape_default(*c))
                             .map(char::from)
                             .collect::<String>()
                     }),
@@ -408,8 +849,39 @@ impl de::Error for Error {
     }
 }
 
-impl StdError for SpannedError {}
-impl StdError for Error {}
+impl StdError for SpannedError {
+    /// Returns the [`Error::source`] of the [`code`](SpannedError::code),
+    /// whose message is already part of this error's message.
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.code.source()
+    }
+}
+
+#[cfg(feature = "serde_path_to_error")]
+impl StdError for TracedError {
+    /// Returns the [`Error::source`] of the [`error`](TracedError::error),
+    /// whose message is already part of this error's message.
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.error.source()
+    }
+}
+
+impl StdError for Error {
+    /// Returns the underlying [`Utf8Error`] or [`fmt::Error`] with the `std`
+    /// feature, i.e. when they are known to implement [`StdError`].
+    ///
+    /// Since [`Error`] is [`Clone`] and [`Eq`], an [`Error::Io`] only stores
+    /// the message of the original [`std::io::Error`], which is thus not
+    /// available as its source.
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Utf8Error(e) => Some(e),
+            Error::Fmt => Some(&fmt::Error),
+            _ => None,
+        }
+    }
+}
 
 impl From<Utf8Error> for Error {
     fn from(e: Utf8Error) -> Self {
@@ -423,6 +895,7 @@ impl From<fmt::Error> for Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self {
         Error::Io(e.to_string())
@@ -435,6 +908,13 @@ impl From<SpannedError> for Error {
     }
 }
 
+#[cfg(feature = "serde_path_to_error")]
+impl From<TracedError> for SpannedError {
+    fn from(e: TracedError) -> Self {
+        e.error
+    }
+}
+
 struct OneOf {
     alts: &'static [&'static str],
     none: &'static str,
@@ -490,7 +970,8 @@ mod tests {
 
     #[test]
     fn error_messages() {
-        check_error_message(&Error::from(std::fmt::Error), "Formatting RON failed");
+        check_error_message(&Error::from(core::fmt::Error), "Formatting RON failed");
+        #[cfg(feature = "std")]
         check_error_message(
             &Error::from(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
@@ -516,6 +997,10 @@ mod tests {
             &Error::ExpectedAttributeEnd,
             "Expected closing `)]` after the enable attribute",
         );
+        check_error_message(
+            &Error::UnexpectedAttribute,
+            "Unexpected `#![...]` attribute while the extension header scan is disabled",
+        );
         check_error_message(&Error::ExpectedBoolean, "Expected boolean");
         check_error_message(&Error::ExpectedComma, "Expected comma");
         check_error_message(&Error::ExpectedChar, "Expected char");
@@ -545,6 +1030,17 @@ mod tests {
             &Error::ExpectedNamedStructLike("_ident"),
             "Expected opening `(` for struct `_ident`",
         );
+        check_error_message(
+            &Error::ExpectedStructGotMap(""),
+            "Expected opening `(` but found a map with string keys, which looks like a \
+            JSON object: RON structs are written as `(field: value)` with unquoted field names",
+        );
+        check_error_message(
+            &Error::ExpectedStructGotMap("_ident"),
+            "Expected opening `(` for struct `_ident` but found a map with string keys, \
+            which looks like a JSON object: RON structs are written as `(field: value)` \
+            with unquoted field names",
+        );
         check_error_message(&Error::ExpectedUnit, "Expected unit");
         check_error_message(&Error::ExpectedString, "Expected string");
         check_error_message(&Error::ExpectedByteString, "Expected byte string");
@@ -563,6 +1059,10 @@ mod tests {
             &Error::NoSuchExtension(String::from("unknown")),
             "No RON extension named `unknown`",
         );
+        check_error_message(
+            &Error::DuplicateExtension(String::from("implicit_some")),
+            "Duplicate RON extension named `implicit_some`",
+        );
         check_error_message(&Error::UnclosedBlockComment, "Unclosed block comment");
         check_error_message(
             &Error::UnclosedLineComment,
@@ -576,12 +1076,14 @@ mod tests {
         check_error_message(&Error::UnexpectedChar('🦀'), "Unexpected char \'🦀\'");
         #[allow(invalid_from_utf8)]
         check_error_message(
-            &Error::Utf8Error(std::str::from_utf8(b"error: \xff\xff\xff\xff").unwrap_err()),
+            &Error::Utf8Error(core::str::from_utf8(b"error: \xff\xff\xff\xff").unwrap_err()),
             "invalid utf-8 sequence of 1 bytes from index 7",
         );
         check_error_message(
-            &Error::TrailingCharacters,
-            "Non-whitespace trailing characters",
+            &Error::TrailingCharacters {
+                found: String::from("4 5"),
+            },
+            "Unexpected trailing characters \"4 5\" after value; expected end of input",
         );
         check_error_message(
             &Error::invalid_value(Unexpected::Enum, &"struct `Hi`"),
@@ -612,6 +1114,31 @@ mod tests {
             "Unexpected variant named `D` in enum `E`, \
             expected either `A` or `r#B+C` instead",
         );
+        check_error_message(
+            &Error::NoSuchEnumVariantIndex {
+                expected: &["A", "B", "C"],
+                found: 3,
+                outer: Some(String::from("E")),
+            },
+            "Unexpected variant index 3 in enum `E`, \
+            expected an index from 0 to 2 instead",
+        );
+        check_error_message(
+            &Error::NoSuchEnumVariantIndex {
+                expected: &["A"],
+                found: 1,
+                outer: None,
+            },
+            "Unexpected enum variant index 1, expected the index 0 instead",
+        );
+        check_error_message(
+            &Error::NoSuchEnumVariantIndex {
+                expected: &[],
+                found: 0,
+                outer: None,
+            },
+            "Unexpected enum variant index 0, there are no variants",
+        );
         check_error_message(
             &Error::unknown_field("unknown", &[]),
             "Unexpected field named `unknown`, there are no fields",
@@ -655,6 +1182,26 @@ mod tests {
             },
             "Unexpected duplicate field named `r#b+c` in `r#S+T`",
         );
+        check_error_message(
+            &Error::ExpectedNamedFields {
+                outer: Some(String::from("S")),
+            },
+            "Expected named fields in `S`, e.g. `(a: 1)`, but found a positional field instead",
+        );
+        check_error_message(
+            &Error::ExpectedNamedFields { outer: None },
+            "Expected named fields, e.g. `(a: 1)`, but found a positional field instead",
+        );
+        check_error_message(
+            &Error::ExpectedPositionalFields {
+                outer: Some(String::from("T")),
+            },
+            "Expected positional fields in `T`, e.g. `(1)`, but found a named field instead",
+        );
+        check_error_message(
+            &Error::ExpectedPositionalFields { outer: None },
+            "Expected positional fields, e.g. `(1)`, but found a named field instead",
+        );
         check_error_message(
             &Error::InvalidIdentifier(String::from("why+🦀+not")),
             "Invalid identifier \"why+🦀+not\"",
@@ -670,34 +1217,297 @@ mod tests {
         );
         check_error_message(
             &Error::ExceededRecursionLimit,
-            "Exceeded recursion limit, try increasing `ron::Options::recursion_limit` \
+            "Exceeded recursion limit, try increasing `ron::Options::depth_limit` \
             and using `serde_stacker` to protect against a stack overflow",
         );
         check_error_message(
             &Error::ExpectedStructName(String::from("Struct")),
             "Expected the explicit struct name `Struct`, but none was found",
         );
+        check_error_message(
+            &Error::ExceededStringLengthLimit,
+            "Exceeded string length limit, try increasing `ron::Options::max_string_length`",
+        );
+        check_error_message(
+            &Error::AmbiguousEnumVariant {
+                found: String::from("http_error"),
+                candidates: vec!["HttpError", "HTTPError"],
+                outer: None,
+            },
+            "Ambiguous enum variant named `http_error`, which matches `HttpError` and `HTTPError`",
+        );
+        check_error_message(
+            &Error::AmbiguousEnumVariant {
+                found: String::from("a"),
+                candidates: vec!["A", "a", "_A"],
+                outer: Some(String::from("E")),
+            },
+            "Ambiguous enum variant named `a` in enum `E`, which matches `A`, `a`, and `_A`",
+        );
+        check_error_message(
+            &Error::NonFiniteFloat(String::from("-inf")),
+            "Refused to serialize the non-finite float `-inf`, \
+            see `ron::ser::PrettyConfig::non_finite_floats`",
+        );
+    }
+
+    #[test]
+    fn position_from_offset() {
+        let src = "(\n  a: 'ä',\n)";
+
+        assert_eq!(
+            Position::from_offset(src, 0),
+            Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
+        );
+        assert_eq!(
+            Position::from_offset(src, 5),
+            Position {
+                line: 2,
+                col: 4,
+                offset: 5
+            }
+        );
+        // offsets inside a multi-byte character are moved to its start
+        assert_eq!(
+            Position::from_offset(src, 9),
+            Position {
+                line: 2,
+                col: 7,
+                offset: 8
+            }
+        );
+        // offsets past the end are clamped
+        assert_eq!(
+            Position::from_offset(src, 100),
+            Position {
+                line: 3,
+                col: 2,
+                offset: 14
+            }
+        );
+        assert_eq!(format!("{}", Position::from_offset(src, 5)), "2:4");
     }
 
-    fn check_error_message<T: std::fmt::Display>(err: &T, msg: &str) {
+    fn check_error_message<T: core::fmt::Display>(err: &T, msg: &str) {
         assert_eq!(format!("{}", err), msg);
     }
 
+    #[test]
+    fn error_categories() {
+        use super::ErrorCategory::{Data, Eof, Io, Recursion, Schema, Syntax};
+
+        // `Error::category` has no catch-all arm, so every new variant must
+        //  be categorized there and should be listed here
+        #[allow(deprecated)]
+        let categories = [
+            (Error::Fmt, Io),
+            (Error::Io(String::from("disk full")), Io),
+            (Error::Message(String::from("custom")), Data),
+            (
+                Error::Base64Error(base64::DecodeError::InvalidPadding),
+                Data,
+            ),
+            (Error::Eof, Eof),
+            (Error::ExpectedArray, Schema),
+            (Error::ExpectedArrayEnd, Syntax),
+            (Error::ExpectedAttribute, Syntax),
+            (Error::ExpectedAttributeEnd, Syntax),
+            (Error::UnexpectedAttribute, Syntax),
+            (Error::ExpectedBoolean, Schema),
+            (Error::ExpectedComma, Syntax),
+            (Error::ExpectedChar, Schema),
+            (Error::ExpectedByteLiteral, Schema),
+            (Error::ExpectedFloat, Schema),
+            (Error::FloatUnderscore, Syntax),
+            (Error::ExpectedInteger, Schema),
+            (Error::ExpectedOption, Schema),
+            (Error::ExpectedOptionEnd, Syntax),
+            (Error::ExpectedMap, Schema),
+            (Error::ExpectedMapColon, Syntax),
+            (Error::ExpectedMapEnd, Syntax),
+            (
+                Error::ExpectedDifferentStructName {
+                    expected: "A",
+                    found: String::from("B"),
+                },
+                Schema,
+            ),
+            (Error::ExpectedStructLike, Schema),
+            (Error::ExpectedNamedStructLike("A"), Schema),
+            (Error::ExpectedStructGotMap("A"), Schema),
+            (Error::ExpectedStructLikeEnd, Syntax),
+            (Error::ExpectedUnit, Schema),
+            (Error::ExpectedString, Schema),
+            (Error::ExpectedByteString, Schema),
+            (Error::ExpectedStringEnd, Syntax),
+            (Error::ExpectedIdentifier, Schema),
+            (Error::InvalidEscape("Unknown escape character"), Syntax),
+            (Error::IntegerOutOfBounds, Data),
+            (
+                Error::InvalidIntegerDigit {
+                    digit: '2',
+                    base: 2,
+                },
+                Syntax,
+            ),
+            (Error::NoSuchExtension(String::from("a")), Syntax),
+            (Error::DuplicateExtension(String::from("a")), Syntax),
+            (Error::UnclosedBlockComment, Syntax),
+            (Error::UnclosedLineComment, Syntax),
+            (Error::UnderscoreAtBeginning, Syntax),
+            (Error::UnexpectedChar('@'), Syntax),
+            (
+                Error::Utf8Error(String::from_utf8(vec![0xff]).unwrap_err().utf8_error()),
+                Data,
+            ),
+            (
+                Error::TrailingCharacters {
+                    found: String::from("a"),
+                },
+                Syntax,
+            ),
+            (
+                Error::InvalidValueForType {
+                    expected: String::from("u8"),
+                    found: String::from("the string \"a\""),
+                },
+                Data,
+            ),
+            (
+                Error::ExpectedDifferentLength {
+                    expected: String::from("a tuple of length 2"),
+                    found: 3,
+                },
+                Schema,
+            ),
+            (
+                Error::NoSuchEnumVariant {
+                    expected: &["A"],
+                    found: String::from("B"),
+                    outer: None,
+                },
+                Schema,
+            ),
+            (
+                Error::NoSuchEnumVariantIndex {
+                    expected: &["A"],
+                    found: 1,
+                    outer: None,
+                },
+                Schema,
+            ),
+            (
+                Error::NoSuchStructField {
+                    expected: &["a"],
+                    found: String::from("b"),
+                    outer: None,
+                },
+                Schema,
+            ),
+            (
+                Error::MissingStructField {
+                    field: "a",
+                    outer: None,
+                },
+                Schema,
+            ),
+            (
+                Error::DuplicateStructField {
+                    field: "a",
+                    outer: None,
+                },
+                Schema,
+            ),
+            (Error::ExpectedNamedFields { outer: None }, Schema),
+            (Error::ExpectedPositionalFields { outer: None }, Schema),
+            (Error::InvalidIdentifier(String::from("a b")), Data),
+            (Error::SuggestRawIdentifier(String::from("a-b")), Syntax),
+            (Error::ExpectedRawValue, Schema),
+            (Error::ExceededRecursionLimit, Recursion),
+            (Error::ExpectedStructName(String::from("A")), Schema),
+            (Error::ExceededStringLengthLimit, Data),
+            (Error::NonFiniteFloat(String::from("NaN")), Data),
+            (
+                Error::AmbiguousEnumVariant {
+                    found: String::from("a"),
+                    candidates: vec!["A", "_A"],
+                    outer: None,
+                },
+                Schema,
+            ),
+        ];
+
+        for (error, category) in categories {
+            assert_eq!(error.category(), category, "{:?}", error);
+        }
+    }
+
     #[test]
     fn spanned_error_into_code() {
         assert_eq!(
             Error::from(SpannedError {
                 code: Error::Eof,
-                position: Position { line: 1, col: 1 }
+                position: Position {
+                    line: 1,
+                    col: 1,
+                    offset: 0
+                }
             }),
             Error::Eof
         );
         assert_eq!(
             Error::from(SpannedError {
                 code: Error::ExpectedRawValue,
-                position: Position { line: 1, col: 1 }
+                position: Position {
+                    line: 1,
+                    col: 1,
+                    offset: 0
+                }
             }),
             Error::ExpectedRawValue
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn error_sources() {
+        use std::error::Error as StdError;
+
+        let utf8_err = String::from_utf8(vec![0xff]).unwrap_err().utf8_error();
+        let err = Error::Utf8Error(utf8_err);
+        assert_eq!(
+            err.source()
+                .and_then(|source| source.downcast_ref::<core::str::Utf8Error>()),
+            Some(&utf8_err)
+        );
+
+        assert!(Error::Fmt
+            .source()
+            .map_or(false, |source| source.is::<core::fmt::Error>()));
+        assert!(Error::Io(String::from("disk full")).source().is_none());
+        assert!(Error::Eof.source().is_none());
+
+        // A spanned error exposes the source of its code, not the code itself
+        let err = crate::Options::default()
+            .from_reader::<_, String>(&b"\"\xff\""[..])
+            .unwrap_err();
+        assert!(matches!(err.code, Error::Utf8Error(_)));
+        assert!(err
+            .source()
+            .map_or(false, |source| source.is::<core::str::Utf8Error>()));
+
+        let err = SpannedError {
+            code: Error::Eof,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        };
+        assert!(err.source().is_none());
+    }
 }
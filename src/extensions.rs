@@ -2,6 +2,37 @@ use serde_derive::{Deserialize, Serialize};
 
 // GRCOV_EXCL_START
 bitflags::bitflags! {
+    /// The set of RON extensions that are enabled, e.g. by an
+    /// `#![enable(implicit_some)]` attribute at the start of a document.
+    ///
+    /// Besides the usual bitset operations, the flags can be listed and
+    /// looked up by name at runtime, e.g. to build command-line flags:
+    /// [`Extensions::iter_names`] yields the name and flag of every set
+    /// extension, while [`Extensions::from_name`] looks up a flag by its
+    /// name, e.g. `IMPLICIT_SOME`, and [`Extensions::from_ident`] by its
+    /// lowercase name in an `#![enable(...)]` attribute, e.g. `implicit_some`.
+    ///
+    /// ```
+    /// use ron::extensions::Extensions;
+    ///
+    /// let extensions = Extensions::IMPLICIT_SOME | Extensions::ARROW_MAPS;
+    ///
+    /// assert_eq!(
+    ///     extensions.iter_names().collect::<Vec<_>>(),
+    ///     [
+    ///         ("IMPLICIT_SOME", Extensions::IMPLICIT_SOME),
+    ///         ("ARROW_MAPS", Extensions::ARROW_MAPS),
+    ///     ],
+    /// );
+    /// assert_eq!(
+    ///     Extensions::from_name("UNWRAP_NEWTYPES"),
+    ///     Some(Extensions::UNWRAP_NEWTYPES),
+    /// );
+    /// assert_eq!(
+    ///     Extensions::from_ident("unwrap_newtypes"),
+    ///     Some(Extensions::UNWRAP_NEWTYPES),
+    /// );
+    /// ```
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
     pub struct Extensions: usize {
         const UNWRAP_NEWTYPES = 0x1;
@@ -11,12 +42,24 @@ bitflags::bitflags! {
         ///
         /// During deserialization, this extension requires that structs' names are stated explicitly.
         const EXPLICIT_STRUCT_NAMES = 0x8;
+        /// During deserialization, this extension accepts `=>` interchangeably with `:` to separate the keys and values of maps, e.g. `{ "a" => 1, "b": 2 }`. Struct fields must still be separated from their values by `:`.
+        ///
+        /// During serialization, maps always use `:`.
+        const ARROW_MAPS = 0x10;
+        /// During deserialization, this extension accepts `=` interchangeably with `:` to separate struct fields from their values, e.g. `(a = 1, b: 2)`. Only a single `=` is accepted, i.e. not `=>` or `==`, and maps must still separate their keys and values by `:`.
+        ///
+        /// During serialization, struct fields always use `:`.
+        const EQUALS_FIELDS = 0x20;
     }
 }
 // GRCOV_EXCL_STOP
 
 impl Extensions {
-    /// Creates an extension flag from an ident.
+    /// Creates an extension flag from an ident, i.e. its lowercase name in
+    /// an `#![enable(...)]` attribute, e.g. `implicit_some`.
+    ///
+    /// See [`Extensions::from_name`] to look up a flag by its name, e.g.
+    /// `IMPLICIT_SOME`, instead.
     #[must_use]
     pub fn from_ident(ident: &str) -> Option<Extensions> {
         for (name, extension) in Extensions::all().iter_names() {
@@ -55,4 +98,35 @@ mod tests {
             roundtrip_extensions(extensions);
         }
     }
+
+    #[test]
+    fn test_extension_names() {
+        let mut all = Extensions::empty();
+
+        for (name, extension) in Extensions::all().iter_names() {
+            assert_eq!(extension.bits().count_ones(), 1, "{}", name);
+            assert_eq!(Extensions::from_name(name), Some(extension));
+            assert_eq!(
+                extension.iter_names().collect::<Vec<_>>(),
+                [(name, extension)]
+            );
+
+            let ident = name.to_lowercase();
+            assert_eq!(Extensions::from_ident(&ident), Some(extension));
+            assert_eq!(Extensions::from_name(&ident), None);
+            assert_eq!(Extensions::from_ident(name), None);
+
+            // the ident enables the extension in the header of a document
+            let ron = alloc::format!("#![enable({ident})] ()");
+            let mut deserializer = crate::Deserializer::from_str(&ron).unwrap();
+            <() as serde::Deserialize>::deserialize(&mut deserializer).unwrap();
+            assert_eq!(deserializer.extensions(), extension);
+
+            all |= extension;
+        }
+
+        assert_eq!(all, Extensions::all());
+        assert_eq!(Extensions::from_name("NOT_AN_EXTENSION"), None);
+        assert_eq!(Extensions::from_ident("not_an_extension"), None);
+    }
 }
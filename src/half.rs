@@ -0,0 +1,102 @@
+//! Serialize and deserialize half-precision [`f16`] floats as RON floats.
+//!
+//! This module is only available with the `half` feature and is meant
+//! to be used with `#[serde(with = "ron::half")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use half::f16;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Layer {
+//!     #[serde(with = "ron::half")]
+//!     scale: f16,
+//! }
+//!
+//! let layer: Layer = ron::from_str("(scale: 0.1)").unwrap();
+//! assert_eq!(layer.scale, f16::from_f32(0.1));
+//!
+//! assert_eq!(ron::to_string(&layer).unwrap(), "(scale:0.1)");
+//! ```
+//!
+//! An [`f16`] is serialized as an [`f32`], which represents every [`f16`]
+//! exactly, using the shortest decimal representation that still
+//! deserializes to the same [`f16`], e.g. `0.1` instead of `0.099975586`.
+//! Since RON has no `f16` type suffix, the float is written with an `f32`
+//! suffix if [`PrettyConfig::number_suffixes`] asks for one.
+//!
+//! When deserializing, the float is first parsed as an [`f32`] and then
+//! narrowed to the nearest [`f16`], with ties rounding to even. Values that
+//! are too large for an [`f16`] become infinite, while values that are too
+//! small become subnormal or zero. Since the value is rounded twice, a
+//! decimal that lies extremely close to the midpoint between two [`f16`]s
+//! may round to the other [`f16`] than a direct conversion would.
+//!
+//! [`PrettyConfig::number_suffixes`]: crate::ser::PrettyConfig::number_suffixes
+
+use alloc::format;
+use core::fmt;
+
+use ::half::f16;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes an [`f16`] as a float, e.g. `0.1`.
+pub fn serialize<S: Serializer>(value: &f16, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f32(shortest_f32(*value))
+}
+
+/// Deserializes an [`f16`] from a float, e.g. `0.1`, by narrowing it.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f16, D::Error> {
+    deserializer.deserialize_f32(F16Visitor)
+}
+
+/// Returns the [`f32`] with the shortest decimal representation that
+/// narrows back to `value`.
+fn shortest_f32(value: f16) -> f32 {
+    let exact = value.to_f32();
+
+    if !exact.is_finite() || exact == 0.0 {
+        return exact;
+    }
+
+    // An f16 needs at most five significant decimal digits to round-trip
+    for precision in 0..5 {
+        if let Ok(candidate) = format!("{exact:.precision$e}").parse::<f32>() {
+            if f16::from_f32(candidate).to_bits() == value.to_bits() {
+                return candidate;
+            }
+        }
+    }
+
+    exact
+}
+
+struct F16Visitor;
+
+impl de::Visitor<'_> for F16Visitor {
+    type Value = f16;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a half-precision floating point number")
+    }
+
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Self::Value, E> {
+        Ok(f16::from_f32(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(f16::from_f64(v))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(f16::from_f64(v as f64))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(f16::from_f64(v as f64))
+    }
+}
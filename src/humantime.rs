@@ -0,0 +1,56 @@
+//! Serialize and deserialize [`Duration`]s as human-readable strings like
+//! `"1h 30m"` or `"5s 250ms"`.
+//!
+//! This module is only available with the `humantime` feature and is meant
+//! to be used with `#[serde(with = "ron::humantime")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//!
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "ron::humantime")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: Config = ron::from_str(r#"(timeout: "1h 30m")"#).unwrap();
+//! assert_eq!(config.timeout, Duration::from_secs(90 * 60));
+//!
+//! assert_eq!(ron::to_string(&config).unwrap(), r#"(timeout:"1h 30m")"#);
+//! ```
+//!
+//! Durations are parsed using [`humantime::parse_duration`], which accepts
+//! a sequence of integer values with units, e.g. `"2days 4h"`, `"15min"`,
+//! or `"1s 500ms"`, and written using [`humantime::format_duration`].
+
+use core::{fmt, time::Duration};
+
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes a [`Duration`] as a human-readable string, e.g. `"1h 30m"`.
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&::humantime::format_duration(*duration))
+}
+
+/// Deserializes a [`Duration`] from a human-readable string, e.g. `"1h 30m"`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    deserializer.deserialize_str(DurationVisitor)
+}
+
+struct DurationVisitor;
+
+impl de::Visitor<'_> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a human-readable duration string, e.g. \"1h 30m\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        ::humantime::parse_duration(v).map_err(E::custom)
+    }
+}
@@ -14,6 +14,9 @@
 #![allow(clippy::missing_errors_doc)] // FIXME
 #![doc = include_str!("../README.md")]
 #![doc(html_root_url = "https://docs.rs/ron/0.9.0-alpha.0")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod de;
 pub mod ser;
@@ -25,10 +28,21 @@ pub mod extensions;
 
 pub mod options;
 
+pub mod required_some;
+
+#[cfg(feature = "half")]
+pub mod half;
+
+#[cfg(feature = "humantime")]
+pub mod humantime;
+
+#[cfg(feature = "uuid")]
+pub mod uuid;
+
 pub use de::{from_str, Deserializer};
 pub use error::{Error, Result};
 pub use options::Options;
 pub use ser::{to_string, Serializer};
 pub use value::{Map, Number, Value};
 
-mod parse;
+pub mod parse;
@@ -1,15 +1,25 @@
 //! Roundtrip serde Options module.
 
-use std::{fmt, io};
+#[cfg(feature = "serde_path_to_error")]
+use alloc::string::ToString;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
 
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(feature = "serde_path_to_error")]
+use crate::error::TracedError;
+#[cfg(feature = "std")]
+use crate::error::{Position, SpannedError};
 use crate::{
-    de::Deserializer,
-    error::{Position, Result, SpannedError, SpannedResult},
+    de::{Deserializer, RedundantParens, SeqIter},
+    error::{Result, SpannedResult, Warning},
     extensions::Extensions,
     ser::{PrettyConfig, Serializer},
+    value::Value,
 };
 
 /// Roundtrip serde options.
@@ -27,6 +37,7 @@ use crate::{
 ///
 /// assert_eq!(ser, "42");
 /// ```
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Serialize, Deserialize)] // GRCOV_EXCL_LINE
 #[serde(default)]
 #[non_exhaustive]
@@ -39,52 +50,701 @@ pub struct Options {
     ///  activation is NOT included in the output RON.
     /// No extensions are enabled by default.
     pub default_extensions: Extensions,
-    /// Default recursion limit that is checked during serialization and
-    ///  deserialization.
+    /// Maximum nesting depth that is checked during both serialization and
+    ///  deserialization, which fail with [`Error::ExceededRecursionLimit`]
+    ///  if a value is nested more deeply.
+    /// Every element of a sequence, tuple, map, or struct, the content of
+    ///  a `Some` or newtype, and the data of an enum variant is nested one
+    ///  level deeper than its parent, e.g. `[[42]]` has a depth of 2.
     /// If set to `None`, infinite recursion is allowed and stack overflow
     ///  errors can crash the serialization or deserialization process.
-    /// Defaults to `Some(128)`, i.e. 128 recursive calls are allowed.
-    pub recursion_limit: Option<usize>,
+    /// Defaults to `Some(128)`.
+    ///
+    /// This limit is unrelated to [`PrettyConfig::depth_limit`], which only
+    ///  controls from which depth pretty output is written on a single line.
+    ///
+    /// [`Error::ExceededRecursionLimit`]: crate::error::Error::ExceededRecursionLimit
+    #[serde(alias = "recursion_limit")]
+    pub depth_limit: Option<usize>,
+    /// Whether a first line starting with `#!`, e.g. a shebang like
+    ///  `#!/usr/bin/env my-tool`, is skipped during deserialization.
+    /// A first line starting with `#![` is always parsed as an attribute.
+    /// Defaults to `false`.
+    pub allow_shebang: bool,
+    /// Whether enabling the same extension more than once in the
+    ///  `#![enable(...)]` attributes of the parsed RON is an error.
+    /// Otherwise, all enabled extensions are combined.
+    /// Defaults to `false`.
+    pub strict_header: bool,
+    /// Whether unit values, unit structs, and unit enum variants are
+    ///  deserialized leniently, e.g. to allow migrating between them:
+    /// - a unit `()` additionally accepts any unit struct name, e.g. `Foo`
+    ///   (a unit struct always accepts `()`), but not a literal like
+    ///   `true`, `false`, `None`, `Some`, `inf`, or `NaN`, unless it is
+    ///   written as a raw identifier, e.g. `r#true`,
+    /// - a unit enum variant `A` additionally accepts `A()`,
+    /// - a newtype enum variant `A(...)` additionally accepts a bare `A` if
+    ///   its content can be deserialized from a unit, e.g. `()`, a unit
+    ///   struct, or an `Option` as `None`.
+    ///
+    /// A struct field of a unit type that is missing entirely is still an
+    ///  [`Error::MissingStructField`], since serde does not let the format
+    ///  provide the value of a missing field. Such fields can instead be
+    ///  marked with `#[serde(default)]`.
+    /// Defaults to `false`.
+    ///
+    /// [`Error::MissingStructField`]: crate::error::Error::MissingStructField
+    pub lenient_unit: bool,
+    /// Predicate that decides whether the trailing content after the
+    ///  deserialized value, e.g. a generated metadata footer, is ignored
+    ///  instead of producing an [`Error::TrailingCharacters`].
+    /// The predicate is only called with the non-empty remainder of the
+    ///  input after any whitespace and comments, which are always ignored.
+    /// This option cannot be (de)serialized.
+    /// Defaults to `None`.
+    ///
+    /// [`Error::TrailingCharacters`]: crate::error::Error::TrailingCharacters
+    #[serde(skip)]
+    pub trailing_ignored: Option<fn(&str) -> bool>,
+    /// Whether the deserializer counts the remaining elements of a sequence
+    ///  or map with a lightweight forward scan before deserializing it, and
+    ///  reports the count as the [`SeqAccess::size_hint`] or
+    ///  [`MapAccess::size_hint`], e.g. such that a `Vec` or `HashMap` can be
+    ///  allocated with the right capacity up front.
+    /// The scan only skips over nested values, strings, characters, and
+    ///  comments without parsing them, so the count is only a hint for
+    ///  invalid RON.
+    /// Defaults to `false`.
+    ///
+    /// [`SeqAccess::size_hint`]: serde::de::SeqAccess::size_hint
+    /// [`MapAccess::size_hint`]: serde::de::MapAccess::size_hint
+    pub precount_collections: bool,
+    /// Whether the `#![enable(...)]` extension attributes at the start of
+    ///  the parsed RON are scanned for during deserialization.
+    /// If disabled, only the [`Options::default_extensions`] are enabled,
+    ///  and parsed RON that starts with a `#`, e.g. an attribute, fails with
+    ///  an [`Error::UnexpectedAttribute`] instead of being misparsed.
+    /// A shebang is still skipped if [`Options::allow_shebang`] is enabled.
+    /// Defaults to `true`.
+    ///
+    /// [`Error::UnexpectedAttribute`]: crate::error::Error::UnexpectedAttribute
+    pub extension_header: bool,
+    /// Additional identifiers, e.g. `yes` or `off`, that are accepted in
+    ///  place of `true` or `false` wherever a boolean is expected during
+    ///  deserialization.
+    /// Booleans are always serialized as `true` or `false`.
+    /// Defaults to no aliases.
+    pub bool_aliases: Vec<(String, bool)>,
+    /// Defaults for missing struct fields or map entries, given as a
+    ///  [`Value::Map`], e.g. parsed from `(port: 8080, verbose: false)`.
+    /// The defaults are applied while the RON is deserialized:
+    /// - a value in the parsed RON always takes precedence over its default,
+    /// - an entry that is missing from a parsed map or struct is filled in
+    ///   from the defaults, recursively for nested maps and structs,
+    /// - the defaults of a map are deserialized before its parsed entries,
+    ///   which replace the defaults with an equal key, even if the key is
+    ///   written differently, e.g. `1u32` for a default `1`,
+    /// - a parsed `()` counts as an empty struct, and the contents of a
+    ///   `Some(...)` are merged like the value they wrap,
+    /// - the fields of enum struct variants and of structs inside sequences,
+    ///   tuples, or enums are not filled in.
+    ///
+    /// The parsed RON is deserialized directly, so it may contain any value,
+    ///  e.g. enums, and errors are reported at their position in it. Only
+    ///  the filled-in defaults are deserialized from their [`Value`], which
+    ///  inherits its limitations, e.g. enums are not supported there.
+    /// Defaults to `None`.
+    pub field_defaults: Option<Value>,
+    /// Whether an enum variant may also be given by its index during
+    ///  deserialization, e.g. `1` for the second variant, as in compact
+    ///  machine-generated RON.
+    /// An index selects the variant at that position in the list of
+    ///  variants that serde derives for the enum. An index without such a
+    ///  variant fails with an [`Error::NoSuchEnumVariantIndex`].
+    /// Enum variants are always serialized by their name.
+    /// Defaults to `false`.
+    ///
+    /// [`Error::NoSuchEnumVariantIndex`]: crate::error::Error::NoSuchEnumVariantIndex
+    pub numeric_enum_variants: bool,
+    /// Whether a top-level value may be wrapped in a single pair of
+    ///  redundant parentheses during deserialization, e.g. `(42)` or
+    ///  `((1, 2))`, as produced by some lenient RON writers.
+    /// The parentheses are only peeled off if they contain exactly one
+    ///  value and if the expected type does not itself expect them, so a
+    ///  tuple or tuple struct with a single element, a newtype struct, an
+    ///  [`Option`] with the [`Extensions::IMPLICIT_SOME`] extension, and
+    ///  an untyped value, e.g. a [`Value`], are never unwrapped.
+    /// Only the `from_*` methods of [`Options`], e.g. [`Options::from_str`]
+    ///  or [`Options::from_str_with_warnings`], unwrap redundant
+    ///  parentheses, but not a [`Deserializer`] that is driven by hand.
+    /// Values are never serialized with redundant parentheses.
+    /// Defaults to `false`.
+    pub unwrap_redundant_parens: bool,
+    /// Maximum length in bytes of any single string, byte string, or
+    ///  identifier during deserialization, which fails with an
+    ///  [`Error::ExceededStringLengthLimit`] at the start of a longer one.
+    /// The length of a string is the length of its unescaped contents,
+    ///  which is checked while an escaped string is being unescaped, such
+    ///  that a huge string is never allocated.
+    /// If set to `None`, strings and identifiers can be arbitrarily long.
+    /// Defaults to `None`.
+    ///
+    /// [`Error::ExceededStringLengthLimit`]: crate::error::Error::ExceededStringLengthLimit
+    pub max_string_length: Option<usize>,
+    /// Whether non-fatal [`Warning`]s are collected during deserialization
+    ///  whenever a lenient but discouraged construct is accepted, e.g. one
+    ///  of the [`Options::bool_aliases`] instead of `true` or `false`, see
+    ///  [`WarningKind`] for all of them.
+    /// The warnings can be inspected with [`Deserializer::warnings`], while
+    ///  [`Options::from_str_with_warnings`] always collects and returns them.
+    /// Defaults to `false`.
+    ///
+    /// [`WarningKind`]: crate::error::WarningKind
+    pub warnings: bool,
+    /// Whether `\r\n` and lone `\r` newlines inside string and byte
+    ///  string literals are converted to `\n` during deserialization, e.g.
+    ///  such that a multi-line string in a file with Windows line endings
+    ///  deserializes to the same value on every platform.
+    /// Only newlines that are written verbatim are converted, while escaped
+    ///  ones like `"\r\n"` are kept.
+    /// Strings with converted newlines can no longer be borrowed from the
+    ///  input, e.g. as a `&str`.
+    /// Defaults to `false`.
+    pub normalize_newlines: bool,
+    /// Function that renames every struct field name in the parsed RON,
+    ///  e.g. from `camelCase` to `snake_case`, before it is matched against
+    ///  the fields of the struct during deserialization.
+    /// The renamed field names also apply to quoted field names and to
+    ///  the fields of flattened structs, but not to the keys of maps or to
+    ///  enum variant names.
+    /// If two field names in the parsed RON are renamed to the same field,
+    ///  e.g. `maxSize` and `max_size`, deserialization fails with an
+    ///  [`Error::DuplicateStructField`], just as if the field had been
+    ///  given twice.
+    /// The keys of the [`Options::field_defaults`] are written like the
+    ///  parsed field names and are renamed in the same way.
+    /// Struct field names are always serialized unchanged.
+    /// This option cannot be (de)serialized.
+    /// Defaults to `None`.
+    ///
+    /// [`Error::DuplicateStructField`]: crate::error::Error::DuplicateStructField
+    #[serde(skip)]
+    pub field_rename: Option<fn(&str) -> Cow<'_, str>>,
+    /// Case convention in which enum variant names may also be written
+    ///  during deserialization, e.g. `my_variant` for the variant
+    ///  `MyVariant` with [`Case::Snake`].
+    /// A variant name that exactly matches one of the variants always
+    ///  selects it. Otherwise, every variant name is converted into the
+    ///  case convention and compared with the written name. If more than
+    ///  one variant matches, e.g. both `HttpError` and `HTTPError` for
+    ///  `http_error`, deserialization fails with an
+    ///  [`Error::AmbiguousEnumVariant`].
+    /// Enum variants are always serialized by their name.
+    /// Defaults to `None`.
+    ///
+    /// [`Error::AmbiguousEnumVariant`]: crate::error::Error::AmbiguousEnumVariant
+    pub variant_case_conversion: Option<Case>,
+    /// Whether a bare `()` is also accepted as `None` wherever an
+    ///  [`Option`] is expected during deserialization, e.g. for RON from
+    ///  generators that write `()` for a missing value.
+    /// Values whose type is unit, e.g. `()` or a unit struct, still
+    ///  deserialize from `()` as usual. A `()` is `None` even with the
+    ///  [`Extensions::IMPLICIT_SOME`] extension, so an `Option<()>` that
+    ///  is `Some` must then be written as `Some(())`.
+    /// `None` is always serialized as `None`.
+    /// Defaults to `false`.
+    pub unit_as_none: bool,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
             default_extensions: Extensions::empty(),
-            recursion_limit: Some(128),
+            depth_limit: Some(128),
+            allow_shebang: false,
+            strict_header: false,
+            lenient_unit: false,
+            trailing_ignored: None,
+            precount_collections: false,
+            extension_header: true,
+            bool_aliases: Vec::new(),
+            field_defaults: None,
+            numeric_enum_variants: false,
+            unwrap_redundant_parens: false,
+            max_string_length: None,
+            warnings: false,
+            normalize_newlines: false,
+            field_rename: None,
+            variant_case_conversion: None,
+            unit_as_none: false,
+        }
+    }
+}
+
+/// A case convention for enum variant names, see
+///  [`Options::variant_case_conversion`].
+///
+/// A variant name is split into words at underscores, at hyphens, and
+///  before an uppercase letter that follows a lowercase letter or digit
+///  or that starts a new word after an acronym, e.g. `HTTPError` is split
+///  into `HTTP` and `Error`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Case {
+    /// `snake_case`, e.g. `my_variant`
+    Snake,
+    /// `kebab-case`, e.g. `my-variant`, which must be written as a raw
+    ///  identifier in RON, e.g. `r#my-variant`
+    Kebab,
+    /// `camelCase`, e.g. `myVariant`
+    Camel,
+}
+
+impl Case {
+    /// Converts the variant `name` into this case convention.
+    pub(crate) fn convert(self, name: &str) -> String {
+        let mut converted = String::with_capacity(name.len() + 2);
+
+        for (i, word) in split_words(name).into_iter().enumerate() {
+            match self {
+                Case::Snake | Case::Kebab => {
+                    if i > 0 {
+                        converted.push(if self == Case::Snake { '_' } else { '-' });
+                    }
+                    converted.extend(word.chars().flat_map(char::to_lowercase));
+                }
+                Case::Camel => {
+                    let mut chars = word.chars();
+
+                    if let Some(first) = chars.next() {
+                        if i > 0 {
+                            converted.extend(first.to_uppercase());
+                        } else {
+                            converted.extend(first.to_lowercase());
+                        }
+                    }
+                    converted.extend(chars.flat_map(char::to_lowercase));
+                }
+            }
+        }
+
+        converted
+    }
+}
+
+/// Splits a variant `name` into its words, see [`Case`].
+fn split_words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    let mut chars = name.char_indices().peekable();
+    let mut prev: Option<char> = None;
+
+    while let Some((i, c)) = chars.next() {
+        if c == '_' || c == '-' {
+            if start < i {
+                words.push(&name[start..i]);
+            }
+            start = i + c.len_utf8();
+            prev = None;
+            continue;
+        }
+
+        let boundary = c.is_uppercase()
+            && prev.map_or(false, |prev| {
+                prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase()
+                        && chars.peek().map_or(false, |&(_, next)| next.is_lowercase()))
+            });
+
+        if boundary && start < i {
+            words.push(&name[start..i]);
+            start = i;
         }
+
+        prev = Some(c);
+    }
+
+    if start < name.len() {
+        words.push(&name[start..]);
     }
+
+    words
 }
 
 impl Options {
     #[must_use]
     /// Enable `default_extension` by default during serialization and deserialization.
     pub fn with_default_extension(mut self, default_extension: Extensions) -> Self {
-        self.default_extensions |= default_extension;
+        self.set_default_extension(default_extension, true);
         self
     }
 
     #[must_use]
     /// Do NOT enable `default_extension` by default during serialization and deserialization.
     pub fn without_default_extension(mut self, default_extension: Extensions) -> Self {
-        self.default_extensions &= !default_extension;
+        self.set_default_extension(default_extension, false);
         self
     }
 
     #[must_use]
-    /// Set a maximum recursion limit during serialization and deserialization.
-    pub fn with_recursion_limit(mut self, recursion_limit: usize) -> Self {
-        self.recursion_limit = Some(recursion_limit);
+    /// Set the maximum nesting depth during serialization and deserialization,
+    ///  see [`Options::depth_limit`].
+    pub fn with_depth_limit(mut self, depth_limit: usize) -> Self {
+        self.set_depth_limit(Some(depth_limit));
         self
     }
 
     #[must_use]
-    /// Disable the recursion limit during serialization and deserialization.
+    /// Disable the nesting depth limit during serialization and deserialization.
     ///
     /// If you expect to handle highly recursive datastructures, consider wrapping
     /// `ron` with [`serde_stacker`](https://docs.rs/serde_stacker/latest/serde_stacker/).
-    pub fn without_recursion_limit(mut self) -> Self {
-        self.recursion_limit = None;
+    pub fn without_depth_limit(mut self) -> Self {
+        self.set_depth_limit(None);
+        self
+    }
+
+    #[must_use]
+    #[deprecated(since = "0.9.0", note = "use `Options::with_depth_limit` instead")]
+    /// Set a maximum recursion limit during serialization and deserialization.
+    pub fn with_recursion_limit(self, recursion_limit: usize) -> Self {
+        self.with_depth_limit(recursion_limit)
+    }
+
+    #[must_use]
+    #[deprecated(since = "0.9.0", note = "use `Options::without_depth_limit` instead")]
+    /// Disable the recursion limit during serialization and deserialization.
+    pub fn without_recursion_limit(self) -> Self {
+        self.without_depth_limit()
+    }
+
+    #[must_use]
+    /// Configure whether a first line starting with `#!` (but not `#![`),
+    ///  e.g. a shebang, is skipped during deserialization.
+    pub fn with_allow_shebang(mut self, allow_shebang: bool) -> Self {
+        self.set_allow_shebang(allow_shebang);
+        self
+    }
+
+    #[must_use]
+    /// Configure whether enabling the same extension more than once in the
+    ///  `#![enable(...)]` attributes, e.g. due to a merge conflict artifact,
+    ///  is an error during deserialization.
+    pub fn with_strict_header(mut self, strict_header: bool) -> Self {
+        self.set_strict_header(strict_header);
+        self
+    }
+
+    #[must_use]
+    /// Configure whether unit values, unit structs, and unit enum variants
+    ///  are deserialized leniently, see [`Options::lenient_unit`].
+    pub fn with_lenient_unit(mut self, lenient_unit: bool) -> Self {
+        self.set_lenient_unit(lenient_unit);
+        self
+    }
+
+    #[must_use]
+    /// Ignore the trailing content after the deserialized value if the
+    ///  `trailing_ignored` predicate returns `true` for it, see
+    ///  [`Options::trailing_ignored`].
+    pub fn with_trailing_ignored(mut self, trailing_ignored: fn(&str) -> bool) -> Self {
+        self.set_trailing_ignored(Some(trailing_ignored));
+        self
+    }
+
+    #[must_use]
+    /// Configure whether the remaining elements of sequences and maps are
+    ///  counted ahead of time to provide accurate size hints during
+    ///  deserialization, see [`Options::precount_collections`].
+    pub fn with_precount_collections(mut self, precount_collections: bool) -> Self {
+        self.set_precount_collections(precount_collections);
+        self
+    }
+
+    #[must_use]
+    /// Skip scanning for `#![enable(...)]` extension attributes at the start
+    ///  of the parsed RON, e.g. when parsing many small documents that never
+    ///  use them, see [`Options::extension_header`].
+    pub fn without_extension_header_scan(mut self) -> Self {
+        self.set_extension_header(false);
+        self
+    }
+
+    #[must_use]
+    /// Register additional identifiers that are accepted as booleans during
+    ///  deserialization, e.g. `&[("yes", true), ("no", false)]`, see
+    ///  [`Options::bool_aliases`].
+    pub fn with_bool_aliases(mut self, bool_aliases: &[(&str, bool)]) -> Self {
+        self.add_bool_aliases(bool_aliases);
+        self
+    }
+
+    #[must_use]
+    /// Fill in missing struct fields and map entries from the `field_defaults`
+    ///  map during deserialization, see [`Options::field_defaults`].
+    pub fn with_field_defaults(mut self, field_defaults: Value) -> Self {
+        self.set_field_defaults(Some(field_defaults));
+        self
+    }
+
+    #[must_use]
+    /// Configure whether enum variants may also be given by their index
+    ///  during deserialization, see [`Options::numeric_enum_variants`].
+    pub fn with_numeric_enum_variants(mut self, numeric_enum_variants: bool) -> Self {
+        self.set_numeric_enum_variants(numeric_enum_variants);
+        self
+    }
+
+    #[must_use]
+    /// Configure whether a top-level value may be wrapped in a single pair
+    ///  of redundant parentheses during deserialization, see
+    ///  [`Options::unwrap_redundant_parens`].
+    pub fn with_unwrap_redundant_parens(mut self, unwrap_redundant_parens: bool) -> Self {
+        self.set_unwrap_redundant_parens(unwrap_redundant_parens);
+        self
+    }
+
+    #[must_use]
+    /// Set the maximum length of strings and identifiers during
+    ///  deserialization, see [`Options::max_string_length`].
+    pub fn with_max_string_length(mut self, max_string_length: usize) -> Self {
+        self.set_max_string_length(Some(max_string_length));
+        self
+    }
+
+    #[must_use]
+    /// Disable the maximum length of strings and identifiers during
+    ///  deserialization.
+    pub fn without_max_string_length(mut self) -> Self {
+        self.set_max_string_length(None);
+        self
+    }
+
+    #[must_use]
+    /// Configure whether warnings about lenient but discouraged constructs
+    ///  are collected during deserialization, see [`Options::warnings`].
+    pub fn with_warnings(mut self, warnings: bool) -> Self {
+        self.set_warnings(warnings);
+        self
+    }
+
+    #[must_use]
+    /// Configure whether newlines inside string literals are converted to
+    ///  `\n` during deserialization, see [`Options::normalize_newlines`].
+    pub fn with_normalize_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.set_normalize_newlines(normalize_newlines);
+        self
+    }
+
+    #[must_use]
+    /// Rename every struct field name in the parsed RON with `field_rename`
+    ///  before it is matched during deserialization, see
+    ///  [`Options::field_rename`].
+    pub fn with_field_rename(mut self, field_rename: fn(&str) -> Cow<'_, str>) -> Self {
+        self.set_field_rename(Some(field_rename));
+        self
+    }
+
+    #[must_use]
+    /// Also accept enum variant names that are written in the `case`
+    ///  convention during deserialization, see
+    ///  [`Options::variant_case_conversion`].
+    pub fn with_variant_case_conversion(mut self, case: Case) -> Self {
+        self.set_variant_case_conversion(Some(case));
+        self
+    }
+
+    #[must_use]
+    /// Configure whether a bare `()` is also accepted as `None` for an
+    ///  [`Option`] during deserialization, see [`Options::unit_as_none`].
+    pub fn with_unit_as_none(mut self, unit_as_none: bool) -> Self {
+        self.set_unit_as_none(unit_as_none);
+        self
+    }
+}
+
+/// In-place counterparts of the `with_*` and `without_*` builder methods,
+///  e.g. to tweak a clone of a base configuration:
+///
+/// ```
+/// use ron::{Options, extensions::Extensions};
+///
+/// let base = Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+///
+/// for depth_limit in [1, 2] {
+///     let mut options = base.clone();
+///     options.set_depth_limit(Some(depth_limit)).set_allow_shebang(true);
+///
+///     assert_eq!(options.depth_limit, Some(depth_limit));
+///     assert!(options.default_extensions.contains(Extensions::IMPLICIT_SOME));
+/// }
+/// ```
+impl Options {
+    /// Enable (`true`) or do NOT enable (`false`) `default_extension` by
+    ///  default during serialization and deserialization.
+    pub fn set_default_extension(
+        &mut self,
+        default_extension: Extensions,
+        enabled: bool,
+    ) -> &mut Self {
+        self.default_extensions.set(default_extension, enabled);
+        self
+    }
+
+    /// Set (`Some`) or disable (`None`) the maximum nesting depth during
+    ///  serialization and deserialization, see [`Options::depth_limit`].
+    pub fn set_depth_limit(&mut self, depth_limit: Option<usize>) -> &mut Self {
+        self.depth_limit = depth_limit;
+        self
+    }
+
+    #[must_use]
+    #[deprecated(since = "0.9.0", note = "use the `Options::depth_limit` field instead")]
+    /// Get the maximum recursion limit during serialization and
+    ///  deserialization, see [`Options::depth_limit`].
+    pub fn recursion_limit(&self) -> Option<usize> {
+        self.depth_limit
+    }
+
+    #[deprecated(since = "0.9.0", note = "use `Options::set_depth_limit` instead")]
+    /// Set (`Some`) or disable (`None`) the maximum recursion limit during
+    ///  serialization and deserialization, see [`Options::depth_limit`].
+    pub fn set_recursion_limit(&mut self, recursion_limit: Option<usize>) -> &mut Self {
+        self.set_depth_limit(recursion_limit)
+    }
+
+    /// Configure whether a first line starting with `#!` (but not `#![`),
+    ///  e.g. a shebang, is skipped during deserialization.
+    pub fn set_allow_shebang(&mut self, allow_shebang: bool) -> &mut Self {
+        self.allow_shebang = allow_shebang;
+        self
+    }
+
+    /// Configure whether enabling the same extension more than once in the
+    ///  `#![enable(...)]` attributes is an error during deserialization,
+    ///  see [`Options::strict_header`].
+    pub fn set_strict_header(&mut self, strict_header: bool) -> &mut Self {
+        self.strict_header = strict_header;
+        self
+    }
+
+    /// Configure whether unit values, unit structs, and unit enum variants
+    ///  are deserialized leniently, see [`Options::lenient_unit`].
+    pub fn set_lenient_unit(&mut self, lenient_unit: bool) -> &mut Self {
+        self.lenient_unit = lenient_unit;
+        self
+    }
+
+    /// Set (`Some`) or remove (`None`) the predicate that decides whether
+    ///  the trailing content after the deserialized value is ignored, see
+    ///  [`Options::trailing_ignored`].
+    pub fn set_trailing_ignored(
+        &mut self,
+        trailing_ignored: Option<fn(&str) -> bool>,
+    ) -> &mut Self {
+        self.trailing_ignored = trailing_ignored;
+        self
+    }
+
+    /// Configure whether the remaining elements of sequences and maps are
+    ///  counted ahead of time to provide accurate size hints during
+    ///  deserialization, see [`Options::precount_collections`].
+    pub fn set_precount_collections(&mut self, precount_collections: bool) -> &mut Self {
+        self.precount_collections = precount_collections;
+        self
+    }
+
+    /// Configure whether the `#![enable(...)]` extension attributes at the
+    ///  start of the parsed RON are scanned for, see
+    ///  [`Options::extension_header`].
+    pub fn set_extension_header(&mut self, extension_header: bool) -> &mut Self {
+        self.extension_header = extension_header;
+        self
+    }
+
+    /// Register additional identifiers that are accepted as booleans during
+    ///  deserialization, in addition to the already registered ones, see
+    ///  [`Options::bool_aliases`].
+    pub fn add_bool_aliases(&mut self, bool_aliases: &[(&str, bool)]) -> &mut Self {
+        self.bool_aliases.extend(
+            bool_aliases
+                .iter()
+                .map(|&(alias, value)| (String::from(alias), value)),
+        );
+        self
+    }
+
+    /// Set (`Some`) or remove (`None`) the defaults for missing struct
+    ///  fields and map entries, see [`Options::field_defaults`].
+    pub fn set_field_defaults(&mut self, field_defaults: Option<Value>) -> &mut Self {
+        self.field_defaults = field_defaults;
+        self
+    }
+
+    /// Configure whether enum variants may also be given by their index
+    ///  during deserialization, see [`Options::numeric_enum_variants`].
+    pub fn set_numeric_enum_variants(&mut self, numeric_enum_variants: bool) -> &mut Self {
+        self.numeric_enum_variants = numeric_enum_variants;
+        self
+    }
+
+    /// Configure whether a top-level value may be wrapped in a single pair
+    ///  of redundant parentheses during deserialization, see
+    ///  [`Options::unwrap_redundant_parens`].
+    pub fn set_unwrap_redundant_parens(&mut self, unwrap_redundant_parens: bool) -> &mut Self {
+        self.unwrap_redundant_parens = unwrap_redundant_parens;
+        self
+    }
+
+    /// Set (`Some`) or disable (`None`) the maximum length of strings and
+    ///  identifiers during deserialization, see
+    ///  [`Options::max_string_length`].
+    pub fn set_max_string_length(&mut self, max_string_length: Option<usize>) -> &mut Self {
+        self.max_string_length = max_string_length;
+        self
+    }
+
+    /// Configure whether warnings about lenient but discouraged constructs
+    ///  are collected during deserialization, see [`Options::warnings`].
+    pub fn set_warnings(&mut self, warnings: bool) -> &mut Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Configure whether newlines inside string literals are converted to
+    ///  `\n` during deserialization, see [`Options::normalize_newlines`].
+    pub fn set_normalize_newlines(&mut self, normalize_newlines: bool) -> &mut Self {
+        self.normalize_newlines = normalize_newlines;
+        self
+    }
+
+    /// Set (`Some`) or remove (`None`) the function that renames struct
+    ///  field names during deserialization, see [`Options::field_rename`].
+    pub fn set_field_rename(
+        &mut self,
+        field_rename: Option<fn(&str) -> Cow<'_, str>>,
+    ) -> &mut Self {
+        self.field_rename = field_rename;
+        self
+    }
+
+    /// Set (`Some`) or disable (`None`) the case convention in which enum
+    ///  variant names may also be written during deserialization, see
+    ///  [`Options::variant_case_conversion`].
+    pub fn set_variant_case_conversion(
+        &mut self,
+        variant_case_conversion: Option<Case>,
+    ) -> &mut Self {
+        self.variant_case_conversion = variant_case_conversion;
+        self
+    }
+
+    /// Configure whether a bare `()` is also accepted as `None` for an
+    ///  [`Option`] during deserialization, see [`Options::unit_as_none`].
+    pub fn set_unit_as_none(&mut self, unit_as_none: bool) -> &mut Self {
+        self.unit_as_none = unit_as_none;
         self
     }
 }
@@ -92,12 +752,15 @@ impl Options {
 impl Options {
     /// A convenience function for building a deserializer
     /// and deserializing a value of type `T` from a reader.
+    ///
+    /// This method is only available with the `std` feature.
+    #[cfg(feature = "std")]
     pub fn from_reader<R, T>(&self, rdr: R) -> SpannedResult<T>
     where
         R: io::Read,
         T: de::DeserializeOwned,
     {
-        self.from_reader_seed(rdr, std::marker::PhantomData)
+        self.from_reader_seed(rdr, core::marker::PhantomData)
     }
 
     /// A convenience function for building a deserializer
@@ -106,7 +769,7 @@ impl Options {
     where
         T: de::Deserialize<'a>,
     {
-        self.from_str_seed(s, std::marker::PhantomData)
+        self.from_str_seed(s, core::marker::PhantomData)
     }
 
     /// A convenience function for building a deserializer
@@ -115,20 +778,27 @@ impl Options {
     where
         T: de::Deserialize<'a>,
     {
-        self.from_bytes_seed(s, std::marker::PhantomData)
+        self.from_bytes_seed(s, core::marker::PhantomData)
     }
 
     /// A convenience function for building a deserializer
     /// and deserializing a value of type `T` from a reader
     /// and a seed.
+    ///
+    /// The seed is deserialized with these options, including their
+    /// extensions, just like [`Options::from_str`], and any trailing
+    /// characters after the value are rejected.
+    ///
+    /// This method is only available with the `std` feature.
     // FIXME: panic is not actually possible, remove once utf8_chunks is stabilized
     #[allow(clippy::missing_panics_doc)]
+    #[cfg(feature = "std")]
     pub fn from_reader_seed<R, S, T>(&self, mut rdr: R, seed: S) -> SpannedResult<T>
     where
         R: io::Read,
         S: for<'a> de::DeserializeSeed<'a, Value = T>,
     {
-        let mut bytes = Vec::new();
+        let mut bytes = alloc::vec::Vec::new();
 
         let io_err = if let Err(err) = rdr.read_to_end(&mut bytes) {
             err
@@ -139,9 +809,9 @@ impl Options {
         // Try to compute a good error position for the I/O error
         // FIXME: use [`utf8_chunks`](https://github.com/rust-lang/rust/issues/99543) once stabilised
         #[allow(clippy::expect_used)]
-        let valid_input = match std::str::from_utf8(&bytes) {
+        let valid_input = match core::str::from_utf8(&bytes) {
             Ok(valid_input) => valid_input,
-            Err(err) => std::str::from_utf8(&bytes[..err.valid_up_to()])
+            Err(err) => core::str::from_utf8(&bytes[..err.valid_up_to()])
                 .expect("source is valid up to error"),
         };
 
@@ -154,17 +824,180 @@ impl Options {
     /// A convenience function for building a deserializer
     /// and deserializing a value of type `T` from a string
     /// and a seed.
+    ///
+    /// The seed is deserialized with these options, including their
+    /// extensions, just like [`Options::from_str`], and any trailing
+    /// characters after the value are rejected.
     pub fn from_str_seed<'a, S, T>(&self, s: &'a str, seed: S) -> SpannedResult<T>
     where
         S: de::DeserializeSeed<'a, Value = T>,
     {
         let mut deserializer = Deserializer::from_str_with_options(s, self)?;
 
-        let value = seed
-            .deserialize(&mut deserializer)
-            .map_err(|e| deserializer.span_error(e))?;
+        self.deserialize_seed(&mut deserializer, seed)
+    }
 
-        deserializer.end().map_err(|e| deserializer.span_error(e))?;
+    /// Lazily deserializes the elements of a top-level sequence `[...]`
+    /// from a string, yielding each element of type `T` as soon as it has
+    /// been parsed, e.g. to process a huge sequence without collecting it
+    /// into a `Vec`.
+    ///
+    /// The iterator ends after the closing `]`, which may only be followed
+    /// by whitespace or comments. Any error is yielded once as the last item
+    /// of the iterator.
+    ///
+    /// ```
+    /// use ron::Options;
+    ///
+    /// let sum = Options::default()
+    ///     .deserialize_seq_iter::<u32>("[1, 2, 3]")
+    ///     .map(Result::unwrap)
+    ///     .sum::<u32>();
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn deserialize_seq_iter<'de, T>(
+        &self,
+        s: &'de str,
+    ) -> impl Iterator<Item = SpannedResult<T>> + 'de
+    where
+        T: de::Deserialize<'de> + 'de,
+    {
+        SeqIter::new(s, self)
+    }
+
+    /// A convenience function for building a deserializer
+    /// and deserializing a value of type `T` from a string,
+    /// which also returns the [`Extensions`] that were enabled
+    /// while deserializing.
+    ///
+    /// The returned extensions combine the ones enabled by the
+    /// `#![enable(...)]` attributes at the start of the document with
+    /// the default extensions configured in these [`Options`].
+    ///
+    /// ```
+    /// use ron::{extensions::Extensions, Options};
+    ///
+    /// let (value, extensions) = Options::default()
+    ///     .with_default_extension(Extensions::UNWRAP_NEWTYPES)
+    ///     .from_str_with_extensions::<Option<u8>>("#![enable(implicit_some)] 42")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value, Some(42));
+    /// assert_eq!(
+    ///     extensions,
+    ///     Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES
+    /// );
+    /// ```
+    pub fn from_str_with_extensions<'a, T>(&self, s: &'a str) -> SpannedResult<(T, Extensions)>
+    where
+        T: de::Deserialize<'a>,
+    {
+        let mut deserializer = Deserializer::from_str_with_options(s, self)?;
+
+        let value = self.deserialize_seed(&mut deserializer, core::marker::PhantomData)?;
+
+        Ok((value, deserializer.extensions()))
+    }
+
+    /// A convenience function for building a deserializer
+    /// and deserializing a value of type `T` from a string,
+    /// which also returns the [`Warning`]s about all lenient but
+    /// discouraged constructs that were accepted while deserializing.
+    ///
+    /// The warnings are collected even if [`Options::warnings`] is not
+    /// enabled.
+    ///
+    /// ```
+    /// use ron::{error::WarningKind, Options};
+    ///
+    /// let (value, warnings) = Options::default()
+    ///     .with_bool_aliases(&[("yes", true), ("no", false)])
+    ///     .from_str_with_warnings::<Vec<bool>>("[true, yes]")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value, [true, true]);
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].position.col, 8);
+    /// assert_eq!(
+    ///     warnings[0].kind,
+    ///     WarningKind::BoolAlias {
+    ///         alias: String::from("yes"),
+    ///         value: true,
+    ///     }
+    /// );
+    /// ```
+    pub fn from_str_with_warnings<'a, T>(&self, s: &'a str) -> SpannedResult<(T, Vec<Warning>)>
+    where
+        T: de::Deserialize<'a>,
+    {
+        let mut deserializer = Deserializer::from_str_with_options(s, self)?;
+        deserializer.warnings = Some(Vec::new());
+
+        let value = self.deserialize_seed(&mut deserializer, core::marker::PhantomData)?;
+
+        Ok((value, deserializer.warnings.unwrap_or_default()))
+    }
+
+    /// A convenience function for building a deserializer
+    /// and deserializing a value of type `T` from a string,
+    /// which also tracks the path to the erroneous value,
+    /// e.g. `config.servers[2].port`, if deserialization fails.
+    ///
+    /// This function is only available with the `serde_path_to_error`
+    /// feature.
+    ///
+    /// ```
+    /// # use serde::Deserialize;
+    /// #[derive(Debug, Deserialize)]
+    /// struct Config {
+    ///     servers: Vec<Server>,
+    /// }
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct Server {
+    ///     port: u16,
+    /// }
+    ///
+    /// let err = ron::Options::default()
+    ///     .from_str_traced::<Config>("(servers: [(port: 80), (port: -1)])")
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.path, "servers[1].port");
+    /// assert_eq!(err.error.code, ron::Error::IntegerOutOfBounds);
+    /// ```
+    #[cfg(feature = "serde_path_to_error")]
+    pub fn from_str_traced<'a, T>(&self, s: &'a str) -> core::result::Result<T, TracedError>
+    where
+        T: de::Deserialize<'a>,
+    {
+        let mut deserializer =
+            Deserializer::from_str_with_options(s, self).map_err(|error| TracedError {
+                error,
+                path: String::from("."),
+            })?;
+        let mut track = serde_path_to_error::Track::new();
+
+        let value = if self.unwrap_redundant_parens {
+            T::deserialize(serde_path_to_error::Deserializer::new(
+                RedundantParens::new(&mut deserializer),
+                &mut track,
+            ))
+        } else {
+            T::deserialize(serde_path_to_error::Deserializer::new(
+                &mut deserializer,
+                &mut track,
+            ))
+        }
+        .map_err(|e| TracedError {
+            error: deserializer.span_error(e),
+            path: track.path().to_string(),
+        })?;
+
+        deserializer.end().map_err(|e| TracedError {
+            error: deserializer.span_error(e),
+            path: String::from("."),
+        })?;
 
         Ok(value)
     }
@@ -172,15 +1005,36 @@ impl Options {
     /// A convenience function for building a deserializer
     /// and deserializing a value of type `T` from bytes
     /// and a seed.
+    ///
+    /// The seed is deserialized with these options, including their
+    /// extensions, just like [`Options::from_str`], and any trailing
+    /// characters after the value are rejected.
     pub fn from_bytes_seed<'a, S, T>(&self, s: &'a [u8], seed: S) -> SpannedResult<T>
     where
         S: de::DeserializeSeed<'a, Value = T>,
     {
         let mut deserializer = Deserializer::from_bytes_with_options(s, self)?;
 
-        let value = seed
-            .deserialize(&mut deserializer)
-            .map_err(|e| deserializer.span_error(e))?;
+        self.deserialize_seed(&mut deserializer, seed)
+    }
+
+    /// Deserializes the whole input of `deserializer` with `seed`, after
+    ///  peeling off redundant parentheses if
+    ///  [`Options::unwrap_redundant_parens`] is enabled.
+    fn deserialize_seed<'a, S, T>(
+        &self,
+        deserializer: &mut Deserializer<'a>,
+        seed: S,
+    ) -> SpannedResult<T>
+    where
+        S: de::DeserializeSeed<'a, Value = T>,
+    {
+        let value = if self.unwrap_redundant_parens {
+            seed.deserialize(RedundantParens::new(deserializer))
+        } else {
+            seed.deserialize(&mut *deserializer)
+        }
+        .map_err(|e| deserializer.span_error(e))?;
 
         deserializer.end().map_err(|e| deserializer.span_error(e))?;
 
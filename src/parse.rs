@@ -1,6 +1,14 @@
+//! Low-level lexing and parsing of RON, e.g. for syntax highlighting or
+//! streaming large documents.
+
 #![allow(clippy::identity_op)]
 
-use std::{
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
     char::from_u32 as char_from_u32,
     str::{self, from_utf8, FromStr, Utf8Error},
 };
@@ -8,11 +16,20 @@ use std::{
 use unicode_ident::{is_xid_continue, is_xid_start};
 
 use crate::{
-    error::{Error, Position, Result, SpannedError, SpannedResult},
+    error::{Error, Position, Result, SpannedError, SpannedResult, Warning, WarningKind},
     extensions::Extensions,
+    options::Options,
     value::Number,
 };
 
+pub use self::{
+    event::{events, Event, EventKind, Events},
+    token::{tokens, Token, TokenKind, Tokens},
+};
+
+mod event;
+mod token;
+
 const fn is_int_char(c: char) -> bool {
     c.is_ascii_hexdigit() || c == '_'
 }
@@ -21,15 +38,147 @@ const fn is_float_char(c: char) -> bool {
     c.is_ascii_digit() || matches!(c, 'e' | 'E' | '.' | '+' | '-' | '_')
 }
 
-pub fn is_ident_first_char(c: char) -> bool {
+/// Returns the index after the closing `quote` of a string or character
+/// literal whose content starts at `i`, skipping over escaped characters.
+fn skip_quoted(bytes: &[u8], mut i: usize, quote: u8) -> usize {
+    while let Some(&b) = bytes.get(i) {
+        match b {
+            b'\\' => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Returns the index after the closing `"` and `hashes` number of `#` of a
+/// raw string whose content starts at `i`.
+fn skip_raw_string(bytes: &[u8], mut i: usize, hashes: usize) -> usize {
+    while let Some(len) = bytes[i..].iter().position(|b| *b == b'"') {
+        i += len + 1;
+
+        if bytes[i..]
+            .iter()
+            .take(hashes)
+            .filter(|b| **b == b'#')
+            .count()
+            == hashes
+        {
+            return i + hashes;
+        }
+    }
+
+    bytes.len()
+}
+
+/// Returns the index after the end of a, possibly nested, block comment
+/// whose content starts at `i`.
+fn skip_block_comment(bytes: &[u8], mut i: usize) -> usize {
+    let mut level = 1_usize;
+
+    while level > 0 && i < bytes.len() {
+        if bytes[i..].starts_with(b"/*") {
+            level += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            level -= 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    i.min(bytes.len())
+}
+
+/// Lexes the longest prefix of `src` that is an optionally signed
+/// `float_num`, following the RON grammar. Returns the length of the prefix
+/// in bytes and whether it has a fractional part or an exponent, i.e.
+/// whether it must be a float.
+///
+/// Digits in the integer, fractional, and exponent parts may be separated
+/// by underscores, but the integer and fractional parts must start with a
+/// digit, e.g. `1_0.1_0e+1_0` is a valid float.
+fn lex_float_num(src: &str) -> (usize, bool) {
+    let bytes = src.as_bytes();
+
+    let is_digit = |i: usize| matches!(bytes.get(i), Some(b'0'..=b'9'));
+    let digits_len = |i: usize| {
+        bytes[i..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit() || **b == b'_')
+            .count()
+    };
+
+    let mut len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let mut is_float = false;
+
+    let has_int = is_digit(len);
+    if has_int {
+        len += digits_len(len);
+    }
+
+    if bytes.get(len) == Some(&b'.') {
+        let has_frac = is_digit(len + 1);
+
+        if has_int || has_frac {
+            len += 1 + if has_frac { digits_len(len + 1) } else { 0 };
+            is_float = true;
+        }
+    }
+
+    if !has_int && !is_float {
+        return (0, false);
+    }
+
+    if matches!(bytes.get(len), Some(b'e' | b'E')) {
+        let exp_start = len + 1 + usize::from(matches!(bytes.get(len + 1), Some(b'+' | b'-')));
+        let exp_len = digits_len(exp_start);
+
+        if bytes[exp_start..exp_start + exp_len]
+            .iter()
+            .any(u8::is_ascii_digit)
+        {
+            len = exp_start + exp_len;
+            is_float = true;
+        }
+    }
+
+    (len, is_float)
+}
+
+/// Checks if `src` ends with a number that was cut off in its exponent,
+/// e.g. `1e` or `[1.5e-`, which may still become valid with more input.
+pub(crate) fn ends_with_truncated_exponent(src: &str) -> bool {
+    let number = src
+        .rfind(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '-')))
+        .map_or(src, |i| &src[i + 1..]);
+
+    let (len, _) = lex_float_num(number);
+
+    // the lexed number must not have an exponent already, e.g. `1e1e`
+    len > 0
+        && !number[..len].contains(['e', 'E'])
+        && matches!(&number[len..], "e" | "E" | "e+" | "e-" | "E+" | "E-")
+}
+
+/// Words that would be parsed as a literal instead of an identifier, and
+/// must therefore be written as raw identifiers, e.g. `r#true`, when used
+/// as a struct name, field name, or enum variant name
+pub(crate) const RESERVED_IDENTIFIERS: &[&str] = &[
+    "true", "false", "Some", "None", "inf", "inff32", "inff64", "NaN", "NaNf32", "NaNf64",
+];
+
+pub(crate) fn is_ident_first_char(c: char) -> bool {
     c == '_' || is_xid_start(c)
 }
 
-pub fn is_ident_raw_char(c: char) -> bool {
+pub(crate) fn is_ident_raw_char(c: char) -> bool {
     matches!(c, '.' | '+' | '-') | is_xid_continue(c)
 }
 
-pub const fn is_whitespace_char(c: char) -> bool {
+pub(crate) const fn is_whitespace_char(c: char) -> bool {
     matches!(
         c,
         ' ' | '\t'
@@ -54,15 +203,20 @@ pub(crate) type LargeSInt = i128;
 #[cfg(not(feature = "integer128"))]
 pub(crate) type LargeSInt = i64;
 
-pub struct Parser<'a> {
+#[derive(Clone)] // GRCOV_EXCL_LINE
+pub(crate) struct Parser<'a> {
     /// Bits set according to the [`Extensions`] enum.
     pub exts: Extensions,
     src: &'a str,
     cursor: ParserCursor,
+    /// See [`Options::max_string_length`]
+    max_string_length: Option<usize>,
+    /// See [`Options::normalize_newlines`]
+    normalize_newlines: bool,
 }
 
 #[derive(Copy, Clone)] // GRCOV_EXCL_LINE
-pub struct ParserCursor {
+pub(crate) struct ParserCursor {
     cursor: usize,
     pre_ws_cursor: usize,
     last_ws_len: usize,
@@ -77,29 +231,51 @@ impl PartialEq for ParserCursor {
 }
 
 impl PartialOrd for ParserCursor {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.cursor.partial_cmp(&other.cursor)
     }
 }
 
 /// constructor and parsing utilities
 impl<'a> Parser<'a> {
+    #[cfg(test)]
     pub fn new(src: &'a str) -> SpannedResult<Self> {
-        let mut parser = Parser {
-            exts: Extensions::empty(),
-            src,
-            cursor: ParserCursor {
-                cursor: 0,
-                pre_ws_cursor: 0,
-                last_ws_len: 0,
-            },
-        };
+        Self::new_with_options(src, &Options::default())
+    }
+
+    /// Creates a new [`Parser`], which skips a leading UTF-8 byte order mark
+    ///  and, if [`Options::allow_shebang`] is set, a first line starting with
+    ///  `#!` that is not an inner attribute like `#![enable(...)]`.
+    ///
+    /// Extensions enabled by repeated attributes are combined, unless
+    ///  [`Options::strict_header`] is set, in which case enabling an
+    ///  extension twice is an error.
+    pub fn new_with_options(src: &'a str, options: &Options) -> SpannedResult<Self> {
+        let mut parser = Self::new_raw(src);
+        parser.max_string_length = options.max_string_length;
+        parser.normalize_newlines = options.normalize_newlines;
+
+        parser.consume_char('\u{FEFF}');
+
+        if options.allow_shebang {
+            parser.skip_shebang();
+        }
 
         parser.skip_ws().map_err(|e| parser.span_error(e))?;
 
+        if !options.extension_header {
+            return if parser.check_char('#') {
+                Err(parser.span_error(Error::UnexpectedAttribute))
+            } else {
+                Ok(parser)
+            };
+        }
+
         // Loop over all extensions attributes
         loop {
-            let attribute = parser.extensions().map_err(|e| parser.span_error(e))?;
+            let attribute = parser
+                .extensions(options.strict_header)
+                .map_err(|e| parser.span_error(e))?;
 
             if attribute.is_empty() {
                 break;
@@ -112,7 +288,45 @@ impl<'a> Parser<'a> {
         Ok(parser)
     }
 
-    fn set_cursor(&mut self, cursor: ParserCursor) {
+    /// Creates a new [`Parser`] at the very start of `src`, which does not
+    ///  skip anything and has no extensions enabled.
+    fn new_raw(src: &'a str) -> Self {
+        Parser {
+            exts: Extensions::empty(),
+            src,
+            cursor: ParserCursor {
+                cursor: 0,
+                pre_ws_cursor: 0,
+                last_ws_len: 0,
+            },
+            max_string_length: None,
+            normalize_newlines: false,
+        }
+    }
+
+    fn skip_shebang(&mut self) {
+        let rest = match self.src().strip_prefix("#!") {
+            Some(rest) => rest,
+            None => return,
+        };
+
+        // `#![enable(...)]` and `#! [enable(...)]` are attributes, not shebangs
+        if rest
+            .trim_start_matches(|c| c != '\n' && is_whitespace_char(c))
+            .starts_with('[')
+        {
+            return;
+        }
+
+        let line_len = self.src().find('\n').map_or(self.src().len(), |i| i + 1);
+        self.advance_bytes(line_len);
+    }
+
+    pub fn cursor(&self) -> ParserCursor {
+        self.cursor
+    }
+
+    pub fn set_cursor(&mut self, cursor: ParserCursor) {
         self.cursor = cursor;
     }
 
@@ -123,6 +337,13 @@ impl<'a> Parser<'a> {
         }
     }
 
+    pub fn span_warning(&self, kind: WarningKind, cursor: ParserCursor) -> Warning {
+        Warning {
+            kind,
+            position: Position::from_src_end(&self.src[..cursor.cursor]),
+        }
+    }
+
     pub fn advance_bytes(&mut self, bytes: usize) {
         self.cursor.cursor += bytes;
     }
@@ -134,7 +355,7 @@ impl<'a> Parser<'a> {
     }
 
     pub fn skip_next_char(&mut self) {
-        std::mem::drop(self.next_char());
+        core::mem::drop(self.next_char());
     }
 
     pub fn peek_char(&self) -> Option<char> {
@@ -285,6 +506,19 @@ impl<'a> Parser<'a> {
     pub fn integer<T: Integer>(&mut self) -> Result<T> {
         let src_backup = self.src();
 
+        // Report a float, e.g. `1.5` or `1f32`, as a type mismatch
+        if self.next_bytes_is_float_literal() {
+            let float_cursor = self.cursor;
+
+            if let Ok(parsed) = self.float::<ParsedFloat>() {
+                let float_ron = &src_backup[..src_backup.len() - self.src().len()];
+
+                return T::try_from_parsed_float(parsed, float_ron);
+            }
+
+            self.set_cursor(float_cursor);
+        }
+
         let is_negative = match self.peek_char_or_eof()? {
             '+' => {
                 self.skip_next_char();
@@ -544,6 +778,27 @@ impl<'a> Parser<'a> {
     /// [`TupleMode::DifferentiateNewtype`] performs an expensive, O(N), look-
     ///  ahead over the entire next value tree, which can span the entirety of
     ///  the remaining document in the worst case.
+    /// Checks whether the next value is a parenthesised struct, optionally
+    /// preceded by its name, e.g. `(a: 1)` or `S(a: 1)`, which cannot be the
+    /// unwrapped fields of a struct inside a newtype variant
+    pub fn check_wrapped_struct(&mut self) -> Result<bool> {
+        if self.check_char('(') {
+            return Ok(true);
+        }
+
+        let backup_cursor = self.cursor;
+
+        let result = if self.skip_identifier().is_some() {
+            self.skip_ws().map(|()| self.check_char('('))
+        } else {
+            Ok(false)
+        };
+
+        self.set_cursor(backup_cursor);
+
+        result
+    }
+
     pub fn check_struct_type(
         &mut self,
         newtype: NewtypeMode,
@@ -567,12 +822,14 @@ impl<'a> Parser<'a> {
                 return Ok(StructType::EmptyTuple);
             }
 
-            if parser.skip_identifier().is_some() {
+            if parser.skip_identifier().is_some() || parser.skip_quoted_key() {
                 parser.skip_ws()?;
 
                 match parser.peek_char() {
                     // Definitely a struct with named fields
-                    Some(':') => return Ok(StructType::Named),
+                    Some(':' | '=') if parser.check_field_separator() => {
+                        return Ok(StructType::Named)
+                    }
                     // Definitely a tuple-like struct with fields
                     Some(',') => {
                         parser.skip_next_char();
@@ -666,6 +923,14 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Checks whether the next value looks like a JSON object, i.e. a map
+    /// whose first key is a string, e.g. `{"a": 1}`
+    pub fn check_json_object(&self) -> bool {
+        self.src().strip_prefix('{').map_or(false, |rest| {
+            rest.trim_start_matches(is_whitespace_char).starts_with('"')
+        })
+    }
+
     pub fn consume_struct_name(&mut self, ident: &'static str) -> Result<bool> {
         if self.check_ident("") {
             if self.exts.contains(Extensions::EXPLICIT_STRUCT_NAMES) {
@@ -697,8 +962,9 @@ impl<'a> Parser<'a> {
         Ok(true)
     }
 
-    /// Returns the extensions bit mask.
-    fn extensions(&mut self) -> Result<Extensions> {
+    /// Returns the extensions bit mask. If `strict` is set, extensions that
+    ///  are already enabled cannot be enabled again.
+    fn extensions(&mut self, strict: bool) -> Result<Extensions> {
         if !self.check_char('#') {
             return Ok(Extensions::empty());
         }
@@ -715,6 +981,10 @@ impl<'a> Parser<'a> {
             let extension = Extensions::from_ident(ident)
                 .ok_or_else(|| Error::NoSuchExtension(ident.into()))?;
 
+            if strict && (self.exts | extensions).contains(extension) {
+                return Err(Error::DuplicateExtension(ident.into()));
+            }
+
             extensions |= extension;
 
             let comma = self.comma()?;
@@ -805,6 +1075,10 @@ impl<'a> Parser<'a> {
             f.push(c);
         }
 
+        if lex_float_num(&self.src()[..num_bytes]).0 != num_bytes {
+            return Err(Error::ExpectedFloat);
+        }
+
         if self.src()[num_bytes..].starts_with('f') {
             let backup_cursor = self.cursor;
             self.advance_bytes(num_bytes);
@@ -841,6 +1115,100 @@ impl<'a> Parser<'a> {
         Ok(value)
     }
 
+    /// Checks whether the next character separates a struct field name from
+    /// its value, i.e. a `:`, or a single `=` with the
+    /// [`Extensions::EQUALS_FIELDS`] extension.
+    pub fn check_field_separator(&self) -> bool {
+        self.check_char(':') || self.check_field_equals()
+    }
+
+    /// Consumes a single `=` struct field separator, which is only accepted
+    /// with the [`Extensions::EQUALS_FIELDS`] extension.
+    pub fn consume_field_equals(&mut self) -> bool {
+        if self.check_field_equals() {
+            self.advance_bytes(1);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks for a single `=`, which must not start an operator like `=>`
+    /// or `==`.
+    fn check_field_equals(&self) -> bool {
+        self.exts.contains(Extensions::EQUALS_FIELDS)
+            && self.check_char('=')
+            && !self.check_str("=>")
+            && !self.check_str("==")
+    }
+
+    /// Checks whether the next field of a struct-like is named, e.g. `a: 1`
+    /// or `"a": 1`, without consuming it.
+    pub fn check_named_field(&mut self) -> bool {
+        let backup_cursor = self.cursor;
+
+        let named = self.skip_quoted_key()
+            || (self.skip_identifier().is_some()
+                && self.skip_ws().is_ok()
+                && self.check_field_separator());
+
+        self.set_cursor(backup_cursor);
+
+        named
+    }
+
+    /// Checks whether the next field of a struct-like is positional, e.g.
+    /// `1` or `A` in `(1, A)`, without consuming it. Anything that is
+    /// neither clearly named nor clearly positional, e.g. `a 1`, is not
+    /// positional.
+    pub fn check_positional_field(&mut self) -> bool {
+        let backup_cursor = self.cursor;
+
+        let positional = if self.skip_quoted_key() {
+            false
+        } else if self.skip_identifier().is_some() {
+            // e.g. a unit variant `A,` or an enum variant `A(...)`
+            self.skip_ws().is_ok() && matches!(self.peek_char(), Some(',' | ')' | '('))
+        } else {
+            match self.peek_char() {
+                // e.g. a number, character, or nested value
+                Some('0'..='9' | '+' | '-' | '.' | '\'' | '(' | '[' | '{') => true,
+                // a (raw) (byte) string, but only if it is complete, since
+                //  e.g. `b"` may also be a mistyped field name
+                Some('"' | 'b' | 'r') => {
+                    self.string().is_ok() || {
+                        self.set_cursor(backup_cursor);
+                        self.byte_string_no_base64().is_ok()
+                    }
+                }
+                _ => false,
+            }
+        };
+
+        self.set_cursor(backup_cursor);
+
+        positional
+    }
+
+    /// Skips a quoted struct field name, e.g. `"a"`, if it is followed by a
+    /// `:`. Returns `false` and keeps the cursor in place otherwise.
+    fn skip_quoted_key(&mut self) -> bool {
+        if !self.check_char('"') {
+            return false;
+        }
+
+        let backup_cursor = self.cursor;
+
+        if self.string().is_ok() && self.skip_ws().is_ok() && self.check_field_separator() {
+            return true;
+        }
+
+        self.set_cursor(backup_cursor);
+
+        false
+    }
+
     pub fn skip_identifier(&mut self) -> Option<&'a str> {
         #[allow(clippy::nonminimal_bool)]
         if self.check_str("b\"") // byte string
@@ -881,6 +1249,7 @@ impl<'a> Parser<'a> {
     }
 
     pub fn identifier(&mut self) -> Result<&'a str> {
+        let start = self.cursor;
         let first = self.peek_char_or_eof()?;
         if !is_ident_first_char(first) {
             if is_ident_raw_char(first) {
@@ -946,13 +1315,42 @@ impl<'a> Parser<'a> {
             std_ident_length
         };
 
+        if let Err(err) = self.check_string_length(length) {
+            self.set_cursor(start);
+            return Err(err);
+        }
+
         let ident = &self.src()[..length];
         self.advance_bytes(length);
 
         Ok(ident)
     }
 
+    /// Checks whether the next bytes are a well-formed float with a
+    /// fractional part, an exponent, or a float suffix, e.g. `1.0` or `1f32`.
+    fn next_bytes_is_float_literal(&self) -> bool {
+        let (float_len, is_float) = lex_float_num(self.src());
+
+        if is_float {
+            return true;
+        }
+
+        float_len > 0
+            && ["f32", "f64"].iter().any(|suffix| {
+                self.src()[float_len..]
+                    .strip_prefix(suffix)
+                    .map_or(false, |rest| {
+                        !rest.chars().next().map_or(false, is_xid_continue)
+                    })
+            })
+    }
+
     pub fn next_bytes_is_float(&mut self) -> bool {
+        if lex_float_num(self.src()).1 {
+            return true;
+        }
+
+        // Malformed floats, e.g. `1e+`, should still produce float errors
         if let Some(c) = self.peek_char() {
             let skip = match c {
                 '+' | '-' => 1,
@@ -1000,7 +1398,83 @@ impl<'a> Parser<'a> {
         self.src().is_empty() && self.cursor.last_ws_len == WS_CURSOR_UNCLOSED_LINE
     }
 
+    /// Counts the remaining comma-separated elements of the sequence, tuple,
+    /// map, or struct whose opening delimiter has already been consumed.
+    ///
+    /// This is a lightweight forward scan that does not move the cursor: it
+    /// only tracks the nesting of delimiters and skips over strings, raw
+    /// strings, characters, and comments without parsing them. The count
+    /// is therefore only an estimate for invalid RON.
+    pub fn count_elements(&self) -> usize {
+        let bytes = self.src().as_bytes();
+
+        let mut i = 0;
+        let mut depth = 0_usize;
+        let mut count = 0;
+        let mut has_element = false;
+
+        while let Some(&b) = bytes.get(i) {
+            i += 1;
+
+            match b {
+                b'(' | b'[' | b'{' => {
+                    depth += 1;
+                    has_element = true;
+                }
+                b')' | b']' | b'}' if depth == 0 => break,
+                b')' | b']' | b'}' => depth -= 1,
+                b',' if depth == 0 => {
+                    count += 1;
+                    has_element = false;
+                }
+                b'"' | b'\'' => {
+                    i = skip_quoted(bytes, i, b);
+                    has_element = true;
+                }
+                b'/' if bytes.get(i) == Some(&b'/') => {
+                    i = bytes[i..]
+                        .iter()
+                        .position(|b| *b == b'\n')
+                        .map_or(bytes.len(), |len| i + len);
+                }
+                b'/' if bytes.get(i) == Some(&b'*') => {
+                    i = skip_block_comment(bytes, i + 1);
+                }
+                b if b.is_ascii_whitespace() => (),
+                b if b == b'_' || b.is_ascii_alphabetic() => {
+                    let start = i - 1;
+                    i += bytes[i..]
+                        .iter()
+                        .take_while(|b| **b == b'_' || b.is_ascii_alphanumeric())
+                        .count();
+
+                    // Raw strings, e.g. `r#"..."#` or `br"..."`, may contain
+                    //  unbalanced delimiters and unescaped quotes
+                    if matches!(&bytes[start..i], b"r" | b"br") {
+                        let hashes = bytes[i..].iter().take_while(|b| **b == b'#').count();
+
+                        if bytes.get(i + hashes) == Some(&b'"') {
+                            i = skip_raw_string(bytes, i + hashes + 1, hashes);
+                        }
+                    }
+
+                    has_element = true;
+                }
+                _ => has_element = true,
+            }
+        }
+
+        count + usize::from(has_element)
+    }
+
     pub fn byte_string(&mut self) -> Result<ParsedByteStr<'a>> {
+        let start = self.cursor;
+
+        self.any_byte_string()
+            .map_err(|err| self.string_length_error(err, start))
+    }
+
+    fn any_byte_string(&mut self) -> Result<ParsedByteStr<'a>> {
         fn expected_byte_string_found_base64(
             base64_str: &ParsedStr,
             byte_str: &ParsedByteStr,
@@ -1010,7 +1484,7 @@ impl<'a> Parser<'a> {
                 ParsedByteStr::Slice(b) => b,
             }
             .iter()
-            .flat_map(|c| std::ascii::escape_default(*c))
+            .flat_map(|c| core::ascii::escape_default(*c))
             .map(char::from)
             .collect::<String>();
             let base64_str = match &base64_str {
@@ -1055,11 +1529,18 @@ impl<'a> Parser<'a> {
                 }
             }
         } else {
-            self.byte_string_no_base64()
+            self.rusty_byte_string()
         }
     }
 
     pub fn byte_string_no_base64(&mut self) -> Result<ParsedByteStr<'a>> {
+        let start = self.cursor;
+
+        self.rusty_byte_string()
+            .map_err(|err| self.string_length_error(err, start))
+    }
+
+    fn rusty_byte_string(&mut self) -> Result<ParsedByteStr<'a>> {
         if self.consume_str("b\"") {
             self.escaped_byte_string()
         } else if self.consume_str("br") {
@@ -1091,6 +1572,13 @@ impl<'a> Parser<'a> {
     }
 
     pub fn string(&mut self) -> Result<ParsedStr<'a>> {
+        let start = self.cursor;
+
+        self.any_string()
+            .map_err(|err| self.string_length_error(err, start))
+    }
+
+    fn any_string(&mut self) -> Result<ParsedStr<'a>> {
         if self.consume_char('"') {
             self.escaped_string()
         } else if self.consume_char('r') {
@@ -1130,21 +1618,25 @@ impl<'a> Parser<'a> {
         if let Some(escape) = escape {
             // Now check if escaping is used inside the string
             let mut i = escape;
-            let mut s = self.src().as_bytes()[..i].to_vec();
+            self.check_string_length(i)?;
+            let mut s = Vec::with_capacity(i);
+            self.push_literal(&mut s, &self.src().as_bytes()[..i]);
 
             loop {
                 self.advance_bytes(i + 1);
 
-                match self.parse_escape(encoding, false)? {
-                    EscapeCharacter::Ascii(c) => s.push(c),
-                    EscapeCharacter::Utf8(c) => match c.len_utf8() {
-                        1 => s.push(c as u8),
-                        len => {
-                            let start = s.len();
-                            s.extend(std::iter::repeat(0).take(len));
-                            c.encode_utf8(&mut s[start..]);
-                        }
-                    },
+                if !self.consume_line_continuation() {
+                    match self.parse_escape(encoding, false)? {
+                        EscapeCharacter::Ascii(c) => s.push(c),
+                        EscapeCharacter::Utf8(c) => match c.len_utf8() {
+                            1 => s.push(c as u8),
+                            len => {
+                                let start = s.len();
+                                s.extend(core::iter::repeat(0).take(len));
+                                c.encode_utf8(&mut s[start..]);
+                            }
+                        },
+                    }
                 }
 
                 // Checking for '"' and '\\' separately is faster than searching for both at the same time
@@ -1152,19 +1644,22 @@ impl<'a> Parser<'a> {
                 let new_escape = self.src()[..new_str_end].find('\\');
 
                 if let Some(new_escape) = new_escape {
-                    s.extend_from_slice(&self.src().as_bytes()[..new_escape]);
+                    self.check_string_length(s.len() + new_escape)?;
+                    self.push_literal(&mut s, &self.src().as_bytes()[..new_escape]);
                     i = new_escape;
                 } else {
-                    s.extend_from_slice(&self.src().as_bytes()[..new_str_end]);
+                    self.check_string_length(s.len() + new_str_end)?;
+                    self.push_literal(&mut s, &self.src().as_bytes()[..new_str_end]);
                     // Advance to the end of the string + 1 for the `"`.
                     break Ok((ParsedByteStr::Allocated(s), new_str_end + 1));
                 }
             }
         } else {
+            self.check_string_length(str_end)?;
             let s = &self.src().as_bytes()[..str_end];
 
             // Advance by the number of bytes of the string + 1 for the `"`.
-            Ok((ParsedByteStr::Slice(s), str_end + 1))
+            Ok((self.literal(s), str_end + 1))
         }
     }
 
@@ -1177,12 +1672,81 @@ impl<'a> Parser<'a> {
 
         let ending = ["\"", hashes].concat();
         let i = self.src().find(&ending).ok_or(Error::ExpectedStringEnd)?;
+        self.check_string_length(i)?;
 
         let s = &self.src().as_bytes()[..i];
 
         // Advance by the number of bytes of the byte string
         // + `num_hashes` + 1 for the `"`.
-        Ok((ParsedByteStr::Slice(s), i + num_hashes + 1))
+        Ok((self.literal(s), i + num_hashes + 1))
+    }
+
+    /// Borrows the unescaped `literal` contents of a string or byte string,
+    ///  unless its newlines must be normalised, see [`Parser::push_literal`]
+    fn literal(&self, literal: &'a [u8]) -> ParsedByteStr<'a> {
+        if self.normalize_newlines && literal.contains(&b'\r') {
+            let mut s = Vec::with_capacity(literal.len());
+            self.push_literal(&mut s, literal);
+            ParsedByteStr::Allocated(s)
+        } else {
+            ParsedByteStr::Slice(literal)
+        }
+    }
+
+    /// Appends the unescaped `literal` contents of a string or byte string
+    ///  to `s`, converting `\r\n` and lone `\r` newlines to `\n` if
+    ///  [`Options::normalize_newlines`] is set
+    fn push_literal(&self, s: &mut Vec<u8>, literal: &[u8]) {
+        if !self.normalize_newlines {
+            s.extend_from_slice(literal);
+            return;
+        }
+
+        let mut bytes = literal.iter().copied().peekable();
+
+        while let Some(byte) = bytes.next() {
+            if byte == b'\r' {
+                bytes.next_if_eq(&b'\n');
+                s.push(b'\n');
+            } else {
+                s.push(byte);
+            }
+        }
+    }
+
+    /// Checks that a string, byte string, or identifier of `len` bytes does
+    ///  not exceed the [`Options::max_string_length`]
+    pub fn check_string_length(&self, len: usize) -> Result<()> {
+        match self.max_string_length {
+            Some(max_string_length) if len > max_string_length => {
+                Err(Error::ExceededStringLengthLimit)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Moves back to the `start` of a string or byte string that exceeded
+    ///  the [`Options::max_string_length`], such that the `err` points at it
+    fn string_length_error(&mut self, err: Error, start: ParserCursor) -> Error {
+        if let Error::ExceededStringLengthLimit = err {
+            self.set_cursor(start);
+        }
+
+        err
+    }
+
+    /// Skips a line continuation after a `\` in a string, i.e. a `\n` or
+    ///  `\r\n` newline together with all following spaces, tabs, and
+    ///  newlines, such that the string continues at the first other
+    ///  character of the next non-blank line
+    fn consume_line_continuation(&mut self) -> bool {
+        if !self.consume_char('\n') && !self.consume_str("\r\n") {
+            return false;
+        }
+
+        self.advance_bytes(self.next_chars_while_len(|c| matches!(c, ' ' | '\t' | '\n' | '\r')));
+
+        true
     }
 
     fn decode_ascii_escape(&mut self) -> Result<u8> {
@@ -1212,6 +1776,23 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Decodes the four hex digits of a fixed-width Unicode escape, e.g.
+    ///  `\u200b`, into a UTF-16 code unit
+    fn decode_fixed_unicode_escape(&mut self) -> Result<u16> {
+        let mut unit: u16 = 0;
+
+        for _ in 0..4 {
+            let digit = self.next_char().map_err(|_| {
+                Error::InvalidEscape("Expected 4 digits in fixed-width Unicode escape")
+            })?;
+            let digit = Self::decode_hex(digit)?;
+
+            unit = (unit << 4) | u16::from(digit);
+        }
+
+        Ok(unit)
+    }
+
     fn parse_escape(&mut self, encoding: EscapeEncoding, is_char: bool) -> Result<EscapeCharacter> {
         let c = match self.next_char()? {
             '\'' => EscapeCharacter::Ascii(b'\''),
@@ -1263,6 +1844,36 @@ impl<'a> Parser<'a> {
                     "Not a valid byte-escaped Unicode character",
                 ));
             }
+            'u' if !self.check_char('{') => {
+                // JSON-like fixed-width escape, e.g. `\u200b` or `\ud83d\ude00`
+                let high = self.decode_fixed_unicode_escape()?;
+
+                let c = if (0xD800..=0xDBFF).contains(&high) {
+                    if !self.consume_str(r"\u") {
+                        return Err(Error::InvalidEscape(
+                            "Expected a low surrogate after a high surrogate in Unicode escape",
+                        ));
+                    }
+
+                    let low = self.decode_fixed_unicode_escape()?;
+
+                    if !(0xDC00..=0xDFFF).contains(&low) {
+                        return Err(Error::InvalidEscape(
+                            "Expected a low surrogate after a high surrogate in Unicode escape",
+                        ));
+                    }
+
+                    0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00)
+                } else {
+                    u32::from(high)
+                };
+
+                let c = char_from_u32(c).ok_or(Error::InvalidEscape(
+                    "Not a valid Unicode-escaped character",
+                ))?;
+
+                EscapeCharacter::Utf8(c)
+            }
             'u' => {
                 self.expect_char('{', Error::InvalidEscape("Missing { in Unicode escape"))?;
 
@@ -1358,7 +1969,7 @@ enum Comment {
     Block,
 }
 
-pub trait Num {
+pub(crate) trait Num {
     fn from_u8(x: u8) -> Self;
 
     /// Returns `true` on overflow
@@ -1419,10 +2030,22 @@ impl_num! { i8 i16 i32 i64 u8 u16 u32 u64 }
 #[cfg(feature = "integer128")]
 impl_num! { i128 u128 }
 
-pub trait Integer: Sized {
+pub(crate) trait Integer: Sized {
     fn parse(parser: &mut Parser, sign: i8) -> Result<Self>;
 
     fn try_from_parsed_integer(parsed: ParsedInteger, ron: &str) -> Result<Self>;
+
+    fn try_from_parsed_float(parsed: ParsedFloat, ron: &str) -> Result<Self>;
+}
+
+/// Describes the expected integer type in an [`Error::InvalidValueForType`]
+fn expected_integer(bits: u32, unsigned: bool) -> String {
+    format!(
+        "a{} {}-bit {}signed integer",
+        if bits == 8 { "n" } else { "" },
+        bits,
+        if unsigned { "un" } else { "" },
+    )
 }
 
 macro_rules! impl_integer {
@@ -1436,16 +2059,18 @@ macro_rules! impl_integer {
                 match parsed {
                     ParsedInteger::$wrap(v) => Ok(v),
                     _ => Err(Error::InvalidValueForType {
-                        expected: format!(
-                            "a{} {}-bit {}signed integer",
-                            if <$ty>::BITS == 8 { "n" } else { "n" },
-                            <$ty>::BITS,
-                            if <$ty>::MIN == 0 { "un" } else { "" },
-                        ),
+                        expected: expected_integer(<$ty>::BITS, <$ty>::MIN == 0),
                         found: String::from(ron),
                     }),
                 }
             }
+
+            fn try_from_parsed_float(_parsed: ParsedFloat, ron: &str) -> Result<Self> {
+                Err(Error::InvalidValueForType {
+                    expected: expected_integer(<$ty>::BITS, <$ty>::MIN == 0),
+                    found: String::from(ron),
+                })
+            }
         }
     };
     ($($wraps:ident($tys:ty))*) => {
@@ -1461,7 +2086,7 @@ impl_integer! {
 #[cfg(feature = "integer128")]
 impl_integer! { I128(i128) U128(u128) }
 
-pub enum ParsedInteger {
+pub(crate) enum ParsedInteger {
     I8(i8),
     I16(i16),
     I32(i32),
@@ -1526,9 +2151,14 @@ impl Integer for ParsedInteger {
     fn try_from_parsed_integer(parsed: ParsedInteger, _ron: &str) -> Result<Self> {
         Ok(parsed)
     }
+
+    fn try_from_parsed_float(_parsed: ParsedFloat, _ron: &str) -> Result<Self> {
+        // Floats are parsed as a fallback by `Parser::any_number`
+        Err(Error::ExpectedInteger)
+    }
 }
 
-pub trait Float: Sized {
+pub(crate) trait Float: Sized {
     fn parse(float: &str) -> Result<Self>;
 
     fn try_from_parsed_float(parsed: ParsedFloat, ron: &str) -> Result<Self>;
@@ -1561,7 +2191,7 @@ macro_rules! impl_float {
 
 impl_float! { F32(f32: 32) F64(f64: 64) }
 
-pub enum ParsedFloat {
+pub(crate) enum ParsedFloat {
     F32(f32),
     F64(f64),
 }
@@ -1583,7 +2213,7 @@ impl Float for ParsedFloat {
     }
 }
 
-pub enum StructType {
+pub(crate) enum StructType {
     AnyTuple,
     EmptyTuple,
     NewtypeTuple,
@@ -1593,23 +2223,23 @@ pub enum StructType {
 }
 
 #[derive(Copy, Clone)] // GRCOV_EXCL_LINE
-pub enum NewtypeMode {
+pub(crate) enum NewtypeMode {
     NoParensMeanUnit,
     InsideNewtype,
 }
 
 #[derive(Copy, Clone)] // GRCOV_EXCL_LINE
-pub enum TupleMode {
+pub(crate) enum TupleMode {
     ImpreciseTupleOrNewtype,
     DifferentiateNewtype,
 }
 
-pub enum ParsedStr<'a> {
+pub(crate) enum ParsedStr<'a> {
     Allocated(String),
     Slice(&'a str),
 }
 
-pub enum ParsedByteStr<'a> {
+pub(crate) enum ParsedByteStr<'a> {
     Allocated(Vec<u8>),
     Slice(&'a [u8]),
 }
@@ -1781,7 +2411,11 @@ mod tests {
                     expected: String::from("the Rusty byte string b\"Hello ron!\""),
                     found: String::from("the ambiguous base64 string \"SGVsbG8gcm9uIQ==\"")
                 },
-                position: Position { line: 1, col: 19 },
+                position: Position {
+                    line: 1,
+                    col: 19,
+                    offset: 18
+                },
             }
         );
 
@@ -1793,7 +2427,11 @@ mod tests {
             crate::from_str::<bytes::Bytes>("\"invalid=\"").unwrap_err(),
             SpannedError {
                 code: Error::ExpectedByteString,
-                position: Position { line: 1, col: 11 },
+                position: Position {
+                    line: 1,
+                    col: 11,
+                    offset: 10
+                },
             }
         );
 
@@ -1801,7 +2439,11 @@ mod tests {
             crate::from_str::<bytes::Bytes>("r\"invalid=\"").unwrap_err(),
             SpannedError {
                 code: Error::ExpectedByteString,
-                position: Position { line: 1, col: 12 },
+                position: Position {
+                    line: 1,
+                    col: 12,
+                    offset: 11
+                },
             }
         );
     }
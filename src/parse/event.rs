@@ -0,0 +1,415 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::Range;
+
+use super::{NewtypeMode, ParsedByteStr, ParsedStr, Parser, StructType, TupleMode};
+use crate::{
+    error::{Error, Result, SpannedError},
+    extensions::Extensions,
+    options::Options,
+    value::Value,
+};
+
+/// The kind of an [`Event`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventKind {
+    /// The start of a struct with named fields, e.g. `(` or `Point(`, whose
+    ///  values are each preceded by an [`EventKind::Field`].
+    StructStart { name: Option<String> },
+    /// The start of a tuple, tuple struct, or newtype, e.g. `(` or `Point(`.
+    TupleStart { name: Option<String> },
+    /// The start of a list, i.e. `[`.
+    SeqStart,
+    /// The start of a map, i.e. `{`, whose keys and values alternate.
+    MapStart,
+    /// The start of an option that is `Some`, i.e. `Some(`, which contains
+    ///  exactly one value.
+    SomeStart,
+    /// The name of the next field of a struct, e.g. `x` in `x: 1`.
+    Field(String),
+    /// A value without contents, e.g. `42`, `"a"`, `true`, or `None`. Both
+    ///  `()` and unit structs or variants, e.g. `Unit`, are a
+    ///  [`Value::Unit`], whose name can be read from the event's span.
+    Scalar(Value),
+    /// The end of the innermost struct, tuple, list, map, or option.
+    End,
+}
+
+/// A single event of a RON document, see [`events`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// The kind of the event.
+    pub kind: EventKind,
+    /// The byte range of the event in the source.
+    pub span: Range<usize>,
+}
+
+/// Parses a RON document into a stream of [`Event`]s, e.g. to process a
+/// large document without building a [`Value`] for all of it.
+///
+/// The kinds of values are inferred from their syntax alone, following the
+/// same rules as when deserializing a [`Value`]. Extensions can be enabled
+/// with `#![enable(...)]` attributes at the start of the document. After
+/// the first error, e.g. a missing comma or trailing characters after the
+/// document's value, the iterator yields the error and then ends.
+///
+/// # Examples
+///
+/// ```
+/// use ron::{
+///     parse::{events, EventKind},
+///     Value,
+/// };
+///
+/// let kinds = events("Point(x: 1, tags: [\"a\"])")
+///     .map(|event| event.map(|event| event.kind))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(
+///     kinds,
+///     [
+///         EventKind::StructStart {
+///             name: Some(String::from("Point"))
+///         },
+///         EventKind::Field(String::from("x")),
+///         EventKind::Scalar(Value::from(1_u8)),
+///         EventKind::Field(String::from("tags")),
+///         EventKind::SeqStart,
+///         EventKind::Scalar(Value::from("a")),
+///         EventKind::End,
+///         EventKind::End,
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn events(src: &str) -> Events<'_> {
+    let (parser, error) = match Parser::new_with_options(src, &Options::default()) {
+        Ok(parser) => (parser, None),
+        Err(err) => (Parser::new_raw(src), Some(err)),
+    };
+
+    Events {
+        parser,
+        stack: Vec::new(),
+        started: false,
+        error,
+        done: false,
+    }
+}
+
+/// Iterator over the [`Event`]s of a RON document, see [`events`].
+pub struct Events<'a> {
+    parser: Parser<'a>,
+    stack: Vec<Frame>,
+    started: bool,
+    error: Option<SpannedError>,
+    done: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Container {
+    Struct,
+    Tuple,
+    Seq,
+    Map,
+    Some,
+}
+
+impl Container {
+    fn terminator(self) -> char {
+        match self {
+            Container::Struct | Container::Tuple | Container::Some => ')',
+            Container::Seq => ']',
+            Container::Map => '}',
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Expect {
+    /// The next element, or the end of the container
+    Element,
+    /// The value after a struct field or map key
+    Value,
+    /// The separator after a map key
+    MapSeparator,
+    /// A comma, or the end of the container
+    Comma,
+}
+
+struct Frame {
+    container: Container,
+    expect: Expect,
+}
+
+impl Events<'_> {
+    fn next_event(&mut self) -> Result<Option<Event>> {
+        loop {
+            self.parser.skip_ws()?;
+
+            let start = self.parser.cursor.cursor;
+
+            let frame = match self.stack.last_mut() {
+                Some(frame) => frame,
+                None if self.started => {
+                    let src = self.parser.src();
+
+                    return if src.is_empty() {
+                        Ok(None)
+                    } else {
+                        Err(Error::TrailingCharacters {
+                            found: crate::de::trailing_snippet(src),
+                        })
+                    };
+                }
+                None => {
+                    self.started = true;
+
+                    return self.value().map(Some);
+                }
+            };
+
+            let container = frame.container;
+
+            match frame.expect {
+                Expect::Element if !self.parser.check_char(container.terminator()) => {
+                    match container {
+                        Container::Struct => {
+                            frame.expect = Expect::Value;
+
+                            return self.field().map(Some);
+                        }
+                        Container::Map => frame.expect = Expect::MapSeparator,
+                        Container::Tuple | Container::Seq => frame.expect = Expect::Comma,
+                        Container::Some => return Err(Error::ExpectedOptionEnd),
+                    }
+
+                    return self.value().map(Some);
+                }
+                Expect::Value => {
+                    frame.expect = Expect::Comma;
+
+                    return self.value().map(Some);
+                }
+                Expect::MapSeparator => {
+                    if self.parser.consume_char(':')
+                        || (self.parser.exts.contains(Extensions::ARROW_MAPS)
+                            && self.parser.consume_str("=>"))
+                    {
+                        frame.expect = Expect::Value;
+
+                        continue;
+                    }
+
+                    return Err(Error::ExpectedMapColon);
+                }
+                Expect::Comma if self.parser.consume_char(',') => {
+                    frame.expect = Expect::Element;
+
+                    continue;
+                }
+                Expect::Element | Expect::Comma => (),
+            }
+
+            if !self.parser.consume_char(container.terminator()) {
+                return Err(match container {
+                    Container::Some => Error::ExpectedOptionEnd,
+                    _ => Error::ExpectedComma,
+                });
+            }
+
+            self.stack.pop();
+
+            return Ok(Some(Event {
+                kind: EventKind::End,
+                span: start..self.parser.cursor.cursor,
+            }));
+        }
+    }
+
+    fn field(&mut self) -> Result<Event> {
+        let start = self.parser.cursor.cursor;
+
+        let name = if self.parser.check_char('"') {
+            match self.parser.string()? {
+                ParsedStr::Allocated(name) => name,
+                ParsedStr::Slice(name) => name.to_string(),
+            }
+        } else {
+            self.parser.identifier()?.to_string()
+        };
+
+        let span = start..self.parser.cursor.cursor;
+
+        self.parser.skip_ws()?;
+
+        if self.parser.consume_char(':') || self.parser.consume_field_equals() {
+            Ok(Event {
+                kind: EventKind::Field(name),
+                span,
+            })
+        } else {
+            Err(Error::ExpectedMapColon)
+        }
+    }
+
+    fn value(&mut self) -> Result<Event> {
+        let start = self.parser.cursor.cursor;
+
+        let kind = self.value_kind()?;
+
+        Ok(Event {
+            kind,
+            span: start..self.parser.cursor.cursor,
+        })
+    }
+
+    fn value_kind(&mut self) -> Result<EventKind> {
+        let parser = &mut self.parser;
+
+        // the kind of a value is inferred like when deserializing a `Value`
+        if parser.consume_ident("true") {
+            return Ok(EventKind::Scalar(Value::Bool(true)));
+        } else if parser.consume_ident("false") {
+            return Ok(EventKind::Scalar(Value::Bool(false)));
+        } else if parser.consume_ident("Some") {
+            parser.skip_ws()?;
+
+            if !parser.consume_char('(') {
+                return Err(Error::ExpectedOption);
+            }
+
+            return Ok(self.start(Container::Some, Expect::Value, EventKind::SomeStart));
+        } else if parser.consume_ident("None") {
+            return Ok(EventKind::Scalar(Value::Option(None)));
+        } else if parser.consume_str("()") {
+            return Ok(EventKind::Scalar(Value::Unit));
+        } else if parser.consume_ident("inf") || parser.consume_ident("inff32") {
+            return Ok(EventKind::Scalar(Value::from(f32::INFINITY)));
+        } else if parser.consume_ident("inff64") {
+            return Ok(EventKind::Scalar(Value::from(f64::INFINITY)));
+        } else if parser.consume_ident("NaN") || parser.consume_ident("NaNf32") {
+            return Ok(EventKind::Scalar(Value::from(f32::NAN)));
+        } else if parser.consume_ident("NaNf64") {
+            return Ok(EventKind::Scalar(Value::from(f64::NAN)));
+        }
+
+        let cursor = parser.cursor();
+        let name = parser.skip_identifier();
+
+        if let Some(name) = name {
+            if let Err(err) = parser.check_string_length(name.len()) {
+                parser.set_cursor(cursor);
+                return Err(err);
+            }
+
+            let after_name = parser.cursor();
+
+            parser.skip_ws()?;
+
+            if !parser.check_char('(') {
+                // the span of a unit struct or variant only covers its name
+                parser.set_cursor(after_name);
+
+                return Ok(EventKind::Scalar(Value::Unit));
+            }
+        }
+
+        let name = name.map(ToString::to_string);
+
+        match parser.peek_char_or_eof()? {
+            '(' => {
+                let named = matches!(
+                    parser.check_struct_type(
+                        NewtypeMode::NoParensMeanUnit,
+                        TupleMode::ImpreciseTupleOrNewtype,
+                    )?,
+                    StructType::Named
+                );
+
+                parser.skip_next_char();
+
+                Ok(if named {
+                    self.start(
+                        Container::Struct,
+                        Expect::Element,
+                        EventKind::StructStart { name },
+                    )
+                } else {
+                    self.start(
+                        Container::Tuple,
+                        Expect::Element,
+                        EventKind::TupleStart { name },
+                    )
+                })
+            }
+            '[' => {
+                parser.skip_next_char();
+
+                Ok(self.start(Container::Seq, Expect::Element, EventKind::SeqStart))
+            }
+            '{' => {
+                parser.skip_next_char();
+
+                Ok(self.start(Container::Map, Expect::Element, EventKind::MapStart))
+            }
+            '0'..='9' | '+' | '-' | '.' => {
+                Ok(EventKind::Scalar(Value::Number(parser.any_number()?)))
+            }
+            '"' | 'r' => Ok(EventKind::Scalar(Value::String(match parser.string()? {
+                ParsedStr::Allocated(string) => string,
+                ParsedStr::Slice(string) => string.to_string(),
+            }))),
+            'b' if parser.check_str("b'") => {
+                Ok(EventKind::Scalar(Value::Number(parser.any_number()?)))
+            }
+            'b' => Ok(EventKind::Scalar(Value::Bytes(
+                match parser.byte_string()? {
+                    ParsedByteStr::Allocated(bytes) => bytes,
+                    ParsedByteStr::Slice(bytes) => bytes.to_vec(),
+                },
+            ))),
+            '\'' => Ok(EventKind::Scalar(Value::Char(parser.char()?))),
+            other => Err(Error::UnexpectedChar(other)),
+        }
+    }
+
+    fn start(&mut self, container: Container, expect: Expect, kind: EventKind) -> EventKind {
+        self.stack.push(Frame { container, expect });
+
+        kind
+    }
+}
+
+impl Iterator for Events<'_> {
+    type Item = Result<Event, SpannedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if let Some(err) = self.error.take() {
+            self.done = true;
+            return Some(Err(err));
+        }
+
+        match self.next_event() {
+            Ok(Some(event)) => Some(Ok(event)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(self.parser.span_error(err)))
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Events<'_> {}
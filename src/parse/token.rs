@@ -0,0 +1,157 @@
+use core::ops::Range;
+
+use super::{is_whitespace_char, Parser};
+use crate::error::{Error, SpannedError};
+
+/// The kind of a [`Token`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// An identifier, e.g. a struct or field name, an enum variant, or a
+    ///  keyword like `true`, `None`, or `inf`, including raw identifiers
+    ///  like `r#foo`.
+    Identifier,
+    /// An integer or float, including its sign and suffix, e.g. `-1_000i32`
+    ///  or `1.5e3`.
+    Number,
+    /// A character or byte literal, e.g. `'a'` or `b'a'`.
+    Char,
+    /// A string or byte string, including raw strings, e.g. `"a"`,
+    ///  `r#"a"#`, or `b"a"`.
+    String,
+    /// A delimiter or separator, e.g. `(`, `,`, `:`, `=>`, or `=`, or part
+    ///  of an attribute like `#![enable(...)]`.
+    Punctuation,
+    /// A line comment, e.g. `// a`, or a block comment, e.g. `/* a */`.
+    Comment,
+}
+
+/// A single token of RON source, see [`tokens`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Token {
+    /// The kind of the token.
+    pub kind: TokenKind,
+    /// The byte range of the token in the source.
+    pub span: Range<usize>,
+}
+
+/// Splits RON source into [`Token`]s, e.g. for syntax highlighting.
+///
+/// The tokens are lexed with the same rules as during deserialization, but
+/// the source is not checked to be a valid RON value. Whitespace between
+/// tokens is skipped. After the first invalid token, e.g. an unterminated
+/// string, the iterator yields the error and then ends.
+///
+/// # Examples
+///
+/// ```
+/// use ron::parse::{tokens, TokenKind};
+///
+/// let kinds = tokens("Point(x: 1) // origin")
+///     .map(|token| token.map(|token| token.kind))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(
+///     kinds,
+///     [
+///         TokenKind::Identifier,
+///         TokenKind::Punctuation,
+///         TokenKind::Identifier,
+///         TokenKind::Punctuation,
+///         TokenKind::Number,
+///         TokenKind::Punctuation,
+///         TokenKind::Comment,
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn tokens(src: &str) -> Tokens<'_> {
+    let mut parser = Parser::new_raw(src);
+    parser.consume_char('\u{FEFF}');
+
+    Tokens {
+        parser,
+        done: false,
+    }
+}
+
+/// Iterator over the [`Token`]s of RON source, see [`tokens`].
+pub struct Tokens<'a> {
+    parser: Parser<'a>,
+    done: bool,
+}
+
+impl Tokens<'_> {
+    fn next_token(&mut self) -> Result<Option<Token>, Error> {
+        let parser = &mut self.parser;
+
+        parser.advance_bytes(parser.next_chars_while_len(is_whitespace_char));
+
+        let start = parser.cursor.cursor;
+
+        let kind = match parser.peek_char() {
+            None => return Ok(None),
+            Some('/') => {
+                parser.skip_comment()?;
+                TokenKind::Comment
+            }
+            Some(_) if parser.skip_identifier().is_some() => TokenKind::Identifier,
+            Some('0'..='9' | '+' | '-' | '.') => {
+                parser.any_number()?;
+                TokenKind::Number
+            }
+            Some('b') if parser.check_str("b'") => {
+                parser.any_number()?;
+                TokenKind::Char
+            }
+            Some('\'') => {
+                parser.char()?;
+                TokenKind::Char
+            }
+            Some('"' | 'r') => {
+                parser.string()?;
+                TokenKind::String
+            }
+            Some('b') => {
+                parser.byte_string_no_base64()?;
+                TokenKind::String
+            }
+            Some(_) if parser.consume_str("=>") => TokenKind::Punctuation,
+            Some('(' | ')' | '[' | ']' | '{' | '}' | ',' | ':' | '=' | '#' | '!') => {
+                parser.skip_next_char();
+                TokenKind::Punctuation
+            }
+            Some(c) => return Err(Error::UnexpectedChar(c)),
+        };
+
+        Ok(Some(Token {
+            kind,
+            span: start..parser.cursor.cursor,
+        }))
+    }
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = Result<Token, SpannedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_token() {
+            Ok(Some(token)) => Some(Ok(token)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(self.parser.span_error(err)))
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Tokens<'_> {}
@@ -0,0 +1,68 @@
+//! Serialize and deserialize an [`Option`] field that must always hold
+//! a value.
+//!
+//! This module is meant to be used with
+//! `#[serde(with = "ron::required_some")]` on an `Option<T>` field, e.g.
+//! to require that a field is given explicitly even though the
+//! `implicit_some` extension is enabled. Deserializing such a field fails
+//! if it is `None` or, since serde only treats missing `Option` fields as
+//! `None` if they are deserialized as usual, if it is missing entirely.
+//! A present value is accepted both as `Some(x)` and, with the
+//! `implicit_some` extension, as just `x`.
+//!
+//! # Examples
+//!
+//! ```
+//! use ron::{extensions::Extensions, Error, Options};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "ron::required_some")]
+//!     port: Option<u16>,
+//!     host: Option<String>,
+//! }
+//!
+//! let options = Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+//!
+//! let config: Config = options.from_str("(port: 8080)").unwrap();
+//! assert_eq!(config, Config { port: Some(8080), host: None });
+//!
+//! assert_eq!(
+//!     options.from_str::<Config>("(host: \"ron\")").unwrap_err().code,
+//!     Error::MissingStructField { field: "port", outer: Some(String::from("Config")) },
+//! );
+//! assert_eq!(
+//!     options.from_str::<Config>("(port: None)").unwrap_err().code,
+//!     Error::InvalidValueForType {
+//!         expected: String::from("a value that is not `None`"),
+//!         found: String::from("`None`"),
+//!     },
+//! );
+//! ```
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes the value of a required [`Option`], failing if it is `None`.
+pub fn serialize<S: Serializer, T: Serialize>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_some(value),
+        None => Err(ser::Error::custom("a required `Option` must not be `None`")),
+    }
+}
+
+/// Deserializes a required [`Option`], failing if it is `None`.
+pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    match Option::<T>::deserialize(deserializer)? {
+        Some(value) => Ok(Some(value)),
+        None => Err(de::Error::invalid_value(
+            de::Unexpected::Other("`None`"),
+            &"a value that is not `None`",
+        )),
+    }
+}
@@ -0,0 +1,145 @@
+//! Pluggable formatting of the structural tokens written by the
+//!  [`Serializer`](super::Serializer).
+
+use core::fmt;
+
+/// Controls how the [`Serializer`](super::Serializer) writes the structural
+///  tokens of the serialized output, e.g. the delimiters of sequences,
+///  structs, and maps, and the separators between their elements.
+///
+/// Every method has a default implementation that writes the token used by
+///  RON, such that a custom formatter only needs to override the tokens it
+///  wants to change. Whitespace, i.e. newlines, indentation, and the spaces
+///  between elements, is still configured by the
+///  [`PrettyConfig`](super::PrettyConfig), while numbers, strings,
+///  characters, identifiers, and comments are always written in RON syntax.
+///
+/// The formatter is cloned for the parts of the output that are buffered to
+///  be laid out according to [`PrettyConfig::max_width`] or reordered
+///  according to [`PrettyConfig::field_order`].
+///
+/// [`PrettyConfig::max_width`]: super::PrettyConfig::max_width
+/// [`PrettyConfig::field_order`]: super::PrettyConfig::field_order
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt;
+///
+/// use ron::{
+///     ser::{Formatter, Serializer},
+///     Options,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Clone)]
+/// struct EqualsFormatter;
+///
+/// impl Formatter for EqualsFormatter {
+///     fn write_key_separator<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+///         writer.write_str(" =")
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let mut ron = String::new();
+/// let mut serializer =
+///     Serializer::with_formatter(&mut ron, None, &Options::default(), EqualsFormatter)
+///         .unwrap();
+/// Point { x: 1, y: 2 }.serialize(&mut serializer).unwrap();
+///
+/// assert_eq!(ron, "(x =1,y =2)");
+/// ```
+pub trait Formatter: Clone {
+    /// Writes a boolean, `true` or `false`.
+    fn write_bool<W: ?Sized + fmt::Write>(&mut self, writer: &mut W, value: bool) -> fmt::Result {
+        writer.write_str(if value { "true" } else { "false" })
+    }
+
+    /// Writes a unit value, `()`.
+    fn write_unit<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("()")
+    }
+
+    /// Writes an empty optional value, `None`.
+    fn write_none<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("None")
+    }
+
+    /// Writes the start of a present optional value, `Some(`.
+    fn begin_some<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("Some(")
+    }
+
+    /// Writes the end of a present optional value, `)`.
+    fn end_some<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char(')')
+    }
+
+    /// Writes the start of a sequence, `[`.
+    fn begin_seq<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char('[')
+    }
+
+    /// Writes the end of a sequence, `]`.
+    fn end_seq<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char(']')
+    }
+
+    /// Writes the start of a tuple, or of the content of a tuple struct,
+    ///  newtype struct, tuple variant, or newtype variant, `(`.
+    fn begin_tuple<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char('(')
+    }
+
+    /// Writes the end of a tuple, or of the content of a tuple struct,
+    ///  newtype struct, tuple variant, or newtype variant, `)`.
+    fn end_tuple<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char(')')
+    }
+
+    /// Writes the start of the fields of a struct or struct variant, `(`.
+    fn begin_struct<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char('(')
+    }
+
+    /// Writes the end of the fields of a struct or struct variant, `)`.
+    fn end_struct<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char(')')
+    }
+
+    /// Writes the start of a map, `{`.
+    fn begin_map<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char('{')
+    }
+
+    /// Writes the end of a map, `}`.
+    fn end_map<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char('}')
+    }
+
+    /// Writes the separator between, and the trailing separator after, the
+    ///  elements, fields, and entries of sequences, tuples, structs, and
+    ///  maps, `,`.
+    fn write_element_separator<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char(',')
+    }
+
+    /// Writes the separator between a struct field name or map key and its
+    ///  value, `:`.
+    fn write_key_separator<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_char(':')
+    }
+}
+
+/// The default [`Formatter`], which writes all structural tokens in RON
+///  syntax.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DefaultFormatter;
+
+impl Formatter for DefaultFormatter {}
@@ -1,6 +1,12 @@
-use std::{borrow::Cow, fmt};
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, fmt::Write};
 
-use serde::{ser, ser::Serialize};
+use serde::{de, ser, ser::Serialize};
 use serde_derive::{Deserialize, Serialize};
 use unicode_ident::is_xid_continue;
 
@@ -8,10 +14,17 @@ use crate::{
     error::{Error, Result},
     extensions::Extensions,
     options::Options,
-    parse::{is_ident_first_char, is_ident_raw_char, is_whitespace_char, LargeSInt, LargeUInt},
+    parse::{
+        is_ident_first_char, is_ident_raw_char, is_whitespace_char, LargeSInt, LargeUInt,
+        RESERVED_IDENTIFIERS,
+    },
 };
 
+pub use self::formatter::{DefaultFormatter, Formatter};
+
+mod formatter;
 pub mod path_meta;
+mod probe;
 
 mod raw;
 #[cfg(test)]
@@ -58,9 +71,59 @@ where
     Options::default().to_string_pretty(value, config)
 }
 
+/// Serializes `value` in a pretty way with the `extensions` enabled in
+/// addition to the [`PrettyConfig::extensions`] of the `config`.
+///
+/// The output starts with an `#![enable(...)]` attribute for every enabled
+/// extension, such that it can be deserialized again without having to
+/// enable the same extensions in the [`Options`].
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// use ron::{extensions::Extensions, ser::PrettyConfig};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// enum Shape {
+///     Circle(Point),
+/// }
+///
+/// let shape = Shape::Circle(Point { x: 1, y: 2 });
+///
+/// let ron = ron::ser::to_string_pretty_with_extensions(
+///     &shape,
+///     PrettyConfig::new().new_line("\n").compact_structs(true),
+///     Extensions::UNWRAP_VARIANT_NEWTYPES,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(ron, "#![enable(unwrap_variant_newtypes)]\nCircle(x: 1, y: 2)");
+/// assert_eq!(ron::from_str::<Shape>(&ron), Ok(shape));
+/// ```
+pub fn to_string_pretty_with_extensions<T>(
+    value: &T,
+    config: PrettyConfig,
+    extensions: Extensions,
+) -> Result<String>
+where
+    T: ?Sized + Serialize,
+{
+    let extensions = config.extensions | extensions;
+
+    to_string_pretty(value, config.extensions(extensions))
+}
+
 /// Pretty serializer state
 struct Pretty {
     indent: usize,
+    /// Number of structs, enums, sequences, tuples, and maps that the
+    ///  currently serialized value is nested inside of
+    depth: usize,
 }
 
 /// Pretty serializer configuration.
@@ -75,25 +138,82 @@ struct Pretty {
 ///     // definitely superior (okay, just joking)
 ///     .indentor("\t");
 /// ```
+///
+/// Since all fields are public, a clone of a base configuration can also be
+/// modified in place:
+///
+/// ```
+/// use ron::ser::PrettyConfig;
+///
+/// let base = PrettyConfig::new().compact_arrays(true);
+///
+/// for depth_limit in [1, 2] {
+///     let mut config = base.clone();
+///     config.depth_limit = depth_limit;
+///
+///     assert!(config.compact_arrays);
+/// }
+/// ```
+///
+/// A configuration can itself be (de)serialized, e.g. to load the output
+/// format from a RON file. Missing fields keep their default values, and the
+/// `extensions` are given by name:
+///
+/// ```
+/// use ron::{extensions::Extensions, ser::PrettyConfig};
+///
+/// let config: PrettyConfig = ron::from_str(
+///     "(indentor: \"\t\", extensions: (\"IMPLICIT_SOME | UNWRAP_NEWTYPES\"))",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     config,
+///     PrettyConfig::new()
+///         .indentor("\t")
+///         .extensions(Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES)
+/// );
+/// ```
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 #[non_exhaustive]
 pub struct PrettyConfig {
-    /// Limit the pretty-ness up to the given depth.
+    /// Limit the pretty-ness up to the given depth, see
+    ///  [`PrettyConfig::depth_limit()`]
     pub depth_limit: usize,
     /// New line string
+    #[serde(deserialize_with = "deserialize_whitespace")]
     pub new_line: Cow<'static, str>,
     /// Indentation string
+    #[serde(deserialize_with = "deserialize_whitespace")]
     pub indentor: Cow<'static, str>,
     /// Separator string
+    #[serde(deserialize_with = "deserialize_whitespace")]
     pub separator: Cow<'static, str>,
     // Whether to emit struct names
     pub struct_names: bool,
+    /// Minimum nesting depth at which struct names are emitted, if
+    ///  `struct_names` is enabled
+    pub struct_names_depth: Option<usize>,
     /// Separate tuple members with indentation
     pub separate_tuple_members: bool,
+    /// Separate the members of tuple structs, tuple variants, and newtype
+    ///  structs with indentation, or follow `separate_tuple_members` for
+    ///  tuple structs and tuple variants if `None`
+    pub separate_tuple_struct_members: Option<bool>,
     /// Enumerate array items in comments
     pub enumerate_arrays: bool,
+    /// Enumerate separated tuple members in comments
+    pub enumerate_tuples: bool,
+    /// Skip struct fields whose value is `None`
+    pub skip_none_fields: bool,
+    /// Skip struct fields whose value is an empty sequence or map
+    pub skip_empty_collections: bool,
+    /// Serialize sequences of bytes that are valid UTF-8 as byte strings
+    pub utf8_bytes_as_string: bool,
+    /// Sort the elements of sequences by their serialized form
+    pub sort_seqs: bool,
     /// Enable extensions. Only configures `implicit_some`,
     ///  `unwrap_newtypes`, and `unwrap_variant_newtypes` for now.
     pub extensions: Extensions,
@@ -103,16 +223,51 @@ pub struct PrettyConfig {
     /// Whether to serialize strings as escaped strings,
     ///  or fall back onto raw strings if necessary.
     pub escape_strings: bool,
+    /// The form of the Unicode escapes in escaped strings
+    pub unicode_escape_style: UnicodeEscape,
+    /// Always serialize strings as raw strings, even if they could be
+    ///  written without escapes
+    pub prefer_raw_strings: bool,
     /// Enable compact structs, which do not insert new lines and indentation
     ///  between the fields of a struct
     pub compact_structs: bool,
     /// Enable compact maps, which do not insert new lines and indentation
     ///  between the entries of a struct
     pub compact_maps: bool,
-    /// Enable explicit number type suffixes like `1u16`
-    pub number_suffixes: bool,
+    /// Where the opening delimiter of a struct, sequence, or map whose
+    ///  contents span multiple lines goes
+    pub brace_style: BraceStyle,
+    /// When to emit explicit number type suffixes like `1u16`
+    pub number_suffixes: SuffixPolicy,
+    /// Number of integer digits that are grouped together by underscores,
+    ///  e.g. `1_000_000`
+    pub digit_grouping: Option<usize>,
     /// Additional path-based field metadata to serialize
     pub path_meta: Option<path_meta::Field>,
+    /// Maximum line width up to which arrays and tuples are kept on a
+    ///  single line
+    pub max_width: Option<usize>,
+    /// Serialize `Some(x)` as just `x`, without enabling the
+    ///  `implicit_some` extension in the output
+    pub implicit_some: bool,
+    /// Serialize newtype structs as their inner value, without enabling
+    ///  the `unwrap_newtypes` extension in the output
+    pub unwrap_newtypes: bool,
+    /// Serialize the content of newtype variants without an extra pair of
+    ///  parentheses, without enabling the `unwrap_variant_newtypes`
+    ///  extension in the output
+    pub unwrap_variant_newtypes: bool,
+    /// Wrap struct field names in double quotes, e.g. `"a": 1`
+    pub quote_keys: bool,
+    /// Order in which struct fields and map entries are emitted, with all
+    ///  unlisted ones following alphabetically
+    pub field_order: Option<Vec<String>>,
+    /// Text that is written as a leading `//` comment before the value and
+    ///  any extension attributes
+    pub header_comment: Option<String>,
+    /// Whether non-finite floats, i.e. `inf`, `-inf`, and `NaN`, are
+    ///  serialized or rejected
+    pub non_finite_floats: NonFinitePolicy,
 }
 
 impl PrettyConfig {
@@ -127,6 +282,9 @@ impl PrettyConfig {
     /// (indentation level) 6, everything will be put into the same line,
     /// without pretty formatting.
     ///
+    /// This only affects the layout of the output. To reject values that
+    /// are nested too deeply, use [`Options::depth_limit`] instead.
+    ///
     /// Default: [`usize::MAX`]
     #[must_use]
     pub fn depth_limit(mut self, depth_limit: usize) -> Self {
@@ -137,6 +295,9 @@ impl PrettyConfig {
 
     /// Configures the newlines used for serialization.
     ///
+    /// Serialization fails with an [`Error::Message`] if it contains
+    /// non-whitespace characters, which would produce invalid RON.
+    ///
     /// Default: `\r\n` on Windows, `\n` otherwise
     #[must_use]
     pub fn new_line(mut self, new_line: impl Into<Cow<'static, str>>) -> Self {
@@ -147,6 +308,9 @@ impl PrettyConfig {
 
     /// Configures the string sequence used for indentation.
     ///
+    /// Serialization fails with an [`Error::Message`] if it contains
+    /// non-whitespace characters, which would produce invalid RON.
+    ///
     /// Default: 4 spaces
     #[must_use]
     pub fn indentor(mut self, indentor: impl Into<Cow<'static, str>>) -> Self {
@@ -157,6 +321,9 @@ impl PrettyConfig {
 
     /// Configures the string sequence used to separate items inline.
     ///
+    /// Serialization fails with an [`Error::Message`] if it contains
+    /// non-whitespace characters, which would produce invalid RON.
+    ///
     /// Default: 1 space
     #[must_use]
     pub fn separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
@@ -177,6 +344,26 @@ impl PrettyConfig {
         self
     }
 
+    /// Configures the minimum nesting depth at which struct names are
+    /// emitted if [`PrettyConfig::struct_names`] is enabled.
+    ///
+    /// The root value is at depth `0`, and the depth increases by one for
+    /// every struct, enum variant, sequence, tuple, or map that a value is
+    /// nested inside of. For instance, `Some(1)` omits the name of the root
+    /// struct but keeps the names of all structs nested inside it.
+    ///
+    /// `None` emits struct names at all depths. Note that the
+    /// [`Extensions::EXPLICIT_STRUCT_NAMES`] extension always requires
+    /// struct names at all depths.
+    ///
+    /// Default: `None`
+    #[must_use]
+    pub fn struct_names_depth(mut self, struct_names_depth: Option<usize>) -> Self {
+        self.struct_names_depth = struct_names_depth;
+
+        self
+    }
+
     /// Configures whether tuples are single- or multi-line.
     /// If set to `true`, tuples will have their fields indented and in new
     /// lines. If set to `false`, tuples will be serialized without any
@@ -190,6 +377,31 @@ impl PrettyConfig {
         self
     }
 
+    /// Configures whether tuple structs, tuple variants, and newtype structs
+    /// are single- or multi-line, independently of plain tuples.
+    ///
+    /// If set to `true`, e.g. `Point(1, 2)` is serialized as
+    /// ```ignore
+    /// Point(
+    ///     1,
+    ///     2,
+    /// )
+    /// # ;
+    /// ```
+    /// while `(1, 2)` still follows
+    /// [`PrettyConfig::separate_tuple_members`]. If set to `false`, they are
+    /// always serialized on a single line.
+    ///
+    /// Default: `None`, i.e. tuple structs and tuple variants follow
+    /// [`PrettyConfig::separate_tuple_members`] and newtype structs are
+    /// kept on a single line
+    #[must_use]
+    pub fn separate_tuple_struct_members(mut self, separate_tuple_struct_members: bool) -> Self {
+        self.separate_tuple_struct_members = Some(separate_tuple_struct_members);
+
+        self
+    }
+
     /// Configures whether a comment shall be added to every array element,
     /// indicating the index.
     ///
@@ -201,6 +413,111 @@ impl PrettyConfig {
         self
     }
 
+    /// Configures whether a comment shall be added to every tuple member,
+    /// indicating the index, e.g. `/*0:*/`.
+    ///
+    /// The comments are only added if tuple members are written on separate
+    /// lines, see [`PrettyConfig::separate_tuple_members`] and
+    /// [`PrettyConfig::separate_tuple_struct_members`].
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn enumerate_tuples(mut self, enumerate_tuples: bool) -> Self {
+        self.enumerate_tuples = enumerate_tuples;
+
+        self
+    }
+
+    /// Configures whether struct fields whose value is `None` are left out
+    /// (`true`) or written as `None` (`false`).
+    ///
+    /// Only a direct `None` is skipped, so with the `implicit_some` extension
+    /// a `Some(None)` is still written in full. Skipped `Option` fields
+    /// deserialize as `None` again, since serde treats missing `Option`
+    /// fields as `None`.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn skip_none_fields(mut self, skip_none_fields: bool) -> Self {
+        self.skip_none_fields = skip_none_fields;
+
+        self
+    }
+
+    /// Configures whether struct fields whose value is an empty sequence or
+    /// map, e.g. an empty `Vec` or `HashMap`, are left out (`true`) or
+    /// written as `[]` or `{}` (`false`).
+    ///
+    /// Only a direct empty collection is skipped, so e.g. `Some([])` or
+    /// a newtype around an empty `Vec` is still written in full. Unlike
+    /// missing `Option` fields, serde does not treat missing collection
+    /// fields as empty, so the fields must be marked with
+    /// `#[serde(default)]` to deserialize the output again.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn skip_empty_collections(mut self, skip_empty_collections: bool) -> Self {
+        self.skip_empty_collections = skip_empty_collections;
+
+        self
+    }
+
+    /// Configures whether sequences of bytes, e.g. a `Vec<u8>`, that are
+    /// valid UTF-8 should be serialized as byte strings (`true`) or as
+    /// arrays (`false`).
+    ///
+    /// When `true`, `vec![104_u8, 105]` will serialize to
+    /// ```ignore
+    /// b"hi"
+    /// # ;
+    /// ```
+    /// which deserializes back into the same sequence of bytes. Sequences
+    /// that are empty, are not valid UTF-8, or contain anything but `u8`s
+    /// are still serialized as arrays.
+    ///
+    /// Since a [`Value`](crate::Value) is deserialized from a byte string as
+    /// a [`Value::Bytes`](crate::Value::Bytes), a [`Value::Seq`](crate::Value::Seq)
+    /// of only [`u8`] numbers does not round-trip when this is enabled and
+    /// comes back as a [`Value::Bytes`](crate::Value::Bytes) instead.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn utf8_bytes_as_string(mut self, utf8_bytes_as_string: bool) -> Self {
+        self.utf8_bytes_as_string = utf8_bytes_as_string;
+
+        self
+    }
+
+    /// Configures whether the elements of sequences should be sorted by
+    /// their serialized form (`true`) or be emitted in the order in which
+    /// they are serialized (`false`).
+    ///
+    /// When `true`, a `HashSet` containing `"b"`, `"c"`, and `"a"` will
+    /// always serialize to
+    /// ```ignore
+    /// ["a", "b", "c"]
+    /// # ;
+    /// ```
+    /// regardless of its iteration order, which makes the output of
+    /// set-like collections stable across runs. This should only be
+    /// enabled if the order of elements is irrelevant, since it is not
+    /// preserved when the output is deserialized. Tuples and sequences
+    /// that are written as byte strings (see
+    /// [`utf8_bytes_as_string`](Self::utf8_bytes_as_string)) are not
+    /// sorted.
+    ///
+    /// Since every element has to be serialized into a separate string
+    /// before it can be compared, sorting makes serialization slower and
+    /// needs memory for the entire serialized sequence.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn sort_seqs(mut self, sort_seqs: bool) -> Self {
+        self.sort_seqs = sort_seqs;
+
+        self
+    }
+
     /// Configures whether every array should be a single line (`true`)
     /// or a multi line one (`false`).
     ///
@@ -228,6 +545,12 @@ impl PrettyConfig {
 
     /// Configures extensions
     ///
+    /// Enum variants, including `Result`'s `Ok` and `Err`, are always
+    /// written as `Variant(..)`. With [`Extensions::UNWRAP_VARIANT_NEWTYPES`],
+    /// a struct or unit inside a newtype variant is written without its own
+    /// parentheses, e.g. `Ok(a: 1)` instead of `Ok((a: 1))`, but both forms
+    /// are still accepted during deserialization.
+    ///
     /// Default: [`Extensions::empty()`]
     #[must_use]
     pub fn extensions(mut self, extensions: Extensions) -> Self {
@@ -259,6 +582,58 @@ impl PrettyConfig {
         self
     }
 
+    /// Configures the form of the Unicode escapes that escaped strings use
+    /// for non-printable characters, see [`PrettyConfig::escape_strings`].
+    ///
+    /// With [`UnicodeEscape::Braced`], `"\u{200b}\u{e0001}"` will serialize to
+    /// ```
+    /// "\u{200b}\u{e0001}"
+    /// # ;
+    /// ```
+    /// With [`UnicodeEscape::Fixed`], it will instead serialize to the
+    /// JSON-like
+    /// ```text
+    /// "\u200b\udb40\udc01"
+    /// ```
+    /// where characters outside the Basic Multilingual Plane are escaped as
+    /// a UTF-16 surrogate pair. The deserializer accepts both forms.
+    ///
+    /// Default: [`UnicodeEscape::Braced`]
+    #[must_use]
+    pub fn unicode_escape_style(mut self, unicode_escape_style: UnicodeEscape) -> Self {
+        self.unicode_escape_style = unicode_escape_style;
+
+        self
+    }
+
+    /// Configures whether strings should always be serialized as raw
+    /// strings (`true`) or as configured by
+    /// [`escape_strings`](Self::escape_strings) (`false`).
+    ///
+    /// When `true`, `"C:\\Users\\ron"` will serialize to
+    /// ```ignore
+    /// r"C:\Users\ron"
+    /// # ;
+    /// ```
+    /// and `"say \"#hi\""` will serialize to
+    /// ```ignore
+    /// r##"say "#hi""##
+    /// # ;
+    /// ```
+    /// even if escaping would be shorter, which keeps paths and regular
+    /// expressions readable. Each raw string uses the minimal number of `#`s,
+    /// i.e. one more than the longest run of `#`s that directly follows a
+    /// `"` in the string, or none if the string does not contain a `"`.
+    /// Byte strings are not affected.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn prefer_raw_strings(mut self, prefer_raw_strings: bool) -> Self {
+        self.prefer_raw_strings = prefer_raw_strings;
+
+        self
+    }
+
     /// Configures whether every struct should be a single line (`true`)
     /// or a multi line one (`false`).
     ///
@@ -310,8 +685,62 @@ impl PrettyConfig {
         self
     }
 
-    /// Configures whether numbers should be printed without (`false`) or
-    /// with (`true`) their explicit type suffixes.
+    /// Configures where the opening `(`, `[`, or `{` of a struct, tuple,
+    /// sequence, or map whose contents span multiple lines goes, relative
+    /// to the struct or variant name or the struct field or map key that
+    /// precedes it.
+    ///
+    /// With [`BraceStyle::SameLine`], the struct
+    /// `Config { name: "ron", point: Point { x: 1, y: 2 }, tags: ["a"] }`
+    /// will serialize with [`PrettyConfig::struct_names`] to
+    /// ```ignore
+    /// Config(
+    ///     name: "ron",
+    ///     point: Point(
+    ///         x: 1,
+    ///         y: 2,
+    ///     ),
+    ///     tags: [
+    ///         "a",
+    ///     ],
+    /// )
+    /// # ;
+    /// ```
+    /// With [`BraceStyle::NextLine`], it will instead serialize to
+    /// ```ignore
+    /// Config
+    /// (
+    ///     name: "ron",
+    ///     point: Point
+    ///     (
+    ///         x: 1,
+    ///         y: 2,
+    ///     ),
+    ///     tags:
+    ///     [
+    ///         "a",
+    ///     ],
+    /// )
+    /// # ;
+    /// ```
+    ///
+    /// Delimiters whose contents are written on a single line, e.g. those
+    /// of compact structs or of arrays laid out by
+    /// [`PrettyConfig::max_width`], always stay on the same line.
+    ///
+    /// Default: [`BraceStyle::SameLine`]
+    #[must_use]
+    pub fn brace_style(mut self, brace_style: BraceStyle) -> Self {
+        self.brace_style = brace_style;
+
+        self
+    }
+
+    /// Configures whether numbers should be printed without
+    /// ([`SuffixPolicy::Never`] or `false`) or with ([`SuffixPolicy::Always`]
+    /// or `true`) their explicit type suffixes, or only with those suffixes
+    /// that are needed to deserialize them as the same type when their type
+    /// is not known ([`SuffixPolicy::WhenAmbiguous`]).
     ///
     /// When `false`, the integer `12345u16` will serialize to
     /// ```ignore
@@ -333,11 +762,224 @@ impl PrettyConfig {
     /// 12345.6789f64
     /// # ;
     /// ```
+    /// With [`SuffixPolicy::WhenAmbiguous`], the integer `12345u16` will
+    /// serialize to `12345`, which is deserialized as a `u16` anyways,
+    /// but `42u16` will serialize to `42u16`, since `42` would be
+    /// deserialized as a `u8`.
+    ///
+    /// Default: [`SuffixPolicy::Never`]
+    #[must_use]
+    pub fn number_suffixes(mut self, number_suffixes: impl Into<SuffixPolicy>) -> Self {
+        self.number_suffixes = number_suffixes.into();
+
+        self
+    }
+
+    /// Configures whether the digits of integers are grouped by underscores
+    /// into groups of the given size, counted from the least significant
+    /// digit, to make large numbers easier to read.
+    ///
+    /// With a `digit_grouping` of `Some(3)`, the integer `-1234567i32` will
+    /// serialize to
+    /// ```
+    /// -1_234_567
+    /// # ;
+    /// ```
+    /// which is deserialized as the same number. Floats are never grouped.
+    ///
+    /// Default: `None`, i.e. digits are not grouped, which is also the
+    /// case for `Some(0)`
+    #[must_use]
+    pub fn digit_grouping(mut self, digit_grouping: Option<usize>) -> Self {
+        self.digit_grouping = digit_grouping;
+
+        self
+    }
+
+    /// Configures the maximum line width up to which arrays and tuples
+    /// are kept on a single line.
+    ///
+    /// Every array and tuple is first laid out on a single line. Only if
+    /// it would then exceed `max_width` characters, it is expanded to one
+    /// element per line, which may in turn allow its elements to stay on
+    /// a single line. When set, this layout overrides
+    /// [`PrettyConfig::compact_arrays`],
+    /// [`PrettyConfig::separate_tuple_members`], and
+    /// [`PrettyConfig::separate_tuple_struct_members`].
+    ///
+    /// With a `max_width` of `20`, `[[1, 2], [3, 4], [5, 6]]` will
+    /// serialize to
+    /// ```
+    /// [
+    ///     [1, 2],
+    ///     [3, 4],
+    ///     [5, 6],
+    /// ]
+    /// # ;
+    /// ```
+    /// while with a `max_width` of `40`, it will serialize to
+    /// ```
+    /// [[1, 2], [3, 4], [5, 6]]
+    /// # ;
+    /// ```
+    ///
+    /// Default: `None`
+    #[must_use]
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+
+        self
+    }
+
+    /// Configures whether `Some(x)` is serialized as just `x`.
+    ///
+    /// Unlike enabling [`Extensions::IMPLICIT_SOME`] with
+    /// [`PrettyConfig::extensions`], this option does not emit the
+    /// `#![enable(implicit_some)]` attribute, i.e. the output must be
+    /// deserialized with the extension enabled by other means, e.g. with
+    /// [`Options::with_default_extension`].
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn implicit_some(mut self, implicit_some: bool) -> Self {
+        self.implicit_some = implicit_some;
+
+        self
+    }
+
+    /// Configures whether newtype structs are serialized as just their inner
+    /// value.
+    ///
+    /// Unlike enabling [`Extensions::UNWRAP_NEWTYPES`] with
+    /// [`PrettyConfig::extensions`], this option does not emit the
+    /// `#![enable(unwrap_newtypes)]` attribute, i.e. the output must be
+    /// deserialized with the extension enabled by other means, e.g. with
+    /// [`Options::with_default_extension`].
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn unwrap_newtypes(mut self, unwrap_newtypes: bool) -> Self {
+        self.unwrap_newtypes = unwrap_newtypes;
+
+        self
+    }
+
+    /// Configures whether the content of newtype variants is serialized
+    /// without an extra pair of parentheses, e.g. `Variant(a: 1)` instead of
+    /// `Variant((a: 1))`.
+    ///
+    /// Unlike enabling [`Extensions::UNWRAP_VARIANT_NEWTYPES`] with
+    /// [`PrettyConfig::extensions`], this option does not emit the
+    /// `#![enable(unwrap_variant_newtypes)]` attribute, i.e. the output must
+    /// be deserialized with the extension enabled by other means, e.g. with
+    /// [`Options::with_default_extension`].
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn unwrap_variant_newtypes(mut self, unwrap_variant_newtypes: bool) -> Self {
+        self.unwrap_variant_newtypes = unwrap_variant_newtypes;
+
+        self
+    }
+
+    /// Configures whether struct field names are wrapped in double quotes.
+    ///
+    /// When `false`, the struct `Struct { a: 1 }` will serialize to
+    /// ```ignore
+    /// Struct(a: 1)
+    /// # ;
+    /// ```
+    /// When `true`, the struct `Struct { a: 1 }` will serialize to
+    /// ```ignore
+    /// Struct("a": 1)
+    /// # ;
+    /// ```
+    ///
+    /// When `false`, a field name that is not a valid RON identifier, e.g.
+    /// `1abc` or `true`, is written as a raw identifier, e.g. `r#1abc`. A
+    /// field name that cannot even be written as a raw identifier, e.g.
+    /// `with space` or the empty string, fails with an
+    /// [`Error::InvalidIdentifier`], and can only be serialized with quoted
+    /// field names.
+    ///
+    /// String map keys are always quoted, regardless of whether they are
+    /// valid identifiers, so that they always parse back to the same key.
     ///
     /// Default: `false`
     #[must_use]
-    pub fn number_suffixes(mut self, number_suffixes: bool) -> Self {
-        self.number_suffixes = number_suffixes;
+    pub fn quote_keys(mut self, quote_keys: bool) -> Self {
+        self.quote_keys = quote_keys;
+
+        self
+    }
+
+    /// Configures the order in which the fields of all structs and the
+    /// entries of all maps are emitted.
+    ///
+    /// Fields and entries whose names are listed in `field_order` are
+    /// emitted first, in the given order, followed by all unlisted ones in
+    /// alphabetical order. Map entries are named by their string key, or
+    /// by the RON representation of any other key, e.g. `42`.
+    ///
+    /// With a `field_order` of `["name", "version"]`, the struct
+    /// `Package { version: 1, authors: [], name: "ron" }` will serialize to
+    /// ```ignore
+    /// (
+    ///     name: "ron",
+    ///     version: 1,
+    ///     authors: [],
+    /// )
+    /// # ;
+    /// ```
+    ///
+    /// This option only affects the order in which fields and entries are
+    /// emitted, e.g. to match an existing hand-written layout, and not the
+    /// value that is deserialized from the output. Since all fields and
+    /// entries of a struct or map have to be buffered before they can be
+    /// reordered, it makes serialization slower.
+    ///
+    /// Default: `None`, i.e. fields and entries are emitted in the order in
+    /// which they are serialized
+    #[must_use]
+    pub fn field_order(mut self, field_order: Vec<String>) -> Self {
+        self.field_order = Some(field_order);
+
+        self
+    }
+
+    /// Configures the text that is written as a leading comment before the
+    /// value and any `#![enable(...)]` extension attributes, e.g. to mark
+    /// the schema version of a file.
+    ///
+    /// Every line of the text is written as its own `//` line comment, so
+    /// a `header_comment` of `Some(String::from("schema v3\nmy-tool"))`
+    /// will start the output with
+    /// ```
+    /// // schema v3
+    /// // my-tool
+    /// # ;
+    /// ```
+    /// Since comments are ignored during deserialization, the value is
+    /// deserialized just as without the header comment.
+    ///
+    /// Default: `None`
+    #[must_use]
+    pub fn header_comment(mut self, header_comment: Option<String>) -> Self {
+        self.header_comment = header_comment;
+
+        self
+    }
+
+    /// Configures whether non-finite floats are serialized as `inf`,
+    /// `-inf`, `NaN`, or `-NaN` ([`NonFinitePolicy::Emit`]), which are
+    /// deserialized as the same floats, or whether serializing them fails
+    /// with an [`Error::NonFiniteFloat`] ([`NonFinitePolicy::Error`]), e.g.
+    /// since a consumer of the output cannot handle them.
+    ///
+    /// Default: [`NonFinitePolicy::Emit`]
+    #[must_use]
+    pub fn non_finite_floats(mut self, non_finite_floats: NonFinitePolicy) -> Self {
+        self.non_finite_floats = non_finite_floats;
 
         self
     }
@@ -355,35 +997,215 @@ impl Default for PrettyConfig {
             indentor: Cow::Borrowed("    "),
             separator: Cow::Borrowed(" "),
             struct_names: false,
+            struct_names_depth: None,
             separate_tuple_members: false,
+            separate_tuple_struct_members: None,
             enumerate_arrays: false,
+            enumerate_tuples: false,
+            skip_none_fields: false,
+            skip_empty_collections: false,
+            utf8_bytes_as_string: false,
+            sort_seqs: false,
             extensions: Extensions::empty(),
             compact_arrays: false,
             escape_strings: true,
+            unicode_escape_style: UnicodeEscape::Braced,
+            prefer_raw_strings: false,
             compact_structs: false,
             compact_maps: false,
-            number_suffixes: false,
+            brace_style: BraceStyle::SameLine,
+            number_suffixes: SuffixPolicy::Never,
+            digit_grouping: None,
             path_meta: None,
+            max_width: None,
+            implicit_some: false,
+            unwrap_newtypes: false,
+            unwrap_variant_newtypes: false,
+            quote_keys: false,
+            field_order: None,
+            header_comment: None,
+            non_finite_floats: NonFinitePolicy::Emit,
         }
     }
 }
 
-/// The RON serializer.
-///
-/// You can just use [`to_string`] for deserializing a value.
-/// If you want it pretty-printed, take a look at [`to_string_pretty`].
-pub struct Serializer<W: fmt::Write> {
-    output: W,
-    pretty: Option<(PrettyConfig, Pretty)>,
-    default_extensions: Extensions,
-    is_empty: Option<bool>,
-    newtype_variant: bool,
-    recursion_limit: Option<usize>,
-    // Tracks the number of opened implicit `Some`s, set to 0 on backtracking
-    implicit_some_depth: usize,
+/// Deserializes the `new_line`, `indentor`, or `separator` of a
+/// [`PrettyConfig`], which may only contain whitespace.
+fn deserialize_whitespace<'de, D>(deserializer: D) -> Result<Cow<'static, str>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let string = <String as de::Deserialize>::deserialize(deserializer)?;
+
+    if string.chars().all(is_whitespace_char) {
+        Ok(Cow::Owned(string))
+    } else {
+        Err(de::Error::invalid_value(
+            de::Unexpected::Str(&string),
+            &"a string of whitespace characters",
+        ))
+    }
 }
 
-fn indent<W: fmt::Write>(output: &mut W, config: &PrettyConfig, pretty: &Pretty) -> fmt::Result {
+/// The form of the Unicode escapes in escaped strings, see
+/// [`PrettyConfig::unicode_escape_style`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UnicodeEscape {
+    /// RON's variable-width escapes with braces, e.g. `\u{200b}` or
+    /// `\u{e0001}`
+    Braced,
+    /// JSON-like fixed-width escapes with four hex digits, e.g. `\u200b`,
+    /// which escape characters outside the Basic Multilingual Plane as a
+    /// UTF-16 surrogate pair, e.g. `\udb40\udc01`
+    Fixed,
+}
+
+/// Where the opening delimiter of a struct, tuple, sequence, or map goes,
+/// see [`PrettyConfig::brace_style`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BraceStyle {
+    /// On the same line as the struct name or field that precedes it, e.g.
+    /// `point: Point(`
+    SameLine,
+    /// On its own line, at the indentation of the struct name or field that
+    /// precedes it, e.g. `point: Point` followed by `(` on the next line
+    NextLine,
+}
+
+/// When [`PrettyConfig::number_suffixes`] emits explicit number type
+/// suffixes, e.g. `1u16` or `1.5f64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SuffixPolicy {
+    /// Never emit a type suffix, e.g. `1`
+    #[serde(alias = "false")]
+    Never,
+    /// Only emit a type suffix if the number would otherwise be deserialized
+    /// as a different type when its type is not known, e.g. into a
+    /// [`Value`](crate::Value).
+    ///
+    /// Such a number is deserialized as the smallest unsigned integer type
+    /// that fits a non-negative integer, e.g. `42` as a `u8`, the smallest
+    /// signed integer type that fits a negative integer, e.g. `-300` as an
+    /// `i16`, and as an `f32` if a float can be represented exactly by an
+    /// `f32`, e.g. `0.5`, or as an `f64` otherwise, e.g. `0.1`.
+    WhenAmbiguous,
+    /// Always emit a type suffix, e.g. `1u8`
+    #[serde(alias = "true")]
+    Always,
+}
+
+/// How [`PrettyConfig::non_finite_floats`] serializes non-finite floats,
+/// i.e. infinities and NaNs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NonFinitePolicy {
+    /// Serialize them as `inf`, `-inf`, `NaN`, or `-NaN`
+    Emit,
+    /// Fail with an [`Error::NonFiniteFloat`]
+    Error,
+}
+
+impl From<bool> for SuffixPolicy {
+    fn from(number_suffixes: bool) -> Self {
+        if number_suffixes {
+            SuffixPolicy::Always
+        } else {
+            SuffixPolicy::Never
+        }
+    }
+}
+
+/// Returns the suffix of the type as which an integer `value` is
+/// deserialized when its type is not known.
+fn untyped_uint_suffix(value: LargeUInt) -> &'static str {
+    match LargeUInt::BITS - value.leading_zeros() {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => "u128",
+    }
+}
+
+/// Returns the suffix of the type as which an integer `value` is
+/// deserialized when its type is not known.
+fn untyped_sint_suffix(value: LargeSInt) -> &'static str {
+    match LargeUInt::try_from(value) {
+        Ok(value) => untyped_uint_suffix(value),
+        // Number of bits of the negative value in two's complement
+        Err(_) => match LargeSInt::BITS - (!value).leading_zeros() + 1 {
+            0..=8 => "i8",
+            9..=16 => "i16",
+            17..=32 => "i32",
+            33..=64 => "i64",
+            _ => "i128",
+        },
+    }
+}
+
+/// The style of a comment written with [`Serializer::write_comment`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CommentStyle {
+    /// A `// line comment`, repeated for every line of the comment
+    Line,
+    /// A `/* block comment */`
+    Block,
+}
+
+/// The RON serializer.
+///
+/// You can just use [`to_string`] for deserializing a value.
+/// If you want it pretty-printed, take a look at [`to_string_pretty`].
+pub struct Serializer<W: fmt::Write, F: Formatter = DefaultFormatter> {
+    output: Output<W>,
+    formatter: F,
+    pretty: Option<(PrettyConfig, Pretty)>,
+    default_extensions: Extensions,
+    is_empty: Option<bool>,
+    newtype_variant: bool,
+    recursion_limit: Option<usize>,
+    // Number of values that the currently serialized value is nested
+    //  inside of, see `Serializer::current_depth`
+    depth: usize,
+    // Tracks the number of opened implicit `Some`s, set to 0 on backtracking
+    implicit_some_depth: usize,
+}
+
+/// Writer that keeps track of the current column if required for
+///  [`PrettyConfig::max_width`]
+struct Output<W: fmt::Write> {
+    writer: W,
+    column: Option<usize>,
+    line_start: bool,
+    /// Separator after a struct field name or map key, which is only
+    ///  written before the value unless a [`BraceStyle::NextLine`]
+    ///  delimiter replaces it with a new line
+    deferred_separator: Option<Cow<'static, str>>,
+}
+
+impl<W: fmt::Write> fmt::Write for Output<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(separator) = self.deferred_separator.take() {
+            self.write_str(&separator)?;
+        }
+
+        self.line_start = s.ends_with('\n');
+
+        if let Some(ref mut column) = self.column {
+            match s.rfind('\n') {
+                Some(i) => *column = s[i + 1..].chars().count(),
+                None => *column += s.chars().count(),
+            }
+        }
+
+        self.writer.write_str(s)
+    }
+}
+
+fn indent<W: fmt::Write>(output: &mut W, config: &PrettyConfig, pretty: &Pretty) -> fmt::Result {
     if pretty.indent <= config.depth_limit {
         for _ in 0..pretty.indent {
             output.write_str(&config.indentor)?;
@@ -406,9 +1228,53 @@ impl<W: fmt::Write> Serializer<W> {
     /// Most of the time you can just use [`to_string`] or
     /// [`to_string_pretty`].
     pub fn with_options(
+        writer: W,
+        config: Option<PrettyConfig>,
+        options: &Options,
+    ) -> Result<Self> {
+        Self::with_formatter(writer, config, options, DefaultFormatter)
+    }
+}
+
+impl Serializer<String> {
+    /// Creates a new [`Serializer`] that writes into the existing `buffer`,
+    /// which is cleared first but keeps its capacity.
+    ///
+    /// Together with [`Serializer::into_inner`], this allows reusing the
+    /// same buffer, e.g. from a pool, to serialize many values without
+    /// reallocating it each time.
+    ///
+    /// ```
+    /// use ron::ser::Serializer;
+    /// use serde::Serialize;
+    ///
+    /// let mut buffer = String::with_capacity(64);
+    ///
+    /// for value in [1, 2, 3] {
+    ///     let mut serializer = Serializer::from_string(buffer, None).unwrap();
+    ///     [value; 4].serialize(&mut serializer).unwrap();
+    ///     buffer = serializer.into_inner();
+    ///
+    ///     assert_eq!(buffer, format!("({value},{value},{value},{value})"));
+    /// }
+    ///
+    /// assert_eq!(buffer.capacity(), 64);
+    /// ```
+    pub fn from_string(mut buffer: String, config: Option<PrettyConfig>) -> Result<Self> {
+        buffer.clear();
+
+        Self::new(buffer, config)
+    }
+}
+
+impl<W: fmt::Write, F: Formatter> Serializer<W, F> {
+    /// Creates a new [`Serializer`] that writes the structural tokens of
+    /// the output with a custom [`Formatter`].
+    pub fn with_formatter(
         mut writer: W,
         config: Option<PrettyConfig>,
         options: &Options,
+        formatter: F,
     ) -> Result<Self> {
         if let Some(conf) = &config {
             if !conf.new_line.chars().all(is_whitespace_char) {
@@ -427,6 +1293,18 @@ impl<W: fmt::Write> Serializer<W> {
                 )));
             }
 
+            if let Some(header_comment) = &conf.header_comment {
+                // a lone `\r` would also end the line comment
+                for line in header_comment.lines().flat_map(|line| line.split('\r')) {
+                    if line.is_empty() {
+                        writer.write_str("//")?;
+                    } else {
+                        write!(writer, "// {line}")?;
+                    }
+                    writer.write_str(&conf.new_line)?;
+                }
+            }
+
             let non_default_extensions = !options.default_extensions;
 
             for (extension_name, _) in (non_default_extensions & conf.extensions).iter_names() {
@@ -435,22 +1313,171 @@ impl<W: fmt::Write> Serializer<W> {
             }
         };
         Ok(Serializer {
-            output: writer,
-            pretty: config.map(|conf| (conf, Pretty { indent: 0 })),
+            output: Output {
+                writer,
+                column: config.as_ref().and_then(|conf| conf.max_width).map(|_| 0),
+                line_start: true,
+                deferred_separator: None,
+            },
+            formatter,
+            pretty: config.map(|conf| {
+                (
+                    conf,
+                    Pretty {
+                        indent: 0,
+                        depth: 0,
+                    },
+                )
+            }),
             default_extensions: options.default_extensions,
             is_empty: None,
             newtype_variant: false,
-            recursion_limit: options.recursion_limit,
+            recursion_limit: options.depth_limit,
+            depth: 0,
             implicit_some_depth: 0,
         })
     }
 
+    /// Returns the nesting depth of the value that is currently being
+    /// serialized, counted like [`Options::depth_limit`], i.e. `0` for the
+    /// top-level value and `2` for the `42` in `[[42]]`.
+    ///
+    /// This is useful when driving a [`Serializer`] manually, e.g. from a
+    /// [`serde::Serializer`] that wraps it to format values differently at
+    /// different nesting levels. Between top-level values, the depth is
+    /// always `0`.
+    #[must_use]
+    pub fn current_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Consumes the [`Serializer`] and returns its writer, e.g. the buffer
+    /// of [`Serializer::from_string`] with the serialized output, such that
+    /// it can be reused.
+    ///
+    /// If serialization failed, the writer may contain partial output.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.output.writer
+    }
+
+    /// Writes a comment on its own line(s) at the current indentation.
+    ///
+    /// This is useful when driving a [`Serializer`] manually, e.g. to add
+    /// a header comment before the serialized value. The comment is
+    /// ignored when the output is deserialized again.
+    ///
+    /// Comments can only be written in pretty output that is not
+    /// compacted by [`PrettyConfig::depth_limit`] and whose
+    /// [`PrettyConfig::new_line`] contains a line break. Block comments
+    /// must not contain `/*` or `*/`.
+    ///
+    /// ```
+    /// use ron::ser::{CommentStyle, PrettyConfig, Serializer};
+    /// use serde::Serialize;
+    ///
+    /// let mut ron = String::new();
+    /// let mut serializer = Serializer::new(&mut ron, Some(PrettyConfig::new())).unwrap();
+    ///
+    /// serializer
+    ///     .write_comment("Generated file, do not edit", CommentStyle::Line)
+    ///     .unwrap();
+    /// (1, 2).serialize(&mut serializer).unwrap();
+    ///
+    /// assert_eq!(ron, "// Generated file, do not edit\n(1, 2)");
+    /// assert_eq!(ron::from_str::<(i32, i32)>(&ron).unwrap(), (1, 2));
+    /// ```
+    pub fn write_comment(&mut self, comment: &str, style: CommentStyle) -> Result<()> {
+        let (config, pretty) = match self.pretty {
+            Some((ref config, ref pretty))
+                if pretty.indent <= config.depth_limit && config.new_line.contains('\n') =>
+            {
+                (config, pretty)
+            }
+            _ => {
+                return Err(Error::Message(String::from(
+                    "Comments can only be written in pretty output",
+                )))
+            }
+        };
+
+        if style == CommentStyle::Block && (comment.contains("/*") || comment.contains("*/")) {
+            return Err(Error::Message(String::from(
+                "Block comments must not contain `/*` or `*/`",
+            )));
+        }
+
+        if !self.output.line_start {
+            self.output.write_str(&config.new_line)?;
+        }
+
+        match style {
+            CommentStyle::Line => {
+                for line in comment.split('\n') {
+                    let line = line.strip_suffix('\r').unwrap_or(line);
+
+                    indent(&mut self.output, config, pretty)?;
+                    self.output.write_str("//")?;
+                    if !line.is_empty() {
+                        write!(self.output, " {line}")?;
+                    }
+                    self.output.write_str(&config.new_line)?;
+                }
+            }
+            CommentStyle::Block => {
+                indent(&mut self.output, config, pretty)?;
+                write!(self.output, "/* {comment} */")?;
+                self.output.write_str(&config.new_line)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn separate_tuple_members(&self) -> bool {
         self.pretty
             .as_ref()
             .map_or(false, |(ref config, _)| config.separate_tuple_members)
     }
 
+    fn separate_tuple_struct_members(&self) -> bool {
+        self.pretty.as_ref().map_or(false, |(ref config, _)| {
+            config
+                .separate_tuple_struct_members
+                .unwrap_or(config.separate_tuple_members)
+        })
+    }
+
+    fn separate_newtype_struct_members(&self) -> bool {
+        self.pretty.as_ref().map_or(false, |(ref config, _)| {
+            config.separate_tuple_struct_members == Some(true)
+        })
+    }
+
+    /// Starts serializing the members of a tuple, tuple struct, or tuple
+    ///  variant, which are written on separate lines if `separate`
+    fn begin_tuple_members(
+        &mut self,
+        len: usize,
+        newtype_variant: bool,
+        separate: bool,
+    ) -> Result<Compound<'_, W, F>> {
+        let mut compound = if self.max_width().is_some() {
+            Compound::new_width_limited(self, newtype_variant)
+        } else {
+            if separate {
+                self.is_empty = Some(len == 0);
+
+                self.start_indent()?;
+            }
+
+            Compound::new(self, newtype_variant)
+        };
+        compound.separate_tuple_members = separate;
+
+        Ok(compound)
+    }
+
     fn compact_arrays(&self) -> bool {
         self.pretty
             .as_ref()
@@ -463,16 +1490,266 @@ impl<W: fmt::Write> Serializer<W> {
             .map_or(false, |(ref config, _)| config.compact_structs)
     }
 
+    fn skip_none_fields(&self) -> bool {
+        self.pretty
+            .as_ref()
+            .map_or(false, |(ref config, _)| config.skip_none_fields)
+    }
+
+    fn skip_empty_collections(&self) -> bool {
+        self.pretty
+            .as_ref()
+            .map_or(false, |(ref config, _)| config.skip_empty_collections)
+    }
+
+    fn utf8_bytes_as_string(&self) -> bool {
+        self.pretty
+            .as_ref()
+            .map_or(false, |(ref config, _)| config.utf8_bytes_as_string)
+    }
+
+    fn sort_seqs(&self) -> bool {
+        self.pretty
+            .as_ref()
+            .map_or(false, |(ref config, _)| config.sort_seqs)
+    }
+
+    /// Whether the new line after the opening parenthesis of a struct with
+    ///  `len` fields is deferred until the first field that is not skipped
+    fn defer_struct_new_line(&self, len: usize) -> bool {
+        len > 0
+            && (self.skip_none_fields() || self.skip_empty_collections())
+            && !self.compact_structs()
+    }
+
     fn compact_maps(&self) -> bool {
         self.pretty
             .as_ref()
             .map_or(false, |(ref config, _)| config.compact_maps)
     }
 
-    fn number_suffixes(&self) -> bool {
+    /// Writes the separator between a struct field name or map key and its
+    ///  value, which is deferred until the value is written with
+    ///  [`BraceStyle::NextLine`], see [`Serializer::break_before_block`]
+    fn write_value_separator(&mut self) -> fmt::Result {
+        if let Some((ref config, _)) = self.pretty {
+            if config.brace_style == BraceStyle::NextLine {
+                self.output.deferred_separator = Some(config.separator.clone());
+            } else {
+                self.output.write_str(&config.separator)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Starts a new line before the opening delimiter of a struct, tuple,
+    ///  sequence, or map with [`BraceStyle::NextLine`] if the delimiter
+    ///  follows a name (`after_name`) or a struct field name or map key, and
+    ///  if its contents span multiple lines (`multi_line`)
+    fn break_before_block(&mut self, after_name: bool, multi_line: bool) -> fmt::Result {
+        if let Some((ref config, ref pretty)) = self.pretty {
+            let after_key = self.output.deferred_separator.is_some();
+
+            if config.brace_style == BraceStyle::NextLine
+                && multi_line
+                && pretty.indent < config.depth_limit
+                && (after_name || after_key)
+            {
+                self.output.deferred_separator = None;
+                self.output.write_str(&config.new_line)?;
+                indent(&mut self.output, config, pretty)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the maximum line width if arrays and tuples at the current
+    ///  depth should be laid out based on their width
+    fn max_width(&self) -> Option<usize> {
+        self.pretty.as_ref().and_then(|(ref config, ref pretty)| {
+            config
+                .max_width
+                .filter(|_| pretty.indent < config.depth_limit)
+        })
+    }
+
+    /// Serializes `value` into a detached string, one indentation level
+    ///  deeper. If `column` is `None`, all arrays and tuples are written on
+    ///  a single line, otherwise they are laid out to fit the maximum width
+    ///  starting from `column`.
+    fn serialize_detached<T>(&self, value: &T, column: Option<usize>) -> Result<String>
+    where
+        T: ?Sized + Serialize,
+    {
+        let mut ser = Serializer {
+            output: Output {
+                writer: String::new(),
+                column,
+                line_start: false,
+                deferred_separator: None,
+            },
+            formatter: self.formatter.clone(),
+            pretty: self.pretty.as_ref().map(|(config, pretty)| {
+                let mut config = config.clone();
+
+                if column.is_none() {
+                    config.max_width = None;
+                    config.compact_arrays = true;
+                    config.separate_tuple_members = false;
+                    config.separate_tuple_struct_members = None;
+                }
+
+                (
+                    config,
+                    Pretty {
+                        indent: pretty.indent + 1,
+                        depth: pretty.depth,
+                    },
+                )
+            }),
+            default_extensions: self.default_extensions,
+            is_empty: None,
+            newtype_variant: false,
+            recursion_limit: self.recursion_limit,
+            depth: self.depth,
+            implicit_some_depth: 0,
+        };
+
+        value.serialize(&mut ser)?;
+
+        Ok(ser.output.writer)
+    }
+
+    /// Returns the width of a structural token written by the [`Formatter`]
+    fn token_width(&self, write: impl FnOnce(&mut F, &mut String) -> fmt::Result) -> usize {
+        let mut token = String::new();
+
+        // writing into a `String` cannot fail
+        let _ = write(&mut self.formatter.clone(), &mut token);
+
+        token.chars().count()
+    }
+
+    /// Serializes a struct field, preceded by its documentation from
+    ///  [`PrettyConfig::path_meta`], after its leading indentation. The
+    ///  caller must guard against exceeding the recursion limit.
+    fn serialize_struct_field<T>(&mut self, key: &str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        if !self.compact_structs() {
+            if let Some((ref config, ref pretty)) = self.pretty {
+                if let Some(ref field) = config.path_meta {
+                    for doc_line in field.doc().lines() {
+                        self.output.write_str("/// ")?;
+                        self.output.write_str(doc_line)?;
+                        self.output.write_char('\n')?;
+                        indent(&mut self.output, config, pretty)?;
+                    }
+                }
+            }
+        }
+
+        if self.quote_keys() {
+            self.serialize_escaped_str(key)?;
+        } else {
+            self.write_identifier(key)?;
+        }
+        self.formatter.write_key_separator(&mut self.output)?;
+        self.write_value_separator()?;
+
+        value.serialize(&mut *self)
+    }
+
+    /// Serializes a struct field or map entry into a detached string at the
+    ///  current indentation level, such that it can later be reordered
+    ///  according to [`PrettyConfig::field_order`]
+    fn serialize_entry_detached(
+        &mut self,
+        serialize: impl FnOnce(&mut Serializer<String, F>) -> Result<()>,
+    ) -> Result<String> {
+        let mut ser = Serializer {
+            output: Output {
+                writer: String::new(),
+                // the entry will start on its own line after the indentation
+                column: self.pretty.as_ref().and_then(|(config, pretty)| {
+                    self.output
+                        .column
+                        .map(|_| pretty.indent * config.indentor.chars().count())
+                }),
+                line_start: false,
+                deferred_separator: None,
+            },
+            formatter: self.formatter.clone(),
+            pretty: self.pretty.as_ref().map(|(config, pretty)| {
+                (
+                    config.clone(),
+                    Pretty {
+                        indent: pretty.indent,
+                        depth: pretty.depth,
+                    },
+                )
+            }),
+            default_extensions: self.default_extensions,
+            is_empty: None,
+            newtype_variant: false,
+            recursion_limit: self.recursion_limit,
+            depth: self.depth,
+            implicit_some_depth: 0,
+        };
+
+        serialize(&mut ser)?;
+
+        Ok(ser.output.writer)
+    }
+
+    /// Returns whether a number type suffix should be emitted, where
+    ///  `is_ambiguous` checks whether the number would otherwise be
+    ///  deserialized as a different type if its type is not known.
+    fn number_suffix(&self, is_ambiguous: impl FnOnce() -> bool) -> bool {
+        match self
+            .pretty
+            .as_ref()
+            .map_or(SuffixPolicy::Never, |(ref config, _)| {
+                config.number_suffixes
+            }) {
+            SuffixPolicy::Never => false,
+            SuffixPolicy::WhenAmbiguous => is_ambiguous(),
+            SuffixPolicy::Always => true,
+        }
+    }
+
+    /// Checks that the non-finite float `v` may be serialized, see
+    ///  [`PrettyConfig::non_finite_floats`].
+    fn check_non_finite_float(&self, v: f64) -> Result<()> {
+        let policy = self
+            .pretty
+            .as_ref()
+            .map_or(NonFinitePolicy::Emit, |(ref config, _)| {
+                config.non_finite_floats
+            });
+
+        match policy {
+            NonFinitePolicy::Emit => Ok(()),
+            NonFinitePolicy::Error if v.is_nan() && v.is_sign_negative() => {
+                Err(Error::NonFiniteFloat(format!("-{v}")))
+            }
+            NonFinitePolicy::Error => Err(Error::NonFiniteFloat(v.to_string())),
+        }
+    }
+
+    fn field_order(&self) -> Option<&[String]> {
         self.pretty
             .as_ref()
-            .map_or(false, |(ref config, _)| config.number_suffixes)
+            .and_then(|(ref config, _)| config.field_order.as_deref())
+    }
+
+    fn quote_keys(&self) -> bool {
+        self.pretty
+            .as_ref()
+            .map_or(false, |(ref config, _)| config.quote_keys)
     }
 
     fn extensions(&self) -> Extensions {
@@ -480,7 +1757,21 @@ impl<W: fmt::Write> Serializer<W> {
             | self
                 .pretty
                 .as_ref()
-                .map_or(Extensions::empty(), |(ref config, _)| config.extensions)
+                .map_or(Extensions::empty(), |(ref config, _)| {
+                    let mut extensions = config.extensions;
+
+                    if config.implicit_some {
+                        extensions |= Extensions::IMPLICIT_SOME;
+                    }
+                    if config.unwrap_newtypes {
+                        extensions |= Extensions::UNWRAP_NEWTYPES;
+                    }
+                    if config.unwrap_variant_newtypes {
+                        extensions |= Extensions::UNWRAP_VARIANT_NEWTYPES;
+                    }
+
+                    extensions
+                })
     }
 
     fn escape_strings(&self) -> bool {
@@ -489,6 +1780,12 @@ impl<W: fmt::Write> Serializer<W> {
             .map_or(true, |(ref config, _)| config.escape_strings)
     }
 
+    fn prefer_raw_strings(&self) -> bool {
+        self.pretty
+            .as_ref()
+            .map_or(false, |(ref config, _)| config.prefer_raw_strings)
+    }
+
     fn start_indent(&mut self) -> Result<()> {
         if let Some((ref config, ref mut pretty)) = self.pretty {
             pretty.indent += 1;
@@ -530,28 +1827,44 @@ impl<W: fmt::Write> Serializer<W> {
 
     fn serialize_escaped_str(&mut self, value: &str) -> fmt::Result {
         self.output.write_char('"')?;
-        let mut scalar = [0u8; 4];
-        for c in value.chars().flat_map(char::escape_debug) {
-            self.output.write_str(c.encode_utf8(&mut scalar))?;
+        for c in value.chars() {
+            self.write_escaped_char(c)?;
         }
         self.output.write_char('"')?;
         Ok(())
     }
 
+    /// Writes `c` escaped like Rust's [`char::escape_debug`], such that
+    ///  quotes, backslashes, and non-printable characters, e.g. control
+    ///  characters or a leading combining character, are never written raw
+    fn write_escaped_char(&mut self, c: char) -> fmt::Result {
+        let fixed_unicode_escapes = self.pretty.as_ref().map_or(false, |(ref config, _)| {
+            config.unicode_escape_style == UnicodeEscape::Fixed
+        });
+
+        let escaped = c.escape_debug();
+
+        // Only non-printable characters use Unicode escapes, e.g. `\u{200b}`
+        if fixed_unicode_escapes && escaped.clone().nth(1) == Some('u') {
+            let mut units = [0u16; 2];
+            for unit in c.encode_utf16(&mut units) {
+                write!(self.output, "\\u{unit:04x}")?;
+            }
+        } else {
+            let mut scalar = [0u8; 4];
+            for c in escaped {
+                self.output.write_str(c.encode_utf8(&mut scalar))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes `value` verbatim as a string, or as a raw string if it
+    ///  contains a `"` or `\\`, e.g. a Windows path like `r"C:\Users"`
     fn serialize_unescaped_or_raw_str(&mut self, value: &str) -> fmt::Result {
         if value.contains('"') || value.contains('\\') {
-            let (_, num_consecutive_hashes) =
-                value.chars().fold((0, 0), |(count, max), c| match c {
-                    '#' => (count + 1, max.max(count + 1)),
-                    _ => (0_usize, max),
-                });
-            let hashes: String = "#".repeat(num_consecutive_hashes + 1);
-            self.output.write_char('r')?;
-            self.output.write_str(&hashes)?;
-            self.output.write_char('"')?;
-            self.output.write_str(value)?;
-            self.output.write_char('"')?;
-            self.output.write_str(&hashes)?;
+            self.serialize_raw_str(value)?;
         } else {
             self.output.write_char('"')?;
             self.output.write_str(value)?;
@@ -560,9 +1873,29 @@ impl<W: fmt::Write> Serializer<W> {
         Ok(())
     }
 
+    /// Writes `value` as a raw string with the minimal number of `#`s that
+    ///  is needed to keep every `"` in `value` from ending the string
+    fn serialize_raw_str(&mut self, value: &str) -> fmt::Result {
+        let num_hashes = value
+            .split('"')
+            .skip(1)
+            .map(|after_quote| after_quote.len() - after_quote.trim_start_matches('#').len() + 1)
+            .max()
+            .unwrap_or(0);
+        let hashes: String = "#".repeat(num_hashes);
+
+        self.output.write_char('r')?;
+        self.output.write_str(&hashes)?;
+        self.output.write_char('"')?;
+        self.output.write_str(value)?;
+        self.output.write_char('"')?;
+        self.output.write_str(&hashes)?;
+        Ok(())
+    }
+
     fn serialize_escaped_byte_str(&mut self, value: &[u8]) -> fmt::Result {
         self.output.write_str("b\"")?;
-        for c in value.iter().flat_map(|c| std::ascii::escape_default(*c)) {
+        for c in value.iter().flat_map(|c| core::ascii::escape_default(*c)) {
             self.output.write_char(char::from(c))?;
         }
         self.output.write_char('"')?;
@@ -591,11 +1924,46 @@ impl<W: fmt::Write> Serializer<W> {
         Ok(())
     }
 
+    /// Writes the decimal digits of an integer, grouped by underscores
+    ///  according to [`PrettyConfig::digit_grouping`]
+    fn write_integer(&mut self, value: impl fmt::Display) -> fmt::Result {
+        let group = self
+            .pretty
+            .as_ref()
+            .and_then(|(ref config, _)| config.digit_grouping)
+            .filter(|group| *group > 0);
+
+        let group = match group {
+            Some(group) => group,
+            // TODO optimize
+            None => return write!(self.output, "{value}"),
+        };
+
+        let value = value.to_string();
+        let (sign, digits) = match value.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", value.as_str()),
+        };
+
+        self.output.write_str(sign)?;
+
+        // integers are written with ASCII digits only
+        for (i, digit) in digits.char_indices() {
+            if i > 0 && (digits.len() - i) % group == 0 {
+                self.output.write_char('_')?;
+            }
+            self.output.write_char(digit)?;
+        }
+
+        Ok(())
+    }
+
     fn serialize_sint(&mut self, value: impl Into<LargeSInt>, suffix: &str) -> Result<()> {
-        // TODO optimize
-        write!(self.output, "{}", value.into())?;
+        let value = value.into();
 
-        if self.number_suffixes() {
+        self.write_integer(value)?;
+
+        if self.number_suffix(|| untyped_sint_suffix(value) != suffix) {
             write!(self.output, "{}", suffix)?;
         }
 
@@ -603,10 +1971,11 @@ impl<W: fmt::Write> Serializer<W> {
     }
 
     fn serialize_uint(&mut self, value: impl Into<LargeUInt>, suffix: &str) -> Result<()> {
-        // TODO optimize
-        write!(self.output, "{}", value.into())?;
+        let value = value.into();
+
+        self.write_integer(value)?;
 
-        if self.number_suffixes() {
+        if self.number_suffix(|| untyped_uint_suffix(value) != suffix) {
             write!(self.output, "{}", suffix)?;
         }
 
@@ -618,11 +1987,7 @@ impl<W: fmt::Write> Serializer<W> {
         let mut chars = name.chars();
         if !chars.next().map_or(false, is_ident_first_char)
             || !chars.all(is_xid_continue)
-            || [
-                "true", "false", "Some", "None", "inf", "inff32", "inff64", "NaN", "NaNf32",
-                "NaNf64",
-            ]
-            .contains(&name)
+            || RESERVED_IDENTIFIERS.contains(&name)
         {
             self.output.write_str("r#")?;
         }
@@ -644,10 +2009,9 @@ impl<W: fmt::Write> Serializer<W> {
     fn struct_names(&self) -> bool {
         self.extensions()
             .contains(Extensions::EXPLICIT_STRUCT_NAMES)
-            || self
-                .pretty
-                .as_ref()
-                .map_or(false, |(pc, _)| pc.struct_names)
+            || self.pretty.as_ref().map_or(false, |(pc, pretty)| {
+                pc.struct_names && pretty.depth >= pc.struct_names_depth.unwrap_or(0)
+            })
     }
 }
 
@@ -661,8 +2025,12 @@ macro_rules! guard_recursion {
             }
         }
 
+        $self.depth += 1;
+
         let result = $expr;
 
+        $self.depth -= 1;
+
         if let Some(limit) = &mut $self.recursion_limit {
             *limit = limit.saturating_add(1);
         }
@@ -671,19 +2039,19 @@ macro_rules! guard_recursion {
     }};
 }
 
-impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::Serializer for &'a mut Serializer<W, F> {
     type Error = Error;
     type Ok = ();
-    type SerializeMap = Compound<'a, W>;
-    type SerializeSeq = Compound<'a, W>;
-    type SerializeStruct = Compound<'a, W>;
-    type SerializeStructVariant = Compound<'a, W>;
-    type SerializeTuple = Compound<'a, W>;
-    type SerializeTupleStruct = Compound<'a, W>;
-    type SerializeTupleVariant = Compound<'a, W>;
+    type SerializeMap = Compound<'a, W, F>;
+    type SerializeSeq = Compound<'a, W, F>;
+    type SerializeStruct = Compound<'a, W, F>;
+    type SerializeStructVariant = Compound<'a, W, F>;
+    type SerializeTuple = Compound<'a, W, F>;
+    type SerializeTupleStruct = Compound<'a, W, F>;
+    type SerializeTupleVariant = Compound<'a, W, F>;
 
     fn serialize_bool(self, v: bool) -> Result<()> {
-        self.output.write_str(if v { "true" } else { "false" })?;
+        self.formatter.write_bool(&mut self.output, v)?;
         Ok(())
     }
 
@@ -730,17 +2098,28 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
     }
 
     fn serialize_f32(self, v: f32) -> Result<()> {
+        if !v.is_finite() {
+            self.check_non_finite_float(f64::from(v))?;
+        }
+
         if v.is_nan() && v.is_sign_negative() {
             write!(self.output, "-")?;
         }
 
         write!(self.output, "{}", v)?;
 
-        if v.fract() == 0.0 {
+        // `v.fract()` is not available without `std`
+        if v % 1.0 == 0.0 {
             write!(self.output, ".0")?;
         }
 
-        if self.number_suffixes() {
+        // The shortest representation of `v` is deserialized as an `f64`
+        //  if it is not exactly the value of `v`, e.g. `0.1`
+        if self.number_suffix(|| {
+            v.to_string()
+                .parse::<f64>()
+                .map_or(true, |parsed| parsed.total_cmp(&f64::from(v)).is_ne())
+        }) {
             write!(self.output, "f32")?;
         }
 
@@ -748,17 +2127,24 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
     }
 
     fn serialize_f64(self, v: f64) -> Result<()> {
+        if !v.is_finite() {
+            self.check_non_finite_float(v)?;
+        }
+
         if v.is_nan() && v.is_sign_negative() {
             write!(self.output, "-")?;
         }
 
         write!(self.output, "{}", v)?;
 
-        if v.fract() == 0.0 {
+        // `v.fract()` is not available without `std`
+        if v % 1.0 == 0.0 {
             write!(self.output, ".0")?;
         }
 
-        if self.number_suffixes() {
+        // `v` is deserialized as an `f32` if it can be represented exactly
+        #[allow(clippy::cast_possible_truncation)]
+        if self.number_suffix(|| f64::from(v as f32).total_cmp(&v).is_eq()) {
             write!(self.output, "f64")?;
         }
 
@@ -767,16 +2153,20 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
 
     fn serialize_char(self, v: char) -> Result<()> {
         self.output.write_char('\'')?;
-        if v == '\\' || v == '\'' {
-            self.output.write_char('\\')?;
+        if v == '"' {
+            // a double quote does not need to be escaped inside a char
+            self.output.write_char(v)?;
+        } else {
+            self.write_escaped_char(v)?;
         }
-        write!(self.output, "{}", v)?;
         self.output.write_char('\'')?;
         Ok(())
     }
 
     fn serialize_str(self, v: &str) -> Result<()> {
-        if self.escape_strings() {
+        if self.prefer_raw_strings() {
+            self.serialize_raw_str(v)?;
+        } else if self.escape_strings() {
             self.serialize_escaped_str(v)?;
         } else {
             self.serialize_unescaped_or_raw_str(v)?;
@@ -788,7 +2178,7 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
     fn serialize_bytes(self, v: &[u8]) -> Result<()> {
         // We need to fall back to escaping if the byte string would be invalid UTF-8
         if !self.escape_strings() {
-            if let Ok(v) = std::str::from_utf8(v) {
+            if let Ok(v) = core::str::from_utf8(v) {
                 return self
                     .serialize_unescaped_or_raw_byte_str(v)
                     .map_err(Error::from);
@@ -806,11 +2196,11 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
         self.implicit_some_depth = 0;
 
         for _ in 0..implicit_some_depth {
-            self.output.write_str("Some(")?;
+            self.formatter.begin_some(&mut self.output)?;
         }
-        self.output.write_str("None")?;
+        self.formatter.write_none(&mut self.output)?;
         for _ in 0..implicit_some_depth {
-            self.output.write_char(')')?;
+            self.formatter.end_some(&mut self.output)?;
         }
 
         Ok(())
@@ -827,13 +2217,13 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
             self.newtype_variant = self
                 .extensions()
                 .contains(Extensions::UNWRAP_VARIANT_NEWTYPES);
-            self.output.write_str("Some(")?;
+            self.formatter.begin_some(&mut self.output)?;
         }
         guard_recursion! { self => value.serialize(&mut *self)? };
         if implicit_some {
             self.implicit_some_depth = 0;
         } else {
-            self.output.write_char(')')?;
+            self.formatter.end_some(&mut self.output)?;
             self.newtype_variant = false;
         }
 
@@ -842,7 +2232,7 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
 
     fn serialize_unit(self) -> Result<()> {
         if !self.newtype_variant {
-            self.output.write_str("()")?;
+            self.formatter.write_unit(&mut self.output)?;
         }
 
         Ok(())
@@ -880,13 +2270,13 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
             self.implicit_some_depth = 0;
 
             for _ in 0..implicit_some_depth {
-                self.output.write_str("Some(")?;
+                self.formatter.begin_some(&mut self.output)?;
             }
 
             guard_recursion! { self => value.serialize(raw::Serializer::new(self)) }?;
 
             for _ in 0..implicit_some_depth {
-                self.output.write_char(')')?;
+                self.formatter.end_some(&mut self.output)?;
             }
 
             return Ok(());
@@ -908,9 +2298,27 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
 
         self.implicit_some_depth = 0;
 
-        self.output.write_char('(')?;
+        let separate = self.separate_newtype_struct_members();
+
+        self.formatter.begin_tuple(&mut self.output)?;
+        if separate {
+            self.is_empty = Some(false);
+            self.start_indent()?;
+            self.indent()?;
+        }
+
         guard_recursion! { self => value.serialize(&mut *self)? };
-        self.output.write_char(')')?;
+
+        if separate {
+            if let Some((ref config, ref pretty)) = self.pretty {
+                if pretty.indent <= config.depth_limit {
+                    self.formatter.write_element_separator(&mut self.output)?;
+                    self.output.write_str(&config.new_line)?;
+                }
+            }
+            self.end_indent()?;
+        }
+        self.formatter.end_tuple(&mut self.output)?;
 
         Ok(())
     }
@@ -927,7 +2335,7 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
     {
         self.validate_identifier(name)?;
         self.write_identifier(variant)?;
-        self.output.write_char('(')?;
+        self.formatter.begin_tuple(&mut self.output)?;
 
         self.newtype_variant = self
             .extensions()
@@ -938,7 +2346,7 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
 
         self.newtype_variant = false;
 
-        self.output.write_char(')')?;
+        self.formatter.end_tuple(&mut self.output)?;
         Ok(())
     }
 
@@ -946,17 +2354,15 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
         self.newtype_variant = false;
         self.implicit_some_depth = 0;
 
-        self.output.write_char('[')?;
-
-        if !self.compact_arrays() {
-            if let Some(len) = len {
-                self.is_empty = Some(len == 0);
-            }
-
-            self.start_indent()?;
+        let mut compound = Compound::new(self, false);
+
+        if compound.ser.utf8_bytes_as_string() {
+            compound.utf8_bytes = Some(Vec::new());
+        } else {
+            compound.begin_seq(len)?;
         }
 
-        Ok(Compound::new(self, false))
+        Ok(compound)
     }
 
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
@@ -964,17 +2370,14 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
         self.newtype_variant = false;
         self.implicit_some_depth = 0;
 
-        if !old_newtype_variant {
-            self.output.write_char('(')?;
-        }
+        let separate = self.separate_tuple_members();
 
-        if self.separate_tuple_members() {
-            self.is_empty = Some(len == 0);
-
-            self.start_indent()?;
+        if !old_newtype_variant {
+            self.break_before_block(false, separate && self.max_width().is_none() && len > 0)?;
+            self.formatter.begin_tuple(&mut self.output)?;
         }
 
-        Ok(Compound::new(self, old_newtype_variant))
+        self.begin_tuple_members(len, old_newtype_variant, separate)
     }
 
     fn serialize_tuple_struct(
@@ -982,13 +2385,26 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
         name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleStruct> {
-        if self.struct_names() && !self.newtype_variant {
+        let named = self.struct_names() && !self.newtype_variant;
+
+        if named {
             self.write_identifier(name)?;
         } else {
             self.validate_identifier(name)?;
         }
 
-        self.serialize_tuple(len)
+        let old_newtype_variant = self.newtype_variant;
+        self.newtype_variant = false;
+        self.implicit_some_depth = 0;
+
+        let separate = self.separate_tuple_struct_members();
+
+        if !old_newtype_variant {
+            self.break_before_block(named, separate && self.max_width().is_none() && len > 0)?;
+            self.formatter.begin_tuple(&mut self.output)?;
+        }
+
+        self.begin_tuple_members(len, old_newtype_variant, separate)
     }
 
     fn serialize_tuple_variant(
@@ -1003,22 +2419,21 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
 
         self.validate_identifier(name)?;
         self.write_identifier(variant)?;
-        self.output.write_char('(')?;
 
-        if self.separate_tuple_members() {
-            self.is_empty = Some(len == 0);
+        let separate = self.separate_tuple_struct_members();
 
-            self.start_indent()?;
-        }
+        self.break_before_block(true, separate && self.max_width().is_none() && len > 0)?;
+        self.formatter.begin_tuple(&mut self.output)?;
 
-        Ok(Compound::new(self, false))
+        self.begin_tuple_members(len, false, separate)
     }
 
     fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
         self.newtype_variant = false;
         self.implicit_some_depth = 0;
 
-        self.output.write_char('{')?;
+        self.break_before_block(false, !self.compact_maps() && len != Some(0))?;
+        self.formatter.begin_map(&mut self.output)?;
 
         if !self.compact_maps() {
             if let Some(len) = len {
@@ -1028,6 +2443,10 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
             self.start_indent()?;
         }
 
+        if self.field_order().is_some() {
+            return Ok(Compound::new_field_ordered(self, false));
+        }
+
         Ok(Compound::new(self, false))
     }
 
@@ -1039,20 +2458,33 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
         if old_newtype_variant {
             self.validate_identifier(name)?;
         } else {
-            if self.struct_names() {
+            let named = self.struct_names();
+
+            if named {
                 self.write_identifier(name)?;
             } else {
                 self.validate_identifier(name)?;
             }
-            self.output.write_char('(')?;
+
+            self.break_before_block(named, !self.compact_structs() && len > 0)?;
+            self.formatter.begin_struct(&mut self.output)?;
         }
 
+        let deferred_new_line = self.defer_struct_new_line(len);
+
         if !self.compact_structs() {
-            self.is_empty = Some(len == 0);
+            self.is_empty = Some(len == 0 || deferred_new_line);
             self.start_indent()?;
         }
 
-        Ok(Compound::new(self, old_newtype_variant))
+        let mut compound = if self.field_order().is_some() {
+            Compound::new_field_ordered(self, old_newtype_variant)
+        } else {
+            Compound::new(self, old_newtype_variant)
+        };
+        compound.deferred_new_line = deferred_new_line;
+
+        Ok(compound)
     }
 
     fn serialize_struct_variant(
@@ -1067,14 +2499,24 @@ impl<'a, W: fmt::Write> ser::Serializer for &'a mut Serializer<W> {
 
         self.validate_identifier(name)?;
         self.write_identifier(variant)?;
-        self.output.write_char('(')?;
+        self.break_before_block(true, !self.compact_structs() && len > 0)?;
+        self.formatter.begin_struct(&mut self.output)?;
+
+        let deferred_new_line = self.defer_struct_new_line(len);
 
         if !self.compact_structs() {
-            self.is_empty = Some(len == 0);
+            self.is_empty = Some(len == 0 || deferred_new_line);
             self.start_indent()?;
         }
 
-        Ok(Compound::new(self, false))
+        let mut compound = if self.field_order().is_some() {
+            Compound::new_field_ordered(self, false)
+        } else {
+            Compound::new(self, false)
+        };
+        compound.deferred_new_line = deferred_new_line;
+
+        Ok(compound)
     }
 }
 
@@ -1083,45 +2525,172 @@ enum State {
     Rest,
 }
 
+/// Element of an array or tuple that is laid out based on
+///  [`PrettyConfig::max_width`]
+struct WidthLimitedElement {
+    prefix: String,
+    compact: String,
+    // `None` if the compact layout already fits onto its own line
+    expanded: Option<String>,
+}
+
+/// Struct field or map entry that is reordered based on
+///  [`PrettyConfig::field_order`]
+struct OrderedEntry {
+    name: String,
+    // the entry without its leading separator and indentation
+    entry: String,
+}
+
 #[doc(hidden)]
-pub struct Compound<'a, W: fmt::Write> {
-    ser: &'a mut Serializer<W>,
+pub struct Compound<'a, W: fmt::Write, F: Formatter = DefaultFormatter> {
+    ser: &'a mut Serializer<W, F>,
     state: State,
     newtype_variant: bool,
     sequence_index: usize,
+    width_limited: Option<Vec<WidthLimitedElement>>,
+    field_ordered: Option<Vec<OrderedEntry>>,
+    // serialized key of the map entry whose value is serialized next
+    field_ordered_key: Option<String>,
+    // whether the new line after the opening parenthesis is only written
+    // before the first field, since all fields might be skipped
+    deferred_new_line: bool,
+    // whether the members of a tuple-like are written on separate lines
+    separate_tuple_members: bool,
+    // bytes of a sequence that might still be written as a byte string,
+    // before its opening bracket has been written
+    utf8_bytes: Option<Vec<u8>>,
+    // serialized elements of a sequence that are sorted before they are
+    // written, see `PrettyConfig::sort_seqs`
+    sorted_elements: Option<Vec<String>>,
 }
 
-impl<'a, W: fmt::Write> Compound<'a, W> {
-    fn new(ser: &'a mut Serializer<W>, newtype_variant: bool) -> Self {
+impl<'a, W: fmt::Write, F: Formatter> Compound<'a, W, F> {
+    fn new(ser: &'a mut Serializer<W, F>, newtype_variant: bool) -> Self {
+        if let Some((_, ref mut pretty)) = ser.pretty {
+            pretty.depth += 1;
+        }
+
         Compound {
             ser,
             state: State::First,
             newtype_variant,
             sequence_index: 0,
+            width_limited: None,
+            field_ordered: None,
+            field_ordered_key: None,
+            deferred_new_line: false,
+            separate_tuple_members: false,
+            utf8_bytes: None,
+            sorted_elements: None,
         }
     }
-}
 
-impl<'a, W: fmt::Write> Drop for Compound<'a, W> {
-    fn drop(&mut self) {
-        if let Some(limit) = &mut self.ser.recursion_limit {
-            *limit = limit.saturating_add(1);
+    fn new_field_ordered(ser: &'a mut Serializer<W, F>, newtype_variant: bool) -> Self {
+        let mut compound = Compound::new(ser, newtype_variant);
+        compound.field_ordered = Some(Vec::new());
+        compound
+    }
+
+    /// Writes the separator before a struct field or map entry and, unless
+    ///  `compact`, indents it onto its own line
+    fn start_entry(&mut self, compact: bool) -> Result<()> {
+        if let State::First = self.state {
+            self.state = State::Rest;
+
+            if self.deferred_new_line {
+                if let Some((ref config, ref pretty)) = self.ser.pretty {
+                    if pretty.indent <= config.depth_limit {
+                        self.ser.output.write_str(&config.new_line)?;
+                    }
+                }
+            }
+        } else {
+            self.ser
+                .formatter
+                .write_element_separator(&mut self.ser.output)?;
+
+            if let Some((ref config, ref pretty)) = self.ser.pretty {
+                if pretty.indent <= config.depth_limit && !compact {
+                    self.ser.output.write_str(&config.new_line)?;
+                } else {
+                    self.ser.output.write_str(&config.separator)?;
+                }
+            }
+        }
+
+        if !compact {
+            self.ser.indent()?;
         }
+
+        Ok(())
     }
-}
 
-impl<'a, W: fmt::Write> ser::SerializeSeq for Compound<'a, W> {
-    type Error = Error;
-    type Ok = ();
+    /// Writes out all buffered struct fields or map entries, first those
+    ///  listed in [`PrettyConfig::field_order`] in the given order and then
+    ///  all others in alphabetical order
+    fn end_field_ordered(&mut self, mut entries: Vec<OrderedEntry>, compact: bool) -> Result<()> {
+        let field_order = self.ser.field_order().unwrap_or_default();
+        let rank = |entry: &OrderedEntry| {
+            field_order
+                .iter()
+                .position(|name| *name == entry.name)
+                .unwrap_or(field_order.len())
+        };
 
-    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
-    where
-        T: ?Sized + Serialize,
-    {
+        entries.sort_by(|a, b| rank(a).cmp(&rank(b)).then_with(|| a.name.cmp(&b.name)));
+
+        for entry in entries {
+            self.start_entry(compact)?;
+            self.ser.output.write_str(&entry.entry)?;
+        }
+
+        Ok(())
+    }
+
+    fn new_width_limited(ser: &'a mut Serializer<W, F>, newtype_variant: bool) -> Self {
+        let mut compound = Compound::new(ser, newtype_variant);
+        compound.width_limited = Some(Vec::new());
+        compound
+    }
+
+    /// Writes the opening bracket of a sequence with `len` elements
+    fn begin_seq(&mut self, len: Option<usize>) -> Result<()> {
+        let multi_line =
+            self.ser.max_width().is_none() && !self.ser.compact_arrays() && len != Some(0);
+
+        self.ser.break_before_block(false, multi_line)?;
+        self.ser.formatter.begin_seq(&mut self.ser.output)?;
+
+        if self.ser.max_width().is_some() {
+            self.width_limited = Some(Vec::new());
+            return Ok(());
+        }
+
+        if self.ser.sort_seqs() {
+            self.sorted_elements = Some(Vec::new());
+        }
+
+        if !self.ser.compact_arrays() {
+            if let Some(len) = len {
+                self.ser.is_empty = Some(len == 0);
+            }
+
+            self.ser.start_indent()?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the separator before a sequence element and, unless arrays
+    ///  are compact, indents it onto its own line
+    fn start_seq_element(&mut self) -> Result<()> {
         if let State::First = self.state {
             self.state = State::Rest;
         } else {
-            self.ser.output.write_char(',')?;
+            self.ser
+                .formatter
+                .write_element_separator(&mut self.ser.output)?;
             if let Some((ref config, ref mut pretty)) = self.ser.pretty {
                 if pretty.indent <= config.depth_limit && !config.compact_arrays {
                     self.ser.output.write_str(&config.new_line)?;
@@ -1142,16 +2711,234 @@ impl<'a, W: fmt::Write> ser::SerializeSeq for Compound<'a, W> {
             }
         }
 
+        Ok(())
+    }
+
+    /// Gives up on writing the sequence as a byte string, opens it as an
+    ///  array with `remaining` more elements after the buffered bytes, and
+    ///  writes the buffered bytes as its first elements
+    fn flush_utf8_bytes(&mut self, remaining: usize) -> Result<()> {
+        let bytes = self.utf8_bytes.take().unwrap_or_default();
+
+        self.begin_seq(Some(bytes.len() + remaining))?;
+
+        for byte in bytes {
+            ser::SerializeSeq::serialize_element(self, &byte)?;
+        }
+
+        Ok(())
+    }
+
+    /// Buffers an element, both in its compact layout and, if that does not
+    ///  fit onto its own line, in its expanded layout
+    fn buffer_width_limited_element<T>(&mut self, value: &T, prefix: String) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let compact = self.ser.serialize_detached(value, None)?;
+
+        let expanded = match self.ser.pretty {
+            Some((ref config, ref pretty)) => {
+                let column =
+                    (pretty.indent + 1) * config.indentor.chars().count() + prefix.chars().count();
+
+                match config.max_width {
+                    Some(max_width)
+                        if compact.contains('\n')
+                            || column + compact.chars().count() > max_width =>
+                    {
+                        Some(self.ser.serialize_detached(value, Some(column))?)
+                    }
+                    _ => None,
+                }
+            }
+            None => None,
+        };
+
+        if let Some(ref mut elements) = self.width_limited {
+            elements.push(WidthLimitedElement {
+                prefix,
+                compact,
+                expanded,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Writes out all buffered elements on a single line if they fit within
+    ///  the maximum width, and one element per line otherwise
+    fn end_width_limited(
+        &mut self,
+        elements: &[WidthLimitedElement],
+        closing_width: usize,
+    ) -> Result<()> {
+        let element_separator_width = self.ser.token_width(F::write_element_separator);
+
+        if let Some((ref config, ref pretty)) = self.ser.pretty {
+            let separator_width = element_separator_width + config.separator.chars().count();
+            let compact_width = elements
+                .iter()
+                .map(|element| element.prefix.chars().count() + element.compact.chars().count())
+                .sum::<usize>()
+                + elements.len().saturating_sub(1) * separator_width
+                + closing_width;
+
+            let fits = elements.is_empty()
+                || elements
+                    .iter()
+                    .all(|element| !element.compact.contains('\n'))
+                    && config.max_width.map_or(true, |max_width| {
+                        self.ser.output.column.unwrap_or(0) + compact_width <= max_width
+                    });
+
+            if fits {
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        self.ser
+                            .formatter
+                            .write_element_separator(&mut self.ser.output)?;
+                        self.ser.output.write_str(&config.separator)?;
+                    }
+                    self.ser.output.write_str(&element.prefix)?;
+                    self.ser.output.write_str(&element.compact)?;
+                }
+            } else {
+                let inner = Pretty {
+                    indent: pretty.indent + 1,
+                    depth: pretty.depth,
+                };
+
+                self.ser.output.write_str(&config.new_line)?;
+                for element in elements {
+                    indent(&mut self.ser.output, config, &inner)?;
+                    self.ser.output.write_str(&element.prefix)?;
+                    self.ser
+                        .output
+                        .write_str(element.expanded.as_ref().unwrap_or(&element.compact))?;
+                    self.ser
+                        .formatter
+                        .write_element_separator(&mut self.ser.output)?;
+                    self.ser.output.write_str(&config.new_line)?;
+                }
+                indent(&mut self.ser.output, config, pretty)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, W: fmt::Write, F: Formatter> Drop for Compound<'a, W, F> {
+    fn drop(&mut self) {
+        if let Some(limit) = &mut self.ser.recursion_limit {
+            *limit = limit.saturating_add(1);
+        }
+
+        if let Some((_, ref mut pretty)) = self.ser.pretty {
+            pretty.depth -= 1;
+        }
+    }
+}
+
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeSeq for Compound<'a, W, F> {
+    type Error = Error;
+    type Ok = ();
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        if let Some(ref mut bytes) = self.utf8_bytes {
+            if let Ok(probe::Probed::U8(byte)) = value.serialize(probe::Probe) {
+                bytes.push(byte);
+                return Ok(());
+            }
+
+            self.flush_utf8_bytes(1)?;
+        }
+
+        if self.width_limited.is_some() {
+            let prefix = match self.ser.pretty {
+                Some((ref config, _)) if config.enumerate_arrays => {
+                    let index = self.sequence_index;
+                    self.sequence_index += 1;
+                    format!("/*[{index}]*/ ")
+                }
+                _ => String::new(),
+            };
+
+            return guard_recursion! { self.ser => self.buffer_width_limited_element(value, prefix) };
+        }
+
+        if self.sorted_elements.is_some() {
+            let element = self.ser.serialize_entry_detached(|ser| {
+                guard_recursion! { ser => value.serialize(&mut *ser) }
+            })?;
+
+            if let Some(ref mut elements) = self.sorted_elements {
+                elements.push(element);
+            }
+
+            return Ok(());
+        }
+
+        self.start_seq_element()?;
+
         guard_recursion! { self.ser => value.serialize(&mut *self.ser)? };
 
         Ok(())
     }
 
-    fn end(self) -> Result<()> {
+    fn end(mut self) -> Result<()> {
+        if let Some(ref bytes) = self.utf8_bytes {
+            if let (false, Ok(string)) = (bytes.is_empty(), core::str::from_utf8(bytes)) {
+                if self.ser.escape_strings() {
+                    self.ser.output.write_char('b')?;
+                    self.ser.serialize_escaped_str(string)?;
+                } else {
+                    self.ser.serialize_unescaped_or_raw_byte_str(string)?;
+                }
+
+                return Ok(());
+            }
+
+            self.flush_utf8_bytes(0)?;
+        }
+
+        if let Some(mut elements) = self.width_limited.take() {
+            if self.ser.sort_seqs() {
+                elements.sort_by(|a, b| a.compact.cmp(&b.compact));
+
+                // renumber the elements in their sorted order
+                for (index, element) in elements.iter_mut().enumerate() {
+                    if !element.prefix.is_empty() {
+                        element.prefix = format!("/*[{index}]*/ ");
+                    }
+                }
+            }
+
+            let closing_width = self.ser.token_width(F::end_seq);
+            self.end_width_limited(&elements, closing_width)?;
+            self.ser.formatter.end_seq(&mut self.ser.output)?;
+            return Ok(());
+        }
+
+        if let Some(mut elements) = self.sorted_elements.take() {
+            elements.sort();
+
+            for element in elements {
+                self.start_seq_element()?;
+                self.ser.output.write_str(&element)?;
+            }
+        }
+
         if let State::Rest = self.state {
             if let Some((ref config, ref mut pretty)) = self.ser.pretty {
                 if pretty.indent <= config.depth_limit && !config.compact_arrays {
-                    self.ser.output.write_char(',')?;
+                    self.ser
+                        .formatter
+                        .write_element_separator(&mut self.ser.output)?;
                     self.ser.output.write_str(&config.new_line)?;
                 }
             }
@@ -1162,12 +2949,12 @@ impl<'a, W: fmt::Write> ser::SerializeSeq for Compound<'a, W> {
         }
 
         // seq always disables `self.newtype_variant`
-        self.ser.output.write_char(']')?;
+        self.ser.formatter.end_seq(&mut self.ser.output)?;
         Ok(())
     }
 }
 
-impl<'a, W: fmt::Write> ser::SerializeTuple for Compound<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeTuple for Compound<'a, W, F> {
     type Error = Error;
     type Ok = ();
 
@@ -1175,12 +2962,27 @@ impl<'a, W: fmt::Write> ser::SerializeTuple for Compound<'a, W> {
     where
         T: ?Sized + Serialize,
     {
+        if self.width_limited.is_some() {
+            let prefix = match self.ser.pretty {
+                Some((ref config, _)) if config.enumerate_tuples && self.separate_tuple_members => {
+                    let index = self.sequence_index;
+                    self.sequence_index += 1;
+                    format!("/*{index}:*/ ")
+                }
+                _ => String::new(),
+            };
+
+            return guard_recursion! { self.ser => self.buffer_width_limited_element(value, prefix) };
+        }
+
         if let State::First = self.state {
             self.state = State::Rest;
         } else {
-            self.ser.output.write_char(',')?;
+            self.ser
+                .formatter
+                .write_element_separator(&mut self.ser.output)?;
             if let Some((ref config, ref pretty)) = self.ser.pretty {
-                if pretty.indent <= config.depth_limit && self.ser.separate_tuple_members() {
+                if pretty.indent <= config.depth_limit && self.separate_tuple_members {
                     self.ser.output.write_str(&config.new_line)?;
                 } else {
                     self.ser.output.write_str(&config.separator)?;
@@ -1188,8 +2990,15 @@ impl<'a, W: fmt::Write> ser::SerializeTuple for Compound<'a, W> {
             }
         }
 
-        if self.ser.separate_tuple_members() {
+        if self.separate_tuple_members {
             self.ser.indent()?;
+
+            if let Some((ref config, ref pretty)) = self.ser.pretty {
+                if pretty.indent <= config.depth_limit && config.enumerate_tuples {
+                    write!(self.ser.output, "/*{}:*/ ", self.sequence_index)?;
+                    self.sequence_index += 1;
+                }
+            }
         }
 
         guard_recursion! { self.ser => value.serialize(&mut *self.ser)? };
@@ -1197,21 +3006,35 @@ impl<'a, W: fmt::Write> ser::SerializeTuple for Compound<'a, W> {
         Ok(())
     }
 
-    fn end(self) -> Result<()> {
+    fn end(mut self) -> Result<()> {
+        if let Some(elements) = self.width_limited.take() {
+            // the closing delimiter is written by the newtype variant otherwise
+            let closing_width = self.ser.token_width(F::end_tuple);
+            self.end_width_limited(&elements, closing_width)?;
+
+            if !self.newtype_variant {
+                self.ser.formatter.end_tuple(&mut self.ser.output)?;
+            }
+
+            return Ok(());
+        }
+
         if let State::Rest = self.state {
             if let Some((ref config, ref pretty)) = self.ser.pretty {
-                if self.ser.separate_tuple_members() && pretty.indent <= config.depth_limit {
-                    self.ser.output.write_char(',')?;
+                if self.separate_tuple_members && pretty.indent <= config.depth_limit {
+                    self.ser
+                        .formatter
+                        .write_element_separator(&mut self.ser.output)?;
                     self.ser.output.write_str(&config.new_line)?;
                 }
             }
         }
-        if self.ser.separate_tuple_members() {
+        if self.separate_tuple_members {
             self.ser.end_indent()?;
         }
 
         if !self.newtype_variant {
-            self.ser.output.write_char(')')?;
+            self.ser.formatter.end_tuple(&mut self.ser.output)?;
         }
 
         Ok(())
@@ -1219,7 +3042,7 @@ impl<'a, W: fmt::Write> ser::SerializeTuple for Compound<'a, W> {
 }
 
 // Same thing but for tuple structs.
-impl<'a, W: fmt::Write> ser::SerializeTupleStruct for Compound<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeTupleStruct for Compound<'a, W, F> {
     type Error = Error;
     type Ok = ();
 
@@ -1235,7 +3058,7 @@ impl<'a, W: fmt::Write> ser::SerializeTupleStruct for Compound<'a, W> {
     }
 }
 
-impl<'a, W: fmt::Write> ser::SerializeTupleVariant for Compound<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeTupleVariant for Compound<'a, W, F> {
     type Error = Error;
     type Ok = ();
 
@@ -1251,7 +3074,7 @@ impl<'a, W: fmt::Write> ser::SerializeTupleVariant for Compound<'a, W> {
     }
 }
 
-impl<'a, W: fmt::Write> ser::SerializeMap for Compound<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeMap for Compound<'a, W, F> {
     type Error = Error;
     type Ok = ();
 
@@ -1259,23 +3082,17 @@ impl<'a, W: fmt::Write> ser::SerializeMap for Compound<'a, W> {
     where
         T: ?Sized + Serialize,
     {
-        if let State::First = self.state {
-            self.state = State::Rest;
-        } else {
-            self.ser.output.write_char(',')?;
+        if self.field_ordered.is_some() {
+            let key = self.ser.serialize_entry_detached(|ser| {
+                guard_recursion! { ser => key.serialize(&mut *ser) }
+            })?;
+            self.field_ordered_key = Some(key);
 
-            if let Some((ref config, ref pretty)) = self.ser.pretty {
-                if pretty.indent <= config.depth_limit && !config.compact_maps {
-                    self.ser.output.write_str(&config.new_line)?;
-                } else {
-                    self.ser.output.write_str(&config.separator)?;
-                }
-            }
+            return Ok(());
         }
 
-        if !self.ser.compact_maps() {
-            self.ser.indent()?;
-        }
+        let compact = self.ser.compact_maps();
+        self.start_entry(compact)?;
 
         guard_recursion! { self.ser => key.serialize(&mut *self.ser) }
     }
@@ -1284,22 +3101,52 @@ impl<'a, W: fmt::Write> ser::SerializeMap for Compound<'a, W> {
     where
         T: ?Sized + Serialize,
     {
-        self.ser.output.write_char(':')?;
+        if let Some(mut entry) = self.field_ordered_key.take() {
+            // string keys are named by their content, all others by their RON
+            let name = match entry
+                .strip_prefix('"')
+                .and_then(|key| key.strip_suffix('"'))
+            {
+                Some(key) if !key.contains(['"', '\\']) => String::from(key),
+                _ => entry.clone(),
+            };
+
+            entry.push_str(&self.ser.serialize_entry_detached(|ser| {
+                ser.formatter.write_key_separator(&mut ser.output)?;
+                ser.write_value_separator()?;
+
+                guard_recursion! { ser => value.serialize(&mut *ser) }
+            })?);
+
+            if let Some(ref mut entries) = self.field_ordered {
+                entries.push(OrderedEntry { name, entry });
+            }
 
-        if let Some((ref config, _)) = self.ser.pretty {
-            self.ser.output.write_str(&config.separator)?;
+            return Ok(());
         }
 
+        self.ser
+            .formatter
+            .write_key_separator(&mut self.ser.output)?;
+        self.ser.write_value_separator()?;
+
         guard_recursion! { self.ser => value.serialize(&mut *self.ser)? };
 
         Ok(())
     }
 
-    fn end(self) -> Result<()> {
+    fn end(mut self) -> Result<()> {
+        if let Some(entries) = self.field_ordered.take() {
+            let compact = self.ser.compact_maps();
+            self.end_field_ordered(entries, compact)?;
+        }
+
         if let State::Rest = self.state {
             if let Some((ref config, ref pretty)) = self.ser.pretty {
                 if pretty.indent <= config.depth_limit && !config.compact_maps {
-                    self.ser.output.write_char(',')?;
+                    self.ser
+                        .formatter
+                        .write_element_separator(&mut self.ser.output)?;
                     self.ser.output.write_str(&config.new_line)?;
                 }
             }
@@ -1310,12 +3157,12 @@ impl<'a, W: fmt::Write> ser::SerializeMap for Compound<'a, W> {
         }
 
         // map always disables `self.newtype_variant`
-        self.ser.output.write_char('}')?;
+        self.ser.formatter.end_map(&mut self.ser.output)?;
         Ok(())
     }
 }
 
-impl<'a, W: fmt::Write> ser::SerializeStruct for Compound<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeStruct for Compound<'a, W, F> {
     type Error = Error;
     type Ok = ();
 
@@ -1323,6 +3170,16 @@ impl<'a, W: fmt::Write> ser::SerializeStruct for Compound<'a, W> {
     where
         T: ?Sized + Serialize,
     {
+        if self.ser.skip_none_fields() || self.ser.skip_empty_collections() {
+            match value.serialize(probe::Probe) {
+                Ok(probe::Probed::None) if self.ser.skip_none_fields() => return Ok(()),
+                Ok(probe::Probed::EmptyCollection) if self.ser.skip_empty_collections() => {
+                    return Ok(())
+                }
+                _ => (),
+            }
+        }
+
         let mut restore_field = self.ser.pretty.as_mut().and_then(|(config, _)| {
             config.path_meta.take().map(|mut field| {
                 if let Some(fields) = field.fields_mut() {
@@ -1332,46 +3189,26 @@ impl<'a, W: fmt::Write> ser::SerializeStruct for Compound<'a, W> {
             })
         });
 
-        if let State::First = self.state {
-            self.state = State::Rest;
-        } else {
-            self.ser.output.write_char(',')?;
-
-            if let Some((ref config, ref pretty)) = self.ser.pretty {
-                if pretty.indent <= config.depth_limit && !config.compact_structs {
-                    self.ser.output.write_str(&config.new_line)?;
-                } else {
-                    self.ser.output.write_str(&config.separator)?;
-                }
-            }
-        }
-
-        if !self.ser.compact_structs() {
-            if let Some((ref config, ref pretty)) = self.ser.pretty {
-                indent(&mut self.ser.output, config, pretty)?;
+        if self.field_ordered.is_some() {
+            let entry = self.ser.serialize_entry_detached(|ser| {
+                guard_recursion! { ser => ser.serialize_struct_field(key, value) }
+            })?;
 
-                if let Some(ref field) = config.path_meta {
-                    for doc_line in field.doc().lines() {
-                        self.ser.output.write_str("/// ")?;
-                        self.ser.output.write_str(doc_line)?;
-                        self.ser.output.write_char('\n')?;
-                        indent(&mut self.ser.output, config, pretty)?;
-                    }
-                }
+            if let Some(ref mut entries) = self.field_ordered {
+                entries.push(OrderedEntry {
+                    name: String::from(key),
+                    entry,
+                });
             }
-        }
-
-        self.ser.write_identifier(key)?;
-        self.ser.output.write_char(':')?;
+        } else {
+            let compact = self.ser.compact_structs();
+            self.start_entry(compact)?;
 
-        if let Some((ref config, _)) = self.ser.pretty {
-            self.ser.output.write_str(&config.separator)?;
+            guard_recursion! { self.ser => self.ser.serialize_struct_field(key, value)? };
         }
 
-        guard_recursion! { self.ser => value.serialize(&mut *self.ser)? };
-
         if let Some((ref mut config, _)) = self.ser.pretty {
-            std::mem::swap(&mut config.path_meta, &mut restore_field);
+            core::mem::swap(&mut config.path_meta, &mut restore_field);
 
             if let Some(ref mut field) = config.path_meta {
                 if let Some(fields) = field.fields_mut() {
@@ -1385,29 +3222,39 @@ impl<'a, W: fmt::Write> ser::SerializeStruct for Compound<'a, W> {
         Ok(())
     }
 
-    fn end(self) -> Result<()> {
+    fn end(mut self) -> Result<()> {
+        if let Some(entries) = self.field_ordered.take() {
+            let compact = self.ser.compact_structs();
+            self.end_field_ordered(entries, compact)?;
+        }
+
         if let State::Rest = self.state {
             if let Some((ref config, ref pretty)) = self.ser.pretty {
                 if pretty.indent <= config.depth_limit && !config.compact_structs {
-                    self.ser.output.write_char(',')?;
+                    self.ser
+                        .formatter
+                        .write_element_separator(&mut self.ser.output)?;
                     self.ser.output.write_str(&config.new_line)?;
                 }
             }
         }
 
         if !self.ser.compact_structs() {
+            if self.deferred_new_line {
+                self.ser.is_empty = Some(matches!(self.state, State::First));
+            }
             self.ser.end_indent()?;
         }
 
         if !self.newtype_variant {
-            self.ser.output.write_char(')')?;
+            self.ser.formatter.end_struct(&mut self.ser.output)?;
         }
 
         Ok(())
     }
 }
 
-impl<'a, W: fmt::Write> ser::SerializeStructVariant for Compound<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::SerializeStructVariant for Compound<'a, W, F> {
     type Error = Error;
     type Ok = ();
 
@@ -98,7 +98,7 @@
 //! );
 //! ```
 
-use std::collections::HashMap;
+use alloc::{collections::BTreeMap, string::String};
 
 use serde_derive::{Deserialize, Serialize};
 
@@ -234,7 +234,7 @@ impl Field {
 /// Mapping of names to [`Field`]s.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct Fields {
-    fields: HashMap<String, Field>,
+    fields: BTreeMap<String, Field>,
 }
 
 impl Fields {
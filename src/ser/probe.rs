@@ -0,0 +1,251 @@
+use serde::{ser, Serialize};
+
+use super::{Error, Result};
+
+/// The kind of a value that a [`Probe`] looked at.
+pub enum Probed {
+    /// The value is `None`
+    None,
+    /// The value is a `u8`
+    U8(u8),
+    /// The value is a sequence or map without any elements
+    EmptyCollection,
+    /// The value is anything else
+    Other,
+}
+
+/// Serializer that only checks what kind of value is serialized, e.g. to
+///  skip `None` struct fields, see
+///  [`super::PrettyConfig::skip_none_fields`], or to find byte sequences,
+///  see [`super::PrettyConfig::utf8_bytes_as_string`].
+///
+/// Compound values are rejected without being walked, except for sequences
+///  and maps without a known non-zero length, whose elements are counted
+///  but not serialized, see [`ProbeCollection`].
+pub struct Probe;
+
+/// Checks whether a sequence or map has any elements, see
+///  [`super::PrettyConfig::skip_empty_collections`].
+pub struct ProbeCollection {
+    empty: bool,
+}
+
+impl ProbeCollection {
+    fn probed(&self) -> Probed {
+        if self.empty {
+            Probed::EmptyCollection
+        } else {
+            Probed::Other
+        }
+    }
+}
+
+impl ser::Serializer for Probe {
+    type Error = Error;
+    type Ok = Probed;
+    type SerializeMap = ProbeCollection;
+    type SerializeSeq = ProbeCollection;
+    type SerializeStruct = ser::Impossible<Probed, Error>;
+    type SerializeStructVariant = ser::Impossible<Probed, Error>;
+    type SerializeTuple = ser::Impossible<Probed, Error>;
+    type SerializeTupleStruct = ser::Impossible<Probed, Error>;
+    type SerializeTupleVariant = ser::Impossible<Probed, Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_i128(self, _: i128) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Probed> {
+        Ok(Probed::U8(v))
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_u128(self, _: u128) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_char(self, _: char) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_str(self, _: &str) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_none(self) -> Result<Probed> {
+        Ok(Probed::None)
+    }
+
+    fn serialize_some<T>(self, _: &T) -> Result<Probed>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_unit(self) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<Probed> {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, _: &T) -> Result<Probed>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Probed>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(Probed::Other)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        match len {
+            Some(0) | None => Ok(ProbeCollection { empty: true }),
+            Some(_) => Err(Error::ExpectedOption),
+        }
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        Err(Error::ExpectedOption)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(Error::ExpectedOption)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(Error::ExpectedOption)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        match len {
+            Some(0) | None => Ok(ProbeCollection { empty: true }),
+            Some(_) => Err(Error::ExpectedOption),
+        }
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct> {
+        Err(Error::ExpectedOption)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(Error::ExpectedOption)
+    }
+}
+
+impl ser::SerializeSeq for ProbeCollection {
+    type Error = Error;
+    type Ok = Probed;
+
+    fn serialize_element<T>(&mut self, _: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.empty = false;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Probed> {
+        Ok(self.probed())
+    }
+}
+
+impl ser::SerializeMap for ProbeCollection {
+    type Error = Error;
+    type Ok = Probed;
+
+    fn serialize_key<T>(&mut self, _: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.empty = false;
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, _: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Probed> {
+        Ok(self.probed())
+    }
+}
@@ -1,20 +1,20 @@
-use std::fmt;
+use core::{fmt, fmt::Write};
 
 use serde::{ser, Serialize};
 
-use super::{Error, Result};
+use super::{Error, Formatter, Result};
 
-pub struct Serializer<'a, W: fmt::Write> {
-    ser: &'a mut super::Serializer<W>,
+pub struct Serializer<'a, W: fmt::Write, F: Formatter> {
+    ser: &'a mut super::Serializer<W, F>,
 }
 
-impl<'a, W: fmt::Write> Serializer<'a, W> {
-    pub fn new(ser: &'a mut super::Serializer<W>) -> Self {
+impl<'a, W: fmt::Write, F: Formatter> Serializer<'a, W, F> {
+    pub fn new(ser: &'a mut super::Serializer<W, F>) -> Self {
         Self { ser }
     }
 }
 
-impl<'a, W: fmt::Write> ser::Serializer for Serializer<'a, W> {
+impl<'a, W: fmt::Write, F: Formatter> ser::Serializer for Serializer<'a, W, F> {
     type Error = Error;
     type Ok = ();
     type SerializeMap = ser::Impossible<(), Error>;
@@ -235,20 +235,20 @@ fn check_ser_any_number<T: Copy + Into<Number> + std::fmt::Display + serde::Seri
 fn recursion_limit() {
     assert_eq!(
         crate::Options::default()
-            .with_recursion_limit(0)
+            .with_depth_limit(0)
             .to_string(&[42]),
         Err(crate::Error::ExceededRecursionLimit),
     );
     assert_eq!(
         crate::Options::default()
-            .with_recursion_limit(1)
+            .with_depth_limit(1)
             .to_string(&[42])
             .as_deref(),
         Ok("(42)"),
     );
     assert_eq!(
         crate::Options::default()
-            .without_recursion_limit()
+            .without_depth_limit()
             .to_string(&[42])
             .as_deref(),
         Ok("(42)"),
@@ -256,20 +256,20 @@ fn recursion_limit() {
 
     assert_eq!(
         crate::Options::default()
-            .with_recursion_limit(1)
+            .with_depth_limit(1)
             .to_string(&[[42]]),
         Err(crate::Error::ExceededRecursionLimit),
     );
     assert_eq!(
         crate::Options::default()
-            .with_recursion_limit(2)
+            .with_depth_limit(2)
             .to_string(&[[42]])
             .as_deref(),
         Ok("((42))"),
     );
     assert_eq!(
         crate::Options::default()
-            .without_recursion_limit()
+            .without_depth_limit()
             .to_string(&[[42]])
             .as_deref(),
         Ok("((42))"),
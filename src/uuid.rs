@@ -0,0 +1,63 @@
+//! Serialize and deserialize [`Uuid`]s as quoted, lowercase, hyphenated
+//! strings like `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+//!
+//! This module is only available with the `uuid` feature and is meant to be
+//! used with `#[serde(with = "ron::uuid")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use uuid::Uuid;
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Entity {
+//!     #[serde(with = "ron::uuid")]
+//!     id: Uuid,
+//! }
+//!
+//! let entity: Entity =
+//!     ron::from_str(r#"(id: "{67E55044-10B1-426F-9247-BB680E5FE0C8}")"#).unwrap();
+//! assert_eq!(entity.id.as_u128(), 0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+//!
+//! assert_eq!(
+//!     ron::to_string(&entity).unwrap(),
+//!     r#"(id:"67e55044-10b1-426f-9247-bb680e5fe0c8")"#,
+//! );
+//! ```
+//!
+//! UUIDs are always written in the lowercase, hyphenated form, but are
+//! parsed using [`Uuid::parse_str`], which also accepts the simple, e.g.
+//! `"67e5504410b1426f9247bb680e5fe0c8"`, braced, e.g.
+//! `"{67e55044-10b1-426f-9247-bb680e5fe0c8}"`, and URN, e.g.
+//! `"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"`, forms in either case.
+
+use core::fmt;
+
+use ::uuid::Uuid;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes a [`Uuid`] as a lowercase, hyphenated string, e.g.
+/// `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&uuid.hyphenated())
+}
+
+/// Deserializes a [`Uuid`] from a simple, hyphenated, braced, or URN string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+    deserializer.deserialize_str(UuidVisitor)
+}
+
+struct UuidVisitor;
+
+impl de::Visitor<'_> for UuidVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a UUID string, e.g. \"67e55044-10b1-426f-9247-bb680e5fe0c8\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Uuid::parse_str(v).map_err(E::custom)
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::value::{Map, Value};
+
+/// The differences between two [`Value`] trees, see [`Value::diff`].
+///
+/// The [`Display`](fmt::Display) implementation renders the differences as
+/// a readable patch, with one line per change.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ValueDiff {
+    /// The changes that turn the old value into the new one, in the order
+    /// of their paths in the old value, followed by any additions
+    pub changes: Vec<ValueChange>,
+}
+
+/// A single change between two [`Value`] trees, see [`Value::diff`].
+///
+/// The `path` of a change is a [JSON Pointer] (RFC 6901) to the changed
+/// value, see [`Value::pointer`].
+///
+/// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueChange {
+    /// A map entry or sequence element that only exists in the new value
+    Added { path: String, value: Value },
+    /// A map entry or sequence element that only exists in the old value
+    Removed { path: String, value: Value },
+    /// A value that is different in the old and the new value
+    Changed {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+impl ValueDiff {
+    /// Returns `true` if the two values have no differences.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl ValueChange {
+    /// Returns the [JSON Pointer] (RFC 6901) to the changed value.
+    ///
+    /// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            ValueChange::Added { path, .. }
+            | ValueChange::Removed { path, .. }
+            | ValueChange::Changed { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for ValueDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+
+            write!(f, "{change}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ValueChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = match self.path() {
+            "" => "(root)",
+            path => path,
+        };
+
+        match self {
+            ValueChange::Added { value, .. } => write!(f, "+ {path}: {value}"),
+            ValueChange::Removed { value, .. } => write!(f, "- {path}: {value}"),
+            ValueChange::Changed { old, new, .. } => write!(f, "~ {path}: {old} -> {new}"),
+        }
+    }
+}
+
+impl Value {
+    /// Computes the [`ValueDiff`] that describes which paths were added,
+    /// removed, or changed between this old value and the `other` new one.
+    ///
+    /// - Two maps are compared entry by entry, where keys and values are
+    ///   matched like in [`Value::semantic_eq`]. The path of an entry is its
+    ///   string key, or otherwise its key written as RON.
+    /// - Two sequences are compared element by element by their index, so
+    ///   inserting an element into the middle of a sequence also reports
+    ///   every following element as changed and the last one as added.
+    /// - Two `Some(...)` are compared by their content, at the same path,
+    ///   just as [`Value::pointer`] looks through them.
+    /// - Any other two values are changed unless they are equal by
+    ///   [`Value::semantic_eq`], e.g. `1` and `1.0` are not changed.
+    ///
+    /// ```
+    /// use ron::Value;
+    ///
+    /// let old: Value = ron::from_str("(name: \"ron\", ports: [80, 443], tls: true)").unwrap();
+    /// let new: Value = ron::from_str("(name: \"ron\", ports: [8080, 443, 9000], debug: true)").unwrap();
+    ///
+    /// assert_eq!(
+    ///     old.diff(&new).to_string(),
+    ///     "~ /ports/0: 80 -> 8080\n+ /ports/2: 9000\n- /tls: true\n+ /debug: true",
+    /// );
+    /// assert!(old.diff(&old).is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Value) -> ValueDiff {
+        let mut diff = ValueDiff::default();
+
+        diff_values(self, other, &mut String::new(), &mut diff.changes);
+
+        diff
+    }
+}
+
+fn diff_values(old: &Value, new: &Value, path: &mut String, changes: &mut Vec<ValueChange>) {
+    match (old, new) {
+        (Value::Map(old), Value::Map(new)) => diff_maps(old, new, path, changes),
+        (Value::Seq(old), Value::Seq(new)) => {
+            for (index, (old, new)) in old.iter().zip(new).enumerate() {
+                with_token(path, &index.to_string(), |path| {
+                    diff_values(old, new, path, changes);
+                });
+            }
+
+            for (index, value) in old.iter().enumerate().skip(new.len()) {
+                changes.push(ValueChange::Removed {
+                    path: format!("{path}/{index}"),
+                    value: value.clone(),
+                });
+            }
+
+            for (index, value) in new.iter().enumerate().skip(old.len()) {
+                changes.push(ValueChange::Added {
+                    path: format!("{path}/{index}"),
+                    value: value.clone(),
+                });
+            }
+        }
+        (Value::Option(Some(old)), Value::Option(Some(new))) => {
+            diff_values(old, new, path, changes);
+        }
+        (old, new) if old.semantic_eq(new) => {}
+        (old, new) => changes.push(ValueChange::Changed {
+            path: path.clone(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+    }
+}
+
+fn diff_maps(old: &Map, new: &Map, path: &mut String, changes: &mut Vec<ValueChange>) {
+    for (key, old) in old.iter() {
+        let token = key_token(key);
+
+        match find_entry(new, key) {
+            Some(new) => with_token(path, &token, |path| diff_values(old, new, path, changes)),
+            None => changes.push(ValueChange::Removed {
+                path: format!("{path}/{token}"),
+                value: old.clone(),
+            }),
+        }
+    }
+
+    for (key, new) in new.iter() {
+        if find_entry(old, key).is_none() {
+            changes.push(ValueChange::Added {
+                path: format!("{path}/{}", key_token(key)),
+                value: new.clone(),
+            });
+        }
+    }
+}
+
+/// Looks up the value for `key` in `map`, matching keys like
+/// [`Value::semantic_eq`].
+fn find_entry<'a>(map: &'a Map, key: &Value) -> Option<&'a Value> {
+    map.get(key).or_else(|| {
+        map.iter()
+            .find(|(other, _)| key.semantic_eq(other))
+            .map(|(_, value)| value)
+    })
+}
+
+/// Appends the escaped reference `token` to the `path` while `f` runs.
+fn with_token(path: &mut String, token: &str, f: impl FnOnce(&mut String)) {
+    let len = path.len();
+
+    path.push('/');
+    path.push_str(token);
+
+    f(path);
+
+    path.truncate(len);
+}
+
+/// Writes a map key as an escaped JSON Pointer reference token, i.e. a
+/// string key as is and any other key as compact RON.
+fn key_token(key: &Value) -> String {
+    let token = match key {
+        Value::String(key) => key.clone(),
+        key => format!("{key}"),
+    };
+
+    token.replace('~', "~0").replace('/', "~1")
+}
@@ -0,0 +1,77 @@
+/// Builds a [`Value::Seq`](crate::Value::Seq) from a list of elements,
+/// which are converted using [`Value::from`](crate::Value).
+///
+/// ```
+/// use ron::value::{map, seq, Value};
+///
+/// let document = map! {
+///     "name" => "proxy",
+///     "ports" => seq![80, 443],
+///     "tls" => Option::<Value>::None,
+///     "upstreams" => seq![
+///         map! { "host" => "10.0.0.1", "weight" => 0.5 },
+///         map! { "backup" => true, "host" => "10.0.0.2" },
+///     ],
+/// };
+///
+/// assert_eq!(
+///     ron::to_string(&document).unwrap(),
+///     "{\"name\":\"proxy\",\"ports\":[80,443],\"tls\":None,\"upstreams\":[\
+///         {\"host\":\"10.0.0.1\",\"weight\":0.5},\
+///         {\"backup\":true,\"host\":\"10.0.0.2\"}\
+///     ]}",
+/// );
+///
+/// assert_eq!(seq![], Value::Seq(Vec::new()));
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __value_seq {
+    ($($element:expr),* $(,)?) => {
+        <$crate::Value as ::core::iter::FromIterator<$crate::Value>>::from_iter([
+            $($crate::Value::from($element)),*
+        ])
+    };
+}
+
+/// Builds a [`Value::Map`](crate::Value::Map) from a list of
+/// `key => value` entries, whose keys and values are converted using
+/// [`Value::from`](crate::Value).
+///
+/// ```
+/// use ron::value::{map, seq, Map, Value};
+///
+/// let dependencies = map! {
+///     "ron" => "0.9",
+///     "serde" => map! { "features" => seq!["derive"], "version" => "1.0" },
+/// };
+///
+/// assert_eq!(
+///     ron::ser::to_string_pretty(&dependencies, Default::default()).unwrap(),
+///     "{
+///     \"ron\": \"0.9\",
+///     \"serde\": {
+///         \"features\": [
+///             \"derive\",
+///         ],
+///         \"version\": \"1.0\",
+///     },
+/// }",
+/// );
+///
+/// assert_eq!(map! {}, Value::Map(Map::new()));
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __value_map {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        $crate::Value::Map(
+            <$crate::value::Map as ::core::iter::FromIterator<(
+                $crate::Value,
+                $crate::Value,
+            )>>::from_iter([
+                $(($crate::Value::from($key), $crate::Value::from($value))),*
+            ]),
+        )
+    };
+}
@@ -1,4 +1,5 @@
-use std::{
+use alloc::{collections::BTreeMap, string::String};
+use core::{
     cmp::{Eq, Ordering},
     hash::{Hash, Hasher},
     iter::FromIterator,
@@ -7,11 +8,11 @@ use std::{
 
 use serde_derive::{Deserialize, Serialize};
 
-use super::Value;
+use super::{Number, Value};
 
 /// A [`Value`] to [`Value`] map.
 ///
-/// This structure either uses a [`BTreeMap`](std::collections::BTreeMap) or the
+/// This structure either uses a [`BTreeMap`](alloc::collections::BTreeMap) or the
 /// [`IndexMap`](indexmap::IndexMap) internally.
 /// The latter can be used by enabling the `indexmap` feature. This can be used
 /// to preserve the order of the parsed map.
@@ -20,10 +21,20 @@ use super::Value;
 pub struct Map(pub(crate) MapInner);
 
 #[cfg(not(feature = "indexmap"))]
-type MapInner = std::collections::BTreeMap<Value, Value>;
+type MapInner = alloc::collections::BTreeMap<Value, Value>;
 #[cfg(feature = "indexmap")]
 type MapInner = indexmap::IndexMap<Value, Value>;
 
+#[cfg(not(feature = "indexmap"))]
+type VacantEntryInner<'a> = alloc::collections::btree_map::VacantEntry<'a, Value, Value>;
+#[cfg(feature = "indexmap")]
+type VacantEntryInner<'a> = indexmap::map::VacantEntry<'a, Value, Value>;
+
+#[cfg(not(feature = "indexmap"))]
+type OccupiedEntryInner<'a> = alloc::collections::btree_map::OccupiedEntry<'a, Value, Value>;
+#[cfg(feature = "indexmap")]
+type OccupiedEntryInner<'a> = indexmap::map::OccupiedEntry<'a, Value, Value>;
+
 impl Map {
     /// Creates a new, empty [`Map`].
     #[must_use]
@@ -54,6 +65,68 @@ impl Map {
         self.0.get_mut(key)
     }
 
+    /// Immutably looks up an element by its string `key`.
+    #[must_use]
+    pub fn get_by_str(&self, key: &str) -> Option<&Value> {
+        self.get(&Value::String(String::from(key)))
+    }
+
+    /// Looks up an element by its `key` and returns it if it is a string.
+    #[must_use]
+    pub fn get_str(&self, key: &Value) -> Option<&str> {
+        match self.get(key) {
+            Some(Value::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Looks up an element by its `key` and returns it if it is an integer
+    /// that fits into an [`i64`].
+    #[must_use]
+    pub fn get_i64(&self, key: &Value) -> Option<i64> {
+        match self.get(key) {
+            Some(Value::Number(number)) => match *number {
+                Number::I8(v) => Some(i64::from(v)),
+                Number::I16(v) => Some(i64::from(v)),
+                Number::I32(v) => Some(i64::from(v)),
+                Number::I64(v) => Some(v),
+                #[cfg(feature = "integer128")]
+                Number::I128(v) => i64::try_from(v).ok(),
+                Number::U8(v) => Some(i64::from(v)),
+                Number::U16(v) => Some(i64::from(v)),
+                Number::U32(v) => Some(i64::from(v)),
+                Number::U64(v) => i64::try_from(v).ok(),
+                #[cfg(feature = "integer128")]
+                Number::U128(v) => i64::try_from(v).ok(),
+                Number::F32(_) | Number::F64(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Looks up an element by its `key` and returns it if it is a boolean.
+    #[must_use]
+    pub fn get_bool(&self, key: &Value) -> Option<bool> {
+        match self.get(key) {
+            Some(Value::Bool(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets the [`Entry`] for the given `key` for in-place manipulation.
+    pub fn entry(&mut self, key: impl Into<Value>) -> Entry<'_> {
+        #[cfg(not(feature = "indexmap"))]
+        use alloc::collections::btree_map::Entry as EntryInner;
+
+        #[cfg(feature = "indexmap")]
+        use indexmap::map::Entry as EntryInner;
+
+        match self.0.entry(key.into()) {
+            EntryInner::Vacant(entry) => Entry::Vacant(VacantEntry(entry)),
+            EntryInner::Occupied(entry) => Entry::Occupied(OccupiedEntry(entry)),
+        }
+    }
+
     /// Inserts a new element, returning the previous element with this `key` if
     /// there was any.
     pub fn insert(&mut self, key: impl Into<Value>, value: impl Into<Value>) -> Option<Value> {
@@ -116,6 +189,133 @@ impl Map {
     }
 }
 
+/// A view into a single entry of a [`Map`], which may either be vacant or
+/// occupied.
+///
+/// This enum is constructed from the [`Map::entry`] method.
+pub enum Entry<'a> {
+    /// A vacant entry.
+    Vacant(VacantEntry<'a>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a>),
+}
+
+/// A view into a vacant entry of a [`Map`].
+pub struct VacantEntry<'a>(VacantEntryInner<'a>);
+
+/// A view into an occupied entry of a [`Map`].
+pub struct OccupiedEntry<'a>(OccupiedEntryInner<'a>);
+
+impl<'a> Entry<'a> {
+    /// Returns a reference to the key of this entry.
+    #[must_use]
+    pub fn key(&self) -> &Value {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `default` if it is
+    /// vacant, and returns a mutable reference to the value in the entry.
+    pub fn or_insert(self, default: impl Into<Value>) -> &'a mut Value {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default`
+    /// if it is vacant, and returns a mutable reference to the value in the
+    /// entry.
+    pub fn or_insert_with<F, V>(self, default: F) -> &'a mut Value
+    where
+        F: FnOnce() -> V,
+        V: Into<Value>,
+    {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Value),
+    {
+        match self {
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+        }
+    }
+}
+
+impl<'a> VacantEntry<'a> {
+    /// Returns a reference to the key that would be used when inserting a
+    /// value through this entry.
+    #[must_use]
+    pub fn key(&self) -> &Value {
+        self.0.key()
+    }
+
+    /// Inserts the `value` into the entry and returns a mutable reference to
+    /// it.
+    pub fn insert(self, value: impl Into<Value>) -> &'a mut Value {
+        self.0.insert(value.into())
+    }
+}
+
+impl<'a> OccupiedEntry<'a> {
+    /// Returns a reference to the key of this entry.
+    #[must_use]
+    pub fn key(&self) -> &Value {
+        self.0.key()
+    }
+
+    /// Returns a reference to the value in this entry.
+    #[must_use]
+    pub fn get(&self) -> &Value {
+        self.0.get()
+    }
+
+    /// Returns a mutable reference to the value in this entry.
+    pub fn get_mut(&mut self) -> &mut Value {
+        self.0.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to its value, which is
+    /// bound to the lifetime of the map.
+    #[must_use]
+    pub fn into_mut(self) -> &'a mut Value {
+        self.0.into_mut()
+    }
+
+    /// Replaces the value in this entry with `value`, returning the old
+    /// value.
+    pub fn insert(&mut self, value: impl Into<Value>) -> Value {
+        self.0.insert(value.into())
+    }
+
+    /// Removes the entry from the map and returns its value.
+    #[must_use]
+    pub fn remove(self) -> Value {
+        #[cfg(feature = "indexmap")]
+        {
+            self.0.shift_remove()
+        }
+        #[cfg(not(feature = "indexmap"))]
+        {
+            self.0.remove()
+        }
+    }
+}
+
 impl Index<&Value> for Map {
     type Output = Value;
 
@@ -151,6 +351,12 @@ impl<K: Into<Value>, V: Into<Value>> FromIterator<(K, V)> for Map {
     }
 }
 
+impl<K: Into<Value>, V: Into<Value>> From<BTreeMap<K, V>> for Map {
+    fn from(value: BTreeMap<K, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
 /// Note: equality is only given if both values and order of values match
 impl PartialEq for Map {
     fn eq(&self, other: &Map) -> bool {
@@ -181,7 +387,7 @@ impl Hash for Map {
 
 #[cfg(test)]
 mod tests {
-    use super::{Map, Value};
+    use super::{Entry, Map, Value};
 
     #[test]
     fn map_usage() {
@@ -241,6 +447,96 @@ mod tests {
         assert_eq!(map.remove(&Value::from("a")), None);
     }
 
+    #[test]
+    fn map_typed_getters() {
+        let map: Map = [
+            (Value::from("name"), Value::from("ron")),
+            (Value::from("size"), Value::from(42_u8)),
+            (Value::from("big"), Value::from(u64::MAX)),
+            (Value::from("ratio"), Value::from(0.5)),
+            (Value::from("enabled"), Value::from(true)),
+            (Value::from(1), Value::from("one")),
+            (Value::from(2), Value::from(-2_i64)),
+            (Value::from(3), Value::from(false)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(map.get_by_str("name"), Some(&Value::from("ron")));
+        assert_eq!(map.get_by_str("size"), Some(&Value::from(42_u8)));
+        assert_eq!(map.get_by_str("missing"), None);
+
+        assert_eq!(map.get_str(&Value::from("name")), Some("ron"));
+        assert_eq!(map.get_str(&Value::from(1)), Some("one"));
+        assert_eq!(map.get_str(&Value::from("size")), None);
+        assert_eq!(map.get_str(&Value::from("missing")), None);
+
+        assert_eq!(map.get_i64(&Value::from("size")), Some(42));
+        assert_eq!(map.get_i64(&Value::from(2)), Some(-2));
+        assert_eq!(map.get_i64(&Value::from("big")), None);
+        assert_eq!(map.get_i64(&Value::from("ratio")), None);
+        assert_eq!(map.get_i64(&Value::from("name")), None);
+        assert_eq!(map.get_i64(&Value::from(4)), None);
+
+        assert_eq!(map.get_bool(&Value::from("enabled")), Some(true));
+        assert_eq!(map.get_bool(&Value::from(3)), Some(false));
+        assert_eq!(map.get_bool(&Value::from("name")), None);
+        assert_eq!(map.get_bool(&Value::from("missing")), None);
+    }
+
+    #[test]
+    fn map_entry() {
+        let mut map = Map::new();
+
+        assert_eq!(map.entry("a").key(), &Value::from("a"));
+        assert_eq!(map.entry("a").or_insert(1), &mut Value::from(1));
+        assert_eq!(map.entry("a").or_insert(2), &mut Value::from(1));
+        assert_eq!(
+            map.entry(1).or_insert_with(|| "one"),
+            &mut Value::from("one")
+        );
+
+        *map.entry(2).or_insert(0) = Value::from(2);
+        assert_eq!(map.get(&Value::from(2)), Some(&Value::from(2)));
+
+        map.entry("a")
+            .and_modify(|value| *value = Value::from(3))
+            .or_insert(4);
+        map.entry("b")
+            .and_modify(|value| *value = Value::from(3))
+            .or_insert(4);
+        assert_eq!(map.get(&Value::from("a")), Some(&Value::from(3)));
+        assert_eq!(map.get(&Value::from("b")), Some(&Value::from(4)));
+
+        match map.entry("c") {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &Value::from("c"));
+                assert_eq!(entry.insert(5), &mut Value::from(5));
+            }
+            Entry::Occupied(_) => panic!("entry `c` must be vacant"),
+        }
+
+        match map.entry(1) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), &Value::from(1));
+                assert_eq!(entry.get(), &Value::from("one"));
+                *entry.get_mut() = Value::from("uno");
+                assert_eq!(entry.insert("eins"), Value::from("uno"));
+                assert_eq!(entry.remove(), Value::from("eins"));
+            }
+            Entry::Vacant(_) => panic!("entry `1` must be occupied"),
+        }
+        assert_eq!(map.get(&Value::from(1)), None);
+
+        match map.entry("a") {
+            Entry::Occupied(entry) => *entry.into_mut() = Value::from(6),
+            Entry::Vacant(_) => panic!("entry `a` must be occupied"),
+        }
+        assert_eq!(map.get(&Value::from("a")), Some(&Value::from(6)));
+
+        assert_eq!(map.len(), 4);
+    }
+
     #[test]
     fn map_hash() {
         assert_same_hash(&Map::new(), &Map::new());
@@ -255,12 +551,12 @@ mod tests {
     }
 
     fn assert_same_hash(a: &Map, b: &Map) {
+        use core::hash::{Hash, Hasher};
         use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
 
         assert_eq!(a, b);
         assert!(a.cmp(b).is_eq());
-        assert_eq!(a.partial_cmp(b), Some(std::cmp::Ordering::Equal));
+        assert_eq!(a.partial_cmp(b), Some(core::cmp::Ordering::Equal));
 
         let mut hasher = DefaultHasher::new();
         a.hash(&mut hasher);
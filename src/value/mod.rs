@@ -1,23 +1,70 @@
 //! Value module.
 
-use std::{borrow::Cow, cmp::Eq, hash::Hash};
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeMap, format, string::String, vec::Vec};
+use core::{cmp::Eq, fmt, hash::Hash};
 
 use serde::{
-    de::{DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor},
-    forward_to_deserialize_any,
+    de::{
+        DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any, Serialize,
 };
 
-use crate::{de::Error, error::Result};
+use crate::{
+    de::Error,
+    error::Result,
+    options::Options,
+    ser::{PrettyConfig, Serializer},
+};
 
+mod diff;
+mod macros;
 mod map;
 mod number;
+mod pointer;
 pub(crate) mod raw;
+mod stats;
+#[cfg(feature = "toml")]
+mod toml;
+mod visit;
 
-pub use map::Map;
+pub use diff::{ValueChange, ValueDiff};
+pub use map::{Entry, Map, OccupiedEntry, VacantEntry};
 pub use number::{Number, F32, F64};
 #[allow(clippy::useless_attribute, clippy::module_name_repetitions)]
 pub use raw::RawValue;
-
+pub use stats::ValueStats;
+pub use visit::{PathSegment, ValueVisitor, ValueVisitorMut};
+
+#[doc(inline)]
+pub use crate::{__value_map as map, __value_seq as seq};
+
+/// A dynamically typed RON value.
+///
+/// Since a [`Value`] is deserialized without a type, which RON usually
+/// relies on, the kind of each value is inferred from its syntax alone:
+///
+/// - `true` and `false` are a [`Value::Bool`], while `()` and any other
+///   identifier on its own, e.g. a unit struct `Unit` or a unit variant
+///   `A`, are a [`Value::Unit`].
+/// - `None` and `Some(x)` are a [`Value::Option`], even without the
+///   `implicit_some` extension, which cannot apply without a type. Enum
+///   variants with these names must be written as `r#None` and `r#Some`.
+/// - Tuples `(a, b)`, tuple structs `Name(a, b)`, and newtype or tuple
+///   variants `A(a)` are a [`Value::Seq`], even with a single element, as
+///   are sequences `[a, b]`.
+/// - Structs `(x: 1)`, named structs `Name(x: 1)`, and struct variants
+///   `A(x: 1)` are a [`Value::Map`] from field name strings to values, as
+///   are maps `{k: v}`.
+/// - Numbers, including `inf` and `NaN`, are a [`Value::Number`], while
+///   strings, chars, and byte strings are a [`Value::String`],
+///   [`Value::Char`], and [`Value::Bytes`]. A number with a decimal point
+///   or an exponent, e.g. `1.0`, `1.`, or `1e0`, is always a float
+///   [`Number`], so it is not equal to the integer `1`, except by
+///   [`Value::semantic_eq`].
+///
+/// Struct and enum variant names are therefore not preserved.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Value {
     Bool(bool),
@@ -55,6 +102,12 @@ impl From<Map> for Value {
     }
 }
 
+impl<K: Into<Value>, V: Into<Value>> From<BTreeMap<K, V>> for Value {
+    fn from(value: BTreeMap<K, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
 impl<T: Into<Number>> From<T> for Value {
     fn from(value: T) -> Self {
         Self::Number(value.into())
@@ -124,6 +177,101 @@ impl Value {
     {
         T::deserialize(self)
     }
+
+    /// Compares this [`Value`] to `other` semantically, i.e. ignoring the
+    /// order of map entries and the representation of numbers. In contrast,
+    /// the [`PartialEq`] implementation is strictly structural.
+    ///
+    /// Two values are semantically equal if
+    /// - both are [`Value::Number`]s with the same mathematical value,
+    ///   regardless of their type, e.g. `1u8`, `1i64`, and `1.0f32` are
+    ///   equal. Floats are compared exactly, so `0.1f32` and `0.1f64` are
+    ///   not equal. `0.0` and `-0.0` are equal, and any `NaN` is equal to
+    ///   any other `NaN`, regardless of its sign and payload.
+    /// - both are [`Value::Map`]s with the same number of entries, where
+    ///   every entry of one map has a semantically equal key and value in
+    ///   the other map, regardless of their order.
+    /// - both are [`Value::Seq`]s of the same length whose elements are
+    ///   pairwise semantically equal, in order.
+    /// - both are `Some` [`Value::Option`]s with semantically equal contents.
+    /// - both are equal according to [`PartialEq`], for all other values.
+    ///
+    /// ```
+    /// use ron::Value;
+    ///
+    /// let a: Value = ron::from_str("{ \"x\": 1, \"y\": [2.0, -0.0] }").unwrap();
+    /// let b: Value = ron::from_str("{ \"y\": [2, 0], \"x\": 1.0 }").unwrap();
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.semantic_eq(&b));
+    /// ```
+    #[must_use]
+    pub fn semantic_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Map(a), Value::Map(b)) => {
+                a.len() == b.len() && semantic_map_subset(a, b) && semantic_map_subset(b, a)
+            }
+            (Value::Number(a), Value::Number(b)) => a.semantic_eq(*b),
+            (Value::Option(Some(a)), Value::Option(Some(b))) => a.semantic_eq(b),
+            (Value::Seq(a), Value::Seq(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.semantic_eq(b))
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// Formats a [`Value`] as compact RON, or as pretty RON with the default
+/// [`PrettyConfig`] in the alternate form, i.e. `{:#}`.
+///
+/// The RON is written straight into the formatter, without first being
+/// collected into a `String`.
+///
+/// ```
+/// use ron::Value;
+///
+/// let value: Value = ron::from_str("{ \"name\": \"ron\", \"tags\": [1, 2] }").unwrap();
+///
+/// assert_eq!(value.to_string(), "{\"name\":\"ron\",\"tags\":[1,2]}");
+/// assert_eq!(
+///     format!("{:#}", value).lines().collect::<Vec<_>>(),
+///     [
+///         "{",
+///         "    \"name\": \"ron\",",
+///         "    \"tags\": [",
+///         "        1,",
+///         "        2,",
+///         "    ],",
+///         "}",
+///     ],
+/// );
+/// ```
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = if f.alternate() {
+            Some(PrettyConfig::default())
+        } else {
+            None
+        };
+
+        // A value that already exists is always written out in full
+        let options = Options::default().without_depth_limit();
+
+        let mut serializer =
+            Serializer::with_options(f, config, &options).map_err(|_| fmt::Error)?;
+
+        self.serialize(&mut serializer).map_err(|_| fmt::Error)
+    }
+}
+
+/// Checks whether every entry in `a` has a semantically equal entry in `b`.
+fn semantic_map_subset(a: &Map, b: &Map) -> bool {
+    a.iter().all(|(key, value)| match b.get(key) {
+        Some(other) if value.semantic_eq(other) => true,
+        _ => b
+            .iter()
+            .any(|(other_key, other)| key.semantic_eq(other_key) && value.semantic_eq(other)),
+    })
 }
 
 /// Deserializer implementation for RON [`Value`].
@@ -133,8 +281,8 @@ impl<'de> Deserializer<'de> for Value {
 
     forward_to_deserialize_any! {
         bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
-        byte_buf option unit unit_struct newtype_struct seq tuple
-        tuple_struct map struct enum identifier ignored_any
+        byte_buf option unit unit_struct newtype_struct seq map enum
+        identifier ignored_any
     }
 
     #[cfg(feature = "integer128")]
@@ -142,6 +290,50 @@ impl<'de> Deserializer<'de> for Value {
         i128 u128
     }
 
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            // An empty tuple is serialized as `()`, which is parsed as a unit
+            Value::Unit if len == 0 => visitor.visit_seq(SeqAccessor {
+                seq: &mut Vec::new(),
+            }),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            // An empty struct is serialized as `()`, which is parsed as a unit
+            Value::Unit if fields.is_empty() => visitor.visit_map(MapAccessor {
+                items: &mut Vec::new(),
+                value: None,
+            }),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
@@ -194,6 +386,35 @@ impl<'de> Deserializer<'de> for Value {
     }
 }
 
+/// Allows a [`Value`] to be passed to any function that is generic over a
+/// [`Deserializer`], e.g. via [`IntoDeserializer::into_deserializer`].
+///
+/// ```
+/// use ron::{Error, Value};
+/// use serde::de::IntoDeserializer;
+///
+/// let value: Value = ron::from_str("(1, true)").unwrap();
+/// let deserializer = IntoDeserializer::<Error>::into_deserializer(value);
+///
+/// assert_eq!(serde::Deserialize::deserialize(deserializer), Ok((1_u8, true)));
+/// ```
+impl IntoDeserializer<'_, Error> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// Deserializes from a clone of the borrowed [`Value`].
+impl IntoDeserializer<'_, Error> for &Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self.clone()
+    }
+}
+
 struct SeqAccessor<'a> {
     seq: &'a mut Vec<Value>,
 }
@@ -454,7 +675,7 @@ mod tests {
             type Value = ();
 
             // GRCOV_EXCL_START
-            fn expecting(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+            fn expecting(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
                 fmt.write_str("a map")
             }
             // GRCOV_EXCL_STOP
@@ -1,10 +1,12 @@
-use std::{
+use core::{
     cmp::{Eq, Ordering},
     hash::{Hash, Hasher},
 };
 
 use serde::{de::Visitor, Serialize, Serializer};
 
+use crate::parse::{LargeSInt, LargeUInt};
+
 /// A wrapper for any numeric primitive type in Rust
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Hash, Ord)]
 pub enum Number {
@@ -220,6 +222,67 @@ impl Number {
     }
 }
 
+impl Number {
+    /// Compares two [`Number`]s by their mathematical value, as described
+    /// in [`Value::semantic_eq`](crate::value::Value::semantic_eq).
+    pub(crate) fn semantic_eq(self, other: Number) -> bool {
+        if let (Some(a), Some(b)) = (self.integer_parts(), other.integer_parts()) {
+            return a == b;
+        }
+
+        match (self, other) {
+            (Number::F32(_) | Number::F64(_), Number::F32(_) | Number::F64(_)) => {
+                let (a, b) = (self.into_f64(), other.into_f64());
+
+                a == b || (a.is_nan() && b.is_nan())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the sign and magnitude of an integer or an integral float,
+    /// where zero is never negative.
+    // 64-bit integers are only widened with the `integer128` feature
+    #[allow(clippy::useless_conversion)]
+    fn integer_parts(self) -> Option<(bool, LargeUInt)> {
+        let signed = |v: LargeSInt| (v < 0, v.unsigned_abs());
+
+        match self {
+            Number::I8(v) => Some(signed(v.into())),
+            Number::I16(v) => Some(signed(v.into())),
+            Number::I32(v) => Some(signed(v.into())),
+            Number::I64(v) => Some(signed(v.into())),
+            #[cfg(feature = "integer128")]
+            Number::I128(v) => Some(signed(v)),
+            Number::U8(v) => Some((false, v.into())),
+            Number::U16(v) => Some((false, v.into())),
+            Number::U32(v) => Some((false, v.into())),
+            Number::U64(v) => Some((false, v.into())),
+            #[cfg(feature = "integer128")]
+            Number::U128(v) => Some((false, v)),
+            Number::F32(v) => float_integer_parts(f64::from(v.get())),
+            Number::F64(v) => float_integer_parts(v.get()),
+        }
+    }
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn float_integer_parts(v: f64) -> Option<(bool, LargeUInt)> {
+    // `f64::fract` and `f64::abs` are not available without `std`
+    let magnitude = if v < 0.0 { -v } else { v };
+
+    // `LargeUInt::MAX` rounds up to the exclusive power-of-two upper bound
+    if v % 1.0 == 0.0 && magnitude < LargeUInt::MAX as f64 {
+        Some((v < 0.0, magnitude as LargeUInt))
+    } else {
+        None
+    }
+}
+
 macro_rules! number_from_impl {
     (Number::$variant:ident($wrap:ident($ty:ty))) => {
         impl From<$ty> for Number {
@@ -254,8 +317,8 @@ number_from_impl! { Number::F64(F64(f64)) }
 
 #[cfg(test)]
 mod tests {
+    use core::hash::{Hash, Hasher};
     use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
 
     use super::*;
 
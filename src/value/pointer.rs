@@ -0,0 +1,156 @@
+use alloc::borrow::Cow;
+
+use crate::{
+    parse::{LargeSInt, LargeUInt},
+    value::{Map, Number, Value},
+};
+
+impl Value {
+    /// Looks up a value by a [JSON Pointer] (RFC 6901), e.g.
+    /// `/server/ports/0`.
+    ///
+    /// The pointer is a sequence of `/`-prefixed reference tokens, in which
+    /// `~1` stands for `/` and `~0` for `~`. The empty pointer `""` refers to
+    /// this value itself. Each token selects
+    /// - the element at its index of a [`Value::Seq`], where the index is
+    ///   written in decimal without a sign or leading zeros,
+    /// - the value of a [`Value::Map`] whose key is the token as a string,
+    ///   e.g. a struct field, or otherwise whose key is an integer that the
+    ///   token spells in decimal, e.g. `1` or `-1`.
+    ///
+    /// A `Some(...)` is looked through, i.e. a token selects from the value
+    /// inside it. `None` is returned if the pointer is malformed or any
+    /// token selects nothing.
+    ///
+    /// ```
+    /// use ron::Value;
+    ///
+    /// let value: Value = ron::from_str(
+    ///     "(server: (ports: [80, 443], tls: Some((cert: \"a/b.pem\"))), \"a/b\": { 7: true })",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(value.pointer("/server/ports/1"), Some(&Value::from(443_u16)));
+    /// assert_eq!(
+    ///     value.pointer("/server/tls/cert"),
+    ///     Some(&Value::from("a/b.pem"))
+    /// );
+    /// assert_eq!(value.pointer("/a~1b/7"), Some(&Value::Bool(true)));
+    /// assert_eq!(value.pointer("/server/ports/2"), None);
+    /// ```
+    ///
+    /// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    #[must_use]
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(unescape_token)
+            .try_fold(self, |target, token| {
+                let mut target = target;
+
+                while let Value::Option(Some(inner)) = target {
+                    target = inner;
+                }
+
+                match target {
+                    Value::Seq(seq) => seq.get(parse_index(&token)?),
+                    Value::Map(map) => map.values().nth(find_key(map, &token)?),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Looks up a value by a [JSON Pointer] (RFC 6901) such that it can be
+    /// modified in place, see [`Value::pointer`].
+    ///
+    /// ```
+    /// use ron::Value;
+    ///
+    /// let mut value: Value = ron::from_str("(ports: [80, 443])").unwrap();
+    ///
+    /// *value.pointer_mut("/ports/0").unwrap() = Value::from(8080_u16);
+    ///
+    /// assert_eq!(value, ron::from_str("(ports: [8080, 443])").unwrap());
+    /// ```
+    ///
+    /// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    #[must_use]
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(unescape_token)
+            .try_fold(self, |target, token| {
+                let mut target = target;
+
+                while let Value::Option(Some(inner)) = target {
+                    target = inner;
+                }
+
+                match target {
+                    Value::Seq(seq) => seq.get_mut(parse_index(&token)?),
+                    Value::Map(map) => {
+                        let index = find_key(map, &token)?;
+                        map.values_mut().nth(index)
+                    }
+                    _ => None,
+                }
+            })
+    }
+}
+
+fn unescape_token(token: &str) -> Cow<'_, str> {
+    if token.contains('~') {
+        Cow::Owned(token.replace("~1", "/").replace("~0", "~"))
+    } else {
+        Cow::Borrowed(token)
+    }
+}
+
+/// Checks that `token` is a non-negative integer in decimal, without a sign
+/// or leading zeros.
+fn is_decimal(token: &str) -> bool {
+    !token.is_empty()
+        && token.bytes().all(|b| b.is_ascii_digit())
+        && !(token.starts_with('0') && token.len() != 1)
+}
+
+fn parse_index(token: &str) -> Option<usize> {
+    if is_decimal(token) {
+        token.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Returns the position of the entry in `map` whose key is the string
+/// `token`, or otherwise the integer that `token` spells.
+fn find_key(map: &Map, token: &str) -> Option<usize> {
+    if let Some(index) = map
+        .keys()
+        .position(|key| matches!(key, Value::String(key) if key == token))
+    {
+        return Some(index);
+    }
+
+    let integer = match token.strip_prefix('-') {
+        Some(magnitude) if is_decimal(magnitude) => Number::from(token.parse::<LargeSInt>().ok()?),
+        None if is_decimal(token) => Number::from(token.parse::<LargeUInt>().ok()?),
+        _ => return None,
+    };
+
+    map.keys().position(|key| match key {
+        Value::Number(Number::F32(_) | Number::F64(_)) => false,
+        Value::Number(key) => key.semantic_eq(integer),
+        _ => false,
+    })
+}
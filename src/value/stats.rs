@@ -0,0 +1,78 @@
+use crate::value::Value;
+
+/// Size and shape metrics of a [`Value`] tree, see [`Value::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ValueStats {
+    /// The total number of values in the tree, including the root value,
+    /// map keys, and the contents of `Some` options
+    pub nodes: usize,
+    /// The maximum nesting depth of the tree, counted like
+    /// [`Options::depth_limit`](crate::Options::depth_limit), i.e. `42`
+    /// has a depth of 0 and `[[42]]` has a depth of 2
+    pub max_depth: usize,
+    /// The total length in bytes of all [`Value::String`]s in the tree,
+    /// including map keys
+    pub string_bytes: usize,
+    /// The total number of entries of all [`Value::Map`]s in the tree
+    pub map_entries: usize,
+    /// The total number of elements of all [`Value::Seq`]s in the tree
+    pub seq_elements: usize,
+}
+
+impl Value {
+    /// Computes the [`ValueStats`] of this [`Value`] tree, e.g. to enforce
+    /// a policy on the size of an already parsed, untrusted document.
+    ///
+    /// ```
+    /// use ron::Value;
+    ///
+    /// let value: Value = ron::from_str(r#"{ "tags": ["a", "bc"], "id": Some(7) }"#).unwrap();
+    /// let stats = value.stats();
+    ///
+    /// assert_eq!(stats.nodes, 8);
+    /// assert_eq!(stats.max_depth, 2);
+    /// assert_eq!(stats.string_bytes, 9);
+    /// assert_eq!(stats.map_entries, 2);
+    /// assert_eq!(stats.seq_elements, 2);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> ValueStats {
+        let mut stats = ValueStats::default();
+
+        collect_stats(self, 0, &mut stats);
+
+        stats
+    }
+}
+
+fn collect_stats(value: &Value, depth: usize, stats: &mut ValueStats) {
+    stats.nodes += 1;
+    stats.max_depth = stats.max_depth.max(depth);
+
+    match value {
+        Value::Map(map) => {
+            stats.map_entries += map.len();
+
+            for (key, value) in map.iter() {
+                collect_stats(key, depth + 1, stats);
+                collect_stats(value, depth + 1, stats);
+            }
+        }
+        Value::Option(Some(value)) => collect_stats(value, depth + 1, stats),
+        Value::String(string) => stats.string_bytes += string.len(),
+        Value::Seq(seq) => {
+            stats.seq_elements += seq.len();
+
+            for value in seq {
+                collect_stats(value, depth + 1, stats);
+            }
+        }
+        Value::Bool(_)
+        | Value::Char(_)
+        | Value::Number(_)
+        | Value::Option(None)
+        | Value::Bytes(_)
+        | Value::Unit => {}
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::string::ToString;
+
+use crate::value::{Map, Number, Value};
+
+/// Converts a TOML value into a RON [`Value`].
+///
+/// This conversion is only available with the `toml` feature and never
+/// fails:
+///
+/// - strings, booleans, and arrays become a [`Value::String`],
+///   [`Value::Bool`], and [`Value::Seq`]
+/// - integers and floats become an `i64` or `f64` [`Value::Number`]
+/// - tables, including arrays of tables, become a [`Value::Map`] with
+///   [`Value::String`] keys
+/// - datetimes become a [`Value::String`] in their RFC 3339 form, e.g.
+///   `"1979-05-27T07:32:00Z"`, which is lossy since they are converted
+///   back into TOML strings and not datetimes
+///
+/// The conversions are implemented for the `toml::Value` of `toml` 0.5,
+/// which is the last release that only depends on `serde`. Later releases
+/// pull in `toml_edit` and its dependencies, whose latest versions require
+/// a newer Rust than ron's minimum supported Rust version of 1.64, so the
+/// values of a newer `toml` cannot be converted with this feature.
+impl From<::toml::Value> for Value {
+    fn from(value: ::toml::Value) -> Self {
+        match value {
+            ::toml::Value::String(string) => Value::String(string),
+            ::toml::Value::Integer(integer) => Value::Number(Number::from(integer)),
+            ::toml::Value::Float(float) => Value::Number(Number::from(float)),
+            ::toml::Value::Boolean(boolean) => Value::Bool(boolean),
+            ::toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+            ::toml::Value::Array(array) => Value::Seq(array.into_iter().map(Value::from).collect()),
+            ::toml::Value::Table(table) => Value::Map(
+                table
+                    .into_iter()
+                    .map(|(key, value)| (Value::String(key), Value::from(value)))
+                    .collect::<Map>(),
+            ),
+        }
+    }
+}
+
+/// Tries to convert a RON [`Value`] into a TOML value.
+///
+/// This conversion is only available with the `toml` feature and follows
+/// the serialization of the [`Value`] into TOML, so it fails if the value
+/// has no TOML equivalent, i.e. for a [`Value::Unit`], a `None`
+/// [`Value::Option`] outside of a map, a map with non-string keys, or an
+/// integer outside the range of an `i64`. The conversion is lossy for
+///
+/// - a `Some` [`Value::Option`], which is replaced by its content, and a
+///   `None` map value, whose entry is left out
+/// - a [`Value::Char`], which becomes a single-character string
+/// - a [`Value::Bytes`] byte string, which becomes an array of integers
+impl TryFrom<Value> for ::toml::Value {
+    type Error = ::toml::ser::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        ::toml::Value::try_from(value)
+    }
+}
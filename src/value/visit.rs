@@ -0,0 +1,190 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::value::{Map, Number, Value};
+
+/// A segment of the path from the root [`Value`] to a visited value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment<'a> {
+    /// The element at the index of a [`Value::Seq`]
+    Index(usize),
+    /// The value for the key of a [`Value::Map`]
+    Key(&'a Value),
+    /// The content of a [`Value::Option`] that is `Some`
+    Some,
+}
+
+/// A visitor to traverse a [`Value`] tree with [`Value::visit`].
+///
+/// Every method is called with the path from the root [`Value`] to the
+/// visited value and does nothing by default. Maps, options, and sequences
+/// are visited before their contents.
+#[allow(unused_variables)]
+pub trait ValueVisitor {
+    fn visit_bool(&mut self, path: &[PathSegment], value: bool) {}
+
+    fn visit_char(&mut self, path: &[PathSegment], value: char) {}
+
+    fn visit_map(&mut self, path: &[PathSegment], value: &Map) {}
+
+    fn visit_number(&mut self, path: &[PathSegment], value: &Number) {}
+
+    fn visit_option(&mut self, path: &[PathSegment], value: Option<&Value>) {}
+
+    fn visit_string(&mut self, path: &[PathSegment], value: &str) {}
+
+    fn visit_bytes(&mut self, path: &[PathSegment], value: &[u8]) {}
+
+    fn visit_seq(&mut self, path: &[PathSegment], value: &[Value]) {}
+
+    fn visit_unit(&mut self, path: &[PathSegment]) {}
+}
+
+/// A visitor to traverse and modify a [`Value`] tree with
+/// [`Value::visit_mut`].
+///
+/// Every method is called with the path from the root [`Value`] to the
+/// visited value and does nothing by default. Maps, options, and sequences
+/// are visited before their contents, i.e. the contents are visited after
+/// any modifications to them have been made.
+#[allow(unused_variables)]
+pub trait ValueVisitorMut {
+    fn visit_bool(&mut self, path: &[PathSegment], value: &mut bool) {}
+
+    fn visit_char(&mut self, path: &[PathSegment], value: &mut char) {}
+
+    fn visit_map(&mut self, path: &[PathSegment], value: &mut Map) {}
+
+    fn visit_number(&mut self, path: &[PathSegment], value: &mut Number) {}
+
+    fn visit_option(&mut self, path: &[PathSegment], value: &mut Option<Box<Value>>) {}
+
+    fn visit_string(&mut self, path: &[PathSegment], value: &mut String) {}
+
+    fn visit_bytes(&mut self, path: &[PathSegment], value: &mut Vec<u8>) {}
+
+    fn visit_seq(&mut self, path: &[PathSegment], value: &mut Vec<Value>) {}
+
+    fn visit_unit(&mut self, path: &[PathSegment]) {}
+}
+
+impl Value {
+    /// Traverses this [`Value`] tree depth-first and calls the `visitor`
+    /// for every value, together with its path from this root value.
+    ///
+    /// ```
+    /// use ron::value::{PathSegment, Value, ValueVisitor};
+    ///
+    /// struct EmptyStrings(usize);
+    ///
+    /// impl ValueVisitor for EmptyStrings {
+    ///     fn visit_string(&mut self, _path: &[PathSegment], value: &str) {
+    ///         if value.is_empty() {
+    ///             self.0 += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let value: Value = ron::from_str(r#"(a: "", b: ["x", ""])"#).unwrap();
+    ///
+    /// let mut visitor = EmptyStrings(0);
+    /// value.visit(&mut visitor);
+    ///
+    /// assert_eq!(visitor.0, 2);
+    /// ```
+    pub fn visit<V: ValueVisitor>(&self, visitor: &mut V) {
+        visit_value(self, &mut Vec::new(), visitor);
+    }
+
+    /// Traverses this [`Value`] tree depth-first and calls the `visitor`
+    /// for every value, together with its path from this root value, such
+    /// that the `visitor` can modify the values in place.
+    ///
+    /// Map keys cannot be modified while visiting a [`Map`]'s contents.
+    pub fn visit_mut<V: ValueVisitorMut>(&mut self, visitor: &mut V) {
+        visit_value_mut(self, &mut Vec::new(), visitor);
+    }
+}
+
+fn visit_value<'a, V: ValueVisitor>(
+    value: &'a Value,
+    path: &mut Vec<PathSegment<'a>>,
+    visitor: &mut V,
+) {
+    match value {
+        Value::Bool(v) => visitor.visit_bool(path, *v),
+        Value::Char(v) => visitor.visit_char(path, *v),
+        Value::Map(map) => {
+            visitor.visit_map(path, map);
+
+            for (key, value) in map.iter() {
+                path.push(PathSegment::Key(key));
+                visit_value(value, path, visitor);
+                path.pop();
+            }
+        }
+        Value::Number(v) => visitor.visit_number(path, v),
+        Value::Option(option) => {
+            visitor.visit_option(path, option.as_deref());
+
+            if let Some(value) = option {
+                path.push(PathSegment::Some);
+                visit_value(value, path, visitor);
+                path.pop();
+            }
+        }
+        Value::String(v) => visitor.visit_string(path, v),
+        Value::Bytes(v) => visitor.visit_bytes(path, v),
+        Value::Seq(seq) => {
+            visitor.visit_seq(path, seq);
+
+            for (index, value) in seq.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                visit_value(value, path, visitor);
+                path.pop();
+            }
+        }
+        Value::Unit => visitor.visit_unit(path),
+    }
+}
+
+fn visit_value_mut<'a, V: ValueVisitorMut>(
+    value: &'a mut Value,
+    path: &mut Vec<PathSegment<'a>>,
+    visitor: &mut V,
+) {
+    match value {
+        Value::Bool(v) => visitor.visit_bool(path, v),
+        Value::Char(v) => visitor.visit_char(path, v),
+        Value::Map(map) => {
+            visitor.visit_map(path, map);
+
+            for (key, value) in map.iter_mut() {
+                path.push(PathSegment::Key(key));
+                visit_value_mut(value, path, visitor);
+                path.pop();
+            }
+        }
+        Value::Number(v) => visitor.visit_number(path, v),
+        Value::Option(option) => {
+            visitor.visit_option(path, option);
+
+            if let Some(value) = option {
+                path.push(PathSegment::Some);
+                visit_value_mut(value, path, visitor);
+                path.pop();
+            }
+        }
+        Value::String(v) => visitor.visit_string(path, v),
+        Value::Bytes(v) => visitor.visit_bytes(path, v),
+        Value::Seq(seq) => {
+            visitor.visit_seq(path, seq);
+
+            for (index, value) in seq.iter_mut().enumerate() {
+                path.push(PathSegment::Index(index));
+                visit_value_mut(value, path, visitor);
+                path.pop();
+            }
+        }
+        Value::Unit => visitor.visit_unit(path),
+    }
+}
@@ -114,8 +114,15 @@ Main({
     }"
         ),
         Err(ron::error::SpannedError {
-            code: ron::error::Error::ExpectedString,
-            position: ron::error::Position { line: 2, col: 9 },
+            code: ron::error::Error::InvalidValueForType {
+                expected: String::from("field identifier"),
+                found: String::from("the identifier `first`"),
+            },
+            position: ron::error::Position {
+                line: 2,
+                col: 9,
+                offset: 10
+            },
         })
     );
 
@@ -130,7 +137,11 @@ Main({
         ),
         Err(ron::error::SpannedError {
             code: ron::error::Error::ExpectedMapColon,
-            position: ron::error::Position { line: 4, col: 10 },
+            position: ron::error::Position {
+                line: 4,
+                col: 10,
+                offset: 51
+            },
         })
     );
 
@@ -144,8 +155,15 @@ Main({
     }"
         ),
         Err(ron::error::SpannedError {
-            code: ron::error::Error::ExpectedString,
-            position: ron::error::Position { line: 4, col: 9 },
+            code: ron::error::Error::InvalidValueForType {
+                expected: String::from("field identifier"),
+                found: String::from("the identifier `third`"),
+            },
+            position: ron::error::Position {
+                line: 4,
+                col: 9,
+                offset: 51
+            },
         })
     );
 
@@ -160,7 +178,11 @@ Main({
         ),
         Err(ron::error::SpannedError {
             code: ron::error::Error::ExpectedStringEnd,
-            position: ron::error::Position { line: 5, col: 10 },
+            position: ron::error::Position {
+                line: 5,
+                col: 10,
+                offset: 78
+            },
         })
     );
 }
@@ -238,8 +260,15 @@ MyType({
     }"
         ),
         Err(ron::error::SpannedError {
-            code: ron::error::Error::ExpectedString,
-            position: ron::error::Position { line: 2, col: 9 },
+            code: ron::error::Error::InvalidValueForType {
+                expected: String::from("field identifier"),
+                found: String::from("the identifier `first`"),
+            },
+            position: ron::error::Position {
+                line: 2,
+                col: 9,
+                offset: 10
+            },
         })
     );
 
@@ -253,7 +282,11 @@ MyType({
         ),
         Err(ron::error::SpannedError {
             code: ron::error::Error::ExpectedMapColon,
-            position: ron::error::Position { line: 4, col: 10 },
+            position: ron::error::Position {
+                line: 4,
+                col: 10,
+                offset: 51
+            },
         })
     );
 
@@ -266,8 +299,15 @@ MyType({
     }"
         ),
         Err(ron::error::SpannedError {
-            code: ron::error::Error::ExpectedString,
-            position: ron::error::Position { line: 4, col: 9 },
+            code: ron::error::Error::InvalidValueForType {
+                expected: String::from("field identifier"),
+                found: String::from("the identifier `third`"),
+            },
+            position: ron::error::Position {
+                line: 4,
+                col: 9,
+                offset: 51
+            },
         })
     );
 
@@ -281,7 +321,11 @@ MyType({
         ),
         Err(ron::error::SpannedError {
             code: ron::error::Error::ExpectedStringEnd,
-            position: ron::error::Position { line: 4, col: 10 },
+            position: ron::error::Position {
+                line: 4,
+                col: 10,
+                offset: 52
+            },
         })
     );
 }
@@ -298,6 +298,101 @@ fn test_untagged_b_roundtrip() {
     test_roundtrip(v);
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Newtype {
+    a: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum AllVariantsExternally {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    Struct { a: u8 },
+}
+
+// Note: serde rejects tuple variants in internally tagged enums at compile
+//  time, and internally tagged newtype variants must wrap a struct or map
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum AllVariantsInternally {
+    Unit,
+    Newtype(Newtype),
+    Struct { a: u8 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+enum AllVariantsAdjacently {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    Struct { a: u8 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum AllVariantsUntagged {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    Struct { a: u8 },
+}
+
+fn test_ser_roundtrip<T>(value: T, expected: &str)
+where
+    T: Serialize + for<'a> Deserialize<'a> + Debug + PartialEq,
+{
+    test_ser(&value, expected);
+    test_roundtrip(value);
+}
+
+#[test]
+fn test_externally_all_variants() {
+    test_ser_roundtrip(AllVariantsExternally::Unit, "Unit");
+    test_ser_roundtrip(AllVariantsExternally::Newtype(1), "Newtype(1)");
+    test_ser_roundtrip(AllVariantsExternally::Tuple(1, true), "Tuple(1,true)");
+    test_ser_roundtrip(AllVariantsExternally::Struct { a: 1 }, "Struct(a:1)");
+}
+
+#[test]
+fn test_internally_all_variants() {
+    test_ser_roundtrip(AllVariantsInternally::Unit, "(type:\"Unit\")");
+    test_ser_roundtrip(
+        AllVariantsInternally::Newtype(Newtype { a: 1 }),
+        "(type:\"Newtype\",a:1)",
+    );
+    test_ser_roundtrip(
+        AllVariantsInternally::Struct { a: 1 },
+        "(type:\"Struct\",a:1)",
+    );
+}
+
+#[test]
+fn test_adjacently_all_variants() {
+    test_ser_roundtrip(AllVariantsAdjacently::Unit, "(type:Unit)");
+    test_ser_roundtrip(
+        AllVariantsAdjacently::Newtype(1),
+        "(type:Newtype,content:1)",
+    );
+    test_ser_roundtrip(
+        AllVariantsAdjacently::Tuple(1, true),
+        "(type:Tuple,content:(1,true))",
+    );
+    test_ser_roundtrip(
+        AllVariantsAdjacently::Struct { a: 1 },
+        "(type:Struct,content:(a:1))",
+    );
+}
+
+#[test]
+fn test_untagged_all_variants() {
+    test_ser_roundtrip(AllVariantsUntagged::Unit, "()");
+    test_ser_roundtrip(AllVariantsUntagged::Newtype(1), "1");
+    test_ser_roundtrip(AllVariantsUntagged::Tuple(1, true), "(1,true)");
+    test_ser_roundtrip(AllVariantsUntagged::Struct { a: 1 }, "(a:1)");
+}
+
 enum ByteStr {}
 
 impl ByteStr {
@@ -31,7 +31,11 @@ fn test_error_positions() {
                 expected: String::from("impossible"),
                 found: String::from("a unit value"),
             },
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
 
@@ -42,7 +46,11 @@ fn test_error_positions() {
                 expected: String::from("a nonzero u32"),
                 found: String::from("the unsigned integer `0`"),
             },
-            position: Position { line: 1, col: 28 },
+            position: Position {
+                line: 1,
+                col: 28,
+                offset: 27
+            },
         })
     );
 
@@ -53,7 +61,11 @@ fn test_error_positions() {
                 expected: String::from("tuple variant Test::TupleVariant with 2 elements"),
                 found: 1,
             },
-            position: Position { line: 1, col: 16 },
+            position: Position {
+                line: 1,
+                col: 16,
+                offset: 15
+            },
         })
     );
 
@@ -65,7 +77,11 @@ fn test_error_positions() {
                 found: String::from("NotAVariant"),
                 outer: Some(String::from("Test")),
             },
-            position: Position { line: 1, col: 12 },
+            position: Position {
+                line: 1,
+                col: 12,
+                offset: 11
+            },
         })
     );
 
@@ -77,7 +93,11 @@ fn test_error_positions() {
                 found: String::from("d"),
                 outer: Some(String::from("StructVariant")),
             },
-            position: Position { line: 1, col: 39 },
+            position: Position {
+                line: 1,
+                col: 39,
+                offset: 38
+            },
         })
     );
 
@@ -88,7 +108,11 @@ fn test_error_positions() {
                 field: "b",
                 outer: Some(String::from("StructVariant")),
             },
-            position: Position { line: 1, col: 30 },
+            position: Position {
+                line: 1,
+                col: 30,
+                offset: 29
+            },
         })
     );
 
@@ -99,7 +123,11 @@ fn test_error_positions() {
                 field: "a",
                 outer: Some(String::from("StructVariant")),
             },
-            position: Position { line: 1, col: 31 },
+            position: Position {
+                line: 1,
+                col: 31,
+                offset: 30
+            },
         })
     );
 }
@@ -35,7 +35,11 @@ fn test_array() {
         de,
         SpannedError {
             code: Error::ExpectedStructLike,
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 
@@ -77,15 +77,13 @@ fn test_deserialise_non_newtypes() {
 fn test_deserialise_tuple_newtypes() {
     assert_eq!(
         from_str::<TestEnum>(r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeUnit(Unit)"#)
-            .unwrap_err()
-            .code,
-        Error::ExpectedStructLikeEnd,
+            .unwrap(),
+        TestEnum::TupleNewtypeUnit(Unit),
     );
     assert_eq!(
         from_str::<TestEnum>(r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeUnit(())"#)
-            .unwrap_err()
-            .code,
-        Error::ExpectedStructLikeEnd,
+            .unwrap(),
+        TestEnum::TupleNewtypeUnit(Unit),
     );
     assert_eq!(
         from_str::<TestEnum>(r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeUnit()"#).unwrap(),
@@ -98,13 +96,19 @@ fn test_deserialise_tuple_newtypes() {
         )
         .unwrap_err()
         .code,
-        Error::ExpectedInteger,
+        Error::InvalidValueForType {
+            expected: String::from("i32"),
+            found: String::from("a sequence"),
+        },
     );
     assert_eq!(
         from_str::<TestEnum>(r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeNewtype((4))"#)
             .unwrap_err()
             .code,
-        Error::ExpectedInteger,
+        Error::InvalidValueForType {
+            expected: String::from("i32"),
+            found: String::from("a sequence"),
+        },
     );
     assert_eq!(
         from_str::<TestEnum>(r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeNewtype(4)"#)
@@ -126,7 +130,10 @@ fn test_deserialise_tuple_newtypes() {
         )
         .unwrap_err()
         .code,
-        Error::ExpectedInteger,
+        Error::InvalidValueForType {
+            expected: String::from("u32"),
+            found: String::from("a sequence"),
+        },
     );
     assert_eq!(
         from_str::<TestEnum>(r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeTuple(4, false)"#)
@@ -140,7 +147,10 @@ fn test_deserialise_tuple_newtypes() {
         )
         .unwrap_err()
         .code,
-        Error::ExpectedInteger,
+        Error::InvalidValueForType {
+            expected: String::from("u32"),
+            found: String::from("a sequence"),
+        },
     );
     assert_eq!(
         from_str::<TestEnum>(
@@ -148,7 +158,10 @@ fn test_deserialise_tuple_newtypes() {
         )
         .unwrap_err()
         .code,
-        Error::ExpectedInteger,
+        Error::InvalidValueForType {
+            expected: String::from("u32"),
+            found: String::from("a sequence"),
+        },
     );
     assert_eq!(
         from_str::<TestEnum>(
@@ -162,17 +175,15 @@ fn test_deserialise_tuple_newtypes() {
         from_str::<TestEnum>(
             r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeStruct(Struct(a: 4, b: false))"#
         )
-        .unwrap_err()
-        .code,
-        Error::ExpectedMapColon,
+        .unwrap(),
+        TestEnum::TupleNewtypeStruct(Struct { a: 4, b: false }),
     );
     assert_eq!(
         from_str::<TestEnum>(
             r#"#![enable(unwrap_variant_newtypes)] TupleNewtypeStruct((a: 4, b: false))"#
         )
-        .unwrap_err()
-        .code,
-        Error::ExpectedIdentifier,
+        .unwrap(),
+        TestEnum::TupleNewtypeStruct(Struct { a: 4, b: false }),
     );
     assert_eq!(
         from_str::<TestEnum>(
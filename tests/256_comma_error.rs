@@ -19,7 +19,11 @@ fn test_missing_comma_error() {
         ron::from_str::<(i32, i32)>(tuple_string).unwrap_err(),
         SpannedError {
             code: Error::ExpectedComma,
-            position: Position { line: 3, col: 9 }
+            position: Position {
+                line: 3,
+                col: 9,
+                offset: 48
+            }
         }
     );
 
@@ -33,7 +37,11 @@ fn test_missing_comma_error() {
         ron::from_str::<Vec<i32>>(list_string).unwrap_err(),
         SpannedError {
             code: Error::ExpectedComma,
-            position: Position { line: 4, col: 9 }
+            position: Position {
+                line: 4,
+                col: 9,
+                offset: 59
+            }
         }
     );
 
@@ -46,7 +54,11 @@ fn test_missing_comma_error() {
         ron::from_str::<Test>(struct_string).unwrap_err(),
         SpannedError {
             code: Error::ExpectedComma,
-            position: Position { line: 3, col: 9 }
+            position: Position {
+                line: 3,
+                col: 9,
+                offset: 55
+            }
         }
     );
 
@@ -59,7 +71,11 @@ fn test_missing_comma_error() {
         ron::from_str::<std::collections::HashMap<String, i32>>(map_string).unwrap_err(),
         SpannedError {
             code: Error::ExpectedComma,
-            position: Position { line: 3, col: 9 }
+            position: Position {
+                line: 3,
+                col: 9,
+                offset: 53
+            }
         }
     );
 
@@ -72,7 +88,11 @@ fn test_missing_comma_error() {
         ron::from_str::<u8>(extensions_string).unwrap_err(),
         SpannedError {
             code: Error::ExpectedComma,
-            position: Position { line: 3, col: 9 }
+            position: Position {
+                line: 3,
+                col: 9,
+                offset: 69
+            }
         }
     );
 }
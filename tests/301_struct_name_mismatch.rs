@@ -30,14 +30,22 @@ fn test_unit_struct_name_mismatch() {
                 expected: "MyUnitStruct",
                 found: String::from("MyUnit")
             },
-            position: Position { line: 1, col: 7 }
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            }
         }),
     );
     assert_eq!(
         ron::from_str::<MyUnitStruct>("42"),
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike("MyUnitStruct"),
-            position: Position { line: 1, col: 1 }
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
         }),
     );
 }
@@ -59,14 +67,22 @@ fn test_tuple_struct_name_mismatch() {
                 expected: "MyTupleStruct",
                 found: String::from("MyTypleStruct")
             },
-            position: Position { line: 1, col: 14 }
+            position: Position {
+                line: 1,
+                col: 14,
+                offset: 13
+            }
         }),
     );
     assert_eq!(
         ron::from_str::<MyTupleStruct>("42"),
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike("MyTupleStruct"),
-            position: Position { line: 1, col: 1 }
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
         }),
     );
 }
@@ -88,14 +104,22 @@ fn test_newtype_struct_name_mismatch() {
                 expected: "MyNewtypeStruct",
                 found: String::from("MyNewtypeStrucl")
             },
-            position: Position { line: 1, col: 16 }
+            position: Position {
+                line: 1,
+                col: 16,
+                offset: 15
+            }
         }),
     );
     assert_eq!(
         ron::from_str::<MyNewtypeStruct>("42"),
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike("MyNewtypeStruct"),
-            position: Position { line: 1, col: 1 }
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
         }),
     );
 }
@@ -117,14 +141,22 @@ fn test_struct_name_mismatch() {
                 expected: "MyStruct",
                 found: String::from("MuStryct")
             },
-            position: Position { line: 1, col: 9 }
+            position: Position {
+                line: 1,
+                col: 9,
+                offset: 8
+            }
         }),
     );
     assert_eq!(
         ron::from_str::<MyStruct>("42"),
         Err(SpannedError {
             code: Error::ExpectedNamedStructLike("MyStruct"),
-            position: Position { line: 1, col: 1 }
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
         }),
     );
 }
@@ -51,7 +51,125 @@ fn test_deserialize_seed() {
                 expected: String::from("an integer"),
                 found: String::from("the string \"a\""),
             },
-            position: ron::error::Position { line: 1, col: 4 },
+            position: ron::error::Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         })
     );
 }
+
+/// Looks up an optional identifier string in a borrowed schema of known names
+struct KnownName<'s>(&'s [&'s str]);
+
+impl<'de, 's> serde::de::DeserializeSeed<'de> for KnownName<'s> {
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, 's> serde::de::Visitor<'de> for KnownName<'s> {
+    type Value = Option<usize>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "an optional known name")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let name = <&str as serde::Deserialize>::deserialize(deserializer)?;
+
+        match self.0.iter().position(|known| *known == name) {
+            Some(index) => Ok(Some(index)),
+            None => Err(serde::de::Error::custom(format!("unknown name `{name}`"))),
+        }
+    }
+}
+
+#[test]
+fn test_deserialize_seed_with_options() {
+    use ron::{error::Position, extensions::Extensions, Error, Options};
+
+    const NAMES: &[&str] = &["alpha", "beta"];
+
+    let options = Options::default();
+    let implicit_some = Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+
+    assert_eq!(options.from_str_seed("None", KnownName(NAMES)), Ok(None));
+    assert_eq!(
+        options.from_str_seed("Some(\"beta\")", KnownName(NAMES)),
+        Ok(Some(1))
+    );
+    assert_eq!(
+        options.from_bytes_seed(b"Some(\"alpha\")", KnownName(NAMES)),
+        Ok(Some(0))
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        options.from_reader_seed(&b"Some(\"alpha\")"[..], KnownName(NAMES)),
+        Ok(Some(0))
+    );
+
+    // extensions from the options and from the document header both apply
+    assert_eq!(
+        implicit_some.from_str_seed("\"beta\"", KnownName(NAMES)),
+        Ok(Some(1))
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        implicit_some.from_reader_seed(&b"\"beta\""[..], KnownName(NAMES)),
+        Ok(Some(1))
+    );
+    assert_eq!(
+        options.from_str_seed("#![enable(implicit_some)] \"alpha\"", KnownName(NAMES)),
+        Ok(Some(0))
+    );
+
+    // errors from the seed are spanned
+    let err = options
+        .from_str_seed("Some(\"gamma\")", KnownName(NAMES))
+        .unwrap_err();
+    assert_eq!(
+        err.code,
+        Error::Message(String::from("unknown name `gamma`"))
+    );
+    assert_eq!(
+        err.position,
+        Position {
+            line: 1,
+            col: 13,
+            offset: 12
+        }
+    );
+
+    // the whole input must be consumed
+    let err = options
+        .from_str_seed("Some(\"alpha\") None", KnownName(NAMES))
+        .unwrap_err();
+    assert_eq!(
+        err.code,
+        Error::TrailingCharacters {
+            found: String::from("None"),
+        }
+    );
+    assert_eq!(
+        err.position,
+        Position {
+            line: 1,
+            col: 15,
+            offset: 14
+        }
+    );
+}
@@ -1,5 +1,6 @@
 use ron::{
     de::{Position, SpannedError},
+    value::Number,
     Error,
 };
 
@@ -42,7 +43,47 @@ fn test_float_literal_parsing() {
         ron::from_str::<f64>("1.0e1.0"),
         Err(SpannedError {
             code: Error::ExpectedFloat,
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
 }
+
+#[test]
+fn test_float_grammar_consistency() {
+    for (ron, expected) in [
+        ("1_0.1_0", 10.1_f64),
+        ("1e1", 10.0),
+        ("1.0e+1", 10.0),
+        ("1.0e-1", 0.1),
+        ("1_0e1_0", 1_0e1_0),
+        ("1_.5", 1.5),
+        ("1.e1", 10.0),
+        ("-1_000.000_1E+0_1", -10000.001),
+    ] {
+        assert_eq!(ron::from_str::<f64>(ron), Ok(expected), "{}", ron);
+        match ron::from_str::<ron::Value>(ron) {
+            Ok(ron::Value::Number(n @ (Number::F32(_) | Number::F64(_)))) => {
+                assert_eq!(n.into_f64(), expected, "{}", ron)
+            }
+            value => panic!("{} was parsed as {:?}", ron, value),
+        }
+    }
+
+    for ron in ["1e1e1", "1e1-", "1.0.0", "1e+", "1+1"] {
+        let err = SpannedError {
+            code: Error::ExpectedFloat,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        };
+
+        assert_eq!(ron::from_str::<f64>(ron), Err(err.clone()), "{}", ron);
+        assert_eq!(ron::from_str::<ron::Value>(ron), Err(err), "{}", ron);
+    }
+}
@@ -43,7 +43,11 @@ fn test_unknown_enum_variant() {
                 found: String::from("NotAVariant"),
                 outer: Some(String::from("TestEnum")),
             },
-            position: Position { line: 1, col: 12 },
+            position: Position {
+                line: 1,
+                col: 12,
+                offset: 11
+            },
         })
     );
 }
@@ -58,7 +62,11 @@ fn test_struct_enum_fields() {
                 found: String::from("d"),
                 outer: Some(String::from("StructVariant")),
             },
-            position: Position { line: 1, col: 41 },
+            position: Position {
+                line: 1,
+                col: 41,
+                offset: 40
+            },
         })
     );
 
@@ -69,7 +77,11 @@ fn test_struct_enum_fields() {
                 field: "b",
                 outer: Some(String::from("StructVariant")),
             },
-            position: Position { line: 1, col: 30 },
+            position: Position {
+                line: 1,
+                col: 30,
+                offset: 29
+            },
         })
     );
 
@@ -80,7 +92,11 @@ fn test_struct_enum_fields() {
                 field: "a",
                 outer: Some(String::from("StructVariant")),
             },
-            position: Position { line: 1, col: 33 },
+            position: Position {
+                line: 1,
+                col: 33,
+                offset: 32
+            },
         })
     );
 }
@@ -95,7 +111,7 @@ fn test_newtype_enum_fields() {
                 found: String::from("d"),
                 outer: Some(String::from("NewtypeVariant")),
             },
-            position: Position { line: 1, col: 78 },
+            position: Position { line: 1, col: 78, offset: 77 },
         })
     );
 
@@ -108,7 +124,11 @@ fn test_newtype_enum_fields() {
                 field: "b",
                 outer: Some(String::from("NewtypeVariant")),
             },
-            position: Position { line: 1, col: 67 },
+            position: Position {
+                line: 1,
+                col: 67,
+                offset: 66
+            },
         })
     );
 
@@ -121,7 +141,11 @@ fn test_newtype_enum_fields() {
                 field: "a",
                 outer: Some(String::from("NewtypeVariant")),
             },
-            position: Position { line: 1, col: 70 },
+            position: Position {
+                line: 1,
+                col: 70,
+                offset: 69
+            },
         })
     );
 }
@@ -136,7 +160,11 @@ fn test_struct_fields() {
                 found: String::from("d"),
                 outer: Some(String::from("TestStruct")),
             },
-            position: Position { line: 1, col: 38 },
+            position: Position {
+                line: 1,
+                col: 38,
+                offset: 37
+            },
         })
     );
 
@@ -147,7 +175,11 @@ fn test_struct_fields() {
                 field: "b",
                 outer: Some(String::from("TestStruct")),
             },
-            position: Position { line: 1, col: 27 },
+            position: Position {
+                line: 1,
+                col: 27,
+                offset: 26
+            },
         })
     );
 
@@ -158,7 +190,11 @@ fn test_struct_fields() {
                 field: "a",
                 outer: Some(String::from("TestStruct")),
             },
-            position: Position { line: 1, col: 30 },
+            position: Position {
+                line: 1,
+                col: 30,
+                offset: 29
+            },
         })
     );
 }
@@ -177,7 +213,11 @@ fn test_internally_tagged_enum() {
                 field: "a",
                 outer: None,
             },
-            position: Position { line: 1, col: 24 },
+            position: Position {
+                line: 1,
+                col: 24,
+                offset: 23
+            },
         })
     );
 }
@@ -194,7 +234,11 @@ fn test_adjacently_tagged_enum() {
                 field: "a",
                 outer: Some(String::from("TestEnumAdjacent")),
             },
-            position: Position { line: 1, col: 37 },
+            position: Position {
+                line: 1,
+                col: 37,
+                offset: 36
+            },
         })
     );
 }
@@ -209,7 +253,11 @@ fn test_untagged_enum() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum TestEnumUntagged"
             )),
-            position: Position { line: 1, col: 20 },
+            position: Position {
+                line: 1,
+                col: 20,
+                offset: 19
+            },
         })
     );
 }
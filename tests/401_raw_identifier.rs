@@ -49,7 +49,11 @@ fn test_invalid_identifiers() {
                 expected: "Hello World",
                 found: String::from("Hello"),
             },
-            position: Position { line: 1, col: 6 },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         }
     );
 
@@ -58,7 +62,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::ExpectedUnit,
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 
@@ -73,7 +81,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::ExpectedNamedStructLike("Hello+World"),
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         },
     );
 
@@ -82,7 +94,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::ExpectedNamedStructLike("Hello+World"),
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         },
     );
 
@@ -91,7 +107,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::SuggestRawIdentifier(String::from("Hello+World")),
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 
@@ -105,7 +125,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::SuggestRawIdentifier(String::from("ab.cd-ef")),
-            position: Position { line: 2, col: 9 },
+            position: Position {
+                line: 2,
+                col: 9,
+                offset: 23
+            },
         }
     );
 
@@ -119,7 +143,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::SuggestRawIdentifier(String::from("rab.cd-ef")),
-            position: Position { line: 2, col: 9 },
+            position: Position {
+                line: 2,
+                col: 9,
+                offset: 23
+            },
         }
     );
 
@@ -137,7 +165,11 @@ fn test_invalid_identifiers() {
                 found: String::from("ab.cd+ef"),
                 outer: Some(String::from("Hello+World")),
             },
-            position: Position { line: 2, col: 19 },
+            position: Position {
+                line: 2,
+                col: 19,
+                offset: 33
+            },
         }
     );
 
@@ -146,7 +178,11 @@ fn test_invalid_identifiers() {
         de,
         SpannedError {
             code: Error::SuggestRawIdentifier(String::from("Hello-World")),
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 
@@ -159,7 +195,11 @@ fn test_invalid_identifiers() {
                 found: String::from("Hello+World"),
                 outer: Some(String::from("RawEnum")),
             },
-            position: Position { line: 1, col: 14 },
+            position: Position {
+                line: 1,
+                col: 14,
+                offset: 13
+            },
         }
     );
 
@@ -46,8 +46,14 @@ fn test_raw_value_invalid() {
     assert_eq!(
         err,
         SpannedError {
-            code: Error::TrailingCharacters,
-            position: Position { line: 1, col: 3 }
+            code: Error::TrailingCharacters {
+                found: String::from("d"),
+            },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            }
         }
     );
 
@@ -56,7 +62,11 @@ fn test_raw_value_invalid() {
         err,
         SpannedError {
             code: Error::UnexpectedChar('\0'),
-            position: Position { line: 1, col: 1 }
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
         }
     )
 }
@@ -70,8 +80,14 @@ fn test_raw_value_from_ron() {
     assert_eq!(
         err,
         SpannedError {
-            code: Error::TrailingCharacters,
-            position: Position { line: 1, col: 3 }
+            code: Error::TrailingCharacters {
+                found: String::from("d"),
+            },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            }
         }
     );
 
@@ -85,7 +101,11 @@ fn test_raw_value_from_ron() {
         err,
         SpannedError {
             code: Error::Eof,
-            position: Position { line: 1, col: 2 },
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         }
     );
 }
@@ -107,8 +127,15 @@ fn test_raw_value_into_rust() {
     assert_eq!(
         err,
         SpannedError {
-            code: Error::ExpectedInteger,
-            position: Position { line: 1, col: 10 },
+            code: Error::InvalidValueForType {
+                expected: String::from("i32"),
+                found: String::from("a map"),
+            },
+            position: Position {
+                line: 1,
+                col: 10,
+                offset: 9
+            },
         }
     );
 }
@@ -273,7 +300,11 @@ fn test_fuzzer_found_issue() {
         RawValue::from_ron(""),
         Err(SpannedError {
             code: Error::Eof,
-            position: Position { line: 1, col: 1 },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
 
@@ -287,7 +318,11 @@ fn test_fuzzer_found_issue() {
             code: Error::Message(String::from(
                 "ron::value::RawValue cannot enable extensions"
             )),
-            position: Position { line: 1, col: 27 },
+            position: Position {
+                line: 1,
+                col: 27,
+                offset: 26
+            },
         })
     );
 
@@ -297,7 +332,11 @@ fn test_fuzzer_found_issue() {
             code: Error::Message(String::from(
                 "ron::value::RawValue cannot enable extensions"
             )),
-            position: Position { line: 1, col: 27 },
+            position: Position {
+                line: 1,
+                col: 27,
+                offset: 26
+            },
         })
     );
 
@@ -305,14 +344,22 @@ fn test_fuzzer_found_issue() {
         RawValue::from_ron("42 //"),
         Err(SpannedError {
             code: Error::UnclosedLineComment,
-            position: Position { line: 1, col: 6 },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     assert_eq!(
         ron::from_str::<&RawValue>("42 //"),
         Err(SpannedError {
             code: Error::UnclosedLineComment,
-            position: Position { line: 1, col: 6 },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     assert_eq!(
@@ -332,14 +379,22 @@ fn test_fuzzer_found_issue() {
         RawValue::from_ron("a//"),
         Err(SpannedError {
             code: Error::UnclosedLineComment,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         })
     );
     assert_eq!(
         ron::from_str::<&RawValue>("a//"),
         Err(SpannedError {
             code: Error::UnclosedLineComment,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         })
     );
     assert_eq!(
@@ -372,3 +427,58 @@ fn test_fuzzer_found_issue() {
         Some(Some(RawValue::from_ron("None").unwrap()))
     );
 }
+
+#[derive(Deserialize, Debug)]
+struct Plugin<'a> {
+    name: String,
+    #[serde(borrow)]
+    settings: &'a RawValue,
+    enabled: bool,
+    #[serde(borrow)]
+    extra: Vec<&'a RawValue>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Logger {
+    level: u8,
+    targets: Vec<String>,
+}
+
+#[test]
+fn test_borrowed_raw_value_field() {
+    let ron = r#"(
+        name: "logger",
+        settings: /* deferred */ (level: 3, targets: ["stdout", "file"]),
+        enabled: true,
+        extra: [Some(1), {"a": 'b'}],
+    )"#;
+
+    let plugin: Plugin = from_str(ron).unwrap();
+
+    assert_eq!(plugin.name, "logger");
+    assert!(plugin.enabled);
+    assert_eq!(
+        plugin.settings.get_ron(),
+        " /* deferred */ (level: 3, targets: [\"stdout\", \"file\"])"
+    );
+    assert_eq!(
+        plugin.settings.trim().get_ron(),
+        "(level: 3, targets: [\"stdout\", \"file\"])"
+    );
+    assert_eq!(plugin.extra[0].get_ron(), "Some(1)");
+    assert_eq!(plugin.extra[1].get_ron(), " {\"a\": 'b'}");
+
+    // the raw values are borrowed from the source, without being copied
+    let source = ron.as_bytes().as_ptr_range();
+    assert!(source.contains(&plugin.settings.get_ron().as_ptr()));
+    assert!(source.contains(&plugin.extra[1].get_ron().as_ptr()));
+
+    // the deferred subtree can be parsed later on, once its type is known
+    assert_eq!(
+        plugin.settings.into_rust::<Logger>(),
+        Ok(Logger {
+            level: 3,
+            targets: vec![String::from("stdout"), String::from("file")],
+        })
+    );
+}
@@ -46,7 +46,11 @@ fn v_9_deprecated_base64_bytes_support() {
     assert_eq!(
         Err(SpannedError {
             code: Error::Base64Error(base64::DecodeError::InvalidByte(0, b'_')),
-            position: Position { line: 1, col: 40 }
+            position: Position {
+                line: 1,
+                col: 40,
+                offset: 39
+            }
         }),
         ron::from_str::<BytesStruct>("BytesStruct( small:[1, 2], large:\"_+!!\" )"),
     );
@@ -55,7 +59,11 @@ fn v_9_deprecated_base64_bytes_support() {
     assert_eq!(
         Err(SpannedError {
             code: Error::Base64Error(base64::DecodeError::InvalidLastSymbol(1, b'x')),
-            position: Position { line: 1, col: 40 }
+            position: Position {
+                line: 1,
+                col: 40,
+                offset: 39
+            }
         }),
         ron::from_str::<BytesStruct>("BytesStruct( small:[1, 2], large:\"/x==\" )"),
     );
@@ -64,7 +72,11 @@ fn v_9_deprecated_base64_bytes_support() {
     assert_eq!(
         Err(SpannedError {
             code: Error::Base64Error(base64::DecodeError::InvalidPadding),
-            position: Position { line: 1, col: 42 }
+            position: Position {
+                line: 1,
+                col: 42,
+                offset: 41
+            }
         }),
         ron::from_str::<BytesStruct>("BytesStruct( small:[1, 2], large:\"AQIDBA\" )"),
     );
@@ -73,7 +85,11 @@ fn v_9_deprecated_base64_bytes_support() {
     assert_eq!(
         Err(SpannedError {
             code: Error::Base64Error(base64::DecodeError::InvalidByte(6, b'=')),
-            position: Position { line: 1, col: 45 }
+            position: Position {
+                line: 1,
+                col: 45,
+                offset: 44
+            }
         }),
         ron::from_str::<BytesStruct>("BytesStruct( small:[1, 2], large:\"AQIDBA===\" )"),
     );
@@ -107,14 +123,22 @@ fn rusty_byte_string() {
         ron::from_str::<bytes::Bytes>("b\"\\xf\"").unwrap_err(),
         SpannedError {
             code: Error::InvalidEscape("Non-hex digit found"),
-            position: Position { line: 1, col: 7 },
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         },
     );
     assert_eq!(
         ron::from_str::<bytes::Bytes>("b\"\\xf🦀\"").unwrap_err(),
         SpannedError {
             code: Error::InvalidEscape("Non-hex digit found"),
-            position: Position { line: 1, col: 7 },
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 9
+            },
         },
     );
     let err = ron::from_str::<bytes::Bytes>("br#q\"").unwrap_err();
@@ -122,7 +146,11 @@ fn rusty_byte_string() {
         err,
         SpannedError {
             code: Error::ExpectedByteString,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         },
     );
     assert_eq!(format!("{}", err.code), "Expected byte string",);
@@ -130,21 +158,33 @@ fn rusty_byte_string() {
         ron::from_str::<bytes::Bytes>("br#\"q").unwrap_err(),
         SpannedError {
             code: Error::ExpectedStringEnd,
-            position: Position { line: 1, col: 5 },
+            position: Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         },
     );
     assert_eq!(
         ron::from_str::<String>("r#q\"").unwrap_err(),
         SpannedError {
             code: Error::ExpectedString,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         },
     );
     assert_eq!(
         ron::from_str::<String>("r#\"q").unwrap_err(),
         SpannedError {
             code: Error::ExpectedStringEnd,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         },
     );
 }
@@ -352,35 +392,55 @@ fn test_weird_escapes() {
         ron::from_str::<String>(r#""\xf0""#),
         Err(SpannedError {
             code: Error::InvalidEscape("Not a valid byte-escaped Unicode character"),
-            position: Position { line: 1, col: 6 }
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            }
         })
     );
     assert_eq!(
         ron::from_str::<String>(r#""\xf0\x9f""#),
         Err(SpannedError {
             code: Error::InvalidEscape("Not a valid byte-escaped Unicode character"),
-            position: Position { line: 1, col: 10 }
+            position: Position {
+                line: 1,
+                col: 10,
+                offset: 9
+            }
         })
     );
     assert_eq!(
         ron::from_str::<String>(r#""\xf0\x9f\x40""#),
         Err(SpannedError {
             code: Error::InvalidEscape("Not a valid byte-escaped Unicode character"),
-            position: Position { line: 1, col: 14 }
+            position: Position {
+                line: 1,
+                col: 14,
+                offset: 13
+            }
         })
     );
     assert_eq!(
         ron::from_str::<String>(r#""\xf0\x9f\xa6""#),
         Err(SpannedError {
             code: Error::InvalidEscape("Not a valid byte-escaped Unicode character"),
-            position: Position { line: 1, col: 14 }
+            position: Position {
+                line: 1,
+                col: 14,
+                offset: 13
+            }
         })
     );
     assert_eq!(
         ron::from_str::<String>(r#""\xff\xff\xff\xff""#),
         Err(SpannedError {
             code: Error::InvalidEscape("Not a valid byte-escaped Unicode character"),
-            position: Position { line: 1, col: 18 }
+            position: Position {
+                line: 1,
+                col: 18,
+                offset: 17
+            }
         })
     );
 
@@ -389,7 +449,11 @@ fn test_weird_escapes() {
         ron::from_str::<char>(r"'\xf0\x9f\xa6\x80'"),
         Err(SpannedError {
             code: Error::InvalidEscape("Not a valid byte-escaped Unicode character"),
-            position: Position { line: 1, col: 6 }
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            }
         })
     );
 }
@@ -430,7 +494,11 @@ fn byte_literal() {
         ron::from_str::<u8>(r#"b'\u{0}'"#),
         Err(SpannedError {
             code: Error::InvalidEscape("Unexpected Unicode escape in byte literal"),
-            position: Position { line: 1, col: 8 },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7
+            },
         })
     );
 
@@ -439,7 +507,11 @@ fn byte_literal() {
         err,
         SpannedError {
             code: Error::ExpectedByteLiteral,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 6
+            },
         }
     );
     assert_eq!(format!("{}", err.code), "Expected byte literal");
@@ -448,7 +520,11 @@ fn byte_literal() {
         ron::from_str::<u8>(r#"b'qq'"#).unwrap_err(),
         SpannedError {
             code: Error::ExpectedByteLiteral,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         }
     );
 
@@ -459,7 +535,11 @@ fn byte_literal() {
                 expected: String::from("an 8-bit signed integer"),
                 found: String::from(r#"b'9'"#)
             },
-            position: Position { line: 1, col: 5 },
+            position: Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
 }
@@ -477,7 +557,11 @@ fn invalid_identifier() {
             ron::from_str::<Test>(&format!("({}: 42)", id)).unwrap_err(),
             SpannedError {
                 code: Error::ExpectedIdentifier,
-                position: Position { line: 1, col: 2 },
+                position: Position {
+                    line: 1,
+                    col: 2,
+                    offset: 1
+                },
             }
         );
     }
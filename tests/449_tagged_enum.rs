@@ -41,10 +41,18 @@ enum OuterEnumUntagged {
     Sum { field: InnerEnum, value: i32 },
 }
 
+/// Mirrors the check in `ron::de`, which ignores generic arguments such as the
+/// `<'_>` that newer compilers include in type names.
+fn type_path<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+
+    name.split_once('<').map_or(name, |(name, _)| name)
+}
+
 #[test]
 fn test_serde_content_hack() {
     assert_eq!(
-        std::any::type_name::<serde::__private::de::Content>(),
+        type_path::<serde::__private::de::Content>(),
         "serde::__private::de::content::Content"
     );
 }
@@ -87,7 +95,7 @@ fn test_serde_internally_tagged_hack() {
         where
             K: serde::de::DeserializeSeed<'de>,
         {
-            assert_eq!(std::any::type_name::<K::Value>(), SERDE_TAG_KEY_CANARY);
+            assert_eq!(type_path::<K::Value>(), SERDE_TAG_KEY_CANARY);
 
             if let Some(tag_key) = self.tag_key.take() {
                 return seed
@@ -109,11 +117,11 @@ fn test_serde_internally_tagged_hack() {
             V: serde::de::DeserializeSeed<'de>,
         {
             if self.field_key.is_some() {
-                assert_ne!(std::any::type_name::<V::Value>(), SERDE_CONTENT_CANARY);
+                assert_ne!(type_path::<V::Value>(), SERDE_CONTENT_CANARY);
                 return seed.deserialize(serde::de::value::StrDeserializer::new(&self.tag_value));
             }
 
-            assert_eq!(std::any::type_name::<V::Value>(), SERDE_CONTENT_CANARY);
+            assert_eq!(type_path::<V::Value>(), SERDE_CONTENT_CANARY);
 
             seed.deserialize(serde::de::value::I32Deserializer::new(self.field_value))
         }
@@ -16,7 +16,11 @@ fn test_deserialise_byte_slice() {
                 expected: String::from("a borrowed byte array"),
                 found: String::from("the byte string b\"\\x00\\x01\\x02\\x03\""),
             },
-            position: ron::error::Position { line: 1, col: 10 },
+            position: ron::error::Position {
+                line: 1,
+                col: 10,
+                offset: 9
+            },
         })
     );
 }
@@ -1,7 +1,7 @@
 #[test]
 fn serialize_backslash_string() {
     check_roundtrip('\\', r"'\\'", r"'\\'");
-    check_roundtrip(String::from("\\"), r#""\\""#, "r#\"\\\"#");
+    check_roundtrip(String::from("\\"), r#""\\""#, "r\"\\\"");
 }
 
 fn check_roundtrip<
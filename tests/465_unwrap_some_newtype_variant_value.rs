@@ -4,7 +4,11 @@ fn deserialise_value_with_unwrap_some_newtype_variant() {
         ron::from_str::<ron::Value>("Some(a: 42)"),
         Err(ron::error::SpannedError {
             code: ron::Error::ExpectedOptionEnd,
-            position: ron::error::Position { line: 1, col: 7 },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         }),
     );
     assert_eq!(
@@ -10,14 +10,22 @@ fn de_integer_underscores() {
         ron::from_str::<u8>("_0b1"),
         Err(ron::error::SpannedError {
             code: ron::Error::UnderscoreAtBeginning,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
     assert_eq!(
         ron::from_str::<u8>("_0b1_u8"),
         Err(ron::error::SpannedError {
             code: ron::Error::UnderscoreAtBeginning,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
     assert_eq!(
@@ -27,7 +35,11 @@ fn de_integer_underscores() {
                 digit: '2',
                 base: 2
             },
-            position: ron::error::Position { line: 1, col: 3 },
+            position: ron::error::Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
@@ -37,7 +49,11 @@ fn de_integer_underscores() {
                 digit: '2',
                 base: 2
             },
-            position: ron::error::Position { line: 1, col: 4 },
+            position: ron::error::Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         })
     );
 
@@ -46,7 +62,11 @@ fn de_integer_underscores() {
         ron::from_str::<u8>("_0o5"),
         Err(ron::error::SpannedError {
             code: ron::Error::UnderscoreAtBeginning,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
     assert_eq!(
@@ -56,7 +76,11 @@ fn de_integer_underscores() {
                 digit: 'A',
                 base: 8
             },
-            position: ron::error::Position { line: 1, col: 3 },
+            position: ron::error::Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
 
@@ -65,14 +89,22 @@ fn de_integer_underscores() {
         ron::from_str::<u8>("_0xF"),
         Err(ron::error::SpannedError {
             code: ron::Error::UnderscoreAtBeginning,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
     assert_eq!(
         ron::from_str::<u8>("0xZ"),
         Err(ron::error::SpannedError {
             code: ron::Error::ExpectedInteger,
-            position: ron::error::Position { line: 1, col: 3 },
+            position: ron::error::Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
 
@@ -81,7 +113,11 @@ fn de_integer_underscores() {
         ron::from_str::<u8>("_123"),
         Err(ron::error::SpannedError {
             code: ron::Error::UnderscoreAtBeginning,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
     assert_eq!(
@@ -91,7 +127,11 @@ fn de_integer_underscores() {
                 digit: 'a',
                 base: 10
             },
-            position: ron::error::Position { line: 1, col: 3 },
+            position: ron::error::Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
 }
@@ -104,14 +144,24 @@ fn de_float_underscores() {
         ron::from_str::<f32>("_286"),
         Err(ron::error::SpannedError {
             code: ron::Error::UnderscoreAtBeginning,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
     assert_eq!(
         ron::from_str::<f32>("2a86"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("a86"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
 
@@ -120,7 +170,11 @@ fn de_float_underscores() {
         ron::from_str::<f32>("2_18__6_._"),
         Err(ron::error::SpannedError {
             code: ron::Error::FloatUnderscore,
-            position: ron::error::Position { line: 1, col: 10 },
+            position: ron::error::Position {
+                line: 1,
+                col: 10,
+                offset: 9
+            },
         })
     );
     assert_eq!(
@@ -133,7 +187,11 @@ fn de_float_underscores() {
         ron::from_str::<f32>("._3__7_"),
         Err(ron::error::SpannedError {
             code: ron::Error::FloatUnderscore,
-            position: ron::error::Position { line: 1, col: 2 },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
 
@@ -145,7 +203,11 @@ fn de_float_underscores() {
         ron::from_str::<f64>("2_18__6_.3__7_e+____"),
         Err(ron::error::SpannedError {
             code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         })
     );
 }
@@ -226,28 +288,44 @@ fn negative_unsigned() {
         ron::from_str::<ron::Value>("-1u8"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
     assert_eq!(
         ron::from_str::<ron::Value>("-1u16"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     assert_eq!(
         ron::from_str::<ron::Value>("-1u32"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     assert_eq!(
         ron::from_str::<ron::Value>("-1u64"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     #[cfg(feature = "integer128")]
@@ -255,7 +333,11 @@ fn negative_unsigned() {
         ron::from_str::<ron::Value>("-1u128"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 7 },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         })
     );
 
@@ -263,28 +345,44 @@ fn negative_unsigned() {
         ron::from_str::<u8>("-1u8"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
     assert_eq!(
         ron::from_str::<u16>("-1u16"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     assert_eq!(
         ron::from_str::<u32>("-1u32"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     assert_eq!(
         ron::from_str::<u64>("-1u64"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
     #[cfg(feature = "integer128")]
@@ -292,7 +390,11 @@ fn negative_unsigned() {
         ron::from_str::<u128>("-1u128"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 7 },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         })
     );
 }
@@ -302,62 +404,110 @@ fn invalid_suffix() {
     assert_eq!(
         ron::from_str::<ron::Value>("1u7"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("u7"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     assert_eq!(
         ron::from_str::<ron::Value>("1f17"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("f17"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     #[cfg(not(feature = "integer128"))]
     assert_eq!(
         ron::from_str::<ron::Value>("1u128"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("u128"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     #[cfg(not(feature = "integer128"))]
     assert_eq!(
         ron::from_str::<ron::Value>("1i128"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("i128"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
 
     assert_eq!(
         ron::from_str::<u8>("1u7"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("u7"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     assert_eq!(
         ron::from_str::<f32>("1f17"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("f17"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     #[cfg(not(feature = "integer128"))]
     assert_eq!(
         ron::from_str::<u64>("1u128"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("u128"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     #[cfg(not(feature = "integer128"))]
     assert_eq!(
         ron::from_str::<i64>("1i128"),
         Err(ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 2 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("i128"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
 }
@@ -371,7 +521,11 @@ fn number_type_mismatch() {
                 expected: String::from("an 8-bit unsigned integer"),
                 found: String::from("1i32")
             },
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
 
@@ -379,7 +533,11 @@ fn number_type_mismatch() {
         ron::from_str::<i64>("-1u8"),
         Err(ron::error::SpannedError {
             code: ron::Error::IntegerOutOfBounds,
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
 
@@ -390,7 +548,11 @@ fn number_type_mismatch() {
                 expected: String::from("a 32-bit floating point number"),
                 found: String::from("1f64")
             },
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
 
@@ -401,7 +563,11 @@ fn number_type_mismatch() {
                 expected: String::from("a 64-bit floating point number"),
                 found: String::from("1f32")
             },
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         })
     );
 
@@ -445,7 +611,8 @@ fn check_number_type_mismatch<T: std::fmt::Debug + serde::de::DeserializeOwned>(
         err.position,
         ron::error::Position {
             line: 1,
-            col: 2 + suffix.len()
+            col: 2 + suffix.len(),
+            offset: 1 + suffix.len(),
         }
     );
 
@@ -459,8 +626,15 @@ fn float_const_prefix() {
     assert_eq!(
         ron::from_str::<f32>("NaNf32a").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            code: ron::Error::InvalidValueForType {
+                expected: String::from("f32"),
+                found: String::from("the identifier `NaNf32a`"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 
@@ -468,7 +642,11 @@ fn float_const_prefix() {
         ron::from_str::<f64>("-inff64a").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 
@@ -476,7 +654,11 @@ fn float_const_prefix() {
         ron::from_str::<f32>("+NaNf17").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 }
@@ -487,21 +669,33 @@ fn invalid_float() {
         ron::from_str::<f32>("1ee3").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
     assert_eq!(
         ron::from_str::<f32>("1ee3f32").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
     assert_eq!(
         ron::from_str::<f64>("1ee3f64").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::ExpectedFloat,
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
 }
@@ -537,8 +731,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("true(false)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 5 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(false)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         }
     );
 
@@ -547,8 +747,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("false(true)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 6 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(true)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         }
     );
 
@@ -564,8 +770,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("None(true)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 5 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(true)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         }
     );
 
@@ -574,8 +786,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("inf(false)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 4 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(false)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         }
     );
 
@@ -590,8 +808,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("inff32(false)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 7 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(false)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         }
     );
 
@@ -606,8 +830,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("inff64(false)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 7 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(false)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         }
     );
 
@@ -616,8 +846,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("NaN(true)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 4 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(true)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         }
     );
 
@@ -629,8 +865,14 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("NaNf32(true)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 7 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(true)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         }
     );
 
@@ -642,8 +884,92 @@ fn fuzzer_found_issues() {
     assert_eq!(
         ron::from_str::<ron::Value>("NaNf64(true)").unwrap_err(),
         ron::error::SpannedError {
-            code: ron::Error::TrailingCharacters,
-            position: ron::error::Position { line: 1, col: 7 },
+            code: ron::Error::TrailingCharacters {
+                found: String::from("(true)"),
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         }
     );
 }
+
+#[test]
+fn float_suffixes() {
+    assert_eq!(ron::from_str::<f32>("1.0f32"), Ok(1.0_f32));
+    assert_eq!(ron::from_str::<f64>("1.0f64"), Ok(1.0_f64));
+    assert_eq!(ron::from_str::<f32>("-1.5e-3f32"), Ok(-1.5e-3_f32));
+    assert_eq!(ron::from_str::<f64>(".5f64"), Ok(0.5_f64));
+    assert_eq!(
+        ron::from_str::<(f32, f64)>("(1.0f32, 2.5f64)"),
+        Ok((1.0_f32, 2.5_f64))
+    );
+    assert_eq!(
+        ron::from_str::<ron::Value>("1.0f64"),
+        Ok(ron::Value::Number(Number::new(1.0_f64)))
+    );
+
+    assert_eq!(
+        ron::from_str::<f32>("1.0f64"),
+        Err(ron::error::SpannedError {
+            code: ron::Error::InvalidValueForType {
+                expected: String::from("a 32-bit floating point number"),
+                found: String::from("1.0f64")
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
+        })
+    );
+}
+
+#[test]
+fn float_into_integer() {
+    assert_eq!(
+        ron::from_str::<i32>("1.5f32"),
+        Err(ron::error::SpannedError {
+            code: ron::Error::InvalidValueForType {
+                expected: String::from("a 32-bit signed integer"),
+                found: String::from("1.5f32")
+            },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<u8>("-2.0").unwrap_err().code,
+        ron::Error::InvalidValueForType {
+            expected: String::from("an 8-bit unsigned integer"),
+            found: String::from("-2.0")
+        }
+    );
+    assert_eq!(
+        ron::from_str::<u64>("1e3").unwrap_err().code,
+        ron::Error::InvalidValueForType {
+            expected: String::from("a 64-bit unsigned integer"),
+            found: String::from("1e3")
+        }
+    );
+    assert_eq!(
+        ron::from_str::<i16>("1f64").unwrap_err().code,
+        ron::Error::InvalidValueForType {
+            expected: String::from("a 16-bit signed integer"),
+            found: String::from("1f64")
+        }
+    );
+
+    // Integers that merely look like they could have a float suffix
+    assert_eq!(ron::from_str::<u32>("0xf32"), Ok(0xf32));
+    assert_eq!(ron::from_str::<u8>("1u8"), Ok(1));
+    assert_eq!(
+        ron::from_str::<ron::Value>("1f32"),
+        Ok(ron::Value::Number(Number::new(1.0_f32)))
+    );
+}
@@ -39,7 +39,11 @@ fn struct_names_inside_internally_tagged() {
                 field: "hi",
                 outer: None
             },
-            position: Position { line: 7, col: 2 }
+            position: Position {
+                line: 7,
+                col: 2,
+                offset: 78
+            }
         })),
     );
 }
@@ -95,7 +99,11 @@ fn struct_names_inside_adjacently_tagged() {
                 field: "ho",
                 outer: Some(String::from("AdjacentlyTagged"))
             },
-            position: Position { line: 1, col: 58 }
+            position: Position {
+                line: 1,
+                col: 58,
+                offset: 57
+            }
         }),
     );
 }
@@ -135,7 +143,11 @@ fn struct_names_inside_untagged() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 6, col: 2 }
+            position: Position {
+                line: 6,
+                col: 2,
+                offset: 56
+            }
         })),
     );
 }
@@ -182,7 +194,11 @@ fn struct_names_inside_flatten_struct() {
                 field: "hi",
                 outer: None
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 51
+            }
         })),
     );
 }
@@ -231,7 +247,11 @@ fn struct_names_inside_flatten_struct_variant() {
                 field: "hi",
                 outer: Some(String::from("C"))
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 53
+            }
         })),
     );
 }
@@ -459,7 +479,11 @@ fn implicit_some_inside_flatten_struct_variant() {
                 expected: String::from("an empty array"),
                 found: String::from("a unit value")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 64
+            }
         }))
     );
     assert_eq!(
@@ -503,7 +527,11 @@ fn newtype_inside_internally_tagged() {
                 expected: String::from("i32"),
                 found: String::from("a sequence")
             },
-            position: Position { line: 5, col: 2 }
+            position: Position {
+                line: 5,
+                col: 2,
+                offset: 42
+            }
         }))
     );
 }
@@ -537,7 +565,11 @@ fn newtype_inside_adjacently_tagged() {
                 expected: String::from("i32"),
                 found: String::from("a sequence")
             },
-            position: Position { line: 1, col: 36 }
+            position: Position {
+                line: 1,
+                col: 36,
+                offset: 35
+            }
         })
     );
 }
@@ -559,7 +591,11 @@ fn newtype_inside_untagged() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 4, col: 2 }
+            position: Position {
+                line: 4,
+                col: 2,
+                offset: 28
+            }
         }))
     );
 }
@@ -594,7 +630,11 @@ fn newtype_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("a sequence")
             },
-            position: Position { line: 4, col: 1 }
+            position: Position {
+                line: 4,
+                col: 1,
+                offset: 31
+            }
         }))
     );
 }
@@ -631,7 +671,11 @@ fn newtype_inside_flatten_struct_variant() {
                 expected: String::from("i32"),
                 found: String::from("a sequence")
             },
-            position: Position { line: 4, col: 1 }
+            position: Position {
+                line: 4,
+                col: 1,
+                offset: 33
+            }
         }))
     );
 }
@@ -672,7 +716,11 @@ fn one_tuple_inside_unwrapped_newtype_variant_inside_internally_tagged() {
                 expected: String::from("a tuple of size 1"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 6, col: 2 }
+            position: Position {
+                line: 6,
+                col: 2,
+                offset: 85
+            }
         }))
     );
 }
@@ -728,7 +776,11 @@ fn one_tuple_inside_unwrapped_newtype_variant_inside_adjacently_tagged() {
                 expected: String::from("an array of length 1"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 1, col: 79 }
+            position: Position {
+                line: 1,
+                col: 79,
+                offset: 78
+            }
         })
     );
 }
@@ -766,7 +818,11 @@ fn one_tuple_inside_unwrapped_newtype_variant_inside_untagged() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 5, col: 2 }
+            position: Position {
+                line: 5,
+                col: 2,
+                offset: 68
+            }
         }))
     );
 }
@@ -817,7 +873,11 @@ fn one_tuple_inside_unwrapped_newtype_variant_inside_flatten_struct() {
                 expected: String::from("an array of length 1"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 74
+            }
         }))
     );
 }
@@ -870,7 +930,11 @@ fn one_tuple_inside_unwrapped_newtype_variant_inside_flatten_struct_variant() {
                 expected: String::from("a tuple of size 1"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 76
+            }
         }))
     );
 }
@@ -909,7 +973,11 @@ fn one_tuple_variant_inside_internally_tagged() {
                 expected: String::from("tuple variant"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 7, col: 2 }
+            position: Position {
+                line: 7,
+                col: 2,
+                offset: 71
+            }
         }))
     );
 }
@@ -961,7 +1029,11 @@ fn one_tuple_variant_inside_adjacently_tagged() {
                 expected: String::from("tuple variant"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 1, col: 50 }
+            position: Position {
+                line: 1,
+                col: 50,
+                offset: 49
+            }
         })
     );
 }
@@ -999,7 +1071,11 @@ fn one_tuple_variant_inside_untagged() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 6, col: 2 }
+            position: Position {
+                line: 6,
+                col: 2,
+                offset: 57
+            }
         }))
     );
 }
@@ -1046,7 +1122,11 @@ fn one_tuple_variant_inside_flatten_struct() {
                 expected: String::from("tuple variant"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 60
+            }
         }))
     );
 }
@@ -1095,7 +1175,11 @@ fn one_tuple_variant_inside_flatten_struct_variant() {
                 expected: String::from("tuple variant"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 62
+            }
         }))
     );
 }
@@ -1129,7 +1213,11 @@ fn raw_value_inside_internally_tagged() {
                 expected: String::from("any valid RON-value-string"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 7, col: 2 }
+            position: Position {
+                line: 7,
+                col: 2,
+                offset: 61
+            }
         }))
     );
 }
@@ -1178,7 +1266,11 @@ fn raw_value_inside_adjacently_tagged() {
                 expected: String::from("any valid RON-value-string"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 1, col: 39 }
+            position: Position {
+                line: 1,
+                col: 39,
+                offset: 38
+            }
         })
     );
 }
@@ -1211,7 +1303,11 @@ fn raw_value_inside_untagged() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 6, col: 2 }
+            position: Position {
+                line: 6,
+                col: 2,
+                offset: 47
+            }
         }))
     );
 }
@@ -1255,7 +1351,11 @@ fn raw_value_inside_flatten_struct() {
                 expected: String::from("any valid RON-value-string"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 50
+            }
         }))
     );
 }
@@ -1301,7 +1401,11 @@ fn raw_value_inside_flatten_struct_variant() {
                 expected: String::from("any valid RON-value-string"),
                 found: String::from("the unsigned integer `42`")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 52
+            }
         }))
     );
 }
@@ -1332,7 +1436,11 @@ fn unit_like_zero_length_inside_internally_tagged() {
                 expected: String::from("an empty array"),
                 found: String::from("a unit value")
             },
-            position: Position { line: 7, col: 2 }
+            position: Position {
+                line: 7,
+                col: 2,
+                offset: 61
+            }
         }))
     );
 }
@@ -1377,7 +1485,11 @@ fn unit_like_zero_length_inside_adjacently_tagged() {
                 expected: String::from("tuple struct TupleStruct"),
                 found: String::from("a unit value")
             },
-            position: Position { line: 1, col: 40 }
+            position: Position {
+                line: 1,
+                col: 40,
+                offset: 39
+            }
         })
     );
 }
@@ -1410,7 +1522,11 @@ fn unit_like_zero_length_inside_untagged() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 6, col: 2 }
+            position: Position {
+                line: 6,
+                col: 2,
+                offset: 47
+            }
         }))
     );
 }
@@ -1454,7 +1570,11 @@ fn unit_like_zero_length_inside_flatten_struct() {
                 expected: String::from("tuple variant"),
                 found: String::from("a unit value")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 55
+            }
         }))
     );
 }
@@ -1502,7 +1622,11 @@ fn unit_like_zero_length_inside_flatten_struct_variant() {
                 expected: String::from("struct variant"),
                 found: String::from("a unit value")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 58
+            }
         }))
     );
 }
@@ -1545,7 +1669,11 @@ fn i128_inside_internally_tagged() {
                 expected: String::from("any value"),
                 found: format!("integer `{}` as u128", i128::MAX)
             },
-            position: Position { line: 5, col: 52 }
+            position: Position {
+                line: 5,
+                col: 52,
+                offset: 88
+            }
         }))
     );
 }
@@ -1607,7 +1735,11 @@ fn u128_inside_adjacently_tagged() {
                 expected: String::from("any value"),
                 found: format!("integer `{}` as u128", u128::MAX)
             },
-            position: Position { line: 1, col: 67 }
+            position: Position {
+                line: 1,
+                col: 67,
+                offset: 66
+            }
         }),
     );
 }
@@ -1650,7 +1782,11 @@ fn i128_inside_untagged() {
                 expected: String::from("any value"),
                 found: format!("integer `{}` as i128", i128::MIN)
             },
-            position: Position { line: 4, col: 53 }
+            position: Position {
+                line: 4,
+                col: 53,
+                offset: 75
+            }
         }))
     );
 }
@@ -1703,7 +1839,11 @@ fn u128_inside_flatten_struct() {
                 expected: String::from("any value"),
                 found: format!("integer `{}` as u128", u128::MAX)
             },
-            position: Position { line: 4, col: 52 }
+            position: Position {
+                line: 4,
+                col: 52,
+                offset: 78
+            }
         }))
     );
 }
@@ -1758,7 +1898,11 @@ fn i128_inside_flatten_struct_variant() {
                 expected: String::from("any value"),
                 found: format!("integer `{}` as i128", i128::MIN)
             },
-            position: Position { line: 4, col: 53 }
+            position: Position {
+                line: 4,
+                col: 53,
+                offset: 81
+            }
         }))
     );
 }
@@ -1785,7 +1929,11 @@ fn duplicate_key_inside_flatten_struct() {
                 field: "ho",
                 outer: None
             },
-            position: Position { line: 3, col: 9 }
+            position: Position {
+                line: 3,
+                col: 9,
+                offset: 24
+            }
         }))
     );
 }
@@ -1828,7 +1976,11 @@ fn duplicate_key_inside_flatten_struct_variant() {
                 field: "ho",
                 outer: Some(String::from("A"))
             },
-            position: Position { line: 3, col: 9 }
+            position: Position {
+                line: 3,
+                col: 9,
+                offset: 26
+            }
         }))
     );
 }
@@ -1842,7 +1994,8 @@ fn non_string_key_inside_flatten_struct() {
         other: HashMap<i32, bool>,
     }
 
-    assert_eq!(
+    // the first key depends on the iteration order of the map
+    assert!(matches!(
         check_roundtrip(
             &FlattenedStruct {
                 ho: 24,
@@ -1851,10 +2004,15 @@ fn non_string_key_inside_flatten_struct() {
             PrettyConfig::default()
         ),
         Err(Err(SpannedError {
-            code: Error::ExpectedString,
-            position: Position { line: 3, col: 5 }
-        }))
-    );
+            code: Error::InvalidValueForType { expected, found },
+            position: Position {
+                line: 3,
+                col: 5,
+                offset: 20
+            }
+        })) if expected == "field identifier"
+            && (found == "the unsigned integer `1`" || found == "the unsigned integer `0`")
+    ));
 }
 
 #[test]
@@ -1868,7 +2026,8 @@ fn non_string_key_inside_flatten_struct_variant() {
         },
     }
 
-    assert_eq!(
+    // the first key depends on the iteration order of the map
+    assert!(matches!(
         check_roundtrip(
             &FlattenedStructVariant::A {
                 ho: 24,
@@ -1877,10 +2036,15 @@ fn non_string_key_inside_flatten_struct_variant() {
             PrettyConfig::default()
         ),
         Err(Err(SpannedError {
-            code: Error::ExpectedString,
-            position: Position { line: 3, col: 5 }
-        }))
-    );
+            code: Error::InvalidValueForType { expected, found },
+            position: Position {
+                line: 3,
+                col: 5,
+                offset: 22
+            }
+        })) if expected == "field identifier"
+            && (found == "the UTF-8 character `h`" || found == "the UTF-8 character `i`")
+    ));
 }
 
 #[test]
@@ -2008,7 +2172,11 @@ fn flatten_struct_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("a map")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 55
+            }
         }))
     );
 }
@@ -2056,7 +2224,11 @@ fn flatten_struct_beside_map_inside_flatten_struct_variant() {
                 expected: String::from("i32"),
                 found: String::from("a map")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 57
+            }
         }))
     );
 }
@@ -2163,7 +2335,11 @@ fn externally_tagged_newtype_variant_beside_map_inside_flatten_struct_variant()
                 expected: String::from("i32"),
                 found: String::from("a unit value")
             },
-            position: Position { line: 4, col: 1 }
+            position: Position {
+                line: 4,
+                col: 1,
+                offset: 37
+            }
         }))
     );
 }
@@ -2198,7 +2374,11 @@ fn externally_tagged_struct_variant_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("a map")
             },
-            position: Position { line: 6, col: 1 }
+            position: Position {
+                line: 6,
+                col: 1,
+                offset: 55
+            }
         }))
     );
 }
@@ -2235,7 +2415,11 @@ fn externally_tagged_tuple_variant_beside_map_inside_flatten_struct_variant() {
                 expected: String::from("i32"),
                 found: String::from("a sequence")
             },
-            position: Position { line: 7, col: 1 }
+            position: Position {
+                line: 7,
+                col: 1,
+                offset: 66
+            }
         }))
     );
 }
@@ -2271,7 +2455,11 @@ fn internally_tagged_unit_variant_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Unit\"")
             },
-            position: Position { line: 4, col: 1 }
+            position: Position {
+                line: 4,
+                col: 1,
+                offset: 35
+            }
         }))
     );
 }
@@ -2309,7 +2497,11 @@ fn internally_tagged_newtype_variant_beside_map_inside_flatten_struct_variant()
                 expected: String::from("i32"),
                 found: String::from("the string \"Newtype\"")
             },
-            position: Position { line: 4, col: 1 }
+            position: Position {
+                line: 4,
+                col: 1,
+                offset: 40
+            }
         }))
     );
 }
@@ -2345,7 +2537,11 @@ fn internally_tagged_struct_variant_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Struct\"")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 51
+            }
         }))
     );
 }
@@ -2381,7 +2577,11 @@ fn adjacently_tagged_unit_variant_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Unit\"")
             },
-            position: Position { line: 4, col: 1 }
+            position: Position {
+                line: 4,
+                col: 1,
+                offset: 33
+            }
         }))
     );
 }
@@ -2419,7 +2619,11 @@ fn adjacently_tagged_newtype_variant_beside_map_inside_flatten_struct_variant()
                 expected: String::from("i32"),
                 found: String::from("the string \"Newtype\"")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 57
+            }
         }))
     );
 }
@@ -2455,7 +2659,11 @@ fn adjacently_tagged_struct_variant_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Struct\"")
             },
-            position: Position { line: 7, col: 1 }
+            position: Position {
+                line: 7,
+                col: 1,
+                offset: 75
+            }
         }))
     );
 }
@@ -2493,7 +2701,11 @@ fn adjacently_tagged_tuple_variant_beside_map_inside_flatten_struct_variant() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Tuple\"")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 63
+            }
         }))
     );
 }
@@ -2529,7 +2741,11 @@ fn tagged_struct_beside_map_inside_flatten_struct() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Flattened\"")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 54
+            }
         }))
     );
 }
@@ -2567,7 +2783,11 @@ fn tagged_struct_beside_map_inside_flatten_struct_variant() {
                 expected: String::from("i32"),
                 found: String::from("the string \"Flattened\"")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 56
+            }
         }))
     );
 }
@@ -2586,7 +2806,11 @@ fn zero_length_untagged_tuple_variant() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 1, col: 3 }
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            }
         }))
     );
 }
@@ -2605,7 +2829,11 @@ fn zero_length_untagged_struct_variant() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 1, col: 3 }
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            }
         }))
     );
 }
@@ -2655,7 +2883,11 @@ fn unit_inside_untagged_newtype_variant_inside_internally_tagged_newtype_variant
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 3, col: 2 }
+            position: Position {
+                line: 3,
+                col: 2,
+                offset: 25
+            }
         }))
     );
 }
@@ -2687,7 +2919,11 @@ fn unit_inside_untagged_newtype_variant_inside_flatten_struct() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 3, col: 1 }
+            position: Position {
+                line: 3,
+                col: 1,
+                offset: 16
+            }
         }))
     );
 }
@@ -2718,7 +2954,11 @@ fn unit_struct_inside_untagged_newtype_variant_inside_internally_tagged_newtype_
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 3, col: 2 }
+            position: Position {
+                line: 3,
+                col: 2,
+                offset: 25
+            }
         }))
     );
 }
@@ -2752,7 +2992,11 @@ fn untagged_unit_variant_inside_internally_tagged_newtype_variant() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 3, col: 1 }
+            position: Position {
+                line: 3,
+                col: 1,
+                offset: 18
+            }
         }))
     );
 }
@@ -2793,7 +3037,11 @@ fn untagged_unit_variant_inside_flatten_struct_variant() {
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 3, col: 2 }
+            position: Position {
+                line: 3,
+                col: 2,
+                offset: 25
+            }
         }))
     );
 }
@@ -2834,7 +3082,11 @@ fn unit_inside_internally_tagged_newtype_variant_inside_multi_flatten_struct() {
                 expected: String::from("unit"),
                 found: String::from("a map")
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 52
+            }
         }))
     );
 }
@@ -2880,7 +3132,11 @@ fn untagged_unit_variant_inside_internally_tagged_newtype_variant_inside_multi_f
             code: Error::Message(String::from(
                 "data did not match any variant of untagged enum Untagged"
             )),
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 52
+            }
         }))
     );
 }
@@ -2920,7 +3176,11 @@ fn flattened_externally_tagged_newtype_variant_beside_flattened_intenally_tagged
                 expected: String::from("map with a single key"),
                 found: String::from("a map"),
             },
-            position: Position { line: 5, col: 1 }
+            position: Position {
+                line: 5,
+                col: 1,
+                offset: 60
+            }
         }))
     );
 }
@@ -2960,7 +3220,11 @@ fn flattened_externally_tagged_struct_variant_beside_flattened_intenally_tagged_
                 expected: String::from("map with a single key"),
                 found: String::from("a map"),
             },
-            position: Position { line: 7, col: 1 }
+            position: Position {
+                line: 7,
+                col: 1,
+                offset: 79
+            }
         }))
     );
 }
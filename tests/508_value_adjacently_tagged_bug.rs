@@ -36,7 +36,11 @@ fn roundtrip_through_value() {
         err,
         ron::error::SpannedError {
             code: ron::Error::ExpectedIdentifier,
-            position: ron::error::Position { line: 1, col: 7 },
+            position: ron::error::Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
         }
     );
 
@@ -0,0 +1,188 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: Option<String>,
+    size: u32,
+}
+
+#[test]
+fn skip_bom() {
+    assert_eq!(ron::from_str::<u32>("\u{FEFF}42"), Ok(42));
+    assert_eq!(ron::from_str::<u32>("\u{FEFF}  \n 42"), Ok(42));
+    assert_eq!(ron::de::from_bytes::<u32>(b"\xEF\xBB\xBF42"), Ok(42));
+    #[cfg(feature = "std")]
+    assert_eq!(
+        ron::de::from_reader::<_, u32>(&b"\xEF\xBB\xBF42"[..]),
+        Ok(42)
+    );
+}
+
+#[test]
+fn skip_bom_with_extensions() {
+    assert_eq!(
+        ron::from_str::<Config>(
+            "\u{FEFF}#![enable(implicit_some)]
+(name: \"ron\", size: 4)"
+        ),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+}
+
+#[test]
+fn only_skip_leading_bom() {
+    assert_eq!(
+        ron::from_str::<u32>("\u{FEFF}\u{FEFF}42"),
+        Err(SpannedError {
+            code: Error::ExpectedInteger,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 3,
+            },
+        })
+    );
+}
+
+#[test]
+fn error_positions_after_bom() {
+    assert_eq!(
+        ron::from_str::<Config>("\u{FEFF}(size: true)"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("u32"),
+                found: String::from("the boolean `true`"),
+            },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 10,
+            },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<Config>("\u{FEFF}#![enable(implicit_some)]\n(size: true)"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("u32"),
+                found: String::from("the boolean `true`"),
+            },
+            position: Position {
+                line: 2,
+                col: 8,
+                offset: 36,
+            },
+        })
+    );
+}
+
+#[test]
+fn shebang_is_rejected_by_default() {
+    assert_eq!(
+        ron::from_str::<u32>("#!/usr/bin/env my-tool\n42"),
+        Err(SpannedError {
+            code: Error::UnexpectedChar('u'),
+            position: Position {
+                line: 1,
+                col: 5,
+                offset: 4,
+            },
+        })
+    );
+}
+
+#[test]
+fn skip_shebang() {
+    let options = Options::default().with_allow_shebang(true);
+
+    assert_eq!(
+        options.from_str::<u32>("#!/usr/bin/env my-tool\n42"),
+        Ok(42)
+    );
+    assert_eq!(options.from_str::<u32>("#!my-tool\r\n  42"), Ok(42));
+    assert_eq!(options.from_str::<u32>("42"), Ok(42));
+    assert_eq!(
+        options.from_str::<Option<u32>>("#!"),
+        Err(SpannedError {
+            code: Error::ExpectedOption,
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2,
+            },
+        })
+    );
+}
+
+#[test]
+fn skip_bom_and_shebang_with_extensions() {
+    let options = Options::default().with_allow_shebang(true);
+
+    assert_eq!(
+        options.from_str::<Config>(
+            "\u{FEFF}#!/usr/bin/env my-tool
+#![enable(implicit_some)]
+(name: \"ron\", size: 4)"
+        ),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+    assert_eq!(
+        options.from_str::<Config>(
+            "\u{FEFF}#!/usr/bin/env my-tool
+#![enable(implicit_some)]
+(size: true)"
+        ),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("u32"),
+                found: String::from("the boolean `true`"),
+            },
+            position: Position {
+                line: 3,
+                col: 8,
+                offset: 59,
+            },
+        })
+    );
+}
+
+#[test]
+fn attributes_are_not_shebangs() {
+    let options = Options::default().with_allow_shebang(true);
+
+    assert_eq!(
+        options.from_str::<Config>("#![enable(implicit_some)]\n(name: \"ron\", size: 4)"),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+    assert_eq!(
+        options.from_str::<Config>("#! [enable(implicit_some)] (name: \"ron\", size: 4)"),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+    assert_eq!(
+        options.from_str::<u32>("#![enable(unknown)]\n42"),
+        Err(SpannedError {
+            code: Error::NoSuchExtension(String::from("unknown")),
+            position: Position {
+                line: 1,
+                col: 18,
+                offset: 17,
+            },
+        })
+    );
+}
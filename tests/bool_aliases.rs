@@ -0,0 +1,105 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    Options,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    enabled: bool,
+    verbose: bool,
+    flags: Vec<bool>,
+    optional: Option<bool>,
+}
+
+fn legacy() -> Options {
+    Options::default().with_bool_aliases(&[
+        ("yes", true),
+        ("no", false),
+        ("on", true),
+        ("off", false),
+    ])
+}
+
+#[test]
+fn registered_aliases_are_accepted() {
+    assert_eq!(
+        legacy()
+            .from_str("(enabled: yes, verbose: off, flags: [on, no, true], optional: Some(on))"),
+        Ok(Config {
+            enabled: true,
+            verbose: false,
+            flags: vec![true, false, true],
+            optional: Some(true),
+        })
+    );
+    assert_eq!(legacy().from_str("no"), Ok(false));
+    assert_eq!(legacy().from_str("true"), Ok(true));
+}
+
+#[test]
+fn aliases_are_rejected_by_default() {
+    assert_eq!(
+        ron::from_str::<bool>("yes"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("a boolean"),
+                found: String::from("the identifier `yes`"),
+            },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+}
+
+#[test]
+fn unregistered_aliases_are_rejected() {
+    assert_eq!(
+        legacy().from_str::<Config>("(enabled: yes, verbose: enabled, flags: [], optional: None)"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("a boolean"),
+                found: String::from("the identifier `enabled`"),
+            },
+            position: Position {
+                line: 1,
+                col: 25,
+                offset: 24,
+            },
+        })
+    );
+
+    // Aliases must match whole identifiers
+    assert_eq!(
+        legacy().from_str::<bool>("yesterday"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("a boolean"),
+                found: String::from("the identifier `yesterday`"),
+            },
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+}
+
+#[test]
+fn aliases_are_never_serialized() {
+    let config = Config {
+        enabled: true,
+        verbose: false,
+        flags: vec![true],
+        optional: Some(false),
+    };
+
+    assert_eq!(
+        legacy().to_string(&config).unwrap(),
+        "(enabled:true,verbose:false,flags:[true],optional:Some(false))"
+    );
+}
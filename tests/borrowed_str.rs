@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -14,3 +16,43 @@ fn borrowed_str() {
         Some(Borrowed { value: "test" })
     );
 }
+
+#[derive(Debug, Deserialize)]
+struct Document<'a> {
+    #[serde(borrow)]
+    plain: Cow<'a, str>,
+    #[serde(borrow)]
+    escaped: Cow<'a, str>,
+    #[serde(borrow)]
+    raw: Cow<'a, str>,
+    #[serde(borrow)]
+    unicode: Cow<'a, str>,
+    #[serde(borrow)]
+    unicode_escape: Cow<'a, str>,
+    #[serde(borrow)]
+    empty: Cow<'a, str>,
+}
+
+#[test]
+fn cow_str_borrows_unless_escaped() {
+    let ron = r##"(
+        plain: "plain",
+        escaped: "line\nbreak",
+        raw: r#"raw "quoted""#,
+        unicode: "🦀",
+        unicode_escape: "\u{1F980}",
+        empty: "",
+    )"##;
+
+    for document in [
+        ron::from_str::<Document>(ron).unwrap(),
+        ron::de::from_bytes::<Document>(ron.as_bytes()).unwrap(),
+    ] {
+        assert!(matches!(document.plain, Cow::Borrowed("plain")));
+        assert!(matches!(document.escaped, Cow::Owned(ref s) if s == "line\nbreak"));
+        assert!(matches!(document.raw, Cow::Borrowed("raw \"quoted\"")));
+        assert!(matches!(document.unicode, Cow::Borrowed("🦀")));
+        assert!(matches!(document.unicode_escape, Cow::Owned(ref s) if s == "🦀"));
+        assert!(matches!(document.empty, Cow::Borrowed("")));
+    }
+}
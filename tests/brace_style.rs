@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use ron::ser::{to_string_pretty, BraceStyle, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Circle { center: Point, radius: u8 },
+    Line(Point, Point),
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Scene {
+    name: String,
+    origin: Point,
+    shapes: Vec<Shape>,
+    labels: BTreeMap<String, Vec<u8>>,
+    empty: Vec<u8>,
+    pair: (u8, u8),
+}
+
+fn scene() -> Scene {
+    Scene {
+        name: String::from("demo"),
+        origin: Point { x: 0, y: 1 },
+        shapes: vec![
+            Shape::Circle {
+                center: Point { x: 1, y: 2 },
+                radius: 3,
+            },
+            Shape::Line(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }),
+            Shape::Empty,
+        ],
+        labels: BTreeMap::from([(String::from("a"), vec![1, 2]), (String::from("b"), vec![])]),
+        empty: vec![],
+        pair: (4, 5),
+    }
+}
+
+fn check_roundtrip(config: PrettyConfig, expected: &str) {
+    let ron = to_string_pretty(&scene(), config).unwrap();
+
+    assert_eq!(ron, expected);
+    assert_eq!(ron::from_str::<Scene>(&ron), Ok(scene()));
+}
+
+#[test]
+fn same_line() {
+    check_roundtrip(
+        PrettyConfig::default().struct_names(true),
+        "Scene(
+    name: \"demo\",
+    origin: Point(
+        x: 0,
+        y: 1,
+    ),
+    shapes: [
+        Circle(
+            center: Point(
+                x: 1,
+                y: 2,
+            ),
+            radius: 3,
+        ),
+        Line(Point(
+            x: 0,
+            y: 0,
+        ), Point(
+            x: 1,
+            y: 1,
+        )),
+        Empty,
+    ],
+    labels: {
+        \"a\": [
+            1,
+            2,
+        ],
+        \"b\": [],
+    },
+    empty: [],
+    pair: (4, 5),
+)",
+    );
+}
+
+#[test]
+fn next_line() {
+    check_roundtrip(
+        PrettyConfig::default()
+            .struct_names(true)
+            .brace_style(BraceStyle::NextLine),
+        "Scene
+(
+    name: \"demo\",
+    origin: Point
+    (
+        x: 0,
+        y: 1,
+    ),
+    shapes:
+    [
+        Circle
+        (
+            center: Point
+            (
+                x: 1,
+                y: 2,
+            ),
+            radius: 3,
+        ),
+        Line(Point
+        (
+            x: 0,
+            y: 0,
+        ), Point
+        (
+            x: 1,
+            y: 1,
+        )),
+        Empty,
+    ],
+    labels:
+    {
+        \"a\":
+        [
+            1,
+            2,
+        ],
+        \"b\": [],
+    },
+    empty: [],
+    pair: (4, 5),
+)",
+    );
+
+    // without struct names, only fields are followed by a new line
+    check_roundtrip(
+        PrettyConfig::default()
+            .separate_tuple_members(true)
+            .brace_style(BraceStyle::NextLine),
+        "(
+    name: \"demo\",
+    origin:
+    (
+        x: 0,
+        y: 1,
+    ),
+    shapes:
+    [
+        Circle
+        (
+            center:
+            (
+                x: 1,
+                y: 2,
+            ),
+            radius: 3,
+        ),
+        Line
+        (
+            (
+                x: 0,
+                y: 0,
+            ),
+            (
+                x: 1,
+                y: 1,
+            ),
+        ),
+        Empty,
+    ],
+    labels:
+    {
+        \"a\":
+        [
+            1,
+            2,
+        ],
+        \"b\": [],
+    },
+    empty: [],
+    pair:
+    (
+        4,
+        5,
+    ),
+)",
+    );
+}
+
+#[test]
+fn next_line_only_for_multi_line_delimiters() {
+    let config = PrettyConfig::default().brace_style(BraceStyle::NextLine);
+
+    for config in [
+        config
+            .clone()
+            .compact_structs(true)
+            .compact_arrays(true)
+            .compact_maps(true),
+        config.clone().depth_limit(1),
+    ] {
+        let ron = to_string_pretty(&scene(), config).unwrap();
+
+        assert!(!ron.contains(":\n"), "{}", ron);
+        assert_eq!(ron::from_str::<Scene>(&ron), Ok(scene()));
+    }
+
+    // arrays that are laid out by their width start on the same line
+    let ron = to_string_pretty(&scene(), config.max_width(40)).unwrap();
+
+    assert!(ron.contains("    origin:\n    (\n"), "{}", ron);
+    assert!(ron.contains("    shapes: [\n"), "{}", ron);
+    assert!(ron.contains("    pair: (4, 5),\n"), "{}", ron);
+    assert_eq!(ron::from_str::<Scene>(&ron), Ok(scene()));
+}
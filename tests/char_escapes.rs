@@ -0,0 +1,107 @@
+use ron::{
+    ser::{to_string_pretty, PrettyConfig, UnicodeEscape},
+    Value,
+};
+
+fn configs() -> Vec<PrettyConfig> {
+    vec![
+        PrettyConfig::new(),
+        PrettyConfig::new().unicode_escape_style(UnicodeEscape::Fixed),
+        PrettyConfig::new().escape_strings(false),
+        PrettyConfig::new().prefer_raw_strings(true),
+    ]
+}
+
+fn chars() -> impl Iterator<Item = char> {
+    let ascii_control = (0_u8..0x20).chain([0x7f]).map(char::from);
+    let special = [
+        '\'',
+        '\\',
+        '"',
+        ' ',
+        'a',
+        'é',
+        '\u{85}',
+        '\u{a0}',
+        '\u{300}',
+        '\u{200b}',
+        '\u{2028}',
+        '\u{feff}',
+        '\u{e000}',
+        '😀',
+        '\u{1d11e}',
+        '\u{e0001}',
+        '\u{10ffff}',
+    ];
+
+    ascii_control.chain(special)
+}
+
+#[test]
+fn chars_roundtrip() {
+    for c in chars() {
+        let compact = ron::to_string(&c).unwrap();
+        assert_eq!(ron::from_str::<char>(&compact), Ok(c), "{:?}", compact);
+        assert_eq!(
+            ron::from_str::<Value>(&compact),
+            Ok(Value::Char(c)),
+            "{:?}",
+            compact
+        );
+
+        for config in configs() {
+            let pretty = to_string_pretty(&c, config).unwrap();
+            assert_eq!(ron::from_str::<char>(&pretty), Ok(c), "{:?}", pretty);
+        }
+    }
+}
+
+#[test]
+fn control_chars_are_never_written_raw() {
+    for c in chars() {
+        let mut outputs = vec![ron::to_string(&c).unwrap()];
+        outputs.extend(
+            configs()
+                .into_iter()
+                .map(|config| to_string_pretty(&c, config).unwrap()),
+        );
+
+        for ron in outputs {
+            assert!(
+                !ron.chars().any(char::is_control),
+                "{:?} is written raw in {:?}",
+                c,
+                ron
+            );
+        }
+    }
+}
+
+#[test]
+fn char_escapes() {
+    assert_eq!(ron::to_string(&'\0').unwrap(), "'\\0'");
+    assert_eq!(ron::to_string(&'\t').unwrap(), "'\\t'");
+    assert_eq!(ron::to_string(&'\n').unwrap(), "'\\n'");
+    assert_eq!(ron::to_string(&'\r').unwrap(), "'\\r'");
+    assert_eq!(ron::to_string(&'\u{7}').unwrap(), "'\\u{7}'");
+    assert_eq!(ron::to_string(&'\u{7f}').unwrap(), "'\\u{7f}'");
+    assert_eq!(ron::to_string(&'\'').unwrap(), "'\\''");
+    assert_eq!(ron::to_string(&'\\').unwrap(), "'\\\\'");
+    assert_eq!(ron::to_string(&'"').unwrap(), "'\"'");
+
+    // a combining character on its own is escaped, printable ones are not
+    assert_eq!(ron::to_string(&'\u{300}').unwrap(), "'\\u{300}'");
+    assert_eq!(ron::to_string(&'é').unwrap(), "'é'");
+    assert_eq!(ron::to_string(&'😀').unwrap(), "'😀'");
+    assert_eq!(ron::to_string(&'\u{10ffff}').unwrap(), "'\\u{10ffff}'");
+
+    let fixed = PrettyConfig::new().unicode_escape_style(UnicodeEscape::Fixed);
+    assert_eq!(
+        to_string_pretty(&'\u{7}', fixed.clone()).unwrap(),
+        "'\\u0007'"
+    );
+    assert_eq!(
+        to_string_pretty(&'\u{10ffff}', fixed).unwrap(),
+        "'\\udbff\\udfff'"
+    );
+}
@@ -30,13 +30,57 @@ fn test_nested() {
     );
 }
 
+#[test]
+fn test_nested_delimiters() {
+    assert_eq!(from_str("/**/ 42"), Ok(42));
+    assert_eq!(from_str("/***/ 42"), Ok(42));
+    assert_eq!(from_str("/*/**/*/ 42"), Ok(42));
+    assert_eq!(from_str("/* // */ 42"), Ok(42));
+    assert_eq!(
+        from_str("[4, /* commented /* out */ 3, */ 2]"),
+        Ok(vec![4, 2])
+    );
+    assert_eq!(
+        ron::Options::default()
+            .with_precount_collections(true)
+            .from_str("[4, /* ], /* ] */ [ */ 2]"),
+        Ok(vec![4, 2])
+    );
+    assert_eq!(
+        from_str::<u8>("/*/ 42"),
+        Err(RonErr {
+            code: Error::UnclosedBlockComment,
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            }
+        })
+    );
+    assert_eq!(
+        from_str::<u8>("/* /* */ 42"),
+        Err(RonErr {
+            code: Error::UnclosedBlockComment,
+            position: Position {
+                line: 1,
+                col: 12,
+                offset: 11
+            }
+        })
+    );
+}
+
 #[test]
 fn test_unclosed() {
     assert_eq!(
         from_str::<String>("\"hi\" /*"),
         Err(RonErr {
             code: Error::UnclosedBlockComment,
-            position: Position { line: 1, col: 8 }
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7
+            }
         })
     );
     assert_eq!(
@@ -53,7 +97,34 @@ fn test_unclosed() {
         ),
         Err(RonErr {
             code: Error::UnclosedBlockComment,
-            position: Position { line: 9, col: 1 }
+            position: Position {
+                line: 9,
+                col: 1,
+                offset: 334
+            }
+        })
+    );
+}
+
+#[test]
+fn test_trailing() {
+    assert_eq!(from_str("42 // trailing"), Ok(42));
+    assert_eq!(from_str("42 // trailing\n"), Ok(42));
+    assert_eq!(from_str("42 //"), Ok(42));
+    assert_eq!(from_str("42\n/* trailing */"), Ok(42));
+    assert_eq!(
+        from_str("[4, 2] /* nested /* trailing */ */ // and more\n\n"),
+        Ok(vec![4, 2])
+    );
+    assert_eq!(
+        from_str::<u8>("42 /* unclosed"),
+        Err(RonErr {
+            code: Error::UnclosedBlockComment,
+            position: Position {
+                line: 1,
+                col: 15,
+                offset: 14
+            }
         })
     );
 }
@@ -64,7 +135,112 @@ fn test_unexpected_byte() {
         from_str::<u8>("42 /q"),
         Err(RonErr {
             code: Error::UnexpectedChar('q'),
-            position: Position { line: 1, col: 6 },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
 }
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Config {
+    name: String,
+    ports: Vec<u16>,
+}
+
+fn config() -> Config {
+    Config {
+        name: String::from("proxy"),
+        ports: vec![80, 443],
+    }
+}
+
+#[test]
+fn test_write_comment() {
+    use ron::ser::{CommentStyle, PrettyConfig, Serializer};
+    use serde::Serialize;
+
+    let mut ron = String::new();
+    let mut ser =
+        Serializer::new(&mut ron, Some(PrettyConfig::new().compact_arrays(true))).unwrap();
+
+    ser.write_comment("Generated by a template\n\nDo not edit", CommentStyle::Line)
+        .unwrap();
+    ser.write_comment("proxy * config", CommentStyle::Block)
+        .unwrap();
+    config().serialize(&mut ser).unwrap();
+    ser.write_comment("end of file", CommentStyle::Line)
+        .unwrap();
+
+    assert_eq!(
+        ron,
+        "// Generated by a template
+//
+// Do not edit
+/* proxy * config */
+(
+    name: \"proxy\",
+    ports: [80, 443],
+)
+// end of file
+"
+    );
+    assert_eq!(from_str::<Config>(&ron), Ok(config()));
+}
+
+#[test]
+fn test_write_comment_after_extensions() {
+    use ron::{
+        extensions::Extensions,
+        ser::{CommentStyle, PrettyConfig, Serializer},
+    };
+    use serde::Serialize;
+
+    let mut ron = String::new();
+    let mut ser = Serializer::new(
+        &mut ron,
+        Some(
+            PrettyConfig::new()
+                .new_line("\r\n")
+                .extensions(Extensions::IMPLICIT_SOME),
+        ),
+    )
+    .unwrap();
+
+    ser.write_comment("a comment", CommentStyle::Block).unwrap();
+    Some(42).serialize(&mut ser).unwrap();
+
+    assert_eq!(ron, "#![enable(implicit_some)]\r\n/* a comment */\r\n42");
+    assert_eq!(from_str::<Option<i32>>(&ron), Ok(Some(42)));
+}
+
+#[test]
+fn test_write_comment_errors() {
+    use ron::ser::{CommentStyle, PrettyConfig, Serializer};
+
+    let mut ser = Serializer::new(String::new(), None).unwrap();
+    assert_eq!(
+        ser.write_comment("compact", CommentStyle::Line),
+        Err(Error::Message(String::from(
+            "Comments can only be written in pretty output"
+        )))
+    );
+
+    let mut ser = Serializer::new(String::new(), Some(PrettyConfig::new().new_line(" "))).unwrap();
+    assert_eq!(
+        ser.write_comment("single line", CommentStyle::Block),
+        Err(Error::Message(String::from(
+            "Comments can only be written in pretty output"
+        )))
+    );
+
+    let mut ser = Serializer::new(String::new(), Some(PrettyConfig::new())).unwrap();
+    assert_eq!(
+        ser.write_comment("nested /* comment */", CommentStyle::Block),
+        Err(Error::Message(String::from(
+            "Block comments must not contain `/*` or `*/`"
+        )))
+    );
+}
@@ -58,3 +58,24 @@ fn depth_limit() {
 
     assert_eq!(s, Ok(EXPECTED.to_string()));
 }
+
+#[test]
+fn current_depth_is_restored_after_each_value() {
+    let mut output = String::new();
+    let mut serializer = ron::Serializer::new(&mut output, None).unwrap();
+    assert_eq!(serializer.current_depth(), 0);
+
+    vec![vec![Some(42)]].serialize(&mut serializer).unwrap();
+    assert_eq!(serializer.current_depth(), 0);
+
+    Nested {
+        a: String::from("a"),
+        b: 'b',
+    }
+    .serialize(&mut serializer)
+    .unwrap();
+    assert_eq!(serializer.current_depth(), 0);
+
+    drop(serializer);
+    assert_eq!(output, "[[Some(42)]](a:\"a\",b:'b')");
+}
@@ -0,0 +1,106 @@
+use ron::{
+    ser::{to_string_pretty, PrettyConfig},
+    Value,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn check_grouped<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(
+    value: T,
+    group: usize,
+    expected: &str,
+) {
+    let ron =
+        to_string_pretty(&value, PrettyConfig::default().digit_grouping(Some(group))).unwrap();
+
+    assert_eq!(ron, expected);
+    assert_eq!(ron::from_str::<T>(&ron).unwrap(), value);
+}
+
+#[test]
+fn group_integer_digits() {
+    check_grouped(0_u8, 3, "0");
+    check_grouped(999_u16, 3, "999");
+    check_grouped(1000_u16, 3, "1_000");
+    check_grouped(1_000_000_u32, 3, "1_000_000");
+    check_grouped(-1_234_567_i32, 3, "-1_234_567");
+    check_grouped(-123_i32, 3, "-123");
+    check_grouped(12_345_678_u32, 4, "1234_5678");
+    check_grouped(12_345_u32, 1, "1_2_3_4_5");
+}
+
+#[test]
+fn group_integer_digits_near_bounds() {
+    check_grouped(u8::MAX, 3, "255");
+    check_grouped(i8::MIN, 3, "-128");
+    check_grouped(i16::MIN, 3, "-32_768");
+    check_grouped(u32::MAX, 3, "4_294_967_295");
+    check_grouped(i64::MIN, 3, "-9_223_372_036_854_775_808");
+    check_grouped(i64::MAX, 3, "9_223_372_036_854_775_807");
+    check_grouped(u64::MAX, 3, "18_446_744_073_709_551_615");
+    check_grouped(u64::MAX, 2, "18_44_67_44_07_37_09_55_16_15");
+}
+
+#[cfg(feature = "integer128")]
+#[test]
+fn group_integer_digits_near_128_bit_bounds() {
+    check_grouped(
+        i128::MIN,
+        3,
+        "-170_141_183_460_469_231_731_687_303_715_884_105_728",
+    );
+    check_grouped(
+        u128::MAX,
+        3,
+        "340_282_366_920_938_463_463_374_607_431_768_211_455",
+    );
+}
+
+#[test]
+fn group_integer_digits_with_suffixes() {
+    let config = PrettyConfig::default()
+        .digit_grouping(Some(3))
+        .number_suffixes(true);
+
+    let ron = to_string_pretty(&(-40_000_i32, 65_535_u16), config).unwrap();
+    assert_eq!(ron, "(-40_000i32, 65_535u16)");
+    assert_eq!(
+        ron::from_str::<(i32, u16)>(&ron).unwrap(),
+        (-40_000, 65_535)
+    );
+
+    let value: Value = ron::from_str(&ron).unwrap();
+    assert_eq!(value, ron::from_str("(-40000i32, 65535u16)").unwrap());
+}
+
+#[test]
+fn floats_are_not_grouped() {
+    check_grouped(1_234_567.5_f64, 3, "1234567.5");
+    check_grouped(-1e20_f64, 3, "-100000000000000000000.0");
+    check_grouped(f32::MAX, 3, &format!("{}.0", f32::MAX));
+}
+
+#[test]
+fn no_digit_grouping() {
+    check_grouped(1_000_000_u32, 0, "1000000");
+    assert_eq!(
+        to_string_pretty(&1_000_000_u32, PrettyConfig::default()).unwrap(),
+        "1000000"
+    );
+    assert_eq!(ron::to_string(&1_000_000_u32).unwrap(), "1000000");
+}
+
+#[test]
+fn group_value_numbers() {
+    let value: Value = ron::from_str("[1_000_000, -65_536, 1e6]").unwrap();
+
+    let ron = to_string_pretty(
+        &value,
+        PrettyConfig::default()
+            .digit_grouping(Some(3))
+            .compact_arrays(true),
+    )
+    .unwrap();
+
+    assert_eq!(ron, "[1_000_000, -65_536, 1000000.0]");
+    assert_eq!(ron::from_str::<Value>(&ron).unwrap(), value);
+}
@@ -0,0 +1,176 @@
+use std::{collections::BTreeMap, fmt::Debug, marker::PhantomData};
+
+use ron::{extensions::Extensions, ser::PrettyConfig, Value};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct UnitStruct;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EmptyTupleStruct();
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EmptyStruct {}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Enum {
+    Unit,
+    EmptyTuple(),
+    EmptyStruct {},
+}
+
+fn configs() -> Vec<(&'static str, Option<PrettyConfig>)> {
+    vec![
+        ("compact", None),
+        ("pretty", Some(PrettyConfig::default())),
+        (
+            "compact_*",
+            Some(
+                PrettyConfig::default()
+                    .compact_arrays(true)
+                    .compact_maps(true)
+                    .compact_structs(true),
+            ),
+        ),
+        (
+            "separate_tuple_members",
+            Some(
+                PrettyConfig::default()
+                    .separate_tuple_members(true)
+                    .enumerate_arrays(true),
+            ),
+        ),
+        ("max_width", Some(PrettyConfig::default().max_width(4))),
+        ("depth_limit", Some(PrettyConfig::default().depth_limit(0))),
+        (
+            "unwrap_variant_newtypes",
+            Some(PrettyConfig::default().extensions(Extensions::UNWRAP_VARIANT_NEWTYPES)),
+        ),
+    ]
+}
+
+fn check_empty<T: Serialize + DeserializeOwned + PartialEq + Debug>(
+    value: &T,
+    expected: &str,
+    via_value: bool,
+) {
+    for (name, config) in configs() {
+        let ron = match config {
+            Some(config) => ron::ser::to_string_pretty(value, config),
+            None => ron::to_string(value),
+        }
+        .unwrap();
+        let ron = ron.rsplit('\n').next().unwrap();
+
+        assert_eq!(ron, expected, "{}", name);
+        assert_eq!(&ron::from_str::<T>(ron).unwrap(), value, "{}", name);
+
+        if via_value {
+            assert_eq!(
+                &ron::from_str::<Value>(ron)
+                    .unwrap()
+                    .into_rust::<T>()
+                    .unwrap(),
+                value,
+                "{}",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn empty_containers_serialize_consistently() {
+    check_empty(&(), "()", true);
+    check_empty(&PhantomData::<i32>, "()", true);
+    check_empty(&UnitStruct, "()", true);
+    check_empty(&EmptyTupleStruct(), "()", true);
+    check_empty(&EmptyStruct {}, "()", true);
+    check_empty(&[0_u8; 0], "()", true);
+    check_empty(&Vec::<u8>::new(), "[]", true);
+    check_empty(&BTreeMap::<u8, u8>::new(), "{}", true);
+    check_empty(&Enum::Unit, "Unit", false);
+    check_empty(&Enum::EmptyTuple(), "EmptyTuple()", false);
+    check_empty(&Enum::EmptyStruct {}, "EmptyStruct()", false);
+}
+
+#[test]
+fn empty_containers_with_struct_names() {
+    let config = PrettyConfig::default().struct_names(true);
+
+    for (value, expected) in [
+        (
+            ron::ser::to_string_pretty(&UnitStruct, config.clone()),
+            "UnitStruct",
+        ),
+        (
+            ron::ser::to_string_pretty(&PhantomData::<i32>, config.clone()),
+            "PhantomData",
+        ),
+        (
+            ron::ser::to_string_pretty(&EmptyTupleStruct(), config.clone()),
+            "EmptyTupleStruct()",
+        ),
+        (
+            ron::ser::to_string_pretty(&EmptyStruct {}, config),
+            "EmptyStruct()",
+        ),
+    ] {
+        assert_eq!(value.unwrap(), expected);
+    }
+
+    assert_eq!(ron::from_str("UnitStruct"), Ok(UnitStruct));
+    assert_eq!(ron::from_str("PhantomData"), Ok(PhantomData::<i32>));
+    assert_eq!(ron::from_str("EmptyTupleStruct()"), Ok(EmptyTupleStruct()));
+    assert_eq!(ron::from_str("EmptyStruct()"), Ok(EmptyStruct {}));
+}
+
+#[test]
+fn nested_empty_containers() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nested {
+        a: Vec<Vec<u8>>,
+        b: Vec<BTreeMap<u8, u8>>,
+        c: Vec<EmptyStruct>,
+        d: Option<EmptyTupleStruct>,
+        e: (Vec<u8>, (), [u8; 0]),
+    }
+
+    let value = Nested {
+        a: vec![vec![]],
+        b: vec![BTreeMap::new()],
+        c: vec![EmptyStruct {}],
+        d: Some(EmptyTupleStruct()),
+        e: (vec![], (), []),
+    };
+
+    for (name, config) in configs() {
+        let ron = match config {
+            Some(config) => ron::ser::to_string_pretty(&value, config),
+            None => ron::to_string(&value),
+        }
+        .unwrap();
+
+        assert_eq!(ron::from_str::<Nested>(&ron).unwrap(), value, "{}", name);
+        assert_eq!(
+            ron::from_str::<Value>(&ron)
+                .unwrap()
+                .into_rust::<Nested>()
+                .unwrap(),
+            value,
+            "{}",
+            name
+        );
+    }
+
+    assert_eq!(
+        ron::ser::to_string_pretty(
+            &value,
+            PrettyConfig::default()
+                .compact_arrays(true)
+                .compact_structs(true)
+        )
+        .unwrap(),
+        "(a: [[]], b: [{}], c: [()], d: Some(()), e: ([], (), ()))"
+    );
+}
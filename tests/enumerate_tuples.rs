@@ -0,0 +1,84 @@
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point(i32, i32);
+
+type Row = (u8, String, bool, Option<Point>, Vec<u8>);
+
+fn row() -> Row {
+    (1, String::from("two"), true, Some(Point(4, 5)), vec![6, 7])
+}
+
+#[test]
+fn enumerate_five_tuple() {
+    let config = PrettyConfig::new()
+        .new_line("\n")
+        .separate_tuple_members(true)
+        .enumerate_tuples(true);
+
+    let ser = to_string_pretty(&row(), config).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    /*0:*/ 1,
+    /*1:*/ \"two\",
+    /*2:*/ true,
+    /*3:*/ Some((
+        /*0:*/ 4,
+        /*1:*/ 5,
+    )),
+    /*4:*/ [
+        6,
+        7,
+    ],
+)"
+    );
+
+    assert_eq!(ron::from_str::<Row>(&ser), Ok(row()));
+}
+
+#[test]
+fn enumerate_tuples_requires_separate_tuple_members() {
+    let config = PrettyConfig::new().new_line("\n").enumerate_tuples(true);
+
+    assert_eq!(
+        to_string_pretty(&row(), config).unwrap(),
+        "(1, \"two\", true, Some((4, 5)), [\n    6,\n    7,\n])"
+    );
+}
+
+#[test]
+fn enumerate_tuples_is_ignored_in_compact_mode() {
+    assert_eq!(
+        ron::to_string(&row()).unwrap(),
+        "(1,\"two\",true,Some((4,5)),[6,7])"
+    );
+}
+
+#[test]
+fn enumerate_tuples_with_max_width() {
+    let config = PrettyConfig::new()
+        .new_line("\n")
+        .separate_tuple_members(true)
+        .enumerate_tuples(true)
+        .max_width(30);
+
+    assert_eq!(
+        to_string_pretty(&Point(1, 2), config.clone()).unwrap(),
+        "(/*0:*/ 1, /*1:*/ 2)"
+    );
+
+    let ser = to_string_pretty(&(1, 2, 3, 4), config).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    /*0:*/ 1,
+    /*1:*/ 2,
+    /*2:*/ 3,
+    /*3:*/ 4,
+)"
+    );
+    assert_eq!(ron::from_str::<(u8, u8, u8, u8)>(&ser), Ok((1, 2, 3, 4)));
+}
@@ -17,7 +17,11 @@ fn test_escape_basic() {
         from_str::<char>("\'\\u{}\'").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::InvalidEscape("Expected 1-6 digits, got 0 digits in Unicode escape"),
-            position: ron::error::Position { line: 1, col: 5 },
+            position: ron::error::Position {
+                line: 1,
+                col: 5,
+                offset: 4
+            },
         }
     );
 
@@ -25,7 +29,11 @@ fn test_escape_basic() {
         from_str::<char>("\'\\q\'").unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::InvalidEscape("Unknown escape character"),
-            position: ron::error::Position { line: 1, col: 4 },
+            position: ron::error::Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         }
     )
 }
@@ -0,0 +1,199 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    parse::{events, Event, EventKind},
+    Map, Value,
+};
+
+/// Reconstructs a [`Value`] from the event stream of `src`.
+fn value_from_events(src: &str) -> Result<Value, SpannedError> {
+    enum Partial {
+        Seq(Vec<Value>),
+        Map(Map, Option<Value>),
+        Some,
+    }
+
+    fn insert(stack: &mut [Partial], value: Value) -> Option<Value> {
+        match stack.last_mut() {
+            None => Some(value),
+            Some(Partial::Seq(seq)) => {
+                seq.push(value);
+                None
+            }
+            Some(Partial::Map(map, key)) => {
+                match key.take() {
+                    Some(key) => {
+                        map.insert(key, value);
+                    }
+                    None => *key = Some(value),
+                }
+                None
+            }
+            Some(Partial::Some) => unreachable!("`Some` is completed by its `End`"),
+        }
+    }
+
+    let mut stack = Vec::new();
+    let mut root = None;
+
+    for event in events(src) {
+        let value = match event?.kind {
+            EventKind::StructStart { .. } | EventKind::MapStart => {
+                stack.push(Partial::Map(Map::new(), None));
+                continue;
+            }
+            EventKind::TupleStart { .. } | EventKind::SeqStart => {
+                stack.push(Partial::Seq(Vec::new()));
+                continue;
+            }
+            EventKind::SomeStart => {
+                stack.push(Partial::Some);
+                stack.push(Partial::Seq(Vec::new()));
+                continue;
+            }
+            EventKind::Field(name) => {
+                insert(&mut stack, Value::String(name));
+                continue;
+            }
+            EventKind::Scalar(value) => value,
+            EventKind::End => match stack.pop() {
+                Some(Partial::Seq(seq)) if matches!(stack.last(), Some(Partial::Some)) => {
+                    stack.pop();
+                    Value::Option(Some(Box::new(seq.into_iter().next().unwrap())))
+                }
+                Some(Partial::Seq(seq)) => Value::Seq(seq),
+                Some(Partial::Map(map, _)) => Value::Map(map),
+                Some(Partial::Some) | None => unreachable!("unbalanced `End`"),
+            },
+            _ => unreachable!(),
+        };
+
+        if let Some(value) = insert(&mut stack, value) {
+            root = Some(value);
+        }
+    }
+
+    assert!(stack.is_empty());
+
+    Ok(root.unwrap())
+}
+
+fn assert_same_as_value(src: &str) {
+    assert_eq!(value_from_events(src), ron::from_str::<Value>(src), "{src}");
+}
+
+#[test]
+fn reconstruct_value() {
+    assert_same_as_value(
+        "#![enable(arrow_maps, equals_fields)]
+// A game config
+Config(
+    name: r#\"Ron's \"game\"\"#, /* nested /* block */ */
+    \"quoted key\" = 'q',
+    size: (-1_000i32, 1.5e3, inf, NaN, b'\\n'),
+    icon: Some(b\"\\x00bytes\",),
+    tags: { 'a' => [true, false], (1, 2): None, Unit: () },
+    nested: Some(Some(Point(x: 1, y: 2,))),
+    tuple: Pair(1, ( ), [], {}),
+    newtype: Wrapper(r#type),
+)",
+    );
+
+    assert_same_as_value("42");
+    assert_same_as_value("  \"just a string\"  // trailing comment");
+    assert_same_as_value("[(a: 1), (b: [2, 3]), (4, 5), (6)]");
+}
+
+#[test]
+fn event_spans() {
+    let src = "Some(Point(x: 1)) ";
+
+    let spanned = events(src)
+        .map(|event| {
+            let Event { kind, span } = event.unwrap();
+            (kind, &src[span])
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        spanned,
+        vec![
+            (EventKind::SomeStart, "Some("),
+            (
+                EventKind::StructStart {
+                    name: Some(String::from("Point"))
+                },
+                "Point("
+            ),
+            (EventKind::Field(String::from("x")), "x"),
+            (EventKind::Scalar(Value::from(1_u8)), "1"),
+            (EventKind::End, ")"),
+            (EventKind::End, ")"),
+        ]
+    );
+
+    let src = "[Unit , ()]";
+
+    assert_eq!(
+        events(src)
+            .map(|event| &src[event.unwrap().span])
+            .collect::<Vec<_>>(),
+        vec!["[", "Unit", "()", "]"]
+    );
+}
+
+#[test]
+fn events_stop_after_error() {
+    // events are streamed before the error is found
+    let mut iter = events("[1, 2 3]");
+
+    assert_eq!(
+        iter.next().map(|event| event.unwrap().kind),
+        Some(EventKind::SeqStart)
+    );
+    assert_eq!(
+        iter.next().map(|event| event.unwrap().kind),
+        Some(EventKind::Scalar(Value::from(1_u8)))
+    );
+    assert_eq!(
+        iter.next().map(|event| event.unwrap().kind),
+        Some(EventKind::Scalar(Value::from(2_u8)))
+    );
+    assert_eq!(
+        iter.next(),
+        Some(Err(SpannedError {
+            code: Error::ExpectedComma,
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
+        }))
+    );
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(
+        value_from_events("(a: 1) (b: 2)"),
+        Err(SpannedError {
+            code: Error::TrailingCharacters {
+                found: String::from("(b: 2)")
+            },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7
+            },
+        })
+    );
+    assert_eq!(
+        value_from_events("(a 1)").map_err(|err| err.code),
+        Err(Error::ExpectedComma)
+    );
+    assert_eq!(
+        value_from_events("Some(1, 2)").map_err(|err| err.code),
+        Err(Error::ExpectedOptionEnd)
+    );
+    assert_eq!(
+        value_from_events("#![enable(unknown)] ()").map_err(|err| err.code),
+        Err(Error::NoSuchExtension(String::from("unknown")))
+    );
+}
@@ -0,0 +1,103 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    extensions::Extensions,
+    Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: Option<String>,
+    size: u32,
+}
+
+#[test]
+fn without_scan_parses_documents_without_header() {
+    let options = Options::default().without_extension_header_scan();
+
+    assert_eq!(
+        options.from_str("\u{FEFF} // comment\n(name: Some(\"ron\"), size: 4)"),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+}
+
+#[test]
+fn without_scan_still_uses_default_extensions() {
+    let options = Options::default()
+        .with_default_extension(Extensions::IMPLICIT_SOME)
+        .without_extension_header_scan();
+
+    assert_eq!(
+        options.from_str("(name: \"ron\", size: 4)"),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+}
+
+#[test]
+fn without_scan_rejects_header() {
+    let options = Options::default().without_extension_header_scan();
+
+    assert_eq!(
+        options.from_str::<Config>("#![enable(implicit_some)]\n(name: \"ron\", size: 4)"),
+        Err(SpannedError {
+            code: Error::UnexpectedAttribute,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+    assert_eq!(
+        options.from_str::<Config>("/* header */ #![enable(implicit_some)]"),
+        Err(SpannedError {
+            code: Error::UnexpectedAttribute,
+            position: Position {
+                line: 1,
+                col: 14,
+                offset: 13,
+            },
+        })
+    );
+
+    // The same document is accepted with the default scan
+    assert_eq!(
+        Options::default().from_str("#![enable(implicit_some)]\n(name: \"ron\", size: 4)"),
+        Ok(Config {
+            name: Some(String::from("ron")),
+            size: 4,
+        })
+    );
+}
+
+#[test]
+fn without_scan_still_skips_shebang() {
+    let options = Options::default()
+        .with_allow_shebang(true)
+        .without_extension_header_scan();
+
+    assert_eq!(
+        options.from_str("#!/usr/bin/env ron-tool\n(name: None, size: 4)"),
+        Ok(Config {
+            name: None,
+            size: 4,
+        })
+    );
+    assert_eq!(
+        options.from_str::<Config>("#!/usr/bin/env ron-tool\n#![enable(implicit_some)]"),
+        Err(SpannedError {
+            code: Error::UnexpectedAttribute,
+            position: Position {
+                line: 2,
+                col: 1,
+                offset: 24,
+            },
+        })
+    );
+}
@@ -90,3 +90,251 @@ fn implicit_some() {
 
     println!("implicit_some: {:#?}", d);
 }
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+enum Wrapper {
+    Newtype(Inner),
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Inner {
+    a: Option<NewType>,
+}
+
+fn check_extensions_without_header(
+    config: ron::ser::PrettyConfig,
+    extensions: ron::extensions::Extensions,
+    expected: &str,
+) {
+    let value = Wrapper::Newtype(Inner {
+        a: Some(NewType(0.5)),
+    });
+
+    let ron = ron::ser::to_string_pretty(&value, config.compact_structs(true).separator(""))
+        .expect("Failed to serialize");
+    assert_eq!(ron, expected);
+
+    let options = ron::Options::default().with_default_extension(extensions);
+    let value2: Wrapper = options.from_str(&ron).expect("Failed to deserialize");
+    assert_eq!(value, value2);
+
+    // Without the extensions, the output cannot be deserialized
+    assert!(ron::from_str::<Wrapper>(&ron).is_err());
+}
+
+#[test]
+fn extensions_without_header() {
+    use ron::{extensions::Extensions, ser::PrettyConfig};
+
+    check_extensions_without_header(
+        PrettyConfig::default().implicit_some(true),
+        Extensions::IMPLICIT_SOME,
+        "Newtype((a:(0.5)))",
+    );
+    check_extensions_without_header(
+        PrettyConfig::default().unwrap_newtypes(true),
+        Extensions::UNWRAP_NEWTYPES,
+        "Newtype((a:Some(0.5)))",
+    );
+    check_extensions_without_header(
+        PrettyConfig::default().unwrap_variant_newtypes(true),
+        Extensions::UNWRAP_VARIANT_NEWTYPES,
+        "Newtype(a:Some(0.5))",
+    );
+    check_extensions_without_header(
+        PrettyConfig::default()
+            .implicit_some(true)
+            .unwrap_newtypes(true)
+            .unwrap_variant_newtypes(true),
+        Extensions::IMPLICIT_SOME
+            | Extensions::UNWRAP_NEWTYPES
+            | Extensions::UNWRAP_VARIANT_NEWTYPES,
+        "Newtype(a:0.5)",
+    );
+}
+
+#[test]
+fn extensions_with_and_without_header() {
+    use ron::{extensions::Extensions, ser::PrettyConfig};
+
+    let value = Inner {
+        a: Some(NewType(0.5)),
+    };
+
+    let ron = ron::ser::to_string_pretty(
+        &value,
+        PrettyConfig::default()
+            .compact_structs(true)
+            .separator("")
+            .extensions(Extensions::IMPLICIT_SOME)
+            .unwrap_newtypes(true),
+    )
+    .expect("Failed to serialize");
+    assert_eq!(ron, "#![enable(implicit_some)]\n(a:0.5)");
+
+    let options = ron::Options::default().with_default_extension(Extensions::UNWRAP_NEWTYPES);
+    let value2: Inner = options.from_str(&ron).expect("Failed to deserialize");
+    assert_eq!(value, value2);
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Routes {
+    name: String,
+    routes: std::collections::BTreeMap<String, u16>,
+}
+
+#[test]
+fn arrow_maps() {
+    use ron::{
+        error::{Error, Position, SpannedError},
+        extensions::Extensions,
+        ser::PrettyConfig,
+    };
+
+    let expected = Routes {
+        name: String::from("proxy"),
+        routes: [(String::from("/api"), 8080), (String::from("/"), 80)]
+            .into_iter()
+            .collect(),
+    };
+
+    // `=>` and `:` can be mixed freely within a map
+    let ron = "#![enable(arrow_maps)]
+(
+    name: \"proxy\",
+    routes: {
+        \"/api\" => 8080,
+        \"/\": 80,
+    },
+)";
+    assert_eq!(ron::from_str::<Routes>(ron), Ok(expected));
+
+    let options = ron::Options::default().with_default_extension(Extensions::ARROW_MAPS);
+    assert_eq!(
+        options.from_str::<HashMap<u8, bool>>("{1=>true,2:false, 3 =>true}"),
+        Ok([(1, true), (2, false), (3, true)].into_iter().collect())
+    );
+    assert_eq!(
+        options.from_str::<ron::Value>("{\"a\" => [1], \"b\": {'c' => ()}}"),
+        ron::from_str("{\"a\": [1], \"b\": {'c': ()}}")
+    );
+
+    // Struct fields must still use `:`
+    assert_eq!(
+        options.from_str::<Routes>("(name => \"proxy\", routes: {})"),
+        Err(SpannedError {
+            code: Error::ExpectedMapColon,
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
+        })
+    );
+
+    // `=>` is rejected without the extension
+    assert_eq!(
+        ron::from_str::<HashMap<u8, bool>>("{1 => true}"),
+        Err(SpannedError {
+            code: Error::ExpectedMapColon,
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
+        })
+    );
+
+    // Serialization always uses `:`
+    assert_eq!(
+        ron::ser::to_string_pretty(
+            &HashMap::from([(1_u8, true)]),
+            PrettyConfig::default().extensions(Extensions::ARROW_MAPS),
+        ),
+        Ok(String::from("#![enable(arrow_maps)]\n{\n    1: true,\n}"))
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Listener {
+    name: String,
+    port: u16,
+    tls: bool,
+}
+
+#[test]
+fn equals_fields() {
+    use ron::{
+        error::{Error, Position, SpannedError},
+        extensions::Extensions,
+        ser::PrettyConfig,
+    };
+
+    let expected = Listener {
+        name: String::from("proxy"),
+        port: 8080,
+        tls: true,
+    };
+
+    // `=` and `:` can be mixed freely within a struct
+    let ron = "#![enable(equals_fields)]
+Listener(
+    name = \"proxy\",
+    port: 8080,
+    tls=true,
+)";
+    assert_eq!(ron::from_str::<Listener>(ron), Ok(expected));
+
+    let options = ron::Options::default().with_default_extension(Extensions::EQUALS_FIELDS);
+    assert_eq!(
+        options.from_str::<ron::Value>("(a = 1, b: (c = 'c'))"),
+        ron::from_str("(a: 1, b: (c: 'c'))")
+    );
+
+    // Only a single `=` is accepted
+    assert_eq!(
+        options.from_str::<Listener>("(name => \"proxy\", port: 80, tls: false)"),
+        Err(SpannedError {
+            code: Error::ExpectedMapColon,
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
+        })
+    );
+    assert!(options
+        .from_str::<Listener>("(name == \"proxy\", port: 80, tls: false)")
+        .is_err());
+
+    // Map entries must still use `:`
+    assert!(options.from_str::<HashMap<u8, bool>>("{1 = true}").is_err());
+
+    // `=` is rejected without the extension
+    assert_eq!(
+        ron::from_str::<Listener>("(name = \"proxy\", port: 80, tls: false)"),
+        Err(SpannedError {
+            code: Error::ExpectedMapColon,
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6
+            },
+        })
+    );
+
+    // Serialization always uses `:`
+    assert_eq!(
+        ron::ser::to_string_pretty(
+            &Listener {
+                name: String::from("proxy"),
+                port: 80,
+                tls: false,
+            },
+            PrettyConfig::default().extensions(Extensions::EQUALS_FIELDS),
+        ),
+        Ok(String::from(
+            "#![enable(equals_fields)]\n(\n    name: \"proxy\",\n    port: 80,\n    tls: false,\n)"
+        ))
+    );
+}
@@ -0,0 +1,271 @@
+use ron::{Options, Value};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: String,
+    port: u16,
+    verbose: bool,
+    tags: Vec<String>,
+    tls: Tls,
+    proxy: Option<Proxy>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Tls {
+    enabled: bool,
+    cert: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+fn defaults() -> Value {
+    ron::from_str(
+        "(
+            name: \"default\",
+            port: 8080,
+            verbose: false,
+            tags: [\"base\"],
+            tls: (enabled: false, cert: \"cert.pem\"),
+            proxy: Some((host: \"localhost\", port: 3128)),
+        )",
+    )
+    .unwrap()
+}
+
+fn options() -> Options {
+    Options::default().with_field_defaults(defaults())
+}
+
+#[test]
+fn missing_fields_are_required_without_defaults() {
+    let err = ron::from_str::<Config>("(name: \"a\")").unwrap_err();
+
+    assert_eq!(
+        err.code,
+        ron::Error::MissingStructField {
+            field: "port",
+            outer: Some(String::from("Config")),
+        }
+    );
+}
+
+#[test]
+fn missing_fields_fall_back_to_defaults() {
+    let config: Config = options()
+        .from_str("(name: \"api\", tls: (enabled: true), proxy: Some((port: 8000)))")
+        .unwrap();
+
+    assert_eq!(
+        config,
+        Config {
+            name: String::from("api"),
+            port: 8080,
+            verbose: false,
+            tags: vec![String::from("base")],
+            tls: Tls {
+                enabled: true,
+                cert: String::from("cert.pem"),
+            },
+            proxy: Some(Proxy {
+                host: String::from("localhost"),
+                port: 8000,
+            }),
+        }
+    );
+}
+
+#[test]
+fn parsed_values_take_precedence() {
+    let config: Config = options()
+        .from_str(
+            "(
+                name: \"edge\",
+                port: 443,
+                verbose: true,
+                tags: [],
+                tls: (enabled: true, cert: \"edge.pem\"),
+                proxy: None,
+            )",
+        )
+        .unwrap();
+
+    assert_eq!(
+        config,
+        Config {
+            name: String::from("edge"),
+            port: 443,
+            verbose: true,
+            tags: vec![],
+            tls: Tls {
+                enabled: true,
+                cert: String::from("edge.pem"),
+            },
+            proxy: None,
+        }
+    );
+}
+
+#[test]
+fn empty_input_uses_all_defaults() {
+    let config: Config = options().from_str("()").unwrap();
+    let from_bytes: Config = options()
+        .from_bytes(b"#![enable(implicit_some)] ()")
+        .unwrap();
+
+    assert_eq!(config, from_bytes);
+    assert_eq!(config.name, "default");
+    assert_eq!(
+        config.proxy,
+        Some(Proxy {
+            host: String::from("localhost"),
+            port: 3128,
+        })
+    );
+}
+
+#[test]
+fn errors_after_merging_are_reported() {
+    let err = options()
+        .from_str::<Config>("(port: \"not a port\")")
+        .unwrap_err();
+
+    assert_eq!(
+        err.code,
+        ron::Error::InvalidValueForType {
+            expected: String::from("u16"),
+            found: String::from("the string \"not a port\""),
+        }
+    );
+
+    let err = Options::default()
+        .with_field_defaults(Value::Map(ron::Map::new()))
+        .from_str::<Config>("(name: \"a\")")
+        .unwrap_err();
+
+    assert_eq!(
+        err.code,
+        ron::Error::MissingStructField {
+            field: "port",
+            outer: Some(String::from("Config")),
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Mode {
+    Fast,
+    Slow(u8),
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Job {
+    a: u8,
+    b: u8,
+    m: Mode,
+    n: Mode,
+    s: String,
+}
+
+fn job_options() -> Options {
+    Options::default().with_field_defaults(ron::from_str("(b: 7)").unwrap())
+}
+
+fn job() -> Job {
+    Job {
+        a: 1,
+        b: 7,
+        m: Mode::Fast,
+        n: Mode::Slow(3),
+        s: String::from("x"),
+    }
+}
+
+#[test]
+fn parsed_enums_are_supported() {
+    assert_eq!(
+        job_options().from_str("(a: 1, m: Fast, n: Slow(3), s: \"x\")"),
+        Ok(job())
+    );
+}
+
+#[test]
+fn errors_keep_their_position() {
+    let err = job_options()
+        .from_str::<Job>("(a: 1, m: Medium, n: Slow(3), s: \"x\")")
+        .unwrap_err();
+
+    assert_eq!(
+        err.code,
+        ron::Error::NoSuchEnumVariant {
+            expected: &["Fast", "Slow"],
+            found: String::from("Medium"),
+            outer: Some(String::from("Mode")),
+        }
+    );
+    assert_eq!(err.position.line, 1);
+    assert_eq!(err.position.col, 17);
+}
+
+#[test]
+fn all_from_str_methods_fill_in_defaults() {
+    let src = "(a: 1, m: Fast, n: Slow(3), s: \"x\")";
+
+    let (value, _) = job_options().from_str_with_extensions::<Job>(src).unwrap();
+    assert_eq!(value, job());
+
+    let (value, warnings) = job_options().from_str_with_warnings::<Job>(src).unwrap();
+    assert_eq!(value, job());
+    assert!(warnings.is_empty());
+
+    assert_eq!(
+        job_options()
+            .with_unwrap_redundant_parens(true)
+            .from_str_with_warnings::<u8>("(42)")
+            .map(|(value, _)| value),
+        Ok(42)
+    );
+
+    #[cfg(feature = "serde_path_to_error")]
+    assert_eq!(job_options().from_str_traced::<Job>(src).ok(), Some(job()));
+}
+
+#[test]
+fn parsed_map_keys_take_precedence() {
+    use std::collections::BTreeMap;
+
+    let options = Options::default().with_field_defaults(ron::from_str("{1: 1, 2: 2}").unwrap());
+
+    assert_eq!(
+        options.from_str::<BTreeMap<u32, u8>>("{1u32: 7}"),
+        Ok(BTreeMap::from([(1, 7), (2, 2)]))
+    );
+
+    let options = Options::default()
+        .with_bool_aliases(&[("yes", true)])
+        .with_field_defaults(ron::from_str("{true: 1, false: 0}").unwrap());
+
+    assert_eq!(
+        options.from_str::<BTreeMap<bool, u8>>("{yes: 2}"),
+        Ok(BTreeMap::from([(true, 2), (false, 0)]))
+    );
+
+    // nested defaults are still merged into a parsed value with a suffixed key
+    let options = Options::default()
+        .with_field_defaults(ron::from_str("{1: (enabled: false, cert: \"cert.pem\")}").unwrap());
+
+    assert_eq!(
+        options.from_str::<BTreeMap<u32, Tls>>("{1u32: (enabled: true)}"),
+        Ok(BTreeMap::from([(
+            1,
+            Tls {
+                enabled: true,
+                cert: String::from("cert.pem"),
+            }
+        )]))
+    );
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Package {
+    version: u32,
+    authors: Vec<String>,
+    name: String,
+    dependencies: BTreeMap<String, Dependency>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Dependency {
+    Version(String),
+    Path { path: String, version: String },
+}
+
+fn package() -> Package {
+    Package {
+        version: 1,
+        authors: vec![String::from("ron-rs")],
+        name: String::from("ron"),
+        dependencies: [
+            (
+                String::from("serde"),
+                Dependency::Version(String::from("1.0")),
+            ),
+            (
+                String::from("base64"),
+                Dependency::Path {
+                    version: String::from("0.21"),
+                    path: String::from("../base64"),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+fn field_order(order: &[&str]) -> PrettyConfig {
+    PrettyConfig::new().field_order(order.iter().map(|name| String::from(*name)).collect())
+}
+
+#[test]
+fn partial_field_order() {
+    let ron = to_string_pretty(&package(), field_order(&["name", "version", "serde"])).unwrap();
+
+    assert_eq!(
+        ron,
+        r#"(
+    name: "ron",
+    version: 1,
+    authors: [
+        "ron-rs",
+    ],
+    dependencies: {
+        "serde": Version("1.0"),
+        "base64": Path(
+            version: "0.21",
+            path: "../base64",
+        ),
+    },
+)"#
+    );
+    assert_eq!(ron::from_str::<Package>(&ron).unwrap(), package());
+}
+
+#[test]
+fn empty_field_order_is_alphabetical() {
+    let ron = to_string_pretty(
+        &package(),
+        field_order(&[])
+            .compact_arrays(true)
+            .compact_structs(true)
+            .compact_maps(true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ron,
+        r#"(authors: ["ron-rs"], dependencies: {"base64": Path(path: "../base64", version: "0.21"), "serde": Version("1.0")}, name: "ron", version: 1)"#
+    );
+    assert_eq!(ron::from_str::<Package>(&ron).unwrap(), package());
+}
+
+#[test]
+fn unlisted_and_non_string_keys() {
+    let map: BTreeMap<u8, bool> = [(1, true), (10, false), (2, true)].into_iter().collect();
+
+    // non-string keys are named by their RON representation
+    assert_eq!(
+        to_string_pretty(&map, field_order(&["2"]).compact_maps(true)).unwrap(),
+        "{2: true, 1: true, 10: false}"
+    );
+
+    // listed names that do not occur are ignored
+    assert_eq!(
+        to_string_pretty(&map, field_order(&["missing", "10"]).compact_maps(true)).unwrap(),
+        "{10: false, 1: true, 2: true}"
+    );
+}
+
+#[test]
+fn field_order_with_path_meta() {
+    let mut config = field_order(&["name"]);
+    config
+        .path_meta
+        .get_or_insert_with(Default::default)
+        .build_fields(|fields| {
+            fields.field("version").with_doc("The package version");
+        });
+
+    #[derive(Serialize)]
+    struct Meta {
+        version: u32,
+        name: &'static str,
+    }
+
+    assert_eq!(
+        to_string_pretty(
+            &Meta {
+                version: 2,
+                name: "ron"
+            },
+            config
+        )
+        .unwrap(),
+        r#"(
+    name: "ron",
+    /// The package version
+    version: 2,
+)"#
+    );
+}
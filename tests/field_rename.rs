@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use ron::{Error, Options};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    max_size: u32,
+    display_name: String,
+    inner: Inner,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Inner {
+    is_enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Flattened {
+    retry_count: u8,
+    #[serde(flatten)]
+    limits: Limits,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Limits {
+    max_size: u32,
+}
+
+fn snake_case(field: &str) -> Cow<'_, str> {
+    if !field.contains(|c: char| c.is_ascii_uppercase()) {
+        return Cow::Borrowed(field);
+    }
+
+    let mut renamed = String::with_capacity(field.len() + 2);
+
+    for c in field.chars() {
+        if c.is_ascii_uppercase() {
+            renamed.push('_');
+            renamed.push(c.to_ascii_lowercase());
+        } else {
+            renamed.push(c);
+        }
+    }
+
+    Cow::Owned(renamed)
+}
+
+#[test]
+fn camel_case_fields_are_renamed() {
+    let options = Options::default().with_field_rename(snake_case);
+
+    assert_eq!(
+        options.from_str::<Config>(
+            r#"(
+                maxSize: 42,
+                "displayName": "ron",
+                inner: (isEnabled: true),
+            )"#
+        ),
+        Ok(Config {
+            max_size: 42,
+            display_name: String::from("ron"),
+            inner: Inner { is_enabled: true },
+        })
+    );
+
+    // flattened structs are written as maps with string keys
+    assert_eq!(
+        options.from_str::<Flattened>(r#"{ "retryCount": 3, "maxSize": 42 }"#),
+        Ok(Flattened {
+            retry_count: 3,
+            limits: Limits { max_size: 42 },
+        })
+    );
+
+    // unchanged field names still match
+    assert_eq!(
+        options.from_str::<Limits>("(max_size: 7)"),
+        Ok(Limits { max_size: 7 })
+    );
+
+    // without the option, the camelCase fields are ignored as unknown
+    assert_eq!(
+        ron::from_str::<Limits>("(maxSize: 7)").unwrap_err().code,
+        Error::MissingStructField {
+            field: "max_size",
+            outer: Some(String::from("Limits")),
+        }
+    );
+}
+
+#[test]
+fn colliding_renamed_fields_are_duplicates() {
+    let options = Options::default().with_field_rename(snake_case);
+
+    assert_eq!(
+        options
+            .from_str::<Limits>("(maxSize: 1, max_size: 2)")
+            .unwrap_err()
+            .code,
+        Error::DuplicateStructField {
+            field: "max_size",
+            outer: Some(String::from("Limits")),
+        }
+    );
+}
+
+#[test]
+fn map_keys_are_not_renamed() {
+    let options = Options::default().with_field_rename(snake_case);
+
+    assert_eq!(
+        options.from_str::<std::collections::BTreeMap<String, u8>>(r#"{"maxSize": 1}"#),
+        Ok([(String::from("maxSize"), 1)].into_iter().collect())
+    );
+}
@@ -0,0 +1,203 @@
+use ron::error::{Error, Position, SpannedError};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    a: u8,
+    b: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Other {
+    c: bool,
+    d: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FlattenOption {
+    x: u8,
+    #[serde(flatten)]
+    inner: Option<Inner>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum Tagged {
+    A { a: u8 },
+    B { b: Option<String> },
+    C,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FlattenEnum {
+    x: u8,
+    #[serde(flatten)]
+    tagged: Tagged,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FlattenTwo {
+    x: u8,
+    #[serde(flatten)]
+    inner: Inner,
+    #[serde(flatten)]
+    other: Other,
+}
+
+/// Checks that `value` is serialized to `ron`, which is the RON equivalent
+///  of the JSON produced by `serde_json`, and that both roundtrip
+fn check_parity<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(
+    value: &T,
+    ron: &str,
+    json: &str,
+) {
+    assert_eq!(ron::to_string(value).unwrap(), ron);
+    assert_eq!(serde_json::to_string(value).unwrap(), json);
+
+    assert_eq!(ron::from_str::<T>(ron).as_ref(), Ok(value));
+    assert_eq!(serde_json::from_str::<T>(json).ok().as_ref(), Some(value));
+
+    let pretty = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()).unwrap();
+    assert_eq!(ron::from_str::<T>(&pretty).as_ref(), Ok(value));
+}
+
+#[test]
+fn flatten_option_struct() {
+    check_parity(
+        &FlattenOption {
+            x: 1,
+            inner: Some(Inner {
+                a: 2,
+                b: Some(String::from("b")),
+            }),
+        },
+        "{\"x\":1,\"a\":2,\"b\":Some(\"b\")}",
+        "{\"x\":1,\"a\":2,\"b\":\"b\"}",
+    );
+    check_parity(
+        &FlattenOption {
+            x: 1,
+            inner: Some(Inner { a: 2, b: None }),
+        },
+        "{\"x\":1,\"a\":2,\"b\":None}",
+        "{\"x\":1,\"a\":2,\"b\":null}",
+    );
+    check_parity(
+        &FlattenOption { x: 1, inner: None },
+        "{\"x\":1}",
+        "{\"x\":1}",
+    );
+
+    // Like serde_json, an incomplete flattened struct is `None`
+    assert_eq!(
+        ron::from_str("{\"x\": 1, \"b\": Some(\"b\")}"),
+        Ok(FlattenOption { x: 1, inner: None })
+    );
+    assert_eq!(
+        ron::from_str("{\"x\": 1, \"a\": 2}"),
+        Ok(FlattenOption {
+            x: 1,
+            inner: Some(Inner { a: 2, b: None }),
+        })
+    );
+}
+
+#[test]
+fn flatten_internally_tagged_enum() {
+    check_parity(
+        &FlattenEnum {
+            x: 1,
+            tagged: Tagged::A { a: 2 },
+        },
+        "{\"x\":1,\"kind\":\"A\",\"a\":2}",
+        "{\"x\":1,\"kind\":\"A\",\"a\":2}",
+    );
+    check_parity(
+        &FlattenEnum {
+            x: 1,
+            tagged: Tagged::B {
+                b: Some(String::from("b")),
+            },
+        },
+        "{\"x\":1,\"kind\":\"B\",\"b\":Some(\"b\")}",
+        "{\"x\":1,\"kind\":\"B\",\"b\":\"b\"}",
+    );
+    check_parity(
+        &FlattenEnum {
+            x: 1,
+            tagged: Tagged::C,
+        },
+        "{\"x\":1,\"kind\":\"C\"}",
+        "{\"x\":1,\"kind\":\"C\"}",
+    );
+
+    // The tag may appear anywhere
+    assert_eq!(
+        ron::from_str("{\"a\": 2, \"x\": 1, \"kind\": \"A\"}"),
+        Ok(FlattenEnum {
+            x: 1,
+            tagged: Tagged::A { a: 2 },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<FlattenEnum>("{\"x\": 1}"),
+        Err(SpannedError {
+            code: Error::MissingStructField {
+                field: "kind",
+                outer: None,
+            },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7,
+            },
+        })
+    );
+}
+
+#[test]
+fn flatten_two_disjoint_structs() {
+    check_parity(
+        &FlattenTwo {
+            x: 1,
+            inner: Inner { a: 2, b: None },
+            other: Other {
+                c: true,
+                d: vec![3, 4],
+            },
+        },
+        "{\"x\":1,\"a\":2,\"b\":None,\"c\":true,\"d\":[3,4]}",
+        "{\"x\":1,\"a\":2,\"b\":null,\"c\":true,\"d\":[3,4]}",
+    );
+
+    // The keys of both flattened structs may be interleaved
+    assert_eq!(
+        ron::from_str("{\"d\": [], \"b\": None, \"x\": 1, \"c\": false, \"a\": 2}"),
+        Ok(FlattenTwo {
+            x: 1,
+            inner: Inner { a: 2, b: None },
+            other: Other {
+                c: false,
+                d: vec![],
+            },
+        })
+    );
+
+    // Only genuinely duplicate keys are reported, like in serde_json
+    let duplicate = "{\"x\": 1, \"a\": 2, \"b\": None, \"c\": true, \"d\": [], \"c\": false}";
+    assert!(serde_json::from_str::<FlattenTwo>(&duplicate.replace("None", "null")).is_err());
+    assert_eq!(
+        ron::from_str::<FlattenTwo>(duplicate),
+        Err(SpannedError {
+            code: Error::DuplicateStructField {
+                field: "c",
+                outer: None,
+            },
+            position: Position {
+                line: 1,
+                col: 59,
+                offset: 58,
+            },
+        })
+    );
+}
@@ -21,3 +21,38 @@ fn decimal_floats() {
     let tiny_pretty = to_string_pretty(&0.00000000000000005, PrettyConfig::new()).unwrap();
     assert_eq!(tiny_pretty, "0.00000000000000005");
 }
+
+#[test]
+fn negative_zero() {
+    let ser = to_string(&-0.0_f64).unwrap();
+    assert_eq!(ser, "-0.0");
+    assert!(from_str::<f64>(&ser).unwrap().is_sign_negative());
+
+    let ser = to_string(&-0.0_f32).unwrap();
+    assert_eq!(ser, "-0.0");
+    assert!(from_str::<f32>(&ser).unwrap().is_sign_negative());
+
+    let ser = to_string_pretty(
+        &-0.0_f64,
+        PrettyConfig::new()
+            .number_suffixes(ron::ser::SuffixPolicy::Always)
+            .digit_grouping(Some(3)),
+    )
+    .unwrap();
+    assert_eq!(ser, "-0.0f64");
+    assert!(from_str::<f64>(&ser).unwrap().is_sign_negative());
+
+    // positive zero keeps its sign as well
+    assert_eq!(to_string(&0.0_f64).unwrap(), "0.0");
+    assert!(from_str::<f64>("0.0").unwrap().is_sign_positive());
+
+    assert!(from_str::<f32>("-0.0f32").unwrap().is_sign_negative());
+
+    for ron in ["-0.0", "-.0", "-0e0", "-0.0f64"] {
+        assert!(from_str::<f64>(ron).unwrap().is_sign_negative(), "{}", ron);
+
+        let value: ron::Value = from_str(ron).unwrap();
+        let roundtrip: f64 = from_str(&to_string(&value).unwrap()).unwrap();
+        assert!(roundtrip.is_sign_negative(), "{}", ron);
+    }
+}
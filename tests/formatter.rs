@@ -0,0 +1,176 @@
+use core::fmt;
+use std::collections::BTreeMap;
+
+use ron::{
+    ser::{DefaultFormatter, Formatter, PrettyConfig, Serializer},
+    Options,
+};
+use serde::Serialize;
+
+/// Writes JSON-like structural tokens
+#[derive(Clone)]
+struct JsonLikeFormatter;
+
+impl Formatter for JsonLikeFormatter {
+    fn write_unit<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("null")
+    }
+
+    fn write_none<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("null")
+    }
+
+    fn begin_some<W: ?Sized + fmt::Write>(&mut self, _writer: &mut W) -> fmt::Result {
+        Ok(())
+    }
+
+    fn end_some<W: ?Sized + fmt::Write>(&mut self, _writer: &mut W) -> fmt::Result {
+        Ok(())
+    }
+
+    fn begin_tuple<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("[")
+    }
+
+    fn end_tuple<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("]")
+    }
+
+    fn begin_struct<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("{")
+    }
+
+    fn end_struct<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("}")
+    }
+}
+
+/// Writes multi-character tokens to check the width-limited layout
+#[derive(Clone)]
+struct WideFormatter;
+
+impl Formatter for WideFormatter {
+    fn begin_seq<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("[[")
+    }
+
+    fn end_seq<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str("]]")
+    }
+
+    fn write_element_separator<W: ?Sized + fmt::Write>(&mut self, writer: &mut W) -> fmt::Result {
+        writer.write_str(";;")
+    }
+}
+
+#[derive(Serialize)]
+struct Config {
+    name: &'static str,
+    point: (i32, i32),
+    tags: Vec<&'static str>,
+    limit: Option<u8>,
+    unit: (),
+    weights: BTreeMap<&'static str, f32>,
+}
+
+fn config() -> Config {
+    Config {
+        name: "test",
+        point: (1, -2),
+        tags: vec!["a", "b"],
+        limit: Some(4),
+        unit: (),
+        weights: [("x", 0.5)].into_iter().collect(),
+    }
+}
+
+fn to_string_with<F: Formatter, T: Serialize>(
+    value: &T,
+    config: Option<PrettyConfig>,
+    formatter: F,
+) -> String {
+    let mut output = String::new();
+    let mut serializer =
+        Serializer::with_formatter(&mut output, config, &Options::default(), formatter).unwrap();
+    value.serialize(&mut serializer).unwrap();
+    output
+}
+
+#[test]
+fn default_formatter_matches_ron() {
+    let value = config();
+
+    assert_eq!(
+        to_string_with(&value, None, DefaultFormatter),
+        ron::to_string(&value).unwrap()
+    );
+    assert_eq!(
+        to_string_with(&value, Some(PrettyConfig::default()), DefaultFormatter),
+        ron::ser::to_string_pretty(&value, PrettyConfig::default()).unwrap()
+    );
+}
+
+#[test]
+fn custom_formatter() {
+    assert_eq!(
+        to_string_with(&config(), None, JsonLikeFormatter),
+        r#"{name:"test",point:[1,-2],tags:["a","b"],limit:4,unit:null,weights:{"x":0.5}}"#
+    );
+    assert_eq!(
+        to_string_with(&config(), Some(PrettyConfig::default()), JsonLikeFormatter),
+        r#"{
+    name: "test",
+    point: [1, -2],
+    tags: [
+        "a",
+        "b",
+    ],
+    limit: 4,
+    unit: null,
+    weights: {
+        "x": 0.5,
+    },
+}"#
+    );
+    assert_eq!(
+        to_string_with(
+            &config(),
+            Some(PrettyConfig::default().field_order(vec![String::from("unit")])),
+            JsonLikeFormatter
+        ),
+        r#"{
+    unit: null,
+    limit: 4,
+    name: "test",
+    point: [1, -2],
+    tags: [
+        "a",
+        "b",
+    ],
+    weights: {
+        "x": 0.5,
+    },
+}"#
+    );
+}
+
+#[test]
+fn custom_formatter_max_width() {
+    // `[[1;; 2;; 3]]` is exactly 13 characters wide
+    assert_eq!(
+        to_string_with(
+            &vec![1, 2, 3],
+            Some(PrettyConfig::default().max_width(13)),
+            WideFormatter
+        ),
+        "[[1;; 2;; 3]]"
+    );
+    assert_eq!(
+        to_string_with(
+            &vec![1, 2, 3],
+            Some(PrettyConfig::default().max_width(12)),
+            WideFormatter
+        ),
+        "[[\n    1;;\n    2;;\n    3;;\n]]"
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "half")]
+
+use half::f16;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Weights {
+    #[serde(with = "ron::half")]
+    value: f16,
+}
+
+fn check_roundtrip(value: f16, expected: &str) {
+    let weights = Weights { value };
+
+    let ron = ron::to_string(&weights).unwrap();
+    assert_eq!(ron, format!("(value:{})", expected));
+
+    let de: Weights = ron::from_str(&ron).unwrap();
+    assert_eq!(de.value.to_bits(), value.to_bits(), "{}", ron);
+}
+
+#[test]
+fn normal_values() {
+    check_roundtrip(f16::ZERO, "0.0");
+    check_roundtrip(f16::NEG_ZERO, "-0.0");
+    check_roundtrip(f16::ONE, "1.0");
+    check_roundtrip(f16::from_f32(-2.5), "-2.5");
+    check_roundtrip(f16::from_f32(0.1), "0.1");
+    check_roundtrip(f16::from_f32(3.14159), "3.14");
+    check_roundtrip(f16::MAX, "65500.0");
+    check_roundtrip(f16::MIN, "-65500.0");
+    check_roundtrip(f16::MIN_POSITIVE, "0.00006104");
+    check_roundtrip(f16::EPSILON, "0.000977");
+}
+
+#[test]
+fn subnormal_values() {
+    check_roundtrip(f16::MIN_POSITIVE_SUBNORMAL, "0.00000006");
+    check_roundtrip(-f16::MIN_POSITIVE_SUBNORMAL, "-0.00000006");
+    check_roundtrip(f16::from_bits(0x03ff), "0.000061");
+    check_roundtrip(f16::from_bits(0x0200), "0.0000305");
+}
+
+#[test]
+fn non_finite_values() {
+    check_roundtrip(f16::INFINITY, "inf");
+    check_roundtrip(f16::NEG_INFINITY, "-inf");
+
+    let ron = ron::to_string(&Weights { value: f16::NAN }).unwrap();
+    assert_eq!(ron, "(value:NaN)");
+    assert!(ron::from_str::<Weights>(&ron).unwrap().value.is_nan());
+}
+
+#[test]
+fn all_values_roundtrip() {
+    for bits in 0..=u16::MAX {
+        let value = f16::from_bits(bits);
+
+        let ron = ron::to_string(&Weights { value }).unwrap();
+        let de: Weights = ron::from_str(&ron).unwrap();
+
+        if value.is_nan() {
+            assert!(de.value.is_nan(), "{}", ron);
+        } else {
+            assert_eq!(de.value.to_bits(), bits, "{}", ron);
+        }
+    }
+}
+
+#[test]
+fn narrowing() {
+    let narrow = |ron: &str| ron::from_str::<Weights>(ron).unwrap().value;
+
+    // Rounds to the nearest f16
+    assert_eq!(narrow("(value: 0.1)"), f16::from_bits(0x2e66));
+    assert_eq!(narrow("(value: 2049)"), f16::from_f32(2048.0));
+    assert_eq!(narrow("(value: 2051.0)"), f16::from_f32(2052.0));
+    // Overflows to infinity
+    assert_eq!(narrow("(value: 65520.0)"), f16::INFINITY);
+    assert_eq!(narrow("(value: -1e10)"), f16::NEG_INFINITY);
+    // Underflows to subnormals and zero
+    assert_eq!(narrow("(value: 4e-8)"), f16::MIN_POSITIVE_SUBNORMAL);
+    assert_eq!(narrow("(value: 1e-8)"), f16::ZERO);
+    assert_eq!(narrow("(value: -1e-8)").to_bits(), f16::NEG_ZERO.to_bits());
+    // Integers are accepted as well
+    assert_eq!(narrow("(value: 42)"), f16::from_f32(42.0));
+}
+
+#[test]
+fn number_suffixes() {
+    let weights = Weights {
+        value: f16::from_f32(0.5),
+    };
+
+    let ron = to_string_pretty(
+        &weights,
+        PrettyConfig::new()
+            .number_suffixes(true)
+            .compact_structs(true),
+    )
+    .unwrap();
+    assert_eq!(ron, "(value: 0.5f32)");
+    assert_eq!(ron::from_str::<Weights>(&ron).unwrap(), weights);
+
+    assert!(ron::from_str::<Weights>("(value: 0.5f64)").is_err());
+}
+
+#[test]
+fn value_roundtrip() {
+    let weights = Weights {
+        value: f16::from_f32(-0.75),
+    };
+
+    let value: ron::Value = ron::from_str(&ron::to_string(&weights).unwrap()).unwrap();
+    assert_eq!(value.into_rust::<Weights>().unwrap(), weights);
+}
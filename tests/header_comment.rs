@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use ron::{
+    extensions::Extensions,
+    ser::{to_string_pretty, PrettyConfig},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    port: Option<u16>,
+}
+
+fn config() -> Config {
+    Config {
+        name: String::from("ron"),
+        port: Some(8080),
+    }
+}
+
+#[test]
+fn header_comment_is_written_first() {
+    let pretty = PrettyConfig::new()
+        .new_line("\n")
+        .header_comment(Some(String::from("schema v3")));
+
+    let ser = to_string_pretty(&config(), pretty).unwrap();
+    assert_eq!(
+        ser,
+        "// schema v3
+(
+    name: \"ron\",
+    port: Some(8080),
+)"
+    );
+    assert_eq!(ron::from_str::<Config>(&ser), Ok(config()));
+}
+
+#[test]
+fn header_comment_precedes_extensions() {
+    let pretty = PrettyConfig::new()
+        .new_line("\n")
+        .extensions(Extensions::IMPLICIT_SOME)
+        .header_comment(Some(String::from("schema v3\n\ngenerated by my-tool\r\n")));
+
+    let ser = to_string_pretty(&config(), pretty).unwrap();
+    assert_eq!(
+        ser,
+        "// schema v3
+//
+// generated by my-tool
+#![enable(implicit_some)]
+(
+    name: \"ron\",
+    port: 8080,
+)"
+    );
+    assert_eq!(ron::from_str::<Config>(&ser), Ok(config()));
+}
+
+#[test]
+fn header_comment_cannot_break_out_of_the_comment() {
+    let pretty = PrettyConfig::new()
+        .new_line("\n")
+        .compact_maps(true)
+        .header_comment(Some(String::from("a\r{\"b\": 1}")));
+
+    let map = BTreeMap::from([(String::from("c"), 2)]);
+
+    let ser = to_string_pretty(&map, pretty).unwrap();
+    assert_eq!(ser, "// a\n// {\"b\": 1}\n{\"c\": 2}");
+    assert_eq!(ron::from_str::<BTreeMap<String, i32>>(&ser), Ok(map));
+}
+
+#[test]
+fn no_header_comment_by_default() {
+    let ser = to_string_pretty(&config(), PrettyConfig::new()).unwrap();
+
+    assert!(ser.starts_with('('), "{}", ser);
+}
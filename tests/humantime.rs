@@ -0,0 +1,92 @@
+#![cfg(feature = "humantime")]
+
+use std::time::Duration;
+
+use ron::error::{Error, Position, SpannedError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Timeout {
+    #[serde(with = "ron::humantime")]
+    duration: Duration,
+}
+
+fn check_roundtrip(duration: Duration, expected: &str) {
+    let value = Timeout { duration };
+
+    let ron = ron::to_string(&value).unwrap();
+    assert_eq!(ron, format!("(duration:\"{}\")", expected));
+
+    assert_eq!(ron::from_str::<Timeout>(&ron).unwrap(), value);
+}
+
+#[test]
+fn roundtrip_units() {
+    check_roundtrip(Duration::ZERO, "0s");
+    check_roundtrip(Duration::from_secs(5), "5s");
+    check_roundtrip(Duration::from_secs(90), "1m 30s");
+    check_roundtrip(Duration::from_secs(90 * 60), "1h 30m");
+    check_roundtrip(Duration::from_secs(2 * 86_400 + 4 * 3_600), "2days 4h");
+}
+
+#[test]
+fn roundtrip_fractional_seconds() {
+    check_roundtrip(Duration::from_millis(1_500), "1s 500ms");
+    check_roundtrip(Duration::from_micros(250), "250us");
+    check_roundtrip(Duration::new(3, 1), "3s 1ns");
+    check_roundtrip(Duration::new(0, 123_456_789), "123ms 456us 789ns");
+}
+
+#[test]
+fn deserialize_alternative_spellings() {
+    for (ron, duration) in [
+        ("(duration: \"5sec\")", Duration::from_secs(5)),
+        ("(duration: \"15min\")", Duration::from_secs(15 * 60)),
+        (
+            "(duration: \"1hour 30minutes\")",
+            Duration::from_secs(90 * 60),
+        ),
+        ("(duration: \"1h30m\")", Duration::from_secs(90 * 60)),
+        ("(duration: \"1s 500ms\")", Duration::from_millis(1_500)),
+        ("(duration: r\"2d\")", Duration::from_secs(2 * 86_400)),
+    ] {
+        assert_eq!(
+            ron::from_str::<Timeout>(ron).unwrap(),
+            Timeout { duration },
+            "{}",
+            ron
+        );
+    }
+}
+
+#[test]
+fn deserialize_invalid() {
+    let err = ron::from_str::<Timeout>("(duration: \"5 parsecs\")").unwrap_err();
+    assert!(
+        matches!(&err.code, Error::Message(msg) if msg.starts_with("unknown time unit \"parsecs\"")),
+        "{:?}",
+        err
+    );
+    assert_eq!(
+        err.position,
+        Position {
+            line: 1,
+            col: 23,
+            offset: 22,
+        }
+    );
+    assert_eq!(
+        ron::from_str::<Timeout>("(duration: 5)"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("a human-readable duration string, e.g. \"1h 30m\""),
+                found: String::from("the unsigned integer `5`"),
+            },
+            position: Position {
+                line: 1,
+                col: 12,
+                offset: 11,
+            },
+        })
+    );
+}
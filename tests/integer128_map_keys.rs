@@ -0,0 +1,101 @@
+#![cfg(feature = "integer128")]
+
+use std::collections::BTreeMap;
+
+use ron::{
+    ser::{to_string_pretty, PrettyConfig, SuffixPolicy},
+    Number, Value,
+};
+
+fn signed() -> BTreeMap<i128, String> {
+    [
+        (i128::MIN, "min"),
+        (i128::from(i64::MIN) - 1, "below i64"),
+        (-1, "minus one"),
+        (0, "zero"),
+        (i128::from(u64::MAX) + 1, "above u64"),
+        (i128::MAX, "max"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key, String::from(value)))
+    .collect()
+}
+
+fn configs() -> Vec<PrettyConfig> {
+    vec![
+        PrettyConfig::new(),
+        PrettyConfig::new().compact_maps(true),
+        PrettyConfig::new().number_suffixes(SuffixPolicy::Always),
+        PrettyConfig::new().number_suffixes(SuffixPolicy::WhenAmbiguous),
+        PrettyConfig::new().digit_grouping(Some(3)),
+        PrettyConfig::new().sort_seqs(true).field_order(vec![]),
+    ]
+}
+
+#[test]
+fn signed_128_bit_map_keys_roundtrip() {
+    let map = signed();
+
+    let compact = ron::to_string(&map).unwrap();
+    assert_eq!(
+        compact,
+        "{-170141183460469231731687303715884105728:\"min\",\
+        -9223372036854775809:\"below i64\",-1:\"minus one\",0:\"zero\",\
+        18446744073709551616:\"above u64\",\
+        170141183460469231731687303715884105727:\"max\"}"
+    );
+    assert_eq!(ron::from_str(&compact).as_ref(), Ok(&map));
+
+    for config in configs() {
+        let pretty = to_string_pretty(&map, config).unwrap();
+
+        assert_eq!(ron::from_str(&pretty).as_ref(), Ok(&map), "{}", pretty);
+    }
+}
+
+#[test]
+fn unsigned_128_bit_map_keys_roundtrip() {
+    let map: BTreeMap<u128, bool> = [
+        (0, false),
+        (u128::from(u64::MAX) + 1, true),
+        (u128::MAX, true),
+    ]
+    .into_iter()
+    .collect();
+
+    for config in configs() {
+        let pretty = to_string_pretty(&map, config).unwrap();
+
+        assert_eq!(ron::from_str(&pretty).as_ref(), Ok(&map), "{}", pretty);
+    }
+}
+
+#[test]
+fn value_map_keys_are_not_truncated() {
+    let map = signed();
+
+    let value: Value = ron::from_str(&ron::to_string(&map).unwrap()).unwrap();
+    let entries = match &value {
+        Value::Map(entries) => entries,
+        value => panic!("expected a map, found {:?}", value),
+    };
+
+    for (key, name) in [
+        (Number::I128(i128::MIN), "min"),
+        (Number::I128(i128::from(i64::MIN) - 1), "below i64"),
+        (Number::U128(u128::from(u64::MAX) + 1), "above u64"),
+        (Number::U128(i128::MAX.unsigned_abs()), "max"),
+    ] {
+        assert_eq!(
+            entries.get(&Value::Number(key)),
+            Some(&Value::from(name)),
+            "{:?}",
+            key
+        );
+    }
+
+    assert_eq!(value.clone().into_rust::<BTreeMap<i128, String>>(), Ok(map));
+
+    let reserialized = ron::to_string(&value).unwrap();
+    assert_eq!(ron::from_str::<Value>(&reserialized), Ok(value));
+}
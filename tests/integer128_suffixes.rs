@@ -0,0 +1,116 @@
+#![cfg(feature = "integer128")]
+
+use ron::{
+    ser::{to_string_pretty, PrettyConfig, SuffixPolicy},
+    Number, Value,
+};
+
+fn with_suffixes<T: serde::Serialize>(value: &T, number_suffixes: SuffixPolicy) -> String {
+    to_string_pretty(value, PrettyConfig::new().number_suffixes(number_suffixes)).unwrap()
+}
+
+#[test]
+fn suffixes_at_the_128_bit_boundary() {
+    for (policy, min, max) in [
+        (
+            SuffixPolicy::Never,
+            "-170141183460469231731687303715884105728",
+            "340282366920938463463374607431768211455",
+        ),
+        (
+            SuffixPolicy::WhenAmbiguous,
+            "-170141183460469231731687303715884105728",
+            "340282366920938463463374607431768211455",
+        ),
+        (
+            SuffixPolicy::Always,
+            "-170141183460469231731687303715884105728i128",
+            "340282366920938463463374607431768211455u128",
+        ),
+    ] {
+        assert_eq!(with_suffixes(&i128::MIN, policy), min);
+        assert_eq!(with_suffixes(&u128::MAX, policy), max);
+
+        assert_eq!(ron::from_str::<i128>(min), Ok(i128::MIN));
+        assert_eq!(ron::from_str::<u128>(max), Ok(u128::MAX));
+    }
+
+    // Values that an untyped integer would also parse as need a suffix
+    assert_eq!(
+        with_suffixes(&i128::MAX, SuffixPolicy::WhenAmbiguous),
+        "170141183460469231731687303715884105727i128"
+    );
+    assert_eq!(
+        with_suffixes(&u128::from(u64::MAX), SuffixPolicy::WhenAmbiguous),
+        "18446744073709551615u128"
+    );
+    assert_eq!(
+        with_suffixes(&(i128::from(i64::MIN) - 1), SuffixPolicy::WhenAmbiguous),
+        "-9223372036854775809"
+    );
+    assert_eq!(
+        with_suffixes(&(u128::from(u64::MAX) + 1), SuffixPolicy::WhenAmbiguous),
+        "18446744073709551616"
+    );
+}
+
+#[test]
+fn value_keeps_the_full_128_bit_range() {
+    for (ron, number) in [
+        (
+            "-170141183460469231731687303715884105728",
+            Number::I128(i128::MIN),
+        ),
+        (
+            "-9223372036854775809",
+            Number::I128(i128::from(i64::MIN) - 1),
+        ),
+        ("-9223372036854775808", Number::I64(i64::MIN)),
+        ("18446744073709551615", Number::U64(u64::MAX)),
+        (
+            "18446744073709551616",
+            Number::U128(u128::from(u64::MAX) + 1),
+        ),
+        (
+            "340282366920938463463374607431768211455",
+            Number::U128(u128::MAX),
+        ),
+        (
+            "-170141183460469231731687303715884105728i128",
+            Number::I128(i128::MIN),
+        ),
+        (
+            "340282366920938463463374607431768211455u128",
+            Number::U128(u128::MAX),
+        ),
+    ] {
+        let value: Value = ron::from_str(ron).unwrap();
+        assert_eq!(value, Value::Number(number));
+
+        for policy in [
+            SuffixPolicy::Never,
+            SuffixPolicy::WhenAmbiguous,
+            SuffixPolicy::Always,
+        ] {
+            let ser = with_suffixes(&value, policy);
+            assert_eq!(ron::from_str::<Value>(&ser), Ok(value.clone()));
+        }
+    }
+
+    assert_eq!(
+        Value::Number(Number::I128(i128::MIN)).into_rust::<i128>(),
+        Ok(i128::MIN)
+    );
+    assert_eq!(
+        Value::Number(Number::U128(u128::MAX)).into_rust::<u128>(),
+        Ok(u128::MAX)
+    );
+}
+
+#[test]
+fn out_of_range_suffixed_integers_are_rejected() {
+    assert!(ron::from_str::<Value>("170141183460469231731687303715884105728i128").is_err());
+    assert!(ron::from_str::<Value>("340282366920938463463374607431768211456u128").is_err());
+    assert!(ron::from_str::<u128>("340282366920938463463374607431768211456").is_err());
+    assert!(ron::from_str::<i128>("-170141183460469231731687303715884105729").is_err());
+}
@@ -0,0 +1,137 @@
+use ron::{
+    error::{Error, SpannedError},
+    Value,
+};
+
+fn parse_error(src: &str) -> Error {
+    ron::from_str::<Value>(src).unwrap_err().code
+}
+
+#[test]
+fn truncated_input_is_eof() {
+    // unterminated strings
+    for src in ["\"abc", "[\"abc", "r##\"abc\"#", "b\"ab", "(name: \"ron"] {
+        assert!(parse_error(src).is_eof(), "{src}");
+    }
+
+    // open brackets and parentheses
+    for src in [
+        "[",
+        "[1, ",
+        "(",
+        "(a: ",
+        "{",
+        "{1: 2, ",
+        "Some(",
+        "Point(x: 1, y: [",
+    ] {
+        assert_eq!(parse_error(src), Error::Eof, "{src}");
+        assert!(parse_error(src).is_eof(), "{src}");
+    }
+
+    // values after a comma or colon, and partial characters and byte literals
+    for src in ["[1, 2.5, ", "(a: -1, b: ", "b'", "b'\\", "'", "'\\"] {
+        assert!(parse_error(src).is_eof(), "{src}");
+    }
+
+    // unclosed block comment
+    assert!(parse_error("[1 /* a comment").is_eof());
+}
+
+#[test]
+fn truncated_input_is_eof_at_its_end() {
+    fn spanned_error(src: &str) -> SpannedError {
+        ron::from_str::<Value>(src).unwrap_err()
+    }
+
+    // partial numbers, characters, and byte literals, and values that are
+    // missing the separator or terminator after them
+    for src in [
+        "-",
+        "+",
+        "0x",
+        "[0b",
+        "1e",
+        "1.5E-",
+        "[1, -2_e+",
+        "(a: 1.5e",
+        "'a",
+        "b'a",
+        "[1, 2",
+        "[1, 2  ",
+        "(a",
+        "{1: 2",
+        "Some(1",
+    ] {
+        assert!(spanned_error(src).is_eof(src), "{src}");
+    }
+
+    assert_eq!(
+        ron::from_str::<u8>("-").unwrap_err().code,
+        Error::ExpectedInteger
+    );
+    assert!(ron::from_str::<u8>("-").unwrap_err().is_eof("-"));
+    assert!(ron::from_str::<char>("'a").unwrap_err().is_eof("'a"));
+    assert!(ron::from_str::<f32>("1e").unwrap_err().is_eof("1e"));
+
+    // invalid input, and errors after a complete value
+    for src in [
+        "[1 2", "[1, 2] 3", "(a: 1))", "'ab'", "1_000_u8", "[1e1e", "[1 2e",
+    ] {
+        assert!(!spanned_error(src).is_eof(src), "{src}");
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    let src = "(x: 1)";
+    let err = ron::from_str::<Point>(src).unwrap_err();
+    assert!(matches!(err.code, Error::MissingStructField { .. }));
+    assert!(!err.is_eof(src));
+}
+
+#[test]
+fn invalid_input_is_not_eof() {
+    for src in [
+        "[1 2]", "(a: 1))", "{1 => 2}", "\"\\q\"", "1_000_u8", "[1, 2] 3",
+    ] {
+        assert!(!parse_error(src).is_eof(), "{src}");
+    }
+
+    // an input that ends right after a complete value reports what is missing
+    assert_eq!(parse_error("[1, 2"), Error::ExpectedComma);
+    assert!(!Error::ExpectedComma.is_eof());
+}
+
+#[test]
+fn eof_with_more_input_succeeds() {
+    let mut buffer = String::new();
+
+    // feed the input in chunks, retrying until it is no longer truncated
+    for chunk in [
+        "(name: \"r",
+        "on\", ports: [80",
+        ", 443], /* tls",
+        " */ tls: true)",
+    ] {
+        buffer.push_str(chunk);
+
+        match ron::from_str::<Value>(&buffer) {
+            Ok(value) => {
+                assert_eq!(
+                    value,
+                    ron::from_str("(name: \"ron\", ports: [80, 443], tls: true)").unwrap()
+                );
+                return;
+            }
+            Err(err) if err.is_eof(&buffer) => (),
+            Err(err) => panic!("unexpected error {err}"),
+        }
+    }
+
+    panic!("the complete input did not parse");
+}
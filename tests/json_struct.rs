@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use ron::error::{Error, Position, SpannedError};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: String,
+    inner: Inner,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Inner {
+    size: u32,
+}
+
+#[test]
+fn json_object_as_struct() {
+    assert_eq!(
+        ron::from_str::<Config>("{\"name\": \"ron\", \"inner\": {\"size\": 4}}"),
+        Err(SpannedError {
+            code: Error::ExpectedStructGotMap("Config"),
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+}
+
+#[test]
+fn nested_json_object_as_struct() {
+    assert_eq!(
+        ron::from_str::<Config>(
+            "(\n    name: \"ron\",\n    inner: {\n        \"size\": 4,\n    },\n)"
+        ),
+        Err(SpannedError {
+            code: Error::ExpectedStructGotMap("Inner"),
+            position: Position {
+                line: 3,
+                col: 12,
+                offset: 30,
+            },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<Config>("(name: \"ron\", inner: Inner { \"size\": 4 })"),
+        Err(SpannedError {
+            code: Error::ExpectedStructGotMap("Inner"),
+            position: Position {
+                line: 1,
+                col: 28,
+                offset: 27,
+            },
+        })
+    );
+}
+
+#[test]
+fn non_json_map_as_struct() {
+    assert_eq!(
+        ron::from_str::<Inner>("{size: 4}"),
+        Err(SpannedError {
+            code: Error::ExpectedNamedStructLike("Inner"),
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<Inner>("{}"),
+        Err(SpannedError {
+            code: Error::ExpectedNamedStructLike("Inner"),
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+}
+
+#[test]
+fn json_object_as_map() {
+    assert_eq!(
+        ron::from_str::<HashMap<String, u32>>("{\"size\": 4}"),
+        Ok(vec![(String::from("size"), 4)].into_iter().collect())
+    );
+}
+
+#[test]
+fn json_object_hint_message() {
+    let err = ron::from_str::<Inner>("{\"size\": 4}").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "1:1: Expected opening `(` for struct `Inner` but found a map with string keys, \
+        which looks like a JSON object: RON structs are written as `(field: value)` with \
+        unquoted field names"
+    );
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use ron::{
+    ser::{to_string_pretty, PrettyConfig},
+    Error, Value,
+};
+use serde::{Deserialize, Serialize};
+
+const KEYS: &[&str] = &[
+    "with space",
+    "1abc",
+    "",
+    "r#raw",
+    "true",
+    "a:b",
+    "quote\"d",
+    "ab.cd-ef",
+    "ünïcödé",
+    "valid_ident",
+];
+
+fn configs() -> Vec<PrettyConfig> {
+    vec![
+        PrettyConfig::default(),
+        PrettyConfig::default().compact_maps(true),
+        PrettyConfig::default().max_width(20),
+        PrettyConfig::default().field_order(vec![String::from("1abc"), String::from("")]),
+        PrettyConfig::default().prefer_raw_strings(true),
+        PrettyConfig::default().escape_strings(false),
+    ]
+}
+
+#[test]
+fn string_map_keys_are_always_quoted() {
+    let map: BTreeMap<String, u8> = KEYS
+        .iter()
+        .enumerate()
+        .map(|(i, key)| (String::from(*key), i as u8))
+        .collect();
+
+    let compact = ron::to_string(&map).unwrap();
+    assert!(compact.starts_with("{\"\":2,"), "{}", compact);
+    assert_eq!(
+        ron::from_str::<BTreeMap<String, u8>>(&compact),
+        Ok(map.clone())
+    );
+
+    for config in configs() {
+        let pretty = to_string_pretty(&map, config).unwrap();
+
+        assert_eq!(
+            ron::from_str::<BTreeMap<String, u8>>(&pretty).as_ref(),
+            Ok(&map),
+            "{}",
+            pretty
+        );
+    }
+
+    let value = Value::Map(
+        KEYS.iter()
+            .map(|key| (Value::String(String::from(*key)), Value::Unit))
+            .collect(),
+    );
+
+    for config in configs() {
+        let pretty = to_string_pretty(&value, config).unwrap();
+
+        // with `indexmap`, `field_order` reorders the entries of the map
+        assert!(
+            ron::from_str::<Value>(&pretty).unwrap().semantic_eq(&value),
+            "{}",
+            pretty
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Raw {
+    #[serde(rename = "1abc")]
+    digit: u8,
+    #[serde(rename = "true")]
+    reserved: u8,
+    #[serde(rename = "ab.cd-ef")]
+    punctuated: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Unrepresentable {
+    #[serde(rename = "with space")]
+    space: u8,
+    #[serde(rename = "")]
+    empty: u8,
+}
+
+#[test]
+fn struct_field_names_are_raw_or_quoted() {
+    let raw = Raw {
+        digit: 1,
+        reserved: 2,
+        punctuated: 3,
+    };
+
+    let compact = ron::to_string(&raw).unwrap();
+    assert_eq!(compact, "(r#1abc:1,r#true:2,r#ab.cd-ef:3)");
+    assert_eq!(ron::from_str::<Raw>(&compact).as_ref(), Ok(&raw));
+
+    for config in configs() {
+        for config in [config.clone(), config.quote_keys(true)] {
+            let pretty = to_string_pretty(&raw, config).unwrap();
+
+            assert_eq!(
+                ron::from_str::<Raw>(&pretty).as_ref(),
+                Ok(&raw),
+                "{}",
+                pretty
+            );
+        }
+    }
+
+    // field names that are not even valid raw identifiers can only be quoted
+    let unrepresentable = Unrepresentable { space: 1, empty: 2 };
+
+    assert_eq!(
+        ron::to_string(&unrepresentable),
+        Err(Error::InvalidIdentifier(String::from("with space")))
+    );
+
+    for config in configs() {
+        assert_eq!(
+            to_string_pretty(&unrepresentable, config.clone()),
+            Err(Error::InvalidIdentifier(String::from("with space")))
+        );
+
+        let pretty = to_string_pretty(&unrepresentable, config.quote_keys(true)).unwrap();
+
+        assert_eq!(
+            ron::from_str::<Unrepresentable>(&pretty).as_ref(),
+            Ok(&unrepresentable),
+            "{}",
+            pretty
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    Options,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct UnitStruct;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Old {
+    a: (),
+    b: UnitStruct,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Enum {
+    Unit,
+    Newtype(UnitStruct),
+    Other(Option<i32>),
+}
+
+fn lenient() -> Options {
+    Options::default().with_lenient_unit(true)
+}
+
+#[test]
+fn unit_struct_and_unit_are_interchangeable() {
+    // A unit struct always accepts `()`
+    assert_eq!(ron::from_str("()"), Ok(UnitStruct));
+    assert_eq!(lenient().from_str("()"), Ok(UnitStruct));
+    assert_eq!(
+        ron::from_str("(a: (), b: ())"),
+        Ok(Old {
+            a: (),
+            b: UnitStruct
+        })
+    );
+
+    // A unit only accepts a unit struct name if lenient
+    assert_eq!(
+        ron::from_str::<()>("UnitStruct"),
+        Err(SpannedError {
+            code: Error::ExpectedUnit,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        })
+    );
+    assert_eq!(lenient().from_str("UnitStruct"), Ok(()));
+    assert_eq!(
+        lenient().from_str("(a: UnitStruct, b: UnitStruct)"),
+        Ok(Old {
+            a: (),
+            b: UnitStruct
+        })
+    );
+
+    // A unit struct still rejects a different struct name
+    assert_eq!(
+        lenient().from_str::<UnitStruct>("Other"),
+        Err(SpannedError {
+            code: Error::ExpectedDifferentStructName {
+                expected: "UnitStruct",
+                found: String::from("Other"),
+            },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5,
+            },
+        })
+    );
+    assert_eq!(
+        lenient().from_str::<()>("42").unwrap_err().code,
+        Error::ExpectedUnit
+    );
+}
+
+#[test]
+fn unit_rejects_literals() {
+    for literal in ["true", "false", "None", "Some", "inf", "NaN", "NaNf64"] {
+        assert_eq!(
+            lenient().from_str::<()>(literal),
+            Err(SpannedError {
+                code: Error::ExpectedUnit,
+                position: Position {
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+            }),
+            "{literal}"
+        );
+        assert_eq!(
+            lenient()
+                .from_str::<Old>(&format!("(a: {literal}, b: UnitStruct)"))
+                .unwrap_err()
+                .code,
+            Error::ExpectedUnit,
+            "{literal}"
+        );
+    }
+
+    // identifiers that merely start like a literal are unit struct names
+    assert_eq!(lenient().from_str("trueish"), Ok(()));
+    assert_eq!(lenient().from_str("r#true"), Ok(()));
+}
+
+#[test]
+fn missing_unit_field_is_still_missing() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct WithDefault {
+        #[serde(default)]
+        a: (),
+        b: UnitStruct,
+    }
+
+    assert_eq!(
+        lenient()
+            .from_str::<Old>("(b: UnitStruct)")
+            .unwrap_err()
+            .code,
+        Error::MissingStructField {
+            field: "a",
+            outer: Some(String::from("Old")),
+        }
+    );
+    assert_eq!(
+        lenient().from_str("(b: UnitStruct)"),
+        Ok(WithDefault {
+            a: (),
+            b: UnitStruct
+        })
+    );
+}
+
+#[test]
+fn unit_variant_with_empty_tuple() {
+    assert_eq!(
+        ron::from_str::<Enum>("Unit()").unwrap_err().code,
+        Error::TrailingCharacters {
+            found: String::from("()")
+        }
+    );
+    assert_eq!(lenient().from_str("Unit"), Ok(Enum::Unit));
+    assert_eq!(lenient().from_str("Unit()"), Ok(Enum::Unit));
+    assert_eq!(
+        lenient().from_str("[Unit ( ), Unit]"),
+        Ok(vec![Enum::Unit, Enum::Unit])
+    );
+    assert_eq!(
+        lenient().from_str::<Enum>("Unit(42)").unwrap_err().code,
+        Error::ExpectedStructLikeEnd
+    );
+}
+
+#[test]
+fn newtype_variant_with_missing_unit() {
+    assert_eq!(
+        ron::from_str::<Enum>("Newtype").unwrap_err().code,
+        Error::ExpectedStructLike
+    );
+    assert_eq!(lenient().from_str("Newtype"), Ok(Enum::Newtype(UnitStruct)));
+    assert_eq!(
+        lenient().from_str("Newtype(())"),
+        Ok(Enum::Newtype(UnitStruct))
+    );
+    assert_eq!(
+        lenient().from_str("Newtype(UnitStruct)"),
+        Ok(Enum::Newtype(UnitStruct))
+    );
+    assert_eq!(
+        lenient().from_str("[Other, Unit]"),
+        Ok(vec![Enum::Other(None), Enum::Unit])
+    );
+    assert_eq!(
+        lenient().from_str("Other(Some(4))"),
+        Ok(Enum::Other(Some(4)))
+    );
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Number {
+        Int(i32),
+    }
+
+    assert_eq!(
+        lenient().from_str::<Number>("Int").unwrap_err().code,
+        Error::ExpectedStructLike
+    );
+}
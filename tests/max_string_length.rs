@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use ron::{
+    error::{Position, SpannedError},
+    Error, Options, Value,
+};
+use serde::Deserialize;
+
+fn limited() -> Options {
+    Options::default().with_max_string_length(5)
+}
+
+fn exceeded(line: usize, col: usize, offset: usize) -> Result<String, SpannedError> {
+    Err(SpannedError {
+        code: Error::ExceededStringLengthLimit,
+        position: Position { line, col, offset },
+    })
+}
+
+#[test]
+fn strings_up_to_the_limit() {
+    assert_eq!(limited().from_str("\"abcde\""), Ok(String::from("abcde")));
+    assert_eq!(limited().from_str("\"abcdef\""), exceeded(1, 1, 0));
+
+    // escapes count as the characters they stand for
+    assert_eq!(limited().from_str("\"a\\nb\\u{1F980}\""), exceeded(1, 1, 0));
+    assert_eq!(
+        limited().from_str("\"\\u{1F980}\\n\""),
+        Ok(String::from("🦀\n"))
+    );
+    assert_eq!(
+        limited().from_str("\"\\t\\t\\t\\t\\t\""),
+        Ok(String::from("\t\t\t\t\t"))
+    );
+    assert_eq!(
+        limited().from_str("\"\\t\\t\\t\\t\\t\\t\""),
+        exceeded(1, 1, 0)
+    );
+    assert_eq!(limited().from_str("\"ab\\tcde\""), exceeded(1, 1, 0));
+
+    assert_eq!(
+        limited().from_str("r#\"ab\"cd\"#"),
+        Ok(String::from("ab\"cd"))
+    );
+    assert_eq!(limited().from_str("r#\"ab\"cde\"#"), exceeded(1, 1, 0));
+
+    assert_eq!(
+        limited().from_str::<Vec<String>>("[\"ok\",\n  \"too long\"]"),
+        exceeded(2, 3, 9).map(|_| Vec::new())
+    );
+}
+
+#[test]
+fn byte_strings_up_to_the_limit() {
+    assert_eq!(
+        limited().from_str::<Value>("b\"abcde\""),
+        Ok(Value::Bytes(b"abcde".to_vec()))
+    );
+    assert_eq!(
+        limited().from_str::<Value>("b\"ab\\x00de\""),
+        Ok(Value::Bytes(b"ab\0de".to_vec()))
+    );
+    assert_eq!(
+        limited().from_str::<Value>(" b\"abcdef\"").unwrap_err(),
+        SpannedError {
+            code: Error::ExceededStringLengthLimit,
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
+        }
+    );
+    assert_eq!(
+        limited()
+            .from_str::<Value>("br\"abcdef\"")
+            .unwrap_err()
+            .code,
+        Error::ExceededStringLengthLimit
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Short {
+    abcde: u8,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Long {
+    abcdef: u8,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Variant {
+    Fives,
+    Sixths,
+}
+
+#[test]
+fn identifiers_up_to_the_limit() {
+    assert_eq!(limited().from_str("(abcde: 1)"), Ok(Short { abcde: 1 }));
+    assert_eq!(
+        limited().from_str::<Long>("(abcdef: 1)").unwrap_err(),
+        SpannedError {
+            code: Error::ExceededStringLengthLimit,
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
+        }
+    );
+    assert_eq!(
+        limited().from_str::<Long>("(r#abcdef: 1)").unwrap_err(),
+        SpannedError {
+            code: Error::ExceededStringLengthLimit,
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1,
+            },
+        }
+    );
+
+    assert_eq!(limited().from_str("Fives"), Ok(Variant::Fives));
+    assert_eq!(
+        limited().from_str::<Variant>("Sixths").unwrap_err().code,
+        Error::ExceededStringLengthLimit
+    );
+
+    // identifiers in untyped values are limited as well
+    assert_eq!(
+        limited().from_str::<Value>("Sixths").unwrap_err().code,
+        Error::ExceededStringLengthLimit
+    );
+    assert_eq!(
+        limited()
+            .from_str::<HashMap<String, Value>>("{\"a\": Sixths(1)}")
+            .unwrap_err(),
+        SpannedError {
+            code: Error::ExceededStringLengthLimit,
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6,
+            },
+        }
+    );
+}
+
+#[test]
+fn no_limit_by_default() {
+    let long = "x".repeat(10_000);
+
+    assert_eq!(
+        Options::default().from_str(&format!("{:?}", long)),
+        Ok(long.clone())
+    );
+    assert_eq!(
+        limited()
+            .without_max_string_length()
+            .from_str(&format!("{:?}", long)),
+        Ok(long)
+    );
+}
@@ -0,0 +1,181 @@
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point(i32, i32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Shape {
+    name: String,
+    points: Vec<Point>,
+    tags: (u8, char, bool),
+}
+
+fn shape() -> Shape {
+    Shape {
+        name: String::from("triangle"),
+        points: vec![Point(0, 0), Point(100, 0), Point(50, 80)],
+        tags: (3, 'a', true),
+    }
+}
+
+fn check_max_width<T>(value: &T, max_width: usize, expected: &str)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+{
+    let config = PrettyConfig::new().new_line("\n").max_width(max_width);
+
+    let ron = to_string_pretty(value, config).unwrap();
+    assert_eq!(ron, expected);
+
+    assert_eq!(&ron::from_str::<T>(&ron).unwrap(), value);
+}
+
+#[test]
+fn nested_arrays() {
+    let value = vec![vec![1, 2], vec![3, 4], vec![5, 6, 7, 8]];
+
+    check_max_width(&value, 80, "[[1, 2], [3, 4], [5, 6, 7, 8]]");
+    check_max_width(&value, 30, "[[1, 2], [3, 4], [5, 6, 7, 8]]");
+    check_max_width(
+        &value,
+        29,
+        "[
+    [1, 2],
+    [3, 4],
+    [5, 6, 7, 8],
+]",
+    );
+    check_max_width(
+        &value,
+        15,
+        "[
+    [1, 2],
+    [3, 4],
+    [
+        5,
+        6,
+        7,
+        8,
+    ],
+]",
+    );
+}
+
+#[test]
+fn empty_arrays() {
+    check_max_width(&Vec::<i32>::new(), 0, "[]");
+    check_max_width(&vec![Vec::<i32>::new()], 3, "[\n    [],\n]");
+}
+
+#[test]
+fn arrays_and_tuples_in_structs() {
+    let value = shape();
+
+    check_max_width(
+        &value,
+        80,
+        "(
+    name: \"triangle\",
+    points: [(0, 0), (100, 0), (50, 80)],
+    tags: (3, 'a', true),
+)",
+    );
+    check_max_width(
+        &value,
+        30,
+        "(
+    name: \"triangle\",
+    points: [
+        (0, 0),
+        (100, 0),
+        (50, 80),
+    ],
+    tags: (3, 'a', true),
+)",
+    );
+    check_max_width(
+        &value,
+        20,
+        "(
+    name: \"triangle\",
+    points: [
+        (0, 0),
+        (100, 0),
+        (50, 80),
+    ],
+    tags: (
+        3,
+        'a',
+        true,
+    ),
+)",
+    );
+}
+
+#[test]
+fn expanded_structs_inside_arrays() {
+    check_max_width(
+        &vec![shape()],
+        80,
+        "[
+    (
+        name: \"triangle\",
+        points: [(0, 0), (100, 0), (50, 80)],
+        tags: (3, 'a', true),
+    ),
+]",
+    );
+}
+
+#[test]
+fn overrides_compact_arrays_and_separate_tuple_members() {
+    let value = (vec![1, 2, 3], (4, 5));
+
+    let config = PrettyConfig::new()
+        .new_line("\n")
+        .compact_arrays(false)
+        .separate_tuple_members(true)
+        .max_width(80);
+
+    assert_eq!(
+        to_string_pretty(&value, config).unwrap(),
+        "([1, 2, 3], (4, 5))"
+    );
+}
+
+#[test]
+fn enumerate_arrays() {
+    let config = PrettyConfig::new()
+        .new_line("\n")
+        .enumerate_arrays(true)
+        .max_width(25);
+
+    assert_eq!(
+        to_string_pretty(&vec![1, 2], config.clone()).unwrap(),
+        "[/*[0]*/ 1, /*[1]*/ 2]"
+    );
+    assert_eq!(
+        to_string_pretty(&vec![1, 2, 3], config).unwrap(),
+        "[
+    /*[0]*/ 1,
+    /*[1]*/ 2,
+    /*[2]*/ 3,
+]"
+    );
+}
+
+#[test]
+fn depth_limit() {
+    let config = PrettyConfig::new()
+        .new_line("\n")
+        .depth_limit(1)
+        .max_width(10);
+
+    assert_eq!(
+        to_string_pretty(&vec![vec![1, 2, 3, 4, 5]], config).unwrap(),
+        "[
+    [1, 2, 3, 4, 5],
+]"
+    );
+}
@@ -0,0 +1,98 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+fn check_roundtrip<T>(value: &T, expected: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let ron = ron::to_string(value).unwrap();
+    assert_eq!(ron, expected);
+
+    assert_eq!(&ron::from_str::<T>(&ron).unwrap(), value);
+    assert_eq!(
+        &ron::from_str::<ron::Value>(&ron)
+            .unwrap()
+            .into_rust::<T>()
+            .unwrap(),
+        value
+    );
+}
+
+#[test]
+fn ip_addresses() {
+    check_roundtrip(&Ipv4Addr::new(192, 168, 0, 1), r#""192.168.0.1""#);
+    check_roundtrip(&Ipv6Addr::LOCALHOST, r#""::1""#);
+    check_roundtrip(
+        &Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x42),
+        r#""2001:db8::42""#,
+    );
+    check_roundtrip(&IpAddr::V4(Ipv4Addr::UNSPECIFIED), r#""0.0.0.0""#);
+    check_roundtrip(
+        &IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc0a8, 1)),
+        r#""::ffff:192.168.0.1""#,
+    );
+}
+
+#[test]
+fn socket_addresses() {
+    check_roundtrip(
+        &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080),
+        r#""127.0.0.1:8080""#,
+    );
+    check_roundtrip(
+        &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0),
+        r#""[::1]:443""#,
+    );
+    check_roundtrip(
+        &SocketAddrV6::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 22, 0, 3),
+        r#""[fe80::1%3]:22""#,
+    );
+    check_roundtrip(&SocketAddr::from(([10, 0, 0, 1], 53)), r#""10.0.0.1:53""#);
+    check_roundtrip(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)), r#""[::]:0""#);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Server {
+    bind: Vec<SocketAddr>,
+    allow: Option<IpAddr>,
+}
+
+#[test]
+fn addresses_inside_struct() {
+    let server = Server {
+        bind: vec![
+            SocketAddr::from(([0, 0, 0, 0], 80)),
+            SocketAddr::from((Ipv6Addr::UNSPECIFIED, 80)),
+        ],
+        allow: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0))),
+    };
+
+    let ron = to_string_pretty(
+        &server,
+        PrettyConfig::new()
+            .new_line("\n")
+            .struct_names(true)
+            .compact_arrays(true),
+    )
+    .unwrap();
+    assert_eq!(
+        ron,
+        r#"Server(
+    bind: ["0.0.0.0:80", "[::]:80"],
+    allow: Some("10.0.0.0"),
+)"#
+    );
+    assert_eq!(ron::from_str::<Server>(&ron).unwrap(), server);
+}
+
+#[test]
+fn invalid_addresses() {
+    assert!(ron::from_str::<Ipv4Addr>(r#""256.0.0.1""#).is_err());
+    assert!(ron::from_str::<Ipv6Addr>(r#""::g""#).is_err());
+    // IPv6 socket addresses must use brackets
+    assert!(ron::from_str::<SocketAddr>(r#""::1:8080""#).is_err());
+    // The address must be quoted
+    assert!(ron::from_str::<IpAddr>("127.0.0.1").is_err());
+}
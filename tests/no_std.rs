@@ -0,0 +1,86 @@
+//! Checks that serializing to and deserializing from strings only needs
+//! `alloc`, i.e. that it can be used from a `#![no_std]` crate. Building
+//! ron itself without `std` is checked by `cargo build --no-default-features`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use ron::{
+    ser::PrettyConfig,
+    value::{Map, Number},
+    Options, Value,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    ports: Vec<u16>,
+    limits: BTreeMap<String, f32>,
+    fallback: Option<bool>,
+}
+
+fn config() -> Config {
+    Config {
+        name: String::from("proxy"),
+        ports: vec![80, 443],
+        limits: [(String::from("rate"), 0.5)].into_iter().collect(),
+        fallback: None,
+    }
+}
+
+#[test]
+fn string_roundtrip() {
+    let ron = ron::to_string(&config()).unwrap();
+    assert_eq!(
+        ron,
+        "(name:\"proxy\",ports:[80,443],limits:{\"rate\":0.5},fallback:None)"
+    );
+    assert_eq!(ron::from_str::<Config>(&ron), Ok(config()));
+    assert_eq!(ron::de::from_bytes::<Config>(ron.as_bytes()), Ok(config()));
+
+    let pretty = ron::ser::to_string_pretty(&config(), PrettyConfig::new()).unwrap();
+    assert_eq!(Options::default().from_str::<Config>(&pretty), Ok(config()));
+
+    let mut writer = String::new();
+    ron::ser::to_writer(&mut writer, &config()).unwrap();
+    assert_eq!(writer, ron);
+}
+
+#[test]
+fn value_roundtrip() {
+    let value: Value = ron::from_str("(ports: [80, 443], fallback: Some(true))").unwrap();
+
+    let mut map = Map::new();
+    map.insert(
+        Value::String(String::from("ports")),
+        Value::Seq(vec![
+            Value::Number(Number::U8(80)),
+            Value::Number(Number::U16(443)),
+        ]),
+    );
+    map.insert(
+        Value::String(String::from("fallback")),
+        Value::Option(Some(alloc::boxed::Box::new(Value::Bool(true)))),
+    );
+    assert_eq!(value, Value::Map(map));
+
+    assert_eq!(ron::from_str(&ron::to_string(&value).unwrap()), Ok(value));
+}
+
+#[test]
+fn error_messages() {
+    let err = ron::from_str::<Config>("(name: 42)").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "1:8: Expected a string but found the unsigned integer `42` instead"
+    );
+}
@@ -0,0 +1,79 @@
+use ron::{
+    ser::{to_string_pretty, NonFinitePolicy, PrettyConfig},
+    Error, Value,
+};
+
+#[test]
+fn non_finite_floats_are_emitted_by_default() {
+    for (float, ron) in [
+        (f64::INFINITY, "inf"),
+        (f64::NEG_INFINITY, "-inf"),
+        (f64::NAN, "NaN"),
+        (-f64::NAN, "-NaN"),
+    ] {
+        for ser in [
+            ron::to_string(&float).unwrap(),
+            to_string_pretty(&float, PrettyConfig::new()).unwrap(),
+            to_string_pretty(
+                &float,
+                PrettyConfig::new().non_finite_floats(NonFinitePolicy::Emit),
+            )
+            .unwrap(),
+        ] {
+            assert_eq!(ser, ron);
+
+            let de: f64 = ron::from_str(&ser).unwrap();
+            assert_eq!(de.total_cmp(&float), std::cmp::Ordering::Equal, "{}", ser);
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let float = float as f32;
+
+        let ser = ron::to_string(&float).unwrap();
+        assert_eq!(ser, ron);
+
+        let de: f32 = ron::from_str(&ser).unwrap();
+        assert_eq!(de.total_cmp(&float), std::cmp::Ordering::Equal, "{}", ser);
+    }
+}
+
+#[test]
+fn non_finite_floats_are_rejected() {
+    let config = PrettyConfig::new().non_finite_floats(NonFinitePolicy::Error);
+
+    for (float, ron) in [
+        (f64::INFINITY, "inf"),
+        (f64::NEG_INFINITY, "-inf"),
+        (f64::NAN, "NaN"),
+        (-f64::NAN, "-NaN"),
+    ] {
+        assert_eq!(
+            to_string_pretty(&float, config.clone()),
+            Err(Error::NonFiniteFloat(String::from(ron)))
+        );
+
+        #[allow(clippy::cast_possible_truncation)]
+        let float = float as f32;
+
+        assert_eq!(
+            to_string_pretty(&float, config.clone()),
+            Err(Error::NonFiniteFloat(String::from(ron)))
+        );
+    }
+
+    // nested non-finite floats are rejected as well
+    assert_eq!(
+        to_string_pretty(&vec![1.0, f64::NAN], config.clone()),
+        Err(Error::NonFiniteFloat(String::from("NaN")))
+    );
+    assert_eq!(
+        to_string_pretty(&Value::Number(f32::INFINITY.into()), config.clone()),
+        Err(Error::NonFiniteFloat(String::from("inf")))
+    );
+
+    // finite floats are still serialized
+    assert_eq!(
+        to_string_pretty(&[0.5, -0.0, 1e10], config).as_deref(),
+        Ok("(0.5, -0.0, 10000000000.0)")
+    );
+}
@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use ron::{Options, Value};
+use serde::Deserialize;
+use serde_bytes::ByteBuf;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Doc {
+    title: String,
+    body: String,
+}
+
+fn normalizing() -> Options {
+    Options::default().with_normalize_newlines(true)
+}
+
+/// A document with a multi-line raw string, as saved with CRLF line endings
+const CRLF_DOC: &str = "(\r\n    title: \"notes\",\r\n    body: r#\"first line\r\nsecond \"line\"\r\nthird line\"#,\r\n)\r\n";
+
+#[test]
+fn crlf_is_kept_verbatim_by_default() {
+    assert_eq!(
+        ron::from_str::<Doc>(CRLF_DOC),
+        Ok(Doc {
+            title: String::from("notes"),
+            body: String::from("first line\r\nsecond \"line\"\r\nthird line"),
+        })
+    );
+}
+
+#[test]
+fn crlf_is_normalized_in_raw_strings() {
+    assert_eq!(
+        normalizing().from_str::<Doc>(CRLF_DOC),
+        Ok(Doc {
+            title: String::from("notes"),
+            body: String::from("first line\nsecond \"line\"\nthird line"),
+        })
+    );
+    assert_eq!(
+        normalizing().from_str::<Value>(CRLF_DOC).unwrap(),
+        ron::from_str::<Value>(&CRLF_DOC.replace("\r\n", "\n")).unwrap()
+    );
+}
+
+#[test]
+fn newlines_are_normalized_in_escaped_strings() {
+    assert_eq!(
+        normalizing().from_str::<String>("\"a\r\nb\rc\n\r\nd\""),
+        Ok(String::from("a\nb\nc\n\nd"))
+    );
+    assert_eq!(
+        normalizing().from_str::<String>("\"tab\\t\r\nafter escape\r\n\\u{1F600}\r\""),
+        Ok(String::from("tab\t\nafter escape\n\u{1F600}\n"))
+    );
+
+    // escaped newlines are kept
+    assert_eq!(
+        normalizing().from_str::<String>("\"\\r\\n\r\n\\r\""),
+        Ok(String::from("\r\n\n\r"))
+    );
+
+    // line continuations are unaffected
+    assert_eq!(
+        normalizing().from_str::<String>("\"a\\\r\n    b\""),
+        Ok(String::from("ab"))
+    );
+}
+
+#[test]
+fn newlines_are_normalized_in_byte_strings() {
+    assert_eq!(
+        normalizing().from_str::<ByteBuf>("b\"a\r\nb\\x00\r\""),
+        Ok(ByteBuf::from(b"a\nb\x00\n".to_vec()))
+    );
+    assert_eq!(
+        normalizing().from_str::<ByteBuf>("br\"a\r\nb\""),
+        Ok(ByteBuf::from(b"a\nb".to_vec()))
+    );
+}
+
+#[test]
+fn normalized_strings_are_no_longer_borrowed() {
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    let Borrowed(unix) = normalizing().from_str("Borrowed(\"a\nb\")").unwrap();
+    assert!(matches!(unix, Cow::Borrowed("a\nb")));
+
+    let Borrowed(windows) = normalizing().from_str("Borrowed(\"a\r\nb\")").unwrap();
+    assert!(matches!(windows, Cow::Owned(ref s) if s == "a\nb"));
+
+    assert!(normalizing().from_str::<&str>("\"a\r\nb\"").is_err());
+    assert_eq!(ron::from_str::<&str>("\"a\r\nb\""), Ok("a\r\nb"));
+}
@@ -0,0 +1,130 @@
+use ron::{
+    ser::{to_string_pretty, PrettyConfig, SuffixPolicy},
+    Number, Value,
+};
+
+fn when_ambiguous<T: serde::Serialize>(value: &T) -> String {
+    to_string_pretty(
+        value,
+        PrettyConfig::new().number_suffixes(SuffixPolicy::WhenAmbiguous),
+    )
+    .unwrap()
+}
+
+#[test]
+fn integers_when_ambiguous() {
+    assert_eq!(when_ambiguous(&42_u8), "42");
+    assert_eq!(when_ambiguous(&42_u16), "42u16");
+    assert_eq!(when_ambiguous(&300_u16), "300");
+    assert_eq!(when_ambiguous(&300_u32), "300u32");
+    assert_eq!(when_ambiguous(&u32::MAX), "4294967295");
+    assert_eq!(when_ambiguous(&u64::MAX), "18446744073709551615");
+    assert_eq!(when_ambiguous(&0_u64), "0u64");
+
+    assert_eq!(when_ambiguous(&42_i8), "42i8");
+    assert_eq!(when_ambiguous(&1_i32), "1i32");
+    assert_eq!(when_ambiguous(&-1_i8), "-1");
+    assert_eq!(when_ambiguous(&-128_i8), "-128");
+    assert_eq!(when_ambiguous(&-129_i16), "-129");
+    assert_eq!(when_ambiguous(&-128_i16), "-128i16");
+    assert_eq!(when_ambiguous(&-300_i16), "-300");
+    assert_eq!(when_ambiguous(&-300_i64), "-300i64");
+    assert_eq!(when_ambiguous(&i64::MIN), "-9223372036854775808");
+}
+
+#[test]
+fn floats_when_ambiguous() {
+    assert_eq!(when_ambiguous(&0.5_f32), "0.5");
+    assert_eq!(when_ambiguous(&0.1_f32), "0.1f32");
+    assert_eq!(when_ambiguous(&f32::INFINITY), "inf");
+    assert_eq!(when_ambiguous(&f32::NAN), "NaN");
+
+    assert_eq!(when_ambiguous(&0.1_f64), "0.1");
+    assert_eq!(when_ambiguous(&1.0_f64), "1.0f64");
+    assert_eq!(when_ambiguous(&-0.0_f64), "-0.0f64");
+    assert_eq!(when_ambiguous(&f64::NEG_INFINITY), "-inff64");
+    assert_eq!(when_ambiguous(&f64::NAN), "NaNf64");
+}
+
+#[test]
+fn bool_policies() {
+    assert_eq!(
+        to_string_pretty(&42_u16, PrettyConfig::new().number_suffixes(true)).unwrap(),
+        "42u16"
+    );
+    assert_eq!(
+        to_string_pretty(&42_u16, PrettyConfig::new().number_suffixes(false)).unwrap(),
+        "42"
+    );
+    assert_eq!(
+        to_string_pretty(
+            &42_u8,
+            PrettyConfig::new().number_suffixes(SuffixPolicy::Always)
+        )
+        .unwrap(),
+        "42u8"
+    );
+}
+
+#[test]
+fn value_roundtrip_when_ambiguous() {
+    let numbers = [
+        Number::U8(1),
+        Number::U16(1),
+        Number::U16(300),
+        Number::U32(300),
+        Number::U64(u64::MAX),
+        Number::I8(-1),
+        Number::I8(1),
+        Number::I16(-1),
+        Number::I32(-300),
+        Number::I64(i64::MIN),
+        Number::F32(0.5_f32.into()),
+        Number::F32(0.1_f32.into()),
+        Number::F32(f32::INFINITY.into()),
+        Number::F64(1.0.into()),
+        Number::F64(0.1.into()),
+        Number::F64((-0.0).into()),
+        Number::F64(f64::INFINITY.into()),
+    ];
+
+    let value = Value::Seq(numbers.iter().cloned().map(Value::Number).collect());
+
+    let ron = when_ambiguous(&value);
+    assert_eq!(
+        ron,
+        "[
+    1,
+    1u16,
+    300,
+    300u32,
+    18446744073709551615,
+    -1,
+    1i8,
+    -1i16,
+    -300i32,
+    -9223372036854775808,
+    0.5,
+    0.1f32,
+    inf,
+    1.0f64,
+    0.1,
+    -0.0f64,
+    inff64,
+]"
+    );
+    assert_eq!(ron::from_str::<Value>(&ron).unwrap(), value);
+}
+
+#[test]
+fn deserialize_policy() {
+    let config = |ron: &str| ron::from_str::<PrettyConfig>(ron).unwrap().number_suffixes;
+
+    assert_eq!(config("(number_suffixes: true)"), SuffixPolicy::Always);
+    assert_eq!(config("(number_suffixes: false)"), SuffixPolicy::Never);
+    assert_eq!(
+        config("(number_suffixes: WhenAmbiguous)"),
+        SuffixPolicy::WhenAmbiguous
+    );
+    assert_eq!(config("()"), SuffixPolicy::Never);
+}
@@ -13,21 +13,33 @@ fn test_hex() {
         from_str::<u8>("0x"),
         Err(SpannedError {
             code: Error::ExpectedInteger,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("0x_1"),
         Err(SpannedError {
             code: Error::UnderscoreAtBeginning,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("0xFFF"),
         Err(SpannedError {
             code: Error::IntegerOutOfBounds,
-            position: Position { line: 1, col: 6 },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         })
     );
 }
@@ -42,21 +54,33 @@ fn test_bin() {
         from_str::<u8>("0b"),
         Err(SpannedError {
             code: Error::ExpectedInteger,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("0b_1"),
         Err(SpannedError {
             code: Error::UnderscoreAtBeginning,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("0b111111111"),
         Err(SpannedError {
             code: Error::IntegerOutOfBounds,
-            position: Position { line: 1, col: 12 },
+            position: Position {
+                line: 1,
+                col: 12,
+                offset: 11
+            },
         })
     );
 }
@@ -71,21 +95,33 @@ fn test_oct() {
         from_str::<u8>("0o"),
         Err(SpannedError {
             code: Error::ExpectedInteger,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("0o_1"),
         Err(SpannedError {
             code: Error::UnderscoreAtBeginning,
-            position: Position { line: 1, col: 3 },
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("0o77777"),
         Err(SpannedError {
             code: Error::IntegerOutOfBounds,
-            position: Position { line: 1, col: 8 },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7
+            },
         })
     );
 }
@@ -100,14 +136,22 @@ fn test_dec() {
         from_str::<i8>("-_1"),
         Err(SpannedError {
             code: Error::UnderscoreAtBeginning,
-            position: Position { line: 1, col: 2 },
+            position: Position {
+                line: 1,
+                col: 2,
+                offset: 1
+            },
         })
     );
     assert_eq!(
         from_str::<u8>("256"),
         Err(SpannedError {
             code: Error::IntegerOutOfBounds,
-            position: Position { line: 1, col: 4 },
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
         })
     );
 }
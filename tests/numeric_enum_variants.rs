@@ -0,0 +1,107 @@
+use ron::{Error, Options};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(u8),
+    Line(u8, u8),
+    #[serde(rename = "Rect")]
+    Rectangle {
+        width: u8,
+        height: u8,
+    },
+}
+
+fn options() -> Options {
+    Options::default().with_numeric_enum_variants(true)
+}
+
+#[test]
+fn variants_by_name_and_index() {
+    let shapes = vec![
+        Shape::Empty,
+        Shape::Empty,
+        Shape::Circle(5),
+        Shape::Circle(5),
+        Shape::Line(1, 2),
+        Shape::Line(1, 2),
+        Shape::Rectangle {
+            width: 3,
+            height: 4,
+        },
+        Shape::Rectangle {
+            width: 3,
+            height: 4,
+        },
+    ];
+
+    assert_eq!(
+        options().from_str::<Vec<Shape>>(
+            "[
+                Empty, 0,
+                Circle(5), 1(5),
+                Line(1, 2), 2 (1, 2),
+                Rect(width: 3, height: 4), 3(width: 3, height: 4),
+            ]"
+        ),
+        Ok(shapes)
+    );
+
+    // Serialization is always by name
+    assert_eq!(
+        options().to_string(&Shape::Line(1, 2)).as_deref(),
+        Ok("Line(1,2)")
+    );
+}
+
+#[test]
+fn indices_are_rejected_by_default() {
+    let err = ron::from_str::<Shape>("1(5)").unwrap_err();
+
+    assert_eq!(err.code, Error::SuggestRawIdentifier(String::from("1")));
+    assert_eq!((err.position.line, err.position.col), (1, 1));
+}
+
+#[test]
+fn indices_out_of_range() {
+    let err = options().from_str::<Vec<Shape>>("[0, 4]").unwrap_err();
+
+    assert_eq!(
+        err.code,
+        Error::NoSuchEnumVariantIndex {
+            expected: &["Empty", "Circle", "Line", "Rect"],
+            found: 4,
+            outer: Some(String::from("Shape")),
+        }
+    );
+    assert_eq!((err.position.line, err.position.col), (1, 6));
+
+    assert_eq!(
+        options()
+            .from_str::<Shape>("4")
+            .unwrap_err()
+            .code
+            .to_string(),
+        "Unexpected variant index 4 in enum `Shape`, expected an index from 0 to 3 instead"
+    );
+
+    assert_eq!(
+        options()
+            .from_str::<Shape>("18446744073709551616")
+            .unwrap_err()
+            .code,
+        Error::IntegerOutOfBounds
+    );
+}
+
+#[test]
+fn errors_inside_indexed_variants_name_the_variant() {
+    assert_eq!(
+        options().from_str::<Shape>("3(width: 3)").unwrap_err().code,
+        Error::MissingStructField {
+            field: "height",
+            outer: Some(String::from("Rect")),
+        }
+    );
+}
@@ -0,0 +1,125 @@
+use ron::{extensions::Extensions, ser::PrettyConfig, Options};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Flags {
+    a: Option<bool>,
+    b: Option<Option<bool>>,
+    #[serde(default)]
+    c: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Newtype(Option<bool>);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Wrapper(Flags);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Toggle {
+    Set(Option<bool>),
+    Nested(Newtype),
+    Fields { on: Option<bool> },
+}
+
+const OPTIONS: [Option<Option<bool>>; 4] = [None, Some(None), Some(Some(false)), Some(Some(true))];
+
+/// Every combination of the extensions that affect how an `Option<bool>`
+///  is written
+fn extension_combinations() -> impl Iterator<Item = Extensions> {
+    let relevant = Extensions::IMPLICIT_SOME
+        | Extensions::UNWRAP_NEWTYPES
+        | Extensions::UNWRAP_VARIANT_NEWTYPES
+        | Extensions::EXPLICIT_STRUCT_NAMES;
+
+    (0..=relevant.bits())
+        .filter_map(Extensions::from_bits)
+        .filter(move |extensions| relevant.contains(*extensions))
+}
+
+/// Checks that `value` roundtrips both compactly and prettily, with the
+///  extensions given by the options or written in the document header
+fn check_roundtrip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T) {
+    for extensions in extension_combinations() {
+        let options = Options::default().with_default_extension(extensions);
+
+        let compact = options.to_string(value).unwrap();
+        assert_eq!(
+            options.from_str::<T>(&compact).as_ref(),
+            Ok(value),
+            "{:?}: {}",
+            extensions,
+            compact
+        );
+
+        let pretty =
+            ron::ser::to_string_pretty(value, PrettyConfig::new().extensions(extensions)).unwrap();
+        assert_eq!(
+            ron::from_str::<T>(&pretty).as_ref(),
+            Ok(value),
+            "{:?}: {}",
+            extensions,
+            pretty
+        );
+    }
+}
+
+#[test]
+fn option_bool_roundtrips() {
+    for value in [None, Some(false), Some(true)] {
+        check_roundtrip(&value);
+        check_roundtrip(&Newtype(value));
+        check_roundtrip(&Toggle::Set(value));
+        check_roundtrip(&Toggle::Nested(Newtype(value)));
+        check_roundtrip(&Toggle::Fields { on: value });
+        check_roundtrip(&vec![value, Some(false), None]);
+        check_roundtrip(&(value, value));
+    }
+
+    for b in OPTIONS {
+        check_roundtrip(&b);
+    }
+}
+
+#[test]
+fn option_bool_fields_roundtrip() {
+    for a in [None, Some(false), Some(true)] {
+        for b in OPTIONS {
+            for c in [None, Some(false), Some(true)] {
+                check_roundtrip(&Flags { a, b, c });
+                check_roundtrip(&Wrapper(Flags { a, b, c }));
+            }
+        }
+    }
+}
+
+#[test]
+fn false_is_never_none() {
+    let options = Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+
+    assert_eq!(options.from_str::<Option<bool>>("false"), Ok(Some(false)));
+    assert_eq!(options.from_str::<Option<bool>>("None"), Ok(None));
+    assert_eq!(
+        options.from_str::<Option<Option<bool>>>("false"),
+        Ok(Some(Some(false)))
+    );
+    assert_eq!(
+        options.from_str::<Option<Option<bool>>>("Some(None)"),
+        Ok(Some(None))
+    );
+    assert_eq!(
+        options.from_str::<Flags>("(a: false, b: None)"),
+        Ok(Flags {
+            a: Some(false),
+            b: None,
+            c: None,
+        })
+    );
+
+    // `false` is only implicitly `Some` with the extension
+    assert!(ron::from_str::<Option<bool>>("false").is_err());
+    assert_eq!(
+        ron::from_str::<Option<bool>>("Some(false)"),
+        Ok(Some(false))
+    );
+}
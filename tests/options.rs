@@ -65,6 +65,7 @@ fn single_default_extension() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn reader_io_error() {
     struct Reader<'a> {
         buf: &'a [u8],
@@ -85,14 +86,22 @@ fn reader_io_error() {
         ron::de::from_reader::<Reader, ()>(Reader { buf: b"" }).unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::Io(String::from("oh no")),
-            position: ron::error::Position { line: 1, col: 1 },
+            position: ron::error::Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
         }
     );
     assert_eq!(
         ron::de::from_reader::<Reader, ()>(Reader { buf: b"hello" }).unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::Io(String::from("oh no")),
-            position: ron::error::Position { line: 1, col: 6 },
+            position: ron::error::Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
         }
     );
     assert_eq!(
@@ -102,7 +111,203 @@ fn reader_io_error() {
         .unwrap_err(),
         ron::error::SpannedError {
             code: ron::Error::Io(String::from("oh no")),
-            position: ron::error::Position { line: 2, col: 4 },
+            position: ron::error::Position {
+                line: 2,
+                col: 4,
+                offset: 9
+            },
         }
     );
 }
+
+#[test]
+fn from_str_with_extensions() {
+    let ron = Options::default();
+
+    let (de, extensions): (Struct, _) = ron.from_str_with_extensions("(Some(42),(4.2))").unwrap();
+    assert_eq!(ron.to_string(&de).unwrap(), "(Some(42),(4.2))");
+    assert_eq!(extensions, Extensions::empty());
+
+    let (de, extensions): (Struct, _) = ron
+        .from_str_with_extensions("#![enable(implicit_some, unwrap_newtypes)] (42,4.2)")
+        .unwrap();
+    assert_eq!(ron.to_string(&de).unwrap(), "(Some(42),(4.2))");
+    assert_eq!(
+        extensions,
+        Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES
+    );
+
+    let ron = Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+
+    let (_, extensions): (Struct, _) = ron
+        .from_str_with_extensions("#![enable(unwrap_newtypes)] (42,4.2)")
+        .unwrap();
+    assert_eq!(
+        extensions,
+        Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES
+    );
+
+    assert!(ron
+        .from_str_with_extensions::<Struct>("#![enable(unwrap_newtypes)] (42,(4.2))")
+        .is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Tree {
+    Leaf(u32),
+    Node { children: Vec<Tree> },
+}
+
+fn nested_tree(depth: usize) -> Tree {
+    (0..depth).fold(Tree::Leaf(42), |tree, _| Tree::Node {
+        children: vec![tree],
+    })
+}
+
+#[test]
+fn depth_limit_is_symmetric() {
+    // Every `Node` nests its children two levels deeper, and the leaf's
+    //  data one more level
+    let tree = nested_tree(50);
+    let depth = 2 * 50 + 1;
+
+    let ron = Options::default()
+        .without_depth_limit()
+        .to_string(&tree)
+        .unwrap();
+
+    for limit in [depth - 1, depth, 0, 1] {
+        let options = Options::default().with_depth_limit(limit);
+
+        let ser = options.to_string(&tree);
+        let de = options.from_str::<Tree>(&ron);
+
+        if limit >= depth {
+            assert_eq!(ser.as_deref(), Ok(ron.as_str()));
+            assert_eq!(de.as_ref(), Ok(&tree));
+        } else {
+            assert_eq!(ser, Err(ron::Error::ExceededRecursionLimit));
+            assert_eq!(de.unwrap_err().code, ron::Error::ExceededRecursionLimit);
+        }
+
+        // Pretty serialization is limited in the same way
+        assert_eq!(
+            options.to_string_pretty(&tree, PrettyConfig::new()).is_ok(),
+            limit >= depth
+        );
+    }
+}
+
+#[test]
+fn depth_limit_aliases() {
+    #[allow(deprecated)]
+    let options = Options::default().with_recursion_limit(1);
+    assert_eq!(options.depth_limit, Some(1));
+
+    #[allow(deprecated)]
+    let options = options.without_recursion_limit();
+    assert_eq!(options.depth_limit, None);
+
+    let mut options: Options = ron::from_str("(recursion_limit: Some(3))").unwrap();
+    assert_eq!(options.depth_limit, Some(3));
+
+    #[allow(deprecated)]
+    {
+        assert_eq!(options.recursion_limit(), Some(3));
+        options.set_recursion_limit(Some(4));
+        assert_eq!(options.recursion_limit(), Some(4));
+    }
+    assert_eq!(options.depth_limit, Some(4));
+
+    let options: Options = ron::from_str("(depth_limit: None)").unwrap();
+    assert_eq!(options.depth_limit, None);
+}
+
+#[test]
+fn variants_from_a_base() {
+    let base = Options::default()
+        .with_default_extension(Extensions::IMPLICIT_SOME)
+        .with_bool_aliases(&[("yes", true)]);
+
+    let mut variants = Vec::new();
+    for lenient_unit in [false, true] {
+        let mut options = base.clone();
+        options
+            .set_lenient_unit(lenient_unit)
+            .set_default_extension(Extensions::UNWRAP_NEWTYPES, lenient_unit)
+            .add_bool_aliases(&[("no", false)]);
+        variants.push(options);
+    }
+
+    // The base is left untouched
+    assert_eq!(base.bool_aliases.len(), 1);
+    assert!(!base.lenient_unit);
+
+    for (options, lenient_unit) in variants.iter().zip([false, true]) {
+        assert_eq!(options.lenient_unit, lenient_unit);
+        assert_eq!(
+            options.default_extensions,
+            if lenient_unit {
+                Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES
+            } else {
+                Extensions::IMPLICIT_SOME
+            }
+        );
+        assert_eq!(
+            options.from_str::<(Option<bool>, bool)>("(yes, no)"),
+            Ok((Some(true), false))
+        );
+    }
+
+    let mut options = base.clone();
+    options
+        .set_depth_limit(None)
+        .set_trailing_ignored(Some(|_| true))
+        .set_extension_header(false)
+        .set_default_extension(Extensions::IMPLICIT_SOME, false);
+    assert_eq!(options.depth_limit, None);
+    assert_eq!(options.from_str::<bool>("true // ok\n???"), Ok(true));
+    assert!(options.from_str::<Option<bool>>("yes").is_err());
+
+    options.set_depth_limit(Some(0)).set_trailing_ignored(None);
+    assert_eq!(
+        options.from_str::<Vec<bool>>("[true]").unwrap_err().code,
+        ron::Error::ExceededRecursionLimit
+    );
+    assert!(options.from_str::<bool>("true ???").is_err());
+}
+
+#[test]
+fn builders_match_setters() {
+    let built = Options::default()
+        .with_default_extension(Extensions::all())
+        .without_default_extension(Extensions::IMPLICIT_SOME)
+        .with_depth_limit(7)
+        .with_allow_shebang(true)
+        .with_strict_header(true)
+        .with_lenient_unit(true)
+        .with_precount_collections(true)
+        .without_extension_header_scan()
+        .with_bool_aliases(&[("on", true)])
+        .with_field_defaults(ron::Value::Unit);
+
+    let mut set = Options::default();
+    set.set_default_extension(Extensions::all(), true)
+        .set_default_extension(Extensions::IMPLICIT_SOME, false)
+        .set_depth_limit(Some(7))
+        .set_allow_shebang(true)
+        .set_strict_header(true)
+        .set_lenient_unit(true)
+        .set_precount_collections(true)
+        .set_extension_header(false)
+        .add_bool_aliases(&[("on", true)])
+        .set_field_defaults(Some(ron::Value::Unit));
+
+    assert_eq!(format!("{:?}", built), format!("{:?}", set));
+
+    let config = PrettyConfig::new().depth_limit(2).compact_arrays(true);
+    let mut tweaked = config.clone();
+    tweaked.depth_limit = 3;
+    assert_eq!(tweaked, config.clone().depth_limit(3));
+    assert_ne!(tweaked, config);
+}
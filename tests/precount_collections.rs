@@ -0,0 +1,266 @@
+use std::{collections::HashMap, fmt, marker::PhantomData};
+
+use ron::Options;
+use serde::{
+    de::{MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+/// Records the size hint before every element of a sequence
+#[derive(Debug, PartialEq)]
+struct SeqHints(Vec<Option<usize>>);
+
+impl<'de> Deserialize<'de> for SeqHints {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SeqHintsVisitor;
+
+        impl<'de> Visitor<'de> for SeqHintsVisitor {
+            type Value = SeqHints;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut hints = vec![seq.size_hint()];
+
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    hints.push(seq.size_hint());
+                }
+
+                Ok(SeqHints(hints))
+            }
+        }
+
+        deserializer.deserialize_seq(SeqHintsVisitor)
+    }
+}
+
+/// Records the size hint before every entry of a map
+#[derive(Debug, PartialEq)]
+struct MapHints(Vec<Option<usize>>);
+
+impl<'de> Deserialize<'de> for MapHints {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MapHintsVisitor;
+
+        impl<'de> Visitor<'de> for MapHintsVisitor {
+            type Value = MapHints;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut hints = vec![map.size_hint()];
+
+                while map
+                    .next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+                    .is_some()
+                {
+                    hints.push(map.size_hint());
+                }
+
+                Ok(MapHints(hints))
+            }
+        }
+
+        deserializer.deserialize_map(MapHintsVisitor)
+    }
+}
+
+/// Collects a sequence into a `Vec` preallocated from the size hint and
+///  counts how often it had to grow
+#[derive(Debug)]
+struct GrowingVec<T> {
+    values: Vec<T>,
+    reallocations: usize,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for GrowingVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GrowingVecVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for GrowingVecVisitor<T> {
+            type Value = GrowingVec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                let mut reallocations = 0;
+
+                while let Some(value) = seq.next_element()? {
+                    let capacity = values.capacity();
+                    values.push(value);
+                    reallocations += usize::from(values.capacity() != capacity);
+                }
+
+                Ok(GrowingVec {
+                    values,
+                    reallocations,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(GrowingVecVisitor(PhantomData))
+    }
+}
+
+/// Collects a map into a `HashMap` preallocated from the size hint and
+///  counts how often it had to grow
+#[derive(Debug)]
+struct GrowingMap {
+    entries: HashMap<String, u32>,
+    reallocations: usize,
+}
+
+impl<'de> Deserialize<'de> for GrowingMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GrowingMapVisitor;
+
+        impl<'de> Visitor<'de> for GrowingMapVisitor {
+            type Value = GrowingMap;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+                let mut reallocations = 0;
+
+                while let Some((key, value)) = map.next_entry()? {
+                    let capacity = entries.capacity();
+                    entries.insert(key, value);
+                    reallocations += usize::from(entries.capacity() != capacity);
+                }
+
+                Ok(GrowingMap {
+                    entries,
+                    reallocations,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(GrowingMapVisitor)
+    }
+}
+
+fn precounted() -> Options {
+    Options::default().with_precount_collections(true)
+}
+
+#[test]
+fn no_size_hints_by_default() {
+    assert_eq!(
+        ron::from_str::<SeqHints>("[1, 2]"),
+        Ok(SeqHints(vec![None, None, None]))
+    );
+    assert_eq!(
+        ron::from_str::<MapHints>("{1: 2}"),
+        Ok(MapHints(vec![None, None]))
+    );
+}
+
+#[test]
+fn seq_size_hints_count_down() {
+    assert_eq!(
+        precounted().from_str::<SeqHints>("[]"),
+        Ok(SeqHints(vec![Some(0)]))
+    );
+    assert_eq!(
+        precounted().from_str::<SeqHints>("[1, 2, 3]"),
+        Ok(SeqHints(vec![Some(3), Some(2), Some(1), Some(0)]))
+    );
+    assert_eq!(
+        precounted().from_str::<SeqHints>("[1, 2, 3,]"),
+        Ok(SeqHints(vec![Some(3), Some(2), Some(1), Some(0)]))
+    );
+}
+
+#[test]
+fn map_size_hints_count_down() {
+    assert_eq!(
+        precounted().from_str::<MapHints>("{}"),
+        Ok(MapHints(vec![Some(0)]))
+    );
+    assert_eq!(
+        precounted().from_str::<MapHints>("{\"a\": 1, \"b\": 2,}"),
+        Ok(MapHints(vec![Some(2), Some(1), Some(0)]))
+    );
+}
+
+#[test]
+fn only_top_level_entries_are_counted() {
+    assert_eq!(
+        precounted()
+            .from_str::<SeqHints>("[[1, 2], (3, 4), {5: 6, 7: 8}, Some((a: 9, b: [10]))]")
+            .map(|hints| hints.0[0]),
+        Ok(Some(4))
+    );
+    assert_eq!(
+        precounted()
+            .from_str::<MapHints>("{[1, 2]: (3, 4), \"x\": {5: [6, 7]}}")
+            .map(|hints| hints.0[0]),
+        Ok(Some(2))
+    );
+}
+
+#[test]
+fn strings_chars_and_comments_are_skipped() {
+    assert_eq!(
+        precounted()
+            .from_str::<SeqHints>(
+                r##"[
+                    "a, ]b", "\", [",
+                    r#"raw "], ["#, br"bytes, ]", b"\"], ",
+                    ',', '\'', ']', b',',
+                    // a, comment ]
+                    /* a, /* nested, ] */ comment ] */
+                    r#ident, last,
+                ]"##
+            )
+            .map(|hints| hints.0[0]),
+        Ok(Some(11))
+    );
+}
+
+#[test]
+fn precounted_vec_does_not_reallocate() {
+    let ron = format!(
+        "[{}]",
+        (0..1000)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let default = ron::from_str::<GrowingVec<u32>>(&ron).unwrap();
+    let precounted = precounted().from_str::<GrowingVec<u32>>(&ron).unwrap();
+
+    assert_eq!(default.values, precounted.values);
+    assert_eq!(precounted.values.capacity(), 1000);
+    assert_eq!(precounted.reallocations, 0);
+    assert!(default.reallocations > 0);
+}
+
+#[test]
+fn precounted_hash_map_does_not_reallocate() {
+    let ron = format!(
+        "{{{}}}",
+        (0..1000)
+            .map(|i| format!("\"key {}\": {}", i, i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let default = ron::from_str::<GrowingMap>(&ron).unwrap();
+    let precounted = precounted().from_str::<GrowingMap>(&ron).unwrap();
+
+    assert_eq!(default.entries, precounted.entries);
+    assert_eq!(precounted.reallocations, 0);
+    assert!(default.reallocations > 0);
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use ron::ser::{to_string_pretty, PrettyConfig};
+
+fn check_raw(value: &str, expected: &str) {
+    let config = PrettyConfig::new().prefer_raw_strings(true);
+
+    let ser = to_string_pretty(value, config.clone()).unwrap();
+    assert_eq!(ser, expected);
+    assert_eq!(ron::from_str::<String>(&ser).as_deref(), Ok(value));
+
+    // escaping does not matter once raw strings are preferred
+    let ser = to_string_pretty(value, config.escape_strings(false)).unwrap();
+    assert_eq!(ser, expected);
+}
+
+#[test]
+fn minimal_number_of_hashes() {
+    check_raw("", "r\"\"");
+    check_raw("C:\\Users\\ron", "r\"C:\\Users\\ron\"");
+    check_raw("^\\d+\\.\\d*$", "r\"^\\d+\\.\\d*$\"");
+    check_raw(
+        "#tag ## not after a quote",
+        "r\"#tag ## not after a quote\"",
+    );
+    check_raw("line\nbreak\ttab", "r\"line\nbreak\ttab\"");
+    check_raw("say \"hi\"", "r#\"say \"hi\"\"#");
+    check_raw("\"", "r#\"\"\"#");
+    check_raw("\"#", "r##\"\"#\"##");
+    check_raw("a\"###b", "r####\"a\"###b\"####");
+    check_raw(
+        "\"# and \"### and \"##",
+        "r####\"\"# and \"### and \"##\"####",
+    );
+    check_raw("### \"", "r#\"### \"\"#");
+    check_raw("ends with \"##", "r###\"ends with \"##\"###");
+}
+
+#[test]
+fn raw_strings_in_collections() {
+    let mut map = BTreeMap::new();
+    map.insert("path", vec!["C:\\ron", "\"quoted\""]);
+
+    let ser = to_string_pretty(
+        &map,
+        PrettyConfig::new()
+            .new_line("\n")
+            .compact_arrays(true)
+            .prefer_raw_strings(true),
+    )
+    .unwrap();
+    assert_eq!(
+        ser,
+        "{
+    r\"path\": [r\"C:\\ron\", r#\"\"quoted\"\"#],
+}"
+    );
+    assert_eq!(ron::from_str(&ser), Ok(map));
+}
+
+#[test]
+fn strings_are_escaped_by_default() {
+    assert_eq!(
+        to_string_pretty("C:\\ron", PrettyConfig::new()).unwrap(),
+        "\"C:\\\\ron\""
+    );
+    assert_eq!(
+        to_string_pretty("C:\\ron", PrettyConfig::new().escape_strings(false)).unwrap(),
+        "r\"C:\\ron\""
+    );
+}
@@ -0,0 +1,94 @@
+use ron::{
+    extensions::Extensions,
+    ser::{BraceStyle, NonFinitePolicy, PrettyConfig, SuffixPolicy, UnicodeEscape},
+    Error,
+};
+
+fn custom() -> PrettyConfig {
+    PrettyConfig::new()
+        .depth_limit(3)
+        .new_line("\r\n")
+        .indentor("\t")
+        .separator("")
+        .struct_names(true)
+        .separate_tuple_members(true)
+        .separate_tuple_struct_members(false)
+        .enumerate_arrays(true)
+        .skip_none_fields(true)
+        .skip_empty_collections(true)
+        .utf8_bytes_as_string(true)
+        .sort_seqs(true)
+        .extensions(Extensions::IMPLICIT_SOME | Extensions::UNWRAP_VARIANT_NEWTYPES)
+        .compact_arrays(true)
+        .escape_strings(false)
+        .unicode_escape_style(UnicodeEscape::Fixed)
+        .prefer_raw_strings(true)
+        .brace_style(BraceStyle::NextLine)
+        .number_suffixes(SuffixPolicy::Always)
+        .digit_grouping(Some(3))
+        .max_width(80)
+        .quote_keys(true)
+        .header_comment(Some(String::from("schema v3\nmy-tool")))
+        .non_finite_floats(NonFinitePolicy::Error)
+}
+
+#[test]
+fn roundtrip_pretty_config() {
+    for config in [PrettyConfig::new(), custom()] {
+        let compact = ron::to_string(&config).unwrap();
+        assert_eq!(ron::from_str::<PrettyConfig>(&compact), Ok(config.clone()));
+
+        let pretty = ron::ser::to_string_pretty(&config, config.clone()).unwrap();
+        assert_eq!(ron::from_str::<PrettyConfig>(&pretty), Ok(config));
+    }
+}
+
+#[test]
+fn load_pretty_config() {
+    let config: PrettyConfig = ron::from_str(
+        "// format settings
+        (
+            new_line: \"\\n\",
+            indentor: \"  \",
+            compact_arrays: true,
+            extensions: (\"UNWRAP_NEWTYPES|IMPLICIT_SOME\"),
+        )",
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        PrettyConfig::new()
+            .new_line("\n")
+            .indentor("  ")
+            .compact_arrays(true)
+            .extensions(Extensions::UNWRAP_NEWTYPES | Extensions::IMPLICIT_SOME)
+    );
+
+    let ser = ron::ser::to_string_pretty(&(Some(1), vec![2, 3]), config).unwrap();
+    assert_eq!(
+        ser,
+        "#![enable(unwrap_newtypes)]
+#![enable(implicit_some)]
+(1, [2, 3])"
+    );
+
+    let config: PrettyConfig = ron::from_str("(extensions: (\"\"))").unwrap();
+    assert_eq!(config.extensions, Extensions::empty());
+}
+
+#[test]
+fn reject_invalid_pretty_config() {
+    assert_eq!(
+        ron::from_str::<PrettyConfig>("(indentor: \"--\")")
+            .unwrap_err()
+            .code,
+        Error::InvalidValueForType {
+            expected: String::from("a string of whitespace characters"),
+            found: String::from("the string \"--\""),
+        }
+    );
+    assert!(ron::from_str::<PrettyConfig>("(new_line: \";\")").is_err());
+    assert!(ron::from_str::<PrettyConfig>("(separator: \",\")").is_err());
+    assert!(ron::from_str::<PrettyConfig>("(extensions: (\"NOT_AN_EXTENSION\"))").is_err());
+}
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use ron::{
+    ser::{to_string_pretty, PrettyConfig},
+    Value,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    r#type: Kind,
+    labels: BTreeMap<String, u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Kind {
+    Server { port: u16 },
+}
+
+fn config() -> Config {
+    Config {
+        name: String::from("x"),
+        r#type: Kind::Server { port: 8080 },
+        labels: vec![(String::from("a"), 1)].into_iter().collect(),
+    }
+}
+
+fn check_quote_keys<T>(value: &T, config: PrettyConfig, expected: &str)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+{
+    let ron = to_string_pretty(value, config.quote_keys(true)).unwrap();
+    assert_eq!(ron, expected);
+
+    assert_eq!(&ron::from_str::<T>(&ron).unwrap(), value);
+}
+
+#[test]
+fn quoted_struct_fields() {
+    check_quote_keys(
+        &config(),
+        PrettyConfig::new().new_line("\n"),
+        r#"(
+    "name": "x",
+    "type": Server(
+        "port": 8080,
+    ),
+    "labels": {
+        "a": 1,
+    },
+)"#,
+    );
+    check_quote_keys(
+        &config(),
+        PrettyConfig::new()
+            .compact_structs(true)
+            .compact_maps(true)
+            .struct_names(true),
+        r#"Config("name": "x", "type": Server("port": 8080), "labels": {"a": 1})"#,
+    );
+}
+
+#[test]
+fn deserialize_quoted_struct_fields() {
+    assert_eq!(
+        ron::from_str::<Config>(r#"( "name" : "x", type: Server("port": 8080), labels: {"a": 1})"#),
+        Ok(config()),
+    );
+
+    // Quoted keys mean a struct, not a tuple
+    assert_eq!(
+        ron::from_str::<Value>(r#"Config("name": "x")"#),
+        ron::from_str::<Value>(r#"{"name": "x"}"#),
+    );
+    assert_eq!(
+        ron::from_str::<Value>(r#"("name", "x")"#),
+        ron::from_str::<Value>(r#"["name", "x"]"#),
+    );
+}
+
+#[test]
+fn unquoted_struct_fields_by_default() {
+    assert_eq!(
+        ron::ser::to_string(&config()).unwrap(),
+        r#"(name:"x",type:Server(port:8080),labels:{"a":1})"#,
+    );
+}
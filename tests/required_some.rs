@@ -0,0 +1,94 @@
+use ron::{extensions::Extensions, Error, Options};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "ron::required_some")]
+    port: Option<u16>,
+    host: Option<String>,
+}
+
+fn implicit_some() -> Options {
+    Options::default().with_default_extension(Extensions::IMPLICIT_SOME)
+}
+
+#[test]
+fn present_values_are_accepted() {
+    let expected = Config {
+        port: Some(8080),
+        host: None,
+    };
+
+    assert_eq!(ron::from_str("(port: Some(8080))"), Ok(expected));
+    assert_eq!(
+        implicit_some().from_str("(port: 8080)"),
+        Ok(Config {
+            port: Some(8080),
+            host: None,
+        })
+    );
+    assert_eq!(
+        implicit_some().from_str("(port: Some(8080))"),
+        Ok(Config {
+            port: Some(8080),
+            host: None,
+        })
+    );
+}
+
+#[test]
+fn omitted_values_are_rejected() {
+    for options in [Options::default(), implicit_some()] {
+        assert_eq!(
+            options
+                .from_str::<Config>("(host: Some(\"ron\"))")
+                .unwrap_err()
+                .code,
+            Error::MissingStructField {
+                field: "port",
+                outer: Some(String::from("Config")),
+            }
+        );
+
+        assert_eq!(
+            options
+                .from_str::<Config>("(port: None, host: None)")
+                .unwrap_err()
+                .code,
+            Error::InvalidValueForType {
+                expected: String::from("a value that is not `None`"),
+                found: String::from("`None`"),
+            }
+        );
+    }
+}
+
+#[test]
+fn required_values_roundtrip() {
+    let config = Config {
+        port: Some(8080),
+        host: None,
+    };
+
+    let ser = ron::to_string(&config).unwrap();
+    assert_eq!(ser, "(port:Some(8080),host:None)");
+    assert_eq!(ron::from_str(&ser), Ok(config));
+
+    let ser = implicit_some()
+        .to_string(&Config {
+            port: Some(1),
+            host: Some(String::from("ron")),
+        })
+        .unwrap();
+    assert_eq!(ser, "(port:1,host:\"ron\")");
+
+    assert_eq!(
+        ron::to_string(&Config {
+            port: None,
+            host: None,
+        }),
+        Err(Error::Message(String::from(
+            "a required `Option` must not be `None`"
+        )))
+    );
+}
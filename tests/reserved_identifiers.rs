@@ -0,0 +1,109 @@
+use ron::{ser::PrettyConfig, Value};
+use serde::{
+    ser::{SerializeStruct, SerializeStructVariant},
+    Serialize, Serializer,
+};
+
+const RESERVED: &[&str] = &[
+    "true", "false", "Some", "None", "inf", "inff32", "inff64", "NaN", "NaNf32", "NaNf64",
+];
+
+/// A struct with a single field named `name`
+struct Field(&'static str);
+
+impl Serialize for Field {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Field", 1)?;
+        s.serialize_field(self.0, &42)?;
+        s.end()
+    }
+}
+
+/// A unit struct named `name`
+struct UnitStruct(&'static str);
+
+impl Serialize for UnitStruct {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct(self.0)
+    }
+}
+
+/// Unit, newtype, and struct enum variants named `name`
+enum Variant {
+    Unit(&'static str),
+    Newtype(&'static str),
+    Struct(&'static str),
+}
+
+impl Serialize for Variant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Variant::Unit(name) => serializer.serialize_unit_variant("Variant", 0, name),
+            Variant::Newtype(name) => serializer.serialize_newtype_variant("Variant", 1, name, &42),
+            Variant::Struct(name) => {
+                let mut s = serializer.serialize_struct_variant("Variant", 2, name, 1)?;
+                s.serialize_field(name, &42)?;
+                s.end()
+            }
+        }
+    }
+}
+
+#[test]
+fn reserved_field_names_are_raw_identifiers() {
+    for name in RESERVED {
+        assert_eq!(
+            ron::to_string(&Field(name)).unwrap(),
+            format!("(r#{}:42)", name)
+        );
+    }
+}
+
+#[test]
+fn reserved_variant_names_are_raw_identifiers() {
+    for name in RESERVED {
+        assert_eq!(
+            ron::to_string(&Variant::Unit(name)).unwrap(),
+            format!("r#{}", name)
+        );
+        assert_eq!(
+            ron::to_string(&Variant::Newtype(name)).unwrap(),
+            format!("r#{}(42)", name)
+        );
+        assert_eq!(
+            ron::to_string(&Variant::Struct(name)).unwrap(),
+            format!("r#{0}(r#{0}:42)", name)
+        );
+    }
+}
+
+#[test]
+fn reserved_struct_names_are_raw_identifiers() {
+    for name in RESERVED {
+        assert_eq!(
+            ron::ser::to_string_pretty(&UnitStruct(name), PrettyConfig::new().struct_names(true))
+                .unwrap(),
+            format!("r#{}", name)
+        );
+    }
+}
+
+#[test]
+fn reserved_field_names_roundtrip() {
+    for name in RESERVED {
+        let expected = Value::Map(
+            [(Value::String(String::from(*name)), Value::from(42_u8))]
+                .into_iter()
+                .collect(),
+        );
+
+        let ron = ron::to_string(&Field(name)).unwrap();
+        assert_eq!(ron::from_str::<Value>(&ron), Ok(expected.clone()));
+
+        // Reserved field names are still accepted without the `r#` prefix
+        assert_eq!(
+            ron::from_str::<Value>(&format!("({}: 42)", name)),
+            Ok(expected)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use ron::{extensions::Extensions, ser::PrettyConfig, Options};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Struct {
+    a: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Newtype(u8);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tuple(u8, u8);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Unit;
+
+const EXTENSIONS: &[Extensions] = &[
+    Extensions::empty(),
+    Extensions::UNWRAP_NEWTYPES,
+    Extensions::IMPLICIT_SOME,
+    Extensions::UNWRAP_VARIANT_NEWTYPES,
+    Extensions::all(),
+];
+
+/// Checks that `value` roundtrips with every combination of extensions
+fn check_roundtrip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T) {
+    for extensions in EXTENSIONS {
+        let config = PrettyConfig::new().extensions(*extensions);
+
+        let compact = ron::Options::default()
+            .with_default_extension(*extensions)
+            .to_string(value)
+            .unwrap();
+        let pretty = ron::ser::to_string_pretty(value, config).unwrap();
+
+        assert_eq!(
+            Options::default()
+                .with_default_extension(*extensions)
+                .from_str::<T>(&compact)
+                .as_ref(),
+            Ok(value),
+            "{}",
+            compact
+        );
+        assert_eq!(
+            ron::from_str::<T>(&pretty).as_ref(),
+            Ok(value),
+            "{}",
+            pretty
+        );
+    }
+}
+
+/// Checks that all `ron` variants deserialize to `value`, both with and
+///  without the `unwrap_variant_newtypes` extension
+fn check_forms<T: DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T, ron: &[&str]) {
+    for extensions in [Extensions::empty(), Extensions::UNWRAP_VARIANT_NEWTYPES] {
+        let options = Options::default().with_default_extension(extensions);
+
+        for ron in ron {
+            assert_eq!(options.from_str::<T>(ron).as_ref(), Ok(value), "{}", ron);
+        }
+    }
+}
+
+#[test]
+fn result_is_written_as_variants() {
+    assert_eq!(ron::to_string(&Ok::<u8, ()>(1)).unwrap(), "Ok(1)");
+    assert_eq!(
+        ron::to_string(&Err::<(), _>(String::from("e"))).unwrap(),
+        "Err(\"e\")"
+    );
+    assert_eq!(
+        ron::to_string(&Ok::<_, ()>(Struct { a: 1 })).unwrap(),
+        "Ok((a:1))"
+    );
+    assert_eq!(
+        ron::Options::default()
+            .with_default_extension(Extensions::UNWRAP_VARIANT_NEWTYPES)
+            .to_string(&Ok::<_, ()>(Struct { a: 1 }))
+            .unwrap(),
+        "Ok(a:1)"
+    );
+}
+
+#[test]
+fn result_roundtrips() {
+    check_roundtrip(&Ok::<u8, String>(1));
+    check_roundtrip(&Err::<u8, String>(String::from("e")));
+    check_roundtrip(&Ok::<(), ()>(()));
+    check_roundtrip(&Err::<(), Unit>(Unit));
+    check_roundtrip(&Ok::<Struct, Struct>(Struct { a: 1 }));
+    check_roundtrip(&Err::<Struct, Struct>(Struct { a: 2 }));
+    check_roundtrip(&Ok::<Newtype, ()>(Newtype(1)));
+    check_roundtrip(&Ok::<Tuple, ()>(Tuple(1, 2)));
+    check_roundtrip(&Ok::<(u8, u8), ()>((1, 2)));
+    check_roundtrip(&Ok::<Option<Struct>, ()>(Some(Struct { a: 1 })));
+    check_roundtrip(&Ok::<Option<Struct>, ()>(None));
+    check_roundtrip(&vec![Ok::<Struct, u8>(Struct { a: 1 }), Err(2)]);
+}
+
+#[test]
+fn nested_result_roundtrips() {
+    check_roundtrip(&Ok::<Result<u8, String>, String>(Ok(1)));
+    check_roundtrip(&Ok::<Result<u8, String>, String>(Err(String::from("e"))));
+    check_roundtrip(&Err::<Result<u8, u8>, Result<u8, u8>>(Ok(1)));
+    check_roundtrip(&Ok::<Result<Struct, Unit>, ()>(Ok(Struct { a: 1 })));
+    check_roundtrip(&Ok::<Result<Struct, Unit>, ()>(Err(Unit)));
+    check_roundtrip(&Ok::<Result<Result<Struct, u8>, u8>, u8>(Ok(Ok(Struct {
+        a: 1,
+    }))));
+    check_roundtrip(&Ok::<Option<Result<Tuple, ()>>, ()>(Some(Ok(Tuple(1, 2)))));
+}
+
+#[test]
+fn wrapped_and_unwrapped_struct_variants() {
+    check_forms(
+        &Ok::<Struct, ()>(Struct { a: 1 }),
+        &["Ok((a: 1))", "Ok(Struct(a: 1))", "Ok( Struct (a: 1, ), )"],
+    );
+    check_forms(
+        &Ok::<Result<Struct, ()>, ()>(Ok(Struct { a: 1 })),
+        &["Ok(Ok((a: 1)))", "Ok(Ok(Struct(a: 1)))"],
+    );
+    check_forms(&Ok::<(), ()>(()), &["Ok(())"]);
+    check_forms(&Err::<(), Unit>(Unit), &["Err(Unit)", "Err(())"]);
+    check_forms(
+        &Some(Struct { a: 1 }),
+        &["Some((a: 1))", "Some(Struct(a: 1))"],
+    );
+
+    let unwrapped = Options::default().with_default_extension(Extensions::UNWRAP_VARIANT_NEWTYPES);
+
+    assert_eq!(
+        unwrapped.from_str("Ok(a: 1)"),
+        Ok(Ok::<Struct, ()>(Struct { a: 1 }))
+    );
+    assert_eq!(unwrapped.from_str("Ok()"), Ok(Ok::<(), ()>(())));
+    assert_eq!(unwrapped.from_str("Err()"), Ok(Err::<(), Unit>(Unit)));
+    assert_eq!(
+        unwrapped.from_str("Ok(Ok(a: 1))"),
+        Ok(Ok::<Result<Struct, ()>, ()>(Ok(Struct { a: 1 })))
+    );
+}
@@ -0,0 +1,157 @@
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point(i32, i32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Meters(u32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Line(Point, Point),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Scene {
+    offset: (i32, i32),
+    origin: Point,
+    height: Meters,
+    shape: Shape,
+}
+
+fn scene() -> Scene {
+    Scene {
+        offset: (1, 2),
+        origin: Point(3, 4),
+        height: Meters(5),
+        shape: Shape::Line(Point(6, 7), Point(8, 9)),
+    }
+}
+
+fn config() -> PrettyConfig {
+    PrettyConfig::new().new_line("\n")
+}
+
+#[test]
+fn tuple_structs_follow_tuples_by_default() {
+    let ser = to_string_pretty(&scene(), config().separate_tuple_members(true)).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    offset: (
+        1,
+        2,
+    ),
+    origin: (
+        3,
+        4,
+    ),
+    height: (5),
+    shape: Line(
+        (
+            6,
+            7,
+        ),
+        (
+            8,
+            9,
+        ),
+    ),
+)"
+    );
+    assert_eq!(ron::from_str::<Scene>(&ser), Ok(scene()));
+}
+
+#[test]
+fn expand_tuple_structs_but_not_tuples() {
+    let ser = to_string_pretty(
+        &scene(),
+        config()
+            .struct_names(true)
+            .separate_tuple_struct_members(true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ser,
+        "Scene(
+    offset: (1, 2),
+    origin: Point(
+        3,
+        4,
+    ),
+    height: Meters(
+        5,
+    ),
+    shape: Line(
+        Point(
+            6,
+            7,
+        ),
+        Point(
+            8,
+            9,
+        ),
+    ),
+)"
+    );
+    assert_eq!(ron::from_str::<Scene>(&ser), Ok(scene()));
+}
+
+#[test]
+fn expand_tuples_but_not_tuple_structs() {
+    let ser = to_string_pretty(
+        &scene(),
+        config()
+            .struct_names(true)
+            .separate_tuple_members(true)
+            .separate_tuple_struct_members(false),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ser,
+        "Scene(
+    offset: (
+        1,
+        2,
+    ),
+    origin: Point(3, 4),
+    height: Meters(5),
+    shape: Line(Point(6, 7), Point(8, 9)),
+)"
+    );
+    assert_eq!(ron::from_str::<Scene>(&ser), Ok(scene()));
+}
+
+#[test]
+fn tuple_struct_members_within_depth_limit() {
+    let config = config()
+        .depth_limit(1)
+        .separate_tuple_struct_members(true)
+        .enumerate_tuples(true);
+
+    let ser = to_string_pretty(&scene(), config.clone()).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    offset: (1, 2),
+    origin: (3, 4),
+    height: (5),
+    shape: Line((6, 7), (8, 9)),
+)"
+    );
+    assert_eq!(ron::from_str::<Scene>(&ser), Ok(scene()));
+
+    let ser = to_string_pretty(&Point(1, 2), config).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    /*0:*/ 1,
+    /*1:*/ 2,
+)"
+    );
+    assert_eq!(ron::from_str::<Point>(&ser), Ok(Point(1, 2)));
+}
@@ -0,0 +1,192 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn large_sequence() {
+    let ron = format!(
+        "[{}]",
+        (0..10_000)
+            .map(|i| format!("(x: {}, y: {})", i, -i))
+            .collect::<Vec<_>>()
+            .join(",\n")
+    );
+
+    let mut count = 0;
+
+    for (i, point) in Options::default()
+        .deserialize_seq_iter::<Point>(&ron)
+        .enumerate()
+    {
+        let i = i32::try_from(i).unwrap();
+        assert_eq!(point, Ok(Point { x: i, y: -i }));
+        count += 1;
+    }
+
+    assert_eq!(count, 10_000);
+}
+
+#[test]
+fn empty_and_trailing_comma() {
+    let options = Options::default();
+
+    assert_eq!(options.deserialize_seq_iter::<u8>("[]").count(), 0);
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>(" /* empty */ [ ] // done\n")
+            .count(),
+        0
+    );
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>("[1, 2, 3,]")
+            .collect::<Result<Vec<_>, _>>(),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<Option<u8>>("#![enable(implicit_some)] [1, None]")
+            .collect::<Result<Vec<_>, _>>(),
+        Ok(vec![Some(1), None])
+    );
+}
+
+#[test]
+fn error_stops_iteration() {
+    let mut iter = Options::default().deserialize_seq_iter::<u8>("[1, 2, true, 4]");
+
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(
+        iter.next(),
+        Some(Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from("u8"),
+                found: String::from("the boolean `true`"),
+            },
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7
+            },
+        }))
+    );
+    assert_eq!(iter.next(), None);
+
+    let mut iter = Options::default().deserialize_seq_iter::<u8>("[1 2]");
+
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(
+        iter.next(),
+        Some(Err(SpannedError {
+            code: Error::ExpectedComma,
+            position: Position {
+                line: 1,
+                col: 4,
+                offset: 3
+            },
+        }))
+    );
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn invalid_sequence() {
+    let options = Options::default();
+
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>("(1, 2)")
+            .collect::<Vec<_>>(),
+        vec![Err(SpannedError {
+            code: Error::ExpectedArray,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
+        })]
+    );
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>("[1, 2")
+            .collect::<Vec<_>>(),
+        vec![
+            Ok(1),
+            Ok(2),
+            Err(SpannedError {
+                code: Error::ExpectedComma,
+                position: Position {
+                    line: 1,
+                    col: 6,
+                    offset: 5
+                },
+            })
+        ]
+    );
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>("[1] 2")
+            .collect::<Vec<_>>(),
+        vec![
+            Ok(1),
+            Err(SpannedError {
+                code: Error::TrailingCharacters {
+                    found: String::from("2")
+                },
+                position: Position {
+                    line: 1,
+                    col: 5,
+                    offset: 4
+                },
+            })
+        ]
+    );
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>("#![enable(unknown)] [1]")
+            .collect::<Vec<_>>(),
+        vec![Err(SpannedError {
+            code: Error::NoSuchExtension(String::from("unknown")),
+            position: Position {
+                line: 1,
+                col: 18,
+                offset: 17
+            },
+        })]
+    );
+}
+
+#[test]
+fn recursion_limit() {
+    // The elements are nested inside the sequence as usual
+    let options = Options::default().with_depth_limit(1);
+
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<u8>("[1, 2]")
+            .collect::<Result<Vec<_>, _>>(),
+        Ok(vec![1, 2])
+    );
+    assert_eq!(
+        options
+            .deserialize_seq_iter::<Vec<u8>>("[[1]]")
+            .collect::<Vec<_>>(),
+        vec![Err(SpannedError {
+            code: Error::ExceededRecursionLimit,
+            position: Position {
+                line: 1,
+                col: 3,
+                offset: 2
+            },
+        })]
+    );
+}
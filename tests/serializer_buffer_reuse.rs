@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+use ron::{
+    extensions::Extensions,
+    ser::{PrettyConfig, Serializer},
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Record {
+    id: u32,
+    name: String,
+    tags: Vec<&'static str>,
+    scores: BTreeMap<&'static str, f32>,
+}
+
+fn record(id: u32) -> Record {
+    Record {
+        id,
+        name: format!("record #{id}"),
+        tags: vec!["a", "b"],
+        scores: [("x", 0.5), ("y", 1.5)].into_iter().collect(),
+    }
+}
+
+#[test]
+fn buffer_is_reused_without_reallocation() {
+    for config in [
+        None,
+        Some(PrettyConfig::new()),
+        Some(PrettyConfig::new().extensions(Extensions::IMPLICIT_SOME)),
+    ] {
+        let mut buffer = String::with_capacity(4096);
+        let (ptr, capacity) = (buffer.as_ptr(), buffer.capacity());
+
+        for id in 0..1000 {
+            let mut serializer = Serializer::from_string(buffer, config.clone()).unwrap();
+            record(id).serialize(&mut serializer).unwrap();
+            buffer = serializer.into_inner();
+
+            let expected = match &config {
+                None => ron::to_string(&record(id)).unwrap(),
+                Some(config) => ron::ser::to_string_pretty(&record(id), config.clone()).unwrap(),
+            };
+            assert_eq!(buffer, expected);
+
+            // the same allocation is written into every time
+            assert_eq!(buffer.as_ptr(), ptr);
+            assert_eq!(buffer.capacity(), capacity);
+        }
+    }
+}
+
+#[test]
+fn reused_buffer_is_cleared() {
+    let mut serializer = Serializer::from_string(String::from("stale contents"), None).unwrap();
+    42.serialize(&mut serializer).unwrap();
+
+    assert_eq!(serializer.into_inner(), "42");
+}
+
+#[test]
+fn into_inner_returns_the_writer() {
+    let mut output = String::new();
+
+    let mut serializer = Serializer::new(&mut output, None).unwrap();
+    (1, "a").serialize(&mut serializer).unwrap();
+    let writer: &mut String = serializer.into_inner();
+    writer.push('!');
+
+    assert_eq!(output, "(1,\"a\")!");
+}
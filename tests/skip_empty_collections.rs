@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Build {
+    name: String,
+    #[serde(default)]
+    features: Vec<String>,
+    #[serde(default)]
+    env: HashMap<String, String>,
+    #[serde(default)]
+    targets: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Strict {
+    name: String,
+    features: Vec<String>,
+    tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Step {
+    Run {
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
+
+fn sparse() -> Build {
+    Build {
+        name: String::from("ron"),
+        features: Vec::new(),
+        env: HashMap::new(),
+        targets: BTreeMap::from([(String::from("wasm"), Vec::new())]),
+    }
+}
+
+fn config() -> PrettyConfig {
+    PrettyConfig::new().new_line("\n")
+}
+
+#[test]
+fn empty_collections_are_written_by_default() {
+    let ser = to_string_pretty(&sparse(), config()).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    name: \"ron\",
+    features: [],
+    env: {},
+    targets: {
+        \"wasm\": [],
+    },
+)"
+    );
+
+    assert_eq!(ron::from_str::<Build>(&ser), Ok(sparse()));
+}
+
+#[test]
+fn empty_collections_are_skipped() {
+    let ser = to_string_pretty(&sparse(), config().skip_empty_collections(true)).unwrap();
+
+    // only struct fields are skipped, not the values of maps
+    assert_eq!(
+        ser,
+        "(
+    name: \"ron\",
+    targets: {
+        \"wasm\": [],
+    },
+)"
+    );
+    assert_eq!(ron::from_str::<Build>(&ser), Ok(sparse()));
+
+    let full = Build {
+        name: String::from("ron"),
+        features: vec![String::from("std")],
+        env: HashMap::from([(String::from("CI"), String::from("1"))]),
+        targets: BTreeMap::new(),
+    };
+
+    let ser = to_string_pretty(&full, config().skip_empty_collections(true)).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    name: \"ron\",
+    features: [
+        \"std\",
+    ],
+    env: {
+        \"CI\": \"1\",
+    },
+)"
+    );
+    assert_eq!(ron::from_str::<Build>(&ser), Ok(full));
+}
+
+#[test]
+fn empty_collections_need_serde_default() {
+    let strict = Strict {
+        name: String::from("ron"),
+        features: Vec::new(),
+        tags: Some(Vec::new()),
+    };
+
+    // a `Some` around an empty collection is not skipped
+    let ser = to_string_pretty(&strict, config().skip_empty_collections(true)).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    name: \"ron\",
+    tags: Some([]),
+)"
+    );
+
+    // without `#[serde(default)]`, a missing collection field is an error
+    assert_eq!(
+        ron::from_str::<Strict>(&ser).unwrap_err().code,
+        ron::Error::MissingStructField {
+            field: "features",
+            outer: Some(String::from("Strict")),
+        }
+    );
+}
+
+#[test]
+fn empty_collections_are_skipped_with_none_fields() {
+    let config = config().skip_empty_collections(true).skip_none_fields(true);
+
+    let strict = Strict {
+        name: String::from("ron"),
+        features: Vec::new(),
+        tags: None,
+    };
+    assert_eq!(
+        to_string_pretty(&strict, config.clone()).unwrap(),
+        "(
+    name: \"ron\",
+)"
+    );
+
+    let steps = vec![
+        Step::Run { args: Vec::new() },
+        Step::Run {
+            args: vec![String::from("--release")],
+        },
+    ];
+
+    let ser = to_string_pretty(&steps, config.clone()).unwrap();
+    assert_eq!(
+        ser,
+        "[
+    Run(),
+    Run(
+        args: [
+            \"--release\",
+        ],
+    ),
+]"
+    );
+    assert_eq!(ron::from_str::<Vec<Step>>(&ser), Ok(steps));
+
+    let ser = to_string_pretty(&sparse(), config.compact_structs(true)).unwrap();
+    assert_eq!(
+        ser,
+        "(name: \"ron\", targets: {
+    \"wasm\": [],
+})"
+    );
+    assert_eq!(ron::from_str::<Build>(&ser), Ok(sparse()));
+}
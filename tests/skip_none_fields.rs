@@ -0,0 +1,151 @@
+use ron::{
+    extensions::Extensions,
+    ser::{to_string_pretty, PrettyConfig},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Server {
+    name: String,
+    port: Option<u16>,
+    fallback: Option<Option<bool>>,
+    inner: Option<Inner>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    a: Option<u8>,
+    b: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Event {
+    Moved { x: Option<i32>, y: Option<i32> },
+}
+
+fn sparse() -> Server {
+    Server {
+        name: String::from("local"),
+        port: None,
+        fallback: Some(None),
+        inner: Some(Inner { a: None, b: None }),
+    }
+}
+
+fn config() -> PrettyConfig {
+    PrettyConfig::new().new_line("\n")
+}
+
+#[test]
+fn none_fields_are_written_by_default() {
+    let ser = to_string_pretty(&sparse(), config()).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    name: \"local\",
+    port: None,
+    fallback: Some(None),
+    inner: Some((
+        a: None,
+        b: None,
+    )),
+)"
+    );
+
+    assert_eq!(ron::from_str::<Server>(&ser), Ok(sparse()));
+}
+
+#[test]
+fn none_fields_are_skipped() {
+    let ser = to_string_pretty(&sparse(), config().skip_none_fields(true)).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    name: \"local\",
+    fallback: Some(None),
+    inner: Some(()),
+)"
+    );
+
+    assert_eq!(ron::from_str::<Server>(&ser), Ok(sparse()));
+}
+
+#[test]
+fn none_fields_are_skipped_with_implicit_some() {
+    let config = config()
+        .skip_none_fields(true)
+        .extensions(Extensions::IMPLICIT_SOME);
+
+    let ser = to_string_pretty(&sparse(), config.clone()).unwrap();
+
+    assert_eq!(
+        ser,
+        "#![enable(implicit_some)]
+(
+    name: \"local\",
+    fallback: Some(None),
+    inner: (),
+)"
+    );
+    assert_eq!(ron::from_str::<Server>(&ser), Ok(sparse()));
+
+    let full = Server {
+        name: String::from("remote"),
+        port: Some(8080),
+        fallback: Some(Some(true)),
+        inner: Some(Inner {
+            a: Some(1),
+            b: None,
+        }),
+    };
+
+    let ser = to_string_pretty(&full, config).unwrap();
+
+    assert_eq!(
+        ser,
+        "#![enable(implicit_some)]
+(
+    name: \"remote\",
+    port: 8080,
+    fallback: true,
+    inner: (
+        a: 1,
+    ),
+)"
+    );
+    assert_eq!(ron::from_str::<Server>(&ser), Ok(full));
+}
+
+#[test]
+fn none_fields_are_skipped_in_compact_structs_and_variants() {
+    let config = config().skip_none_fields(true);
+
+    let ser = to_string_pretty(&sparse(), config.clone().compact_structs(true)).unwrap();
+    assert_eq!(
+        ser,
+        "(name: \"local\", fallback: Some(None), inner: Some(()))"
+    );
+    assert_eq!(ron::from_str::<Server>(&ser), Ok(sparse()));
+
+    let events = vec![
+        Event::Moved {
+            x: None,
+            y: Some(2),
+        },
+        Event::Moved { x: None, y: None },
+    ];
+
+    let ser = to_string_pretty(&events, config).unwrap();
+    assert_eq!(
+        ser,
+        "[
+    Moved(
+        y: Some(2),
+    ),
+    Moved(),
+]"
+    );
+    assert_eq!(ron::from_str::<Vec<Event>>(&ser), Ok(events));
+}
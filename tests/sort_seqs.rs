@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, HashSet};
+
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Tags {
+    names: HashSet<String>,
+    ids: HashSet<u32>,
+}
+
+fn tags() -> Tags {
+    Tags {
+        names: ["gamma", "alpha", "beta"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        ids: [3, 1, 2].into_iter().collect(),
+    }
+}
+
+fn config() -> PrettyConfig {
+    PrettyConfig::new().new_line("\n").sort_seqs(true)
+}
+
+#[test]
+fn sorted_seqs_are_stable() {
+    let expected = "(
+    names: [
+        \"alpha\",
+        \"beta\",
+        \"gamma\",
+    ],
+    ids: [
+        1,
+        2,
+        3,
+    ],
+)";
+
+    // every set has its own random iteration order
+    for _ in 0..16 {
+        assert_eq!(to_string_pretty(&tags(), config()).unwrap(), expected);
+    }
+
+    let ser = to_string_pretty(&tags(), config().compact_arrays(true)).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    names: [\"alpha\", \"beta\", \"gamma\"],
+    ids: [1, 2, 3],
+)"
+    );
+
+    let ser = to_string_pretty(&tags(), config().max_width(60).enumerate_arrays(true)).unwrap();
+    assert_eq!(
+        ser,
+        "(
+    names: [
+        /*[0]*/ \"alpha\",
+        /*[1]*/ \"beta\",
+        /*[2]*/ \"gamma\",
+    ],
+    ids: [/*[0]*/ 1, /*[1]*/ 2, /*[2]*/ 3],
+)"
+    );
+}
+
+#[test]
+fn nested_elements_are_sorted_by_their_serialized_form() {
+    let mut b = BTreeMap::new();
+    b.insert("b", vec![2, 1]);
+    let mut a = BTreeMap::new();
+    a.insert("a", vec![4, 3]);
+
+    let ser = to_string_pretty(&vec![b, a], config().enumerate_arrays(true)).unwrap();
+    assert_eq!(
+        ser,
+        "[
+    /*[0]*/ {
+        \"a\": [
+            /*[0]*/ 3,
+            /*[1]*/ 4,
+        ],
+    },
+    /*[1]*/ {
+        \"b\": [
+            /*[0]*/ 1,
+            /*[1]*/ 2,
+        ],
+    },
+]"
+    );
+}
+
+#[test]
+fn seqs_are_unsorted_by_default() {
+    let ser = to_string_pretty(&vec![3, 1, 2], PrettyConfig::new().compact_arrays(true)).unwrap();
+    assert_eq!(ser, "[3, 1, 2]");
+
+    let ser = to_string_pretty(&(3, 1, 2), config()).unwrap();
+    assert_eq!(ser, "(3, 1, 2)");
+
+    let ser = to_string_pretty(&Vec::<u8>::new(), config()).unwrap();
+    assert_eq!(ser, "[]");
+}
@@ -0,0 +1,93 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    extensions::Extensions,
+    Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: Option<String>,
+    size: Size,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Size(u32);
+
+fn config() -> Config {
+    Config {
+        name: Some(String::from("ron")),
+        size: Size(4),
+    }
+}
+
+const DUPLICATE_HEADER: &str = "#![enable(implicit_some)]
+#![enable(unwrap_newtypes)]
+#![enable(implicit_some)]
+(name: \"ron\", size: 4)";
+
+#[test]
+fn lenient_header_unions_extensions() {
+    assert_eq!(ron::from_str::<Config>(DUPLICATE_HEADER), Ok(config()));
+    assert_eq!(
+        ron::from_str::<Config>(
+            "#![enable(implicit_some, unwrap_newtypes, implicit_some)] (name: \"ron\", size: 4)"
+        ),
+        Ok(config())
+    );
+
+    let (_, extensions) = Options::default()
+        .from_str_with_extensions::<Config>(DUPLICATE_HEADER)
+        .unwrap();
+    assert_eq!(
+        extensions,
+        Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES
+    );
+}
+
+#[test]
+fn strict_header_rejects_duplicates() {
+    let options = Options::default().with_strict_header(true);
+
+    assert_eq!(
+        options.from_str::<Config>(DUPLICATE_HEADER),
+        Err(SpannedError {
+            code: Error::DuplicateExtension(String::from("implicit_some")),
+            position: Position {
+                line: 3,
+                col: 24,
+                offset: 77,
+            },
+        })
+    );
+    assert_eq!(
+        options.from_str::<Config>(
+            "#![enable(implicit_some, unwrap_newtypes, implicit_some)] (name: \"ron\", size: 4)"
+        ),
+        Err(SpannedError {
+            code: Error::DuplicateExtension(String::from("implicit_some")),
+            position: Position {
+                line: 1,
+                col: 56,
+                offset: 55,
+            },
+        })
+    );
+}
+
+#[test]
+fn strict_header_accepts_distinct_extensions() {
+    let options = Options::default()
+        .with_strict_header(true)
+        .with_default_extension(Extensions::IMPLICIT_SOME);
+
+    // Default extensions may still be enabled explicitly
+    assert_eq!(
+        options.from_str::<Config>(
+            "#![enable(implicit_some)]
+#![enable(unwrap_newtypes)]
+(name: \"ron\", size: 4)"
+        ),
+        Ok(config())
+    );
+}
@@ -0,0 +1,116 @@
+use ron::error::{Error, Position, SpannedError};
+use serde_bytes::ByteBuf;
+
+#[test]
+fn continuation_skips_newline_and_indentation() {
+    assert_eq!(
+        ron::from_str::<String>("\"foo\\\n   bar\""),
+        Ok(String::from("foobar"))
+    );
+    assert_eq!(
+        ron::from_str::<String>("\"foo\\\r\n\tbar\""),
+        Ok(String::from("foobar"))
+    );
+
+    // Whitespace before the backslash is kept
+    assert_eq!(
+        ron::from_str::<String>("\"a long \\\n    sentence\""),
+        Ok(String::from("a long sentence"))
+    );
+}
+
+#[test]
+fn continuation_over_multiple_lines() {
+    let ron = "(
+    text: \"Lorem ipsum dolor sit amet, \\
+            consectetur adipiscing elit, \\
+
+            \\n sed do eiusmod\\
+            \\
+            \\ttempor\",
+)";
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Doc {
+        text: String,
+    }
+
+    assert_eq!(
+        ron::from_str::<Doc>(ron),
+        Ok(Doc {
+            text: String::from(
+                "Lorem ipsum dolor sit amet, consectetur adipiscing elit, \n sed do eiusmod\ttempor"
+            ),
+        })
+    );
+}
+
+#[test]
+fn continuation_keeps_other_whitespace() {
+    assert_eq!(
+        ron::from_str::<String>("\"a\\\n\u{a0}b\""),
+        Ok(String::from("a\u{a0}b"))
+    );
+    assert_eq!(
+        ron::from_str::<String>("\"a\\\n  \\  b\""),
+        Err(SpannedError {
+            code: Error::InvalidEscape("Unknown escape character"),
+            position: Position {
+                line: 2,
+                col: 5,
+                offset: 8,
+            },
+        })
+    );
+}
+
+#[test]
+fn continuation_at_the_end_of_a_string() {
+    assert_eq!(
+        ron::from_str::<String>("\"foo\\\n\""),
+        Ok(String::from("foo"))
+    );
+    assert_eq!(ron::from_str::<String>("\"\\\n  \""), Ok(String::new()));
+}
+
+#[test]
+fn continuation_in_byte_strings() {
+    assert_eq!(
+        ron::from_str::<ByteBuf>("b\"\\x00\\\n    \\xff\""),
+        Ok(ByteBuf::from(vec![0x00, 0xff]))
+    );
+}
+
+#[test]
+fn no_continuation_in_chars_or_raw_strings() {
+    assert_eq!(
+        ron::from_str::<char>("'\\\n'"),
+        Err(SpannedError {
+            code: Error::InvalidEscape("Unknown escape character"),
+            position: Position {
+                line: 2,
+                col: 1,
+                offset: 3,
+            },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<String>("r\"foo\\\n  bar\""),
+        Ok(String::from("foo\\\n  bar"))
+    );
+}
+
+#[test]
+fn lone_carriage_return_is_not_a_continuation() {
+    assert_eq!(
+        ron::from_str::<String>("\"foo\\\rbar\""),
+        Err(SpannedError {
+            code: Error::InvalidEscape("Unknown escape character"),
+            position: Position {
+                line: 1,
+                col: 7,
+                offset: 6,
+            },
+        })
+    );
+}
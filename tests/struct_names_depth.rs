@@ -0,0 +1,108 @@
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Scene {
+    camera: Camera,
+    objects: Vec<Object>,
+    background: Option<Color>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Camera {
+    position: Point,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point(f32, f32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Color(u8);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Object {
+    Sphere { center: Point },
+    Light,
+}
+
+fn scene() -> Scene {
+    Scene {
+        camera: Camera {
+            position: Point(0.0, 1.0),
+        },
+        objects: vec![
+            Object::Sphere {
+                center: Point(2.0, 3.0),
+            },
+            Object::Light,
+        ],
+        background: Some(Color(7)),
+    }
+}
+
+fn check_struct_names_depth(struct_names_depth: Option<usize>, expected: &str) {
+    let config = PrettyConfig::new()
+        .struct_names(true)
+        .struct_names_depth(struct_names_depth)
+        .compact_structs(true)
+        .compact_arrays(true);
+
+    let ron = to_string_pretty(&scene(), config).unwrap();
+    assert_eq!(ron, expected);
+
+    assert_eq!(ron::from_str::<Scene>(&ron).unwrap(), scene());
+}
+
+#[test]
+fn struct_names_at_all_depths() {
+    check_struct_names_depth(
+        None,
+        "Scene(camera: Camera(position: Point(0.0, 1.0)), objects: [Sphere(center: Point(2.0, 3.0)), Light], background: Some(Color(7)))",
+    );
+    check_struct_names_depth(
+        Some(0),
+        "Scene(camera: Camera(position: Point(0.0, 1.0)), objects: [Sphere(center: Point(2.0, 3.0)), Light], background: Some(Color(7)))",
+    );
+}
+
+#[test]
+fn struct_names_below_root() {
+    check_struct_names_depth(
+        Some(1),
+        "(camera: Camera(position: Point(0.0, 1.0)), objects: [Sphere(center: Point(2.0, 3.0)), Light], background: Some(Color(7)))",
+    );
+}
+
+#[test]
+fn struct_names_deeply_nested() {
+    check_struct_names_depth(
+        Some(2),
+        "(camera: (position: Point(0.0, 1.0)), objects: [Sphere(center: Point(2.0, 3.0)), Light], background: Some((7)))",
+    );
+    check_struct_names_depth(
+        Some(3),
+        "(camera: (position: (0.0, 1.0)), objects: [Sphere(center: Point(2.0, 3.0)), Light], background: Some((7)))",
+    );
+    check_struct_names_depth(
+        Some(4),
+        "(camera: (position: (0.0, 1.0)), objects: [Sphere(center: (2.0, 3.0)), Light], background: Some((7)))",
+    );
+}
+
+#[test]
+fn struct_names_depth_requires_struct_names() {
+    let config = PrettyConfig::new()
+        .struct_names_depth(Some(1))
+        .compact_structs(true);
+
+    assert_eq!(
+        to_string_pretty(
+            &Camera {
+                position: Point(0.0, 1.0)
+            },
+            config
+        )
+        .unwrap(),
+        "(position: (0.0, 1.0))"
+    );
+}
@@ -0,0 +1,113 @@
+use ron::Error;
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Named {
+    a: u8,
+    b: u8,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Positional(u8, u8);
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Shape {
+    Named { a: u8, b: u8 },
+    Positional(u8, u8),
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Unit {
+    A,
+}
+
+fn check_error<T: std::fmt::Debug + serde::de::DeserializeOwned>(
+    ron: &str,
+    code: Error,
+    col: usize,
+) {
+    let err = ron::from_str::<T>(ron).unwrap_err();
+
+    assert_eq!(err.code, code, "{}", ron);
+    assert_eq!((err.position.line, err.position.col), (1, col), "{}", ron);
+}
+
+#[test]
+fn positional_fields_for_named_struct() {
+    let code = || Error::ExpectedNamedFields {
+        outer: Some(String::from("Named")),
+    };
+
+    check_error::<Named>("Named(1, 2)", code(), 7);
+    check_error::<Named>("(1, 2)", code(), 2);
+    check_error::<Named>("(a: 1, \"b\")", code(), 8);
+    check_error::<Named>("( /* a */ A, b: 2)", code(), 11);
+    check_error::<Named>("(Some(1))", code(), 2);
+    check_error::<Named>("([1], b: 2)", code(), 2);
+    check_error::<Named>("(b\"1\", r\"2\")", code(), 2);
+    check_error::<Named>("(a: 1, r#\"2\"#)", code(), 8);
+
+    check_error::<Shape>(
+        "Named(1, 2)",
+        Error::ExpectedNamedFields {
+            outer: Some(String::from("Named")),
+        },
+        7,
+    );
+
+    assert_eq!(
+        ron::from_str::<Named>("(1, 2)")
+            .unwrap_err()
+            .code
+            .to_string(),
+        "Expected named fields in `Named`, e.g. `(a: 1)`, but found a positional field instead"
+    );
+}
+
+#[test]
+fn named_fields_for_positional_struct() {
+    let code = || Error::ExpectedPositionalFields {
+        outer: Some(String::from("Positional")),
+    };
+
+    check_error::<Positional>("Positional(a: 1, b: 2)", code(), 12);
+    check_error::<Positional>("(a: 1, b: 2)", code(), 2);
+    check_error::<Positional>("(1, \"b\": 2)", code(), 5);
+    check_error::<Positional>("(1, r#b : 2)", code(), 5);
+
+    check_error::<Shape>(
+        "Positional(a: 1, b: 2)",
+        Error::ExpectedPositionalFields {
+            outer: Some(String::from("Positional")),
+        },
+        12,
+    );
+    check_error::<(u8, u8)>(
+        "(a: 1, b: 2)",
+        Error::ExpectedPositionalFields { outer: None },
+        2,
+    );
+    check_error::<(Unit, Unit)>(
+        "(A, A: 1)",
+        Error::ExpectedPositionalFields { outer: None },
+        5,
+    );
+}
+
+#[test]
+fn other_errors_are_unchanged() {
+    assert_eq!(
+        ron::from_str::<Named>("(a 1, b: 2)").unwrap_err().code,
+        Error::ExpectedMapColon
+    );
+    assert_eq!(
+        ron::from_str::<Positional>("(1 2)").unwrap_err().code,
+        Error::ExpectedComma
+    );
+    assert_eq!(
+        ron::from_str::<Named>("(a: 1, b: 2)"),
+        Ok(Named { a: 1, b: 2 })
+    );
+    assert_eq!(ron::from_str::<Positional>("(1, 2)"), Ok(Positional(1, 2)));
+    assert_eq!(ron::from_str::<(Unit,)>("(A,)"), Ok((Unit::A,)));
+}
@@ -0,0 +1,169 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    parse::{tokens, Token, TokenKind},
+};
+
+fn lex(src: &str) -> Vec<(TokenKind, &str)> {
+    tokens(src)
+        .map(|token| {
+            let Token { kind, span } = token.unwrap();
+            (kind, &src[span])
+        })
+        .collect()
+}
+
+#[test]
+fn tokenize_document() {
+    use TokenKind::{Char, Comment, Identifier, Number, Punctuation, String};
+
+    let src = "#![enable(implicit_some)]
+// A game config
+Config(
+    name: r#\"Ron's \"game\"\"#, /* nested /* block */ */
+    size: (-1_000i32, 1.5e3, inf),
+    icon: b'\\n',
+    tags: { 'a' => b\"bytes\", r#type: None },
+)";
+
+    assert_eq!(
+        lex(src),
+        vec![
+            (Punctuation, "#"),
+            (Punctuation, "!"),
+            (Punctuation, "["),
+            (Identifier, "enable"),
+            (Punctuation, "("),
+            (Identifier, "implicit_some"),
+            (Punctuation, ")"),
+            (Punctuation, "]"),
+            (Comment, "// A game config"),
+            (Identifier, "Config"),
+            (Punctuation, "("),
+            (Identifier, "name"),
+            (Punctuation, ":"),
+            (String, "r#\"Ron's \"game\"\"#"),
+            (Punctuation, ","),
+            (Comment, "/* nested /* block */ */"),
+            (Identifier, "size"),
+            (Punctuation, ":"),
+            (Punctuation, "("),
+            (Number, "-1_000i32"),
+            (Punctuation, ","),
+            (Number, "1.5e3"),
+            (Punctuation, ","),
+            (Identifier, "inf"),
+            (Punctuation, ")"),
+            (Punctuation, ","),
+            (Identifier, "icon"),
+            (Punctuation, ":"),
+            (Char, "b'\\n'"),
+            (Punctuation, ","),
+            (Identifier, "tags"),
+            (Punctuation, ":"),
+            (Punctuation, "{"),
+            (Char, "'a'"),
+            (Punctuation, "=>"),
+            (String, "b\"bytes\""),
+            (Punctuation, ","),
+            (Identifier, "r#type"),
+            (Punctuation, ":"),
+            (Identifier, "None"),
+            (Punctuation, "}"),
+            (Punctuation, ","),
+            (Punctuation, ")"),
+        ]
+    );
+}
+
+#[test]
+fn tokenize_equals_fields() {
+    use TokenKind::{Identifier, Number, Punctuation};
+
+    assert_eq!(
+        lex("#![enable(equals_fields)] (a = 1, b => 2)"),
+        vec![
+            (Punctuation, "#"),
+            (Punctuation, "!"),
+            (Punctuation, "["),
+            (Identifier, "enable"),
+            (Punctuation, "("),
+            (Identifier, "equals_fields"),
+            (Punctuation, ")"),
+            (Punctuation, "]"),
+            (Punctuation, "("),
+            (Identifier, "a"),
+            (Punctuation, "="),
+            (Number, "1"),
+            (Punctuation, ","),
+            (Identifier, "b"),
+            (Punctuation, "=>"),
+            (Number, "2"),
+            (Punctuation, ")"),
+        ]
+    );
+}
+
+#[test]
+fn token_spans() {
+    assert_eq!(
+        tokens("\u{FEFF}(a: \"x\")  // c").collect::<Vec<_>>(),
+        vec![
+            Ok(Token {
+                kind: TokenKind::Punctuation,
+                span: 3..4,
+            }),
+            Ok(Token {
+                kind: TokenKind::Identifier,
+                span: 4..5,
+            }),
+            Ok(Token {
+                kind: TokenKind::Punctuation,
+                span: 5..6,
+            }),
+            Ok(Token {
+                kind: TokenKind::String,
+                span: 7..10,
+            }),
+            Ok(Token {
+                kind: TokenKind::Punctuation,
+                span: 10..11,
+            }),
+            Ok(Token {
+                kind: TokenKind::Comment,
+                span: 13..17,
+            }),
+        ]
+    );
+    assert_eq!(tokens(" \n\t").next(), None);
+}
+
+#[test]
+fn invalid_token_ends_tokens() {
+    let mut tokens = tokens("[1, \"unterminated]");
+
+    assert_eq!(tokens.by_ref().filter_map(Result::ok).count(), 3);
+    assert_eq!(tokens.next(), None);
+
+    assert_eq!(
+        ron::parse::tokens("(a: 1) @").last(),
+        Some(Err(SpannedError {
+            code: Error::UnexpectedChar('@'),
+            position: Position {
+                line: 1,
+                col: 8,
+                offset: 7,
+            },
+        }))
+    );
+    assert_eq!(
+        ron::parse::tokens("[1, \"unterminated]").nth(3),
+        Some(Err(SpannedError {
+            code: Error::ExpectedStringEnd,
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5,
+            },
+        }))
+    );
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "toml")]
+
+use ron::{Map, Number, Value};
+
+const DOCUMENT: &str = r#"
+title = "TOML Example"
+version = 3
+ratio = 0.5
+enabled = true
+released = 1979-05-27T07:32:00Z
+
+[owner]
+name = "Tom"
+tags = ["admin", "ops"]
+
+[[products]]
+name = "Hammer"
+sku = 738594937
+
+[[products]]
+name = "Nail"
+sizes = [[1, 2], [3.5]]
+"#;
+
+fn string(s: &str) -> Value {
+    Value::String(String::from(s))
+}
+
+fn table<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Map(
+        entries
+            .into_iter()
+            .map(|(key, value)| (string(key), value))
+            .collect::<Map>(),
+    )
+}
+
+#[test]
+fn toml_document_converts_to_value() {
+    let toml: toml::Value = DOCUMENT.parse().unwrap();
+    let value = Value::from(toml);
+
+    let expected = table([
+        ("title", string("TOML Example")),
+        ("version", Value::Number(Number::from(3_i64))),
+        ("ratio", Value::Number(Number::from(0.5_f64))),
+        ("enabled", Value::Bool(true)),
+        // datetimes are converted to strings
+        ("released", string("1979-05-27T07:32:00Z")),
+        (
+            "owner",
+            table([
+                ("name", string("Tom")),
+                ("tags", Value::Seq(vec![string("admin"), string("ops")])),
+            ]),
+        ),
+        (
+            "products",
+            Value::Seq(vec![
+                table([
+                    ("name", string("Hammer")),
+                    ("sku", Value::Number(Number::from(738_594_937_i64))),
+                ]),
+                table([
+                    ("name", string("Nail")),
+                    (
+                        "sizes",
+                        Value::Seq(vec![
+                            Value::Seq(vec![
+                                Value::Number(Number::from(1_i64)),
+                                Value::Number(Number::from(2_i64)),
+                            ]),
+                            Value::Seq(vec![Value::Number(Number::from(3.5_f64))]),
+                        ]),
+                    ),
+                ]),
+            ]),
+        ),
+    ]);
+
+    assert!(value.semantic_eq(&expected), "{:?}", value);
+}
+
+#[test]
+fn toml_document_roundtrips_through_value() {
+    let toml: toml::Value = DOCUMENT.parse().unwrap();
+    let value = Value::from(toml.clone());
+
+    // through RON text as well
+    let ron = ron::to_string(&value).unwrap();
+    let value: Value = ron::from_str(&ron).unwrap();
+
+    let roundtripped = toml::Value::try_from(value).unwrap();
+
+    // only the datetime is lossy and comes back as a string
+    let mut expected = toml;
+    expected["released"] = toml::Value::String(String::from("1979-05-27T07:32:00Z"));
+
+    assert_eq!(roundtripped, expected);
+}
+
+#[test]
+fn values_are_converted_lossily_or_rejected() {
+    let lossy = table([
+        ("letter", Value::Char('a')),
+        ("maybe", Value::Option(Some(Box::new(Value::Bool(false))))),
+        ("bytes", Value::Bytes(vec![1, 2])),
+        ("missing", Value::Option(None)),
+    ]);
+    let lossy = toml::Value::try_from(lossy).unwrap();
+    assert_eq!(lossy["letter"].as_str(), Some("a"));
+    assert_eq!(lossy["maybe"].as_bool(), Some(false));
+    assert_eq!(
+        lossy["bytes"],
+        toml::Value::Array(vec![toml::Value::Integer(1), toml::Value::Integer(2)])
+    );
+    assert_eq!(lossy.get("missing"), None);
+
+    assert!(toml::Value::try_from(table([("unit", Value::Unit)])).is_err());
+    assert!(toml::Value::try_from(Value::Option(None)).is_err());
+    assert!(toml::Value::try_from(Value::Seq(vec![Value::Option(None)])).is_err());
+    assert!(toml::Value::try_from(Value::Map(
+        [(Value::Bool(true), Value::Bool(false))]
+            .into_iter()
+            .collect::<Map>()
+    ))
+    .is_err());
+    assert!(
+        toml::Value::try_from(table([("huge", Value::Number(Number::from(u64::MAX)))])).is_err()
+    );
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "serde_path_to_error")]
+
+use ron::{
+    error::{Error, Position, SpannedError, TracedError},
+    Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: String,
+    servers: Vec<Server>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn traced_ok() {
+    assert_eq!(
+        Options::default()
+            .from_str_traced::<Config>(r#"(name: "x", servers: [(host: "a", port: 80)])"#),
+        Ok(Config {
+            name: String::from("x"),
+            servers: vec![Server {
+                host: String::from("a"),
+                port: 80,
+            }],
+        })
+    );
+}
+
+#[test]
+fn traced_nested_struct_error() {
+    let err = Options::default()
+        .from_str_traced::<Config>(
+            r#"(
+    name: "x",
+    servers: [
+        (host: "a", port: 80),
+        (host: "b", port: 443),
+        (host: "c", port: "8080"),
+    ],
+)"#,
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TracedError {
+            error: SpannedError {
+                code: Error::InvalidValueForType {
+                    expected: String::from("u16"),
+                    found: String::from("the string \"8080\""),
+                },
+                position: Position {
+                    line: 6,
+                    col: 27,
+                    offset: 121,
+                },
+            },
+            path: String::from("servers[2].port"),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "6:27: Expected u16 but found the string \"8080\" instead at `servers[2].port`"
+    );
+}
+
+#[test]
+fn traced_missing_field() {
+    let err = Options::default()
+        .from_str_traced::<Config>(r#"(name: "x", servers: [(host: "a")])"#)
+        .unwrap_err();
+
+    assert_eq!(err.path, "servers[0]");
+    assert_eq!(
+        err.error.code,
+        Error::MissingStructField {
+            field: "port",
+            outer: Some(String::from("Server")),
+        }
+    );
+}
+
+#[test]
+fn traced_root_errors() {
+    let err = Options::default()
+        .from_str_traced::<Config>(r#"(name: "x", servers: []) 42"#)
+        .unwrap_err();
+
+    assert_eq!(err.path, ".");
+    assert_eq!(
+        err.error.code,
+        Error::TrailingCharacters {
+            found: String::from("42"),
+        }
+    );
+
+    let err = Options::default()
+        .from_str_traced::<Config>("#![enable(unknown)] ()")
+        .unwrap_err();
+
+    assert_eq!(err.path, ".");
+    assert_eq!(
+        SpannedError::from(err).code,
+        Error::NoSuchExtension(String::from("unknown"))
+    );
+}
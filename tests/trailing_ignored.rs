@@ -0,0 +1,85 @@
+use ron::{
+    de::Deserializer,
+    error::{Error, Position, SpannedError},
+    Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: String,
+}
+
+fn is_metadata_footer(trailing: &str) -> bool {
+    trailing.starts_with("#![metadata(") && trailing.trim_end().ends_with(")]")
+}
+
+#[test]
+fn trailing_footer_is_rejected_by_default() {
+    assert_eq!(
+        ron::from_str::<Config>("(name: \"a\")\n#![metadata(generated)]"),
+        Err(SpannedError {
+            code: Error::TrailingCharacters {
+                found: String::from("#![metadata(gene")
+            },
+            position: Position {
+                line: 2,
+                col: 1,
+                offset: 12,
+            },
+        })
+    );
+}
+
+#[test]
+fn trailing_footer_is_ignored() {
+    let options = Options::default().with_trailing_ignored(is_metadata_footer);
+
+    assert_eq!(
+        options.from_str("(name: \"a\")\n#![metadata(generated)]\n"),
+        Ok(Config {
+            name: String::from("a")
+        })
+    );
+    assert_eq!(
+        options.from_str("(name: \"a\") // comment\n/* block */ #![metadata(generated)]"),
+        Ok(Config {
+            name: String::from("a")
+        })
+    );
+    assert_eq!(
+        options.from_bytes(b"(name: \"a\")\n#![metadata(generated)]"),
+        Ok(Config {
+            name: String::from("a")
+        })
+    );
+    assert_eq!(
+        options.from_str("(name: \"a\")  "),
+        Ok(Config {
+            name: String::from("a")
+        })
+    );
+
+    // The predicate decides which trailing content is ignored
+    assert_eq!(
+        options
+            .from_str::<Config>("(name: \"a\")\n#![enable(implicit_some)]")
+            .unwrap_err()
+            .code,
+        Error::TrailingCharacters {
+            found: String::from("#![enable(implic")
+        }
+    );
+}
+
+#[test]
+fn trailing_footer_is_consumed_by_end() {
+    let options = Options::default().with_trailing_ignored(is_metadata_footer);
+
+    let mut deserializer =
+        Deserializer::from_str_with_options("42 #![metadata(generated)]", &options).unwrap();
+    assert_eq!(u8::deserialize(&mut deserializer), Ok(42));
+    assert_eq!(deserializer.remainder(), " #![metadata(generated)]");
+    assert_eq!(deserializer.end(), Ok(()));
+    assert_eq!(deserializer.remainder(), "");
+}
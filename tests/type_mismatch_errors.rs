@@ -0,0 +1,134 @@
+use ron::{
+    error::{Position, SpannedError},
+    Error,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    name: String,
+    port: u16,
+    verbose: bool,
+    ratio: f32,
+    separator: char,
+}
+
+fn check_error(ron: &str, expected: &str, found: &str, col: usize) {
+    let err = ron::from_str::<Config>(ron).unwrap_err();
+
+    assert_eq!(
+        err.code,
+        Error::InvalidValueForType {
+            expected: String::from(expected),
+            found: String::from(found),
+        },
+        "{}",
+        ron
+    );
+    assert_eq!((err.position.line, err.position.col), (1, col), "{}", ron);
+}
+
+#[test]
+fn mismatched_values_are_described() {
+    check_error("(name: 42)", "a string", "the unsigned integer `42`", 8);
+    check_error(
+        "(name: -4.5)",
+        "a string",
+        "the floating point number `-4.5`",
+        8,
+    );
+    check_error("(name: true)", "a string", "the boolean `true`", 8);
+    check_error("(name: 'x')", "a string", "the UTF-8 character `x`", 8);
+    check_error("(name: b\"x\")", "a string", "the byte string b\"x\"", 8);
+    check_error("(name: [\"x\"])", "a string", "a sequence", 8);
+    check_error("(name: {\"x\": 1})", "a string", "a map", 8);
+    check_error("(name: (first: \"x\"))", "a string", "a map", 8);
+    check_error("(name: ())", "a string", "a unit value", 8);
+    check_error("(name: None)", "a string", "an optional value", 8);
+    check_error(
+        "(name: localhost)",
+        "a string",
+        "the identifier `localhost`",
+        8,
+    );
+
+    check_error(
+        "(name: \"a\", port: \"80\")",
+        "u16",
+        "the string \"80\"",
+        19,
+    );
+    check_error(
+        "(name: \"a\", port: 80, verbose: 1)",
+        "a boolean",
+        "the unsigned integer `1`",
+        32,
+    );
+    check_error(
+        "(name: \"a\", port: 80, verbose: true, ratio: \"1\")",
+        "f32",
+        "the string \"1\"",
+        45,
+    );
+    check_error(
+        "(name: \"a\", port: 80, verbose: true, ratio: 1, separator: \",\")",
+        "a character",
+        "the string \",\"",
+        59,
+    );
+
+    assert_eq!(
+        ron::from_str::<Config>("(name: 42)")
+            .unwrap_err()
+            .to_string(),
+        "1:8: Expected a string but found the unsigned integer `42` instead"
+    );
+}
+
+#[test]
+fn malformed_values_are_not_described() {
+    assert_eq!(
+        ron::from_str::<String>("\"unterminated").unwrap_err().code,
+        Error::ExpectedStringEnd
+    );
+    assert_eq!(
+        ron::from_str::<f32>("1ee3").unwrap_err(),
+        SpannedError {
+            code: Error::ExpectedFloat,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+        }
+    );
+    assert_eq!(
+        ron::from_str::<u8>("[1, 2").unwrap_err().code,
+        Error::ExpectedInteger
+    );
+    assert_eq!(ron::from_str::<u8>("").unwrap_err().code, Error::Eof);
+}
+
+#[test]
+fn long_found_values_are_truncated() {
+    let long = "a".repeat(200);
+
+    check_error(
+        &format!("(name: \"n\", port: \"{long}\")"),
+        "u16",
+        &format!("the string starting with \"{}\"", &long[..32]),
+        19,
+    );
+    check_error(
+        &format!("(name: \"n\", port: \"{}\")", &long[..32]),
+        "u16",
+        &format!("the string \"{}\"", &long[..32]),
+        19,
+    );
+    check_error(
+        &format!("(name: b\"{long}\")"),
+        "a string",
+        &format!("the byte string starting with b\"{}\"", &long[..32]),
+        8,
+    );
+}
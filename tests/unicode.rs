@@ -30,10 +30,24 @@ fn ident_starts_with_non_ascii_byte() {
 fn test_file_invalid_unicode() {
     let error = from_bytes::<Value>(&[b'\n', b'a', 0b11000000, 0]).unwrap_err();
     assert!(matches!(error.code, Error::Utf8Error(_)));
-    assert_eq!(error.position, Position { line: 2, col: 2 });
+    assert_eq!(
+        error.position,
+        Position {
+            line: 2,
+            col: 2,
+            offset: 2
+        }
+    );
     let error = from_bytes::<Value>(&[b'\n', b'\n', 0b11000000]).unwrap_err();
     assert!(matches!(error.code, Error::Utf8Error(_)));
-    assert_eq!(error.position, Position { line: 3, col: 1 });
+    assert_eq!(
+        error.position,
+        Position {
+            line: 3,
+            col: 1,
+            offset: 2
+        }
+    );
 }
 
 #[test]
@@ -52,6 +66,17 @@ fn serialize_invalid_whitespace() {
             "Invalid non-whitespace `PrettyConfig::indentor`"
         ))
     );
+    assert_eq!(
+        ron::ser::to_string_pretty(&42, ron::ser::PrettyConfig::default().indentor("\t //"))
+            .unwrap_err(),
+        Error::Message(String::from(
+            "Invalid non-whitespace `PrettyConfig::indentor`"
+        ))
+    );
+    assert_eq!(
+        ron::ser::to_string_pretty(&vec![1], ron::ser::PrettyConfig::default().indentor("\t  ")),
+        Ok(String::from("[\n\t  1,\n]"))
+    );
     assert_eq!(
         ron::ser::to_string_pretty(&42, ron::ser::PrettyConfig::default().separator("a"))
             .unwrap_err(),
@@ -0,0 +1,83 @@
+use ron::{
+    de::from_str,
+    error::Error,
+    ser::{to_string_pretty, PrettyConfig, UnicodeEscape},
+};
+
+fn test_string_roundtrip(s: &str, style: UnicodeEscape) -> String {
+    let ser = to_string_pretty(s, PrettyConfig::default().unicode_escape_style(style)).unwrap();
+
+    let de: String = from_str(&ser).unwrap();
+
+    assert_eq!(s, de);
+
+    ser
+}
+
+#[test]
+fn braced_unicode_escapes() {
+    assert_eq!(
+        test_string_roundtrip("a\u{200b}b", UnicodeEscape::Braced),
+        r#""a\u{200b}b""#
+    );
+    assert_eq!(
+        test_string_roundtrip("\u{e0001}\u{1d173}", UnicodeEscape::Braced),
+        r#""\u{e0001}\u{1d173}""#
+    );
+    assert_eq!(
+        test_string_roundtrip("a\n😀", UnicodeEscape::Braced),
+        r#""a\n😀""#
+    );
+}
+
+#[test]
+fn fixed_unicode_escapes() {
+    assert_eq!(
+        test_string_roundtrip("a\u{200b}b", UnicodeEscape::Fixed),
+        r#""a\u200bb""#
+    );
+    assert_eq!(
+        test_string_roundtrip("\u{e0001}\u{1d173}", UnicodeEscape::Fixed),
+        r#""\udb40\udc01\ud834\udd73""#
+    );
+    assert_eq!(
+        test_string_roundtrip("a\n😀", UnicodeEscape::Fixed),
+        r#""a\n😀""#
+    );
+    assert_eq!(
+        test_string_roundtrip("\u{7f}", UnicodeEscape::Fixed),
+        r#""\u007f""#
+    );
+}
+
+#[test]
+fn deserialize_fixed_unicode_escapes() {
+    assert_eq!(from_str::<String>(r#""\u0041\u00e9""#).unwrap(), "Aé");
+    assert_eq!(from_str::<String>(r#""\ud83d\ude00""#).unwrap(), "😀");
+    assert_eq!(from_str::<char>(r"'\uD83D\uDE00'").unwrap(), '😀');
+    assert_eq!(from_str::<char>(r"'\u{1f600}'").unwrap(), '😀');
+
+    assert_eq!(
+        from_str::<String>(r#""\ud83d""#).unwrap_err().code,
+        Error::InvalidEscape("Expected a low surrogate after a high surrogate in Unicode escape")
+    );
+    assert_eq!(
+        from_str::<String>(r#""\ud83dA""#).unwrap_err().code,
+        Error::InvalidEscape("Expected a low surrogate after a high surrogate in Unicode escape")
+    );
+    assert_eq!(
+        from_str::<String>(r#""\ude00""#).unwrap_err().code,
+        Error::InvalidEscape("Not a valid Unicode-escaped character")
+    );
+    assert_eq!(
+        from_str::<String>(r#""\u12""#).unwrap_err().code,
+        Error::InvalidEscape("Non-hex digit found")
+    );
+}
+
+#[test]
+fn deserialize_unicode_escape_style() {
+    let config: PrettyConfig = from_str("(unicode_escape_style: Fixed)").unwrap();
+
+    assert_eq!(config.unicode_escape_style, UnicodeEscape::Fixed);
+}
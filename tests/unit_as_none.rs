@@ -0,0 +1,102 @@
+use ron::{
+    error::{Error, Position, SpannedError},
+    extensions::Extensions,
+    Options,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Record {
+    id: Option<i32>,
+    parent: Option<i32>,
+    marker: (),
+}
+
+fn unit_as_none() -> Options {
+    Options::default().with_unit_as_none(true)
+}
+
+#[test]
+fn unit_is_rejected_as_option_by_default() {
+    assert_eq!(
+        ron::from_str::<Record>("(id: (), parent: Some(1), marker: ())"),
+        Err(SpannedError {
+            code: Error::ExpectedOption,
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5
+            },
+        })
+    );
+}
+
+#[test]
+fn unit_deserializes_as_none() {
+    assert_eq!(
+        unit_as_none().from_str("(id: (), parent: Some(1), marker: ())"),
+        Ok(Record {
+            id: None,
+            parent: Some(1),
+            marker: (),
+        })
+    );
+    assert_eq!(
+        unit_as_none().from_str("(id: None, parent: (), marker: ())"),
+        Ok(Record {
+            id: None,
+            parent: None,
+            marker: (),
+        })
+    );
+    assert_eq!(
+        unit_as_none().from_str::<Vec<Option<i32>>>("[(), Some(2), None, ()]"),
+        Ok(vec![None, Some(2), None, None])
+    );
+
+    // a unit-typed field still requires `()`
+    assert_eq!(
+        unit_as_none()
+            .from_str::<Record>("(id: (), parent: (), marker: None)")
+            .map_err(|err| err.code),
+        Err(Error::ExpectedUnit)
+    );
+}
+
+#[test]
+fn unit_as_none_with_implicit_some() {
+    let options = unit_as_none().with_default_extension(Extensions::IMPLICIT_SOME);
+
+    assert_eq!(
+        options.from_str("(id: 1, parent: (), marker: ())"),
+        Ok(Record {
+            id: Some(1),
+            parent: None,
+            marker: (),
+        })
+    );
+
+    // `()` is `None` even if the `Option` wraps a unit
+    assert_eq!(options.from_str::<Option<()>>("()"), Ok(None));
+    assert_eq!(options.from_str::<Option<()>>("Some(())"), Ok(Some(())));
+    assert_eq!(
+        Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str::<Option<()>>("()"),
+        Ok(Some(()))
+    );
+}
+
+#[test]
+fn none_is_still_serialized_as_none() {
+    let record = Record {
+        id: None,
+        parent: Some(1),
+        marker: (),
+    };
+
+    assert_eq!(
+        unit_as_none().to_string(&record).as_deref(),
+        Ok("(id:None,parent:Some(1),marker:())")
+    );
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use ron::{extensions::Extensions, Error, Options, Value};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Pair(i32, i32);
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Wrapper(u8);
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(u8),
+}
+
+fn lenient() -> Options {
+    Options::default().with_unwrap_redundant_parens(true)
+}
+
+#[test]
+fn redundant_parens_are_peeled() {
+    assert_eq!(lenient().from_str::<u8>("(42)"), Ok(42));
+    assert_eq!(lenient().from_str::<f32>(" ( 0.5 ) "), Ok(0.5));
+    assert_eq!(lenient().from_str::<bool>("(true)"), Ok(true));
+    assert_eq!(lenient().from_str::<String>("(\"a\")").as_deref(), Ok("a"));
+    assert_eq!(lenient().from_str::<Vec<u8>>("([1, 2])"), Ok(vec![1, 2]));
+    assert_eq!(
+        lenient().from_str::<BTreeMap<u8, bool>>("({1: true})"),
+        Ok(BTreeMap::from([(1, true)]))
+    );
+    assert_eq!(lenient().from_str::<Option<u8>>("(Some(1))"), Ok(Some(1)));
+    assert_eq!(
+        lenient().from_str::<Shape>("(Circle(3))"),
+        Ok(Shape::Circle(3))
+    );
+    assert_eq!(lenient().from_str::<Shape>("(Empty)"), Ok(Shape::Empty));
+    assert_eq!(lenient().from_str::<Unit>("(Unit)"), Ok(Unit));
+    assert_eq!(lenient().from_str::<()>("(())"), Ok(()));
+}
+
+#[test]
+fn redundant_parens_around_structs_and_tuples() {
+    assert_eq!(lenient().from_str::<(i32, i32)>("((1, 2))"), Ok((1, 2)));
+    assert_eq!(lenient().from_str::<Pair>("(Pair(1, 2))"), Ok(Pair(1, 2)));
+    assert_eq!(lenient().from_str::<Pair>("((1, 2))"), Ok(Pair(1, 2)));
+    assert_eq!(
+        lenient().from_str::<Point>("((x: 1, y: 2))"),
+        Ok(Point { x: 1, y: 2 })
+    );
+    assert_eq!(
+        lenient().from_str::<Point>("(Point(x: 1, y: 2))"),
+        Ok(Point { x: 1, y: 2 })
+    );
+}
+
+#[test]
+fn legitimate_parens_are_kept() {
+    assert_eq!(lenient().from_str::<()>("()"), Ok(()));
+    assert_eq!(lenient().from_str::<(i32, i32)>("(1, 2)"), Ok((1, 2)));
+    assert_eq!(
+        lenient().from_str::<Point>("(x: 1, y: 2)"),
+        Ok(Point { x: 1, y: 2 })
+    );
+
+    // the parens are expected by the type itself
+    assert_eq!(lenient().from_str::<(u8,)>("(4)"), Ok((4,)));
+    assert_eq!(lenient().from_str::<(u8,)>("(4,)"), Ok((4,)));
+    assert_eq!(lenient().from_str::<((u8, u8),)>("((4, 5))"), Ok(((4, 5),)));
+    assert_eq!(lenient().from_str::<Wrapper>("(4)"), Ok(Wrapper(4)));
+    assert_eq!(
+        lenient()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str::<Option<(u8,)>>("(4)"),
+        Ok(Some((4,)))
+    );
+
+    // an untyped value keeps its parens
+    assert_eq!(
+        lenient().from_str::<Value>("(4)"),
+        Ok(Value::Seq(vec![Value::Number(4_u8.into())]))
+    );
+
+    // only a single pair of parens is peeled off
+    assert_eq!(
+        lenient().from_str::<u8>("((4))").unwrap_err().code,
+        Error::InvalidValueForType {
+            expected: String::from("u8"),
+            found: String::from("a sequence"),
+        }
+    );
+
+    // a trailing comma makes it a tuple
+    assert_eq!(
+        lenient().from_str::<u8>("(4,)").unwrap_err().code,
+        Error::InvalidValueForType {
+            expected: String::from("u8"),
+            found: String::from("a sequence"),
+        }
+    );
+}
+
+#[test]
+fn redundant_parens_are_rejected_by_default() {
+    assert_eq!(
+        ron::from_str::<u8>("(42)").unwrap_err().code,
+        Error::InvalidValueForType {
+            expected: String::from("u8"),
+            found: String::from("a sequence"),
+        }
+    );
+    assert_eq!(
+        ron::from_str::<(i32, i32)>("((1, 2))").unwrap_err().code,
+        Error::InvalidValueForType {
+            expected: String::from("i32"),
+            found: String::from("a sequence"),
+        }
+    );
+}
@@ -0,0 +1,200 @@
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Packet {
+    payload: Vec<u8>,
+    checksum: Vec<u8>,
+    padding: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+enum Element {
+    Byte(u8),
+    Word(String),
+}
+
+fn packet() -> Packet {
+    Packet {
+        payload: b"hello".to_vec(),
+        checksum: vec![0xff, 0x00],
+        padding: vec![],
+    }
+}
+
+fn config() -> PrettyConfig {
+    PrettyConfig::new().new_line("\n")
+}
+
+#[test]
+fn bytes_are_arrays_by_default() {
+    let ser = to_string_pretty(&packet(), config().compact_arrays(true)).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    payload: [104, 101, 108, 108, 111],
+    checksum: [255, 0],
+    padding: [],
+)"
+    );
+    assert_eq!(ron::from_str::<Packet>(&ser), Ok(packet()));
+}
+
+#[test]
+fn utf8_bytes_are_byte_strings() {
+    let ser = to_string_pretty(&packet(), config().utf8_bytes_as_string(true)).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    payload: b\"hello\",
+    checksum: [
+        255,
+        0,
+    ],
+    padding: [],
+)"
+    );
+    assert_eq!(ron::from_str::<Packet>(&ser), Ok(packet()));
+
+    let ser = to_string_pretty(
+        &packet(),
+        config()
+            .utf8_bytes_as_string(true)
+            .compact_arrays(true)
+            .enumerate_arrays(true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    payload: b\"hello\",
+    checksum: [/*[0]*/ 255, /*[1]*/ 0],
+    padding: [],
+)"
+    );
+    assert_eq!(ron::from_str::<Packet>(&ser), Ok(packet()));
+
+    let ser =
+        to_string_pretty(&packet(), config().utf8_bytes_as_string(true).max_width(40)).unwrap();
+
+    assert_eq!(
+        ser,
+        "(
+    payload: b\"hello\",
+    checksum: [255, 0],
+    padding: [],
+)"
+    );
+    assert_eq!(ron::from_str::<Packet>(&ser), Ok(packet()));
+}
+
+#[test]
+fn utf8_bytes_are_escaped() {
+    let bytes = "a\"b\\c\n€".as_bytes().to_vec();
+
+    let ser = to_string_pretty(&bytes, config().utf8_bytes_as_string(true)).unwrap();
+    assert_eq!(ser, "b\"a\\\"b\\\\c\\n€\"");
+    assert_eq!(ron::from_str::<Vec<u8>>(&ser), Ok(bytes.clone()));
+
+    let ser = to_string_pretty(
+        &bytes,
+        config().utf8_bytes_as_string(true).escape_strings(false),
+    )
+    .unwrap();
+    assert_eq!(ser, "br#\"a\"b\\c\n€\"#");
+    assert_eq!(ron::from_str::<Vec<u8>>(&ser), Ok(bytes));
+}
+
+#[test]
+fn non_bytes_fall_back_to_arrays() {
+    let elements = vec![
+        Element::Byte(104),
+        Element::Byte(105),
+        Element::Word(String::from("!")),
+    ];
+
+    let ser = to_string_pretty(&elements, config().utf8_bytes_as_string(true)).unwrap();
+    assert_eq!(
+        ser,
+        "[
+    104,
+    105,
+    \"!\",
+]"
+    );
+
+    let words = vec![String::from("hi")];
+    let ser = to_string_pretty(&words, config().utf8_bytes_as_string(true)).unwrap();
+    assert_eq!(
+        ser,
+        "[
+    \"hi\",
+]"
+    );
+}
+
+#[test]
+fn byte_strings_deserialize_into_sequences() {
+    assert_eq!(ron::from_str::<Vec<u8>>("b\"\""), Ok(vec![]));
+    assert_eq!(
+        ron::from_str::<Vec<u8>>("b\"\\xff\\x00\""),
+        Ok(vec![0xff, 0x00])
+    );
+    assert_eq!(
+        ron::from_str::<Vec<u8>>("br##\"a\"#b\"##"),
+        Ok(b"a\"#b".to_vec())
+    );
+}
+
+#[test]
+fn byte_strings_only_deserialize_into_byte_sequences() {
+    use std::collections::HashSet;
+
+    use ron::{
+        error::{Error, Position, SpannedError},
+        Value,
+    };
+
+    assert_eq!(
+        ron::from_str::<HashSet<u8>>("b\"hi\""),
+        Ok(HashSet::from([b'h', b'i']))
+    );
+
+    assert_eq!(
+        ron::from_str::<Vec<u32>>("b\"hi\""),
+        Err(SpannedError {
+            code: Error::ExpectedArray,
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            },
+        })
+    );
+    assert_eq!(
+        ron::from_str::<Vec<Value>>("b\"hi\"").map_err(|err| err.code),
+        Err(Error::ExpectedArray)
+    );
+    assert_eq!(
+        ron::from_str::<Vec<char>>("b\"hi\"").map_err(|err| err.code),
+        Err(Error::ExpectedArray)
+    );
+}
+
+#[test]
+fn value_seq_of_bytes_comes_back_as_bytes() {
+    use ron::{value::Number, Value};
+
+    let value = Value::Seq(vec![Value::Number(Number::U8(104))]);
+
+    let ser = to_string_pretty(&value, config().utf8_bytes_as_string(true)).unwrap();
+    assert_eq!(ser, "b\"h\"");
+    assert_eq!(
+        ron::from_str::<Value>(&ser),
+        Ok(Value::Bytes(b"h".to_vec()))
+    );
+}
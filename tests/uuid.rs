@@ -0,0 +1,79 @@
+#![cfg(feature = "uuid")]
+
+use ron::error::{Error, Position, SpannedError};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "ron::uuid")]
+    id: Uuid,
+}
+
+const ID: Uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+
+#[test]
+fn roundtrip_hyphenated() {
+    for id in [Uuid::nil(), ID, Uuid::from_u128(u128::MAX)] {
+        let value = Entity { id };
+
+        let ron = ron::to_string(&value).unwrap();
+        assert_eq!(ron, format!("(id:\"{}\")", id.hyphenated()));
+
+        assert_eq!(ron::from_str::<Entity>(&ron).unwrap(), value);
+    }
+
+    assert_eq!(
+        ron::to_string(&Entity { id: ID }).unwrap(),
+        "(id:\"67e55044-10b1-426f-9247-bb680e5fe0c8\")"
+    );
+    assert_eq!(
+        ron::ser::to_string_pretty(&Entity { id: ID }, ron::ser::PrettyConfig::default()).unwrap(),
+        "(\n    id: \"67e55044-10b1-426f-9247-bb680e5fe0c8\",\n)"
+    );
+}
+
+#[test]
+fn deserialize_alternative_forms() {
+    for ron in [
+        "(id: \"67e55044-10b1-426f-9247-bb680e5fe0c8\")",
+        "(id: \"67E55044-10B1-426F-9247-BB680E5FE0C8\")",
+        "(id: \"67e5504410b1426f9247bb680e5fe0c8\")",
+        "(id: \"{67e55044-10b1-426f-9247-bb680e5fe0c8}\")",
+        "(id: \"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8\")",
+        "(id: r\"67e55044-10b1-426f-9247-bb680e5fe0c8\")",
+    ] {
+        assert_eq!(
+            ron::from_str::<Entity>(ron).unwrap(),
+            Entity { id: ID },
+            "{}",
+            ron
+        );
+    }
+}
+
+#[test]
+fn deserialize_invalid() {
+    let err = ron::from_str::<Entity>("(id: \"67e55044-10b1-426f-9247\")").unwrap_err();
+    assert!(
+        matches!(&err.code, Error::Message(msg) if msg.starts_with("invalid")),
+        "{:?}",
+        err
+    );
+    assert_eq!(
+        ron::from_str::<Entity>("(id: 42)"),
+        Err(SpannedError {
+            code: Error::InvalidValueForType {
+                expected: String::from(
+                    "a UUID string, e.g. \"67e55044-10b1-426f-9247-bb680e5fe0c8\""
+                ),
+                found: String::from("the unsigned integer `42`"),
+            },
+            position: Position {
+                line: 1,
+                col: 6,
+                offset: 5,
+            },
+        })
+    );
+}
@@ -4,6 +4,7 @@ use ron::{
     error::Error,
     value::{Map, Number, Value},
 };
+use serde::de::{Deserializer, IntoDeserializer};
 use serde_derive::{Deserialize, Serialize};
 
 #[test]
@@ -52,6 +53,30 @@ fn number() {
     );
 }
 
+#[test]
+fn integer_and_float_numbers_are_distinct() {
+    let integer: Value = "1".parse().unwrap();
+    assert_eq!(integer, Value::Number(Number::U8(1)));
+
+    for float in ["1.0", "1e0", "1.", "1.0e0", "1f32"] {
+        let float: Value = float.parse().unwrap();
+
+        assert_eq!(float, Value::Number(Number::F32(1.0.into())));
+        assert_ne!(float, integer);
+        assert!(float.semantic_eq(&integer));
+        assert_eq!(ron::to_string(&float).unwrap(), "1.0");
+    }
+
+    assert_eq!(ron::to_string(&integer).unwrap(), "1");
+
+    // the distinction is kept inside nested values as well
+    let integers: Value = "{\"a\": [1, -1]}".parse().unwrap();
+    let floats: Value = "{\"a\": [1.0, -1.0]}".parse().unwrap();
+
+    assert_ne!(integers, floats);
+    assert!(integers.semantic_eq(&floats));
+}
+
 #[test]
 fn option() {
     let opt = Some(Box::new(Value::Char('c')));
@@ -158,13 +183,78 @@ fn unit() {
         "".parse::<Value>(),
         Err(SpannedError {
             code: Error::Eof,
-            position: Position { col: 1, line: 1 }
+            position: Position {
+                line: 1,
+                col: 1,
+                offset: 0
+            }
         })
     );
 
     assert_eq!(ron::to_string(&Value::Unit).unwrap(), "()");
 }
 
+#[test]
+fn shapes() {
+    fn number(n: u8) -> Value {
+        Value::Number(Number::U8(n))
+    }
+
+    fn some(value: Value) -> Value {
+        Value::Option(Some(Box::new(value)))
+    }
+
+    fn fields(value: Value) -> Value {
+        Value::Map(
+            [(Value::String(String::from("x")), value)]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    let shapes = [
+        // options are recognised regardless of the `implicit_some` extension
+        ("Some(1)", some(number(1))),
+        ("Some (1,)", some(number(1))),
+        ("Some(Some(1))", some(some(number(1)))),
+        ("Some(None)", some(Value::Option(None))),
+        ("Some(())", some(Value::Unit)),
+        ("Some((x: 1))", some(fields(number(1)))),
+        ("Some((1, 2))", some(Value::Seq(vec![number(1), number(2)]))),
+        ("None", Value::Option(None)),
+        ("#![enable(implicit_some)] 1", number(1)),
+        // unit structs and unit enum variants
+        ("()", Value::Unit),
+        ("Unit", Value::Unit),
+        ("Nonesuch", Value::Unit),
+        ("r#None", Value::Unit),
+        ("r#true", Value::Unit),
+        // tuples, tuple structs, and newtype and tuple enum variants
+        ("(1)", Value::Seq(vec![number(1)])),
+        ("(1, 2)", Value::Seq(vec![number(1), number(2)])),
+        ("Newtype(1)", Value::Seq(vec![number(1)])),
+        ("Somewhere(1)", Value::Seq(vec![number(1)])),
+        ("r#Some(1)", Value::Seq(vec![number(1)])),
+        ("Variant()", Value::Seq(vec![])),
+        ("Variant(None)", Value::Seq(vec![Value::Option(None)])),
+        ("Variant((x: 1))", Value::Seq(vec![fields(number(1))])),
+        // structs, named structs, and struct enum variants
+        ("(x: 1)", fields(number(1))),
+        ("Struct(x: 1)", fields(number(1))),
+        ("Variant(x: Some(1))", fields(some(number(1)))),
+        ("Variant(x: Unit)", fields(Value::Unit)),
+    ];
+
+    for (ron, value) in shapes {
+        assert_eq!(ron.parse().as_ref(), Ok(&value), "{}", ron);
+    }
+
+    // `Some` must always wrap a single value
+    for ron in ["Some", "Some(x: 1)", "Some(1, 2)"] {
+        assert!(ron.parse::<Value>().is_err(), "{}", ron);
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
 struct Scene(Option<(u32, u32)>);
 
@@ -223,3 +313,65 @@ fn map_roundtrip_338() {
 
     assert_eq!(v, roundtrip);
 }
+
+#[test]
+fn display() {
+    let value: Value = "(a: Some([1, 2.5, 'c']), b: b\"\\x00\", c: ())"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        value.to_string(),
+        "{\"a\":Some([1,2.5,'c']),\"b\":b\"\\x00\",\"c\":()}"
+    );
+    assert_eq!(value.to_string().parse(), Ok(value.clone()));
+    assert_eq!(format!("{:#}", value).parse(), Ok(value));
+}
+
+/// A library function that accepts any [`Deserializer`]
+fn from_deserializer<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    T::deserialize(deserializer)
+}
+
+#[test]
+fn into_deserializer() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Server {
+        host: String,
+        ports: Vec<u16>,
+        tls: Option<bool>,
+    }
+
+    let value: Value =
+        ron::from_str("(host: \"localhost\", ports: [80, 443], tls: Some(true))").unwrap();
+    let server = Server {
+        host: String::from("localhost"),
+        ports: vec![80, 443],
+        tls: Some(true),
+    };
+
+    assert_eq!(
+        from_deserializer::<_, Server>((&value).into_deserializer()),
+        Ok(Server {
+            host: String::from("localhost"),
+            ports: vec![80, 443],
+            tls: Some(true),
+        })
+    );
+    assert_eq!(
+        from_deserializer::<_, Server>(value.clone().into_deserializer()),
+        Ok(server)
+    );
+
+    assert_eq!(
+        from_deserializer::<_, Vec<u16>>(value.into_deserializer()),
+        Err(Error::InvalidValueForType {
+            expected: String::from("a sequence"),
+            found: String::from("a map"),
+        })
+    );
+}
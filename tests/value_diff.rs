@@ -0,0 +1,123 @@
+use ron::value::{Value, ValueChange};
+
+fn parse(ron: &str) -> Value {
+    ron::from_str(ron).unwrap()
+}
+
+#[test]
+fn diff_of_nested_documents() {
+    let old = parse(
+        r#"(
+            "a/b~c": 1,
+            limits: { 1: 10, 2: 20 },
+            name: "ron",
+            servers: [
+                (host: "a", ports: [80, 443]),
+                (host: "b", ports: []),
+            ],
+            tls: Some((cert: "a.pem")),
+        )"#,
+    );
+    let new = parse(
+        r#"(
+            "a/b~c": 1.0,
+            limits: { 1: 10, 3: 30 },
+            name: "ron",
+            servers: [
+                (host: "a", ports: [8080]),
+                (host: "b", ports: [], backup: true),
+                (host: "c", ports: []),
+            ],
+            tls: Some((cert: "b.pem")),
+        )"#,
+    );
+
+    // the keys are sorted such that the order of the changes does not
+    // depend on whether the `indexmap` feature is enabled
+    let diff = old.diff(&new);
+
+    assert_eq!(
+        diff.changes,
+        vec![
+            ValueChange::Removed {
+                path: String::from("/limits/2"),
+                value: Value::from(20_u8),
+            },
+            ValueChange::Added {
+                path: String::from("/limits/3"),
+                value: Value::from(30_u8),
+            },
+            ValueChange::Changed {
+                path: String::from("/servers/0/ports/0"),
+                old: Value::from(80_u8),
+                new: Value::from(8080_u16),
+            },
+            ValueChange::Removed {
+                path: String::from("/servers/0/ports/1"),
+                value: Value::from(443_u16),
+            },
+            ValueChange::Added {
+                path: String::from("/servers/1/backup"),
+                value: Value::Bool(true),
+            },
+            ValueChange::Added {
+                path: String::from("/servers/2"),
+                value: parse("(host: \"c\", ports: [])"),
+            },
+            ValueChange::Changed {
+                path: String::from("/tls/cert"),
+                old: Value::from("a.pem"),
+                new: Value::from("b.pem"),
+            },
+        ]
+    );
+
+    // every path can be looked up in the value that contains it
+    for change in &diff.changes {
+        match change {
+            ValueChange::Added { path, value } => assert_eq!(new.pointer(path), Some(value)),
+            ValueChange::Removed { path, value } => assert_eq!(old.pointer(path), Some(value)),
+            ValueChange::Changed {
+                path,
+                old: o,
+                new: n,
+            } => {
+                assert_eq!(old.pointer(path), Some(o));
+                assert_eq!(new.pointer(path), Some(n));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(
+        diff.to_string(),
+        "- /limits/2: 20
++ /limits/3: 30
+~ /servers/0/ports/0: 80 -> 8080
+- /servers/0/ports/1: 443
++ /servers/1/backup: true
++ /servers/2: {\"host\":\"c\",\"ports\":[]}
+~ /tls/cert: \"a.pem\" -> \"b.pem\""
+    );
+}
+
+#[test]
+fn diff_of_equal_and_mismatched_values() {
+    let value = parse("(a: [1, Some(2)], b: {\"x\": ()})");
+
+    assert!(value.diff(&value).is_empty());
+    assert_eq!(value.diff(&value).to_string(), "");
+
+    let diff = value.diff(&parse("[1, 2]"));
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].path(), "");
+    assert_eq!(
+        diff.to_string(),
+        "~ (root): {\"a\":[1,Some(2)],\"b\":{\"x\":()}} -> [1,2]"
+    );
+
+    // keys that are not strings are written as RON, with `~` and `/` escaped
+    let diff = parse("{ \"a~/\": 1, [1, 2]: 2 }").diff(&parse("{ \"a~/\": 3 }"));
+    let paths: Vec<_> = diff.changes.iter().map(ValueChange::path).collect();
+    assert_eq!(paths, ["/a~0~1", "/[1,2]"]);
+}
@@ -0,0 +1,121 @@
+use ron::{Map, Value};
+
+fn value(ron: &str) -> Value {
+    ron::from_str(ron).unwrap()
+}
+
+#[test]
+fn pointer() {
+    let data = value(
+        r#"{
+            "foo": ["bar", "baz"],
+            "": 0,
+            "a/b": 1,
+            "c%d": 2,
+            "e^f": 3,
+            "g|h": 4,
+            "i\\j": 5,
+            "k\"l": 6,
+            " ": 7,
+            "m~n": 8,
+        }"#,
+    );
+
+    assert_eq!(data.pointer(""), Some(&data));
+    assert_eq!(data.pointer("/foo"), Some(&value(r#"["bar", "baz"]"#)));
+    assert_eq!(data.pointer("/foo/0"), Some(&Value::from("bar")));
+    assert_eq!(data.pointer("/"), Some(&value("0")));
+    assert_eq!(data.pointer("/a~1b"), Some(&value("1")));
+    assert_eq!(data.pointer("/c%d"), Some(&value("2")));
+    assert_eq!(data.pointer("/e^f"), Some(&value("3")));
+    assert_eq!(data.pointer("/g|h"), Some(&value("4")));
+    assert_eq!(data.pointer("/i\\j"), Some(&value("5")));
+    assert_eq!(data.pointer("/k\"l"), Some(&value("6")));
+    assert_eq!(data.pointer("/ "), Some(&value("7")));
+    assert_eq!(data.pointer("/m~0n"), Some(&value("8")));
+
+    // Invalid pointers
+    assert_eq!(data.pointer("/unknown"), None);
+    assert_eq!(data.pointer("/e^f/ertz"), None);
+    assert_eq!(data.pointer("/foo/00"), None);
+    assert_eq!(data.pointer("/foo/01"), None);
+    assert_eq!(data.pointer("/foo/+1"), None);
+    assert_eq!(data.pointer("/foo/-1"), None);
+    assert_eq!(data.pointer("/foo/2"), None);
+    assert_eq!(data.pointer("foo"), None);
+}
+
+#[test]
+fn pointer_into_structs_and_options() {
+    let data = value(
+        "(
+            server: (
+                name: \"api\",
+                ports: [80, 443],
+                tls: Some((cert: \"cert.pem\")),
+                proxy: None,
+            ),
+        )",
+    );
+
+    assert_eq!(data.pointer("/server/name"), Some(&Value::from("api")));
+    assert_eq!(data.pointer("/server/ports/1"), Some(&value("443")));
+    assert_eq!(
+        data.pointer("/server/tls"),
+        Some(&value("Some((cert: \"cert.pem\"))"))
+    );
+    assert_eq!(
+        data.pointer("/server/tls/cert"),
+        Some(&Value::from("cert.pem"))
+    );
+    assert_eq!(data.pointer("/server/proxy"), Some(&Value::Option(None)));
+    assert_eq!(data.pointer("/server/proxy/host"), None);
+    assert_eq!(data.pointer("/server/name/0"), None);
+}
+
+#[test]
+fn pointer_into_integer_keyed_maps() {
+    let data =
+        value("{ 0: \"zero\", -1: \"minus one\", 18446744073709551615: \"max\", 2.0: \"float\" }");
+
+    assert_eq!(data.pointer("/0"), Some(&Value::from("zero")));
+    assert_eq!(data.pointer("/-1"), Some(&Value::from("minus one")));
+    assert_eq!(
+        data.pointer("/18446744073709551615"),
+        Some(&Value::from("max"))
+    );
+
+    // Integer keys are matched by their value, but floats are never matched
+    assert_eq!(data.pointer("/2"), None);
+    assert_eq!(data.pointer("/00"), None);
+    assert_eq!(data.pointer("/-01"), None);
+    assert_eq!(data.pointer("/+0"), None);
+
+    // String keys take precedence over integer keys
+    let mut map = Map::new();
+    map.insert(1, "integer");
+    map.insert("1", "string");
+    assert_eq!(Value::Map(map).pointer("/1"), Some(&Value::from("string")));
+}
+
+#[test]
+fn pointer_mut() {
+    let mut data = value("(x: (a: 1, b: [2, 3]), y: Some({ 4: \"four\" }))");
+
+    assert_eq!(data.pointer_mut("/x/a"), Some(&mut value("1")));
+    *data.pointer_mut("/x/a").unwrap() = value("6");
+    *data.pointer_mut("/x/b/1").unwrap() = value("7");
+    *data.pointer_mut("/y/4").unwrap() = Value::from("eight");
+
+    assert_eq!(
+        data,
+        value("(x: (a: 6, b: [2, 7]), y: Some({ 4: \"eight\" }))")
+    );
+
+    assert_eq!(data.pointer_mut("/x/c"), None);
+    assert_eq!(data.pointer_mut("/x/b/2"), None);
+    assert_eq!(data.pointer_mut("x"), None);
+
+    *data.pointer_mut("").unwrap() = Value::Unit;
+    assert_eq!(data, Value::Unit);
+}
@@ -0,0 +1,112 @@
+use ron::{
+    value::{Map, Number},
+    Value,
+};
+
+fn value(ron: &str) -> Value {
+    ron::from_str(ron).unwrap()
+}
+
+#[test]
+fn numbers_compare_by_value() {
+    let one = [
+        Number::from(1u8),
+        Number::from(1i16),
+        Number::from(1u32),
+        Number::from(1i64),
+        Number::from(1.0f32),
+        Number::from(1.0f64),
+    ];
+
+    for a in one {
+        for b in one {
+            assert!(Value::Number(a).semantic_eq(&Value::Number(b)));
+        }
+    }
+
+    assert!(Value::from(-1i8).semantic_eq(&Value::from(-1.0f64)));
+    assert!(Value::from(u64::MAX).semantic_eq(&Value::from(u64::MAX)));
+    assert!(Value::from(1u64 << 63).semantic_eq(&Value::from(9_223_372_036_854_775_808.0f64)));
+    assert!(!Value::from(u64::MAX).semantic_eq(&Value::from(u64::MAX as f64)));
+    assert!(!Value::from(-1i8).semantic_eq(&Value::from(u64::MAX)));
+    assert!(!Value::from(1).semantic_eq(&Value::from(1.5)));
+    assert!(!Value::from(0.1f32).semantic_eq(&Value::from(0.1f64)));
+    assert!(Value::from(0.5f32).semantic_eq(&Value::from(0.5f64)));
+    assert!(Value::from(f32::INFINITY).semantic_eq(&Value::from(f64::INFINITY)));
+    assert!(!Value::from(f64::INFINITY).semantic_eq(&Value::from(f64::NEG_INFINITY)));
+}
+
+#[test]
+fn signed_zeros_and_nans() {
+    assert_ne!(Value::from(0.0), Value::from(-0.0));
+    assert!(Value::from(0.0).semantic_eq(&Value::from(-0.0)));
+    assert!(Value::from(-0.0f32).semantic_eq(&Value::from(0)));
+
+    assert!(Value::from(f64::NAN).semantic_eq(&Value::from(f64::NAN)));
+    assert!(Value::from(f64::NAN).semantic_eq(&Value::from(-f32::NAN)));
+    assert!(!Value::from(f64::NAN).semantic_eq(&Value::from(0.0)));
+    assert!(!Value::from(f64::NAN).semantic_eq(&Value::from(f64::INFINITY)));
+}
+
+#[test]
+fn maps_ignore_order() {
+    let mut a = Map::new();
+    a.insert("x", 1);
+    a.insert("y", 2);
+    let mut b = Map::new();
+    b.insert("y", 2.0);
+    b.insert("x", 1.0);
+
+    assert!(Value::Map(a.clone()).semantic_eq(&Value::Map(b.clone())));
+
+    b.insert("z", 3);
+    assert!(!Value::Map(a.clone()).semantic_eq(&Value::Map(b)));
+
+    // Keys are compared semantically as well
+    let mut c = Map::new();
+    c.insert(1.0, "one");
+    let mut d = Map::new();
+    d.insert(1u8, "one");
+    assert!(Value::Map(c.clone()).semantic_eq(&Value::Map(d)));
+
+    let mut e = Map::new();
+    e.insert(1u8, "two");
+    assert!(!Value::Map(c).semantic_eq(&Value::Map(e)));
+}
+
+#[test]
+fn nested_values() {
+    let golden = value(
+        r#"(
+            name: "proxy",
+            ports: [80, 443],
+            timeout: Some(1.5),
+            weights: { "a": 1, "b": 0.0 },
+        )"#,
+    );
+    let actual = value(
+        r#"(weights: { "b": -0.0, "a": 1.0 }, timeout: Some(1.5), ports: [80.0, 443], name: "proxy")"#,
+    );
+
+    assert!(golden.semantic_eq(&actual));
+    assert!(actual.semantic_eq(&golden));
+
+    assert!(!golden.semantic_eq(&value(
+        r#"(name: "proxy", ports: [443, 80], timeout: Some(1.5), weights: { "a": 1, "b": 0 })"#
+    )));
+    assert!(!golden.semantic_eq(&value(
+        r#"(name: "proxy", ports: [80, 443], timeout: None, weights: { "a": 1, "b": 0 })"#
+    )));
+}
+
+#[test]
+fn other_values_are_strict() {
+    assert!(value("\"a\"").semantic_eq(&value("\"a\"")));
+    assert!(!value("\"a\"").semantic_eq(&value("b\"a\"")));
+    assert!(!value("'a'").semantic_eq(&value("\"a\"")));
+    assert!(!value("true").semantic_eq(&value("1")));
+    assert!(value("None").semantic_eq(&value("None")));
+    assert!(!value("None").semantic_eq(&value("()")));
+    assert!(!value("[]").semantic_eq(&value("{}")));
+    assert!(!value("[1]").semantic_eq(&value("[1, 1]")));
+}
@@ -0,0 +1,44 @@
+use ron::value::Value;
+
+#[test]
+fn stats_of_nested_document() {
+    let value: Value = ron::from_str(
+        r#"(
+            name: "ron",
+            servers: [
+                (host: "a", ports: [80, 443]),
+                (host: "bc", ports: []),
+            ],
+            backup: Some(Some([true])),
+            data: b"xyz",
+            nothing: None,
+        )"#,
+    )
+    .unwrap();
+
+    let stats = value.stats();
+
+    // root, 5 keys, "ron", [...], 2 * (struct, 2 keys, host, ports),
+    // 80, 443, Some, Some, [...], true, b"xyz", None
+    assert_eq!(stats.nodes, 1 + 5 + 1 + 1 + 2 * 5 + 2 + 4 + 1 + 1);
+    // root > Some > Some > [...] > true
+    assert_eq!(stats.max_depth, 4);
+    // name + servers + backup + data + nothing + ron + 2 * (host + ports) + a + bc
+    assert_eq!(
+        stats.string_bytes,
+        4 + 7 + 6 + 4 + 7 + 3 + 2 * (4 + 5) + 1 + 2
+    );
+    assert_eq!(stats.map_entries, 5 + 2 + 2);
+    assert_eq!(stats.seq_elements, 2 + 2 + 1);
+}
+
+#[test]
+fn stats_of_scalar() {
+    let stats = Value::from(42).stats();
+
+    assert_eq!(stats.nodes, 1);
+    assert_eq!(stats.max_depth, 0);
+    assert_eq!(stats.string_bytes, 0);
+    assert_eq!(stats.map_entries, 0);
+    assert_eq!(stats.seq_elements, 0);
+}
@@ -0,0 +1,183 @@
+use ron::value::{Number, PathSegment, Value, ValueVisitor, ValueVisitorMut};
+
+fn format_path(path: &[PathSegment]) -> String {
+    let mut formatted = String::new();
+
+    for segment in path {
+        match segment {
+            PathSegment::Index(index) => formatted.push_str(&format!("[{}]", index)),
+            PathSegment::Key(Value::String(key)) => {
+                formatted.push('.');
+                formatted.push_str(key);
+            }
+            PathSegment::Key(key) => {
+                formatted.push_str(&format!("[{}]", ron::to_string(key).unwrap()))
+            }
+            PathSegment::Some => formatted.push_str("?"),
+        }
+    }
+
+    formatted
+}
+
+#[derive(Default)]
+struct StringCollector {
+    strings: Vec<(String, String)>,
+}
+
+impl ValueVisitor for StringCollector {
+    fn visit_string(&mut self, path: &[PathSegment], value: &str) {
+        self.strings.push((format_path(path), String::from(value)));
+    }
+}
+
+#[derive(Default)]
+struct PortRangeLinter {
+    errors: Vec<String>,
+}
+
+impl ValueVisitor for PortRangeLinter {
+    fn visit_number(&mut self, path: &[PathSegment], value: &Number) {
+        let is_port = matches!(
+            path.last(),
+            Some(PathSegment::Key(Value::String(key))) if key == "port"
+        );
+
+        if is_port && !(1.0..=65535.0).contains(&value.into_f64()) {
+            self.errors.push(format_path(path));
+        }
+    }
+}
+
+const CONFIG: &str = r#"(
+    name: "proxy",
+    servers: [
+        (host: "a.example", port: 80, alias: Some("a")),
+        (host: "", port: 0, alias: None),
+    ],
+    labels: { 1: "one", "two": b"2" },
+)"#;
+
+#[test]
+fn visit_collects_strings_with_paths() {
+    let value: Value = ron::from_str(CONFIG).unwrap();
+
+    let mut visitor = StringCollector::default();
+    value.visit(&mut visitor);
+
+    // Maps are ordered by key unless the `indexmap` feature is enabled
+    visitor.strings.sort();
+
+    assert_eq!(
+        visitor.strings,
+        vec![
+            (String::from(".labels[1]"), String::from("one")),
+            (String::from(".name"), String::from("proxy")),
+            (String::from(".servers[0].alias?"), String::from("a")),
+            (String::from(".servers[0].host"), String::from("a.example")),
+            (String::from(".servers[1].host"), String::new()),
+        ]
+    );
+}
+
+#[test]
+fn visit_lints_numbers() {
+    let value: Value = ron::from_str(CONFIG).unwrap();
+
+    let mut visitor = PortRangeLinter::default();
+    value.visit(&mut visitor);
+
+    assert_eq!(visitor.errors, vec![String::from(".servers[1].port")]);
+}
+
+#[derive(Default)]
+struct NodeCounter {
+    nodes: Vec<&'static str>,
+}
+
+impl ValueVisitor for NodeCounter {
+    fn visit_bool(&mut self, _path: &[PathSegment], _value: bool) {
+        self.nodes.push("bool");
+    }
+
+    fn visit_char(&mut self, _path: &[PathSegment], _value: char) {
+        self.nodes.push("char");
+    }
+
+    fn visit_map(&mut self, _path: &[PathSegment], _value: &ron::Map) {
+        self.nodes.push("map");
+    }
+
+    fn visit_number(&mut self, _path: &[PathSegment], _value: &Number) {
+        self.nodes.push("number");
+    }
+
+    fn visit_option(&mut self, _path: &[PathSegment], _value: Option<&Value>) {
+        self.nodes.push("option");
+    }
+
+    fn visit_string(&mut self, _path: &[PathSegment], _value: &str) {
+        self.nodes.push("string");
+    }
+
+    fn visit_bytes(&mut self, _path: &[PathSegment], _value: &[u8]) {
+        self.nodes.push("bytes");
+    }
+
+    fn visit_seq(&mut self, _path: &[PathSegment], _value: &[Value]) {
+        self.nodes.push("seq");
+    }
+
+    fn visit_unit(&mut self, _path: &[PathSegment]) {
+        self.nodes.push("unit");
+    }
+}
+
+#[test]
+fn visit_all_node_types_in_order() {
+    let value: Value =
+        ron::from_str(r#"[true, 'c', {"k": 1}, Some(()), None, "s", b"b"]"#).unwrap();
+
+    let mut visitor = NodeCounter::default();
+    value.visit(&mut visitor);
+
+    assert_eq!(
+        visitor.nodes,
+        vec!["seq", "bool", "char", "map", "number", "option", "unit", "option", "string", "bytes"]
+    );
+}
+
+struct Redactor;
+
+impl ValueVisitorMut for Redactor {
+    fn visit_string(&mut self, path: &[PathSegment], value: &mut String) {
+        if matches!(
+            path.last(),
+            Some(PathSegment::Key(Value::String(key))) if key == "host"
+        ) {
+            *value = String::from("<redacted>");
+        }
+    }
+
+    fn visit_seq(&mut self, _path: &[PathSegment], value: &mut Vec<Value>) {
+        value.retain(|server| server != &Value::Unit);
+    }
+}
+
+#[test]
+fn visit_mut_modifies_in_place() {
+    let mut value: Value = ron::from_str(
+        r#"(servers: [(host: "a.example", port: 80), (), (host: "b.example", port: 81)])"#,
+    )
+    .unwrap();
+
+    value.visit_mut(&mut Redactor);
+
+    assert_eq!(
+        value,
+        ron::from_str(
+            r#"(servers: [(host: "<redacted>", port: 80), (host: "<redacted>", port: 81)])"#
+        )
+        .unwrap()
+    );
+}
@@ -0,0 +1,124 @@
+use ron::{error::Position, options::Case, Error, Options};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Event {
+    Started,
+    MyVariant(u8),
+    HTTPRequest {
+        status: u16,
+    },
+    Retry2Times,
+    #[serde(rename = "already_snake")]
+    AlreadySnake,
+}
+
+fn options(case: Case) -> Options {
+    Options::default().with_variant_case_conversion(case)
+}
+
+#[test]
+fn snake_case_variants() {
+    let options = options(Case::Snake);
+
+    assert_eq!(options.from_str("started"), Ok(Event::Started));
+    assert_eq!(options.from_str("my_variant(4)"), Ok(Event::MyVariant(4)));
+    assert_eq!(
+        options.from_str("http_request(status: 200)"),
+        Ok(Event::HTTPRequest { status: 200 })
+    );
+    assert_eq!(options.from_str("retry2_times"), Ok(Event::Retry2Times));
+    assert_eq!(options.from_str("already_snake"), Ok(Event::AlreadySnake));
+    assert_eq!(
+        options.from_str::<Vec<Event>>("[Started, started, MyVariant(1), my_variant(2)]"),
+        Ok(vec![
+            Event::Started,
+            Event::Started,
+            Event::MyVariant(1),
+            Event::MyVariant(2)
+        ])
+    );
+}
+
+#[test]
+fn kebab_case_variants() {
+    let options = options(Case::Kebab);
+
+    assert_eq!(options.from_str("r#my-variant(4)"), Ok(Event::MyVariant(4)));
+    assert_eq!(
+        options.from_str("r#http-request(status: 404)"),
+        Ok(Event::HTTPRequest { status: 404 })
+    );
+    assert_eq!(options.from_str("r#already-snake"), Ok(Event::AlreadySnake));
+    assert_eq!(options.from_str("MyVariant(4)"), Ok(Event::MyVariant(4)));
+}
+
+#[test]
+fn camel_case_variants() {
+    let options = options(Case::Camel);
+
+    assert_eq!(options.from_str("started"), Ok(Event::Started));
+    assert_eq!(options.from_str("myVariant(4)"), Ok(Event::MyVariant(4)));
+    assert_eq!(
+        options.from_str("httpRequest(status: 500)"),
+        Ok(Event::HTTPRequest { status: 500 })
+    );
+    assert_eq!(options.from_str("alreadySnake"), Ok(Event::AlreadySnake));
+}
+
+#[test]
+fn other_case_conventions_are_rejected() {
+    assert_eq!(
+        options(Case::Camel)
+            .from_str::<Event>("my_variant(4)")
+            .unwrap_err()
+            .code,
+        Error::NoSuchEnumVariant {
+            expected: &[
+                "Started",
+                "MyVariant",
+                "HTTPRequest",
+                "Retry2Times",
+                "already_snake"
+            ],
+            found: String::from("my_variant"),
+            outer: Some(String::from("Event")),
+        }
+    );
+
+    // variants are only converted with the option
+    assert!(ron::from_str::<Event>("my_variant(4)").is_err());
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Protocol {
+    HttpError,
+    HTTPError,
+    Ok,
+}
+
+#[test]
+fn ambiguous_variants_are_rejected() {
+    assert_eq!(
+        options(Case::Snake).from_str::<Protocol>("\n  http_error"),
+        Err(ron::error::SpannedError {
+            code: Error::AmbiguousEnumVariant {
+                found: String::from("http_error"),
+                candidates: vec!["HttpError", "HTTPError"],
+                outer: Some(String::from("Protocol")),
+            },
+            position: Position {
+                line: 2,
+                col: 3,
+                offset: 3,
+            },
+        })
+    );
+
+    // exact matches are never ambiguous
+    assert_eq!(
+        options(Case::Snake).from_str("HTTPError"),
+        Ok(Protocol::HTTPError)
+    );
+    assert_eq!(options(Case::Snake).from_str("ok"), Ok(Protocol::Ok));
+}
@@ -0,0 +1,145 @@
+use ron::{
+    error::{Position, Warning, WarningKind},
+    Deserializer, Error, Options,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Config {
+    enabled: bool,
+    level: Level,
+}
+
+fn lenient() -> Options {
+    Options::default()
+        .with_bool_aliases(&[("yes", true), ("off", false)])
+        .with_numeric_enum_variants(true)
+}
+
+#[test]
+fn lenient_constructs_are_warned_about() {
+    let (config, warnings) = lenient()
+        .from_str_with_warnings::<Config>("(\n    enabled: yes,\n    level: 1,\n)")
+        .unwrap();
+
+    assert_eq!(
+        config,
+        Config {
+            enabled: true,
+            level: Level::High,
+        }
+    );
+    assert_eq!(
+        warnings,
+        [
+            Warning {
+                kind: WarningKind::BoolAlias {
+                    alias: String::from("yes"),
+                    value: true,
+                },
+                position: Position {
+                    line: 2,
+                    col: 14,
+                    offset: 15,
+                },
+            },
+            Warning {
+                kind: WarningKind::NumericEnumVariant {
+                    index: 1,
+                    variant: "High",
+                },
+                position: Position {
+                    line: 3,
+                    col: 12,
+                    offset: 31,
+                },
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "2:14: The boolean alias `yes` is discouraged, write `true` instead"
+    );
+    assert_eq!(
+        warnings[1].to_string(),
+        "3:12: The enum variant index `1` is discouraged, write `High` instead"
+    );
+}
+
+#[test]
+fn canonical_constructs_are_not_warned_about() {
+    assert_eq!(
+        lenient().from_str_with_warnings("(enabled: false, level: Low)"),
+        Ok((
+            Config {
+                enabled: false,
+                level: Level::Low,
+            },
+            Vec::new()
+        ))
+    );
+}
+
+#[test]
+fn warnings_are_collected_by_the_deserializer() {
+    let mut deserializer =
+        Deserializer::from_str_with_options("[yes, off, true]", &lenient()).unwrap();
+    assert_eq!(
+        Vec::<bool>::deserialize(&mut deserializer),
+        Ok(vec![true, false, true])
+    );
+    assert_eq!(deserializer.warnings(), []);
+
+    let mut deserializer =
+        Deserializer::from_str_with_options("[yes, off, true]", &lenient().with_warnings(true))
+            .unwrap();
+    assert_eq!(
+        Vec::<bool>::deserialize(&mut deserializer),
+        Ok(vec![true, false, true])
+    );
+    assert_eq!(
+        deserializer
+            .warnings()
+            .iter()
+            .map(|warning| (warning.position.col, &warning.kind))
+            .collect::<Vec<_>>(),
+        [
+            (
+                2,
+                &WarningKind::BoolAlias {
+                    alias: String::from("yes"),
+                    value: true,
+                }
+            ),
+            (
+                7,
+                &WarningKind::BoolAlias {
+                    alias: String::from("off"),
+                    value: false,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn errors_take_precedence_over_warnings() {
+    let err = lenient()
+        .from_str_with_warnings::<Config>("(enabled: yes, level: 2)")
+        .unwrap_err();
+
+    assert_eq!(
+        err.code,
+        Error::NoSuchEnumVariantIndex {
+            expected: &["Low", "High"],
+            found: 2,
+            outer: Some(String::from("Level")),
+        }
+    );
+}
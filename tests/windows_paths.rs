@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use ron::{
+    ser::{to_string_pretty, PrettyConfig},
+    Error,
+};
+use serde::{Deserialize, Serialize};
+
+/// Windows paths of different shapes, written as a raw string
+const PATHS: &[(&str, &str)] = &[
+    ("C:\\Users\\x", r#"r"C:\Users\x""#),
+    ("C:\\", r#"r"C:\""#),
+    (
+        "C:\\Program Files (x86)\\ron\\",
+        r#"r"C:\Program Files (x86)\ron\""#,
+    ),
+    (
+        "\\\\server\\share\\file.ron",
+        r#"r"\\server\share\file.ron""#,
+    ),
+    ("\\\\?\\C:\\very\\long\\path", r#"r"\\?\C:\very\long\path""#),
+    ("C:/mixed\\separators/x", r#"r"C:/mixed\separators/x""#),
+    ("D:\\#tmp\\##cache", r#"r"D:\#tmp\##cache""#),
+    (
+        "relative\\..\\dir\\ünïcödé.ron",
+        r#"r"relative\..\dir\ünïcödé.ron""#,
+    ),
+    ("\\n\\t\\u{0}", r#"r"\n\t\u{0}""#),
+];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    path: String,
+    search: Vec<String>,
+}
+
+#[test]
+fn raw_string_paths_are_parsed_verbatim() {
+    for &(path, raw) in PATHS {
+        assert_eq!(ron::from_str::<String>(raw).as_deref(), Ok(path), "{}", raw);
+    }
+
+    assert_eq!(
+        ron::from_str::<Config>(r#"(path: r"C:\Users\x", search: [r"\\server\share", "C:\\ron"])"#),
+        Ok(Config {
+            path: String::from("C:\\Users\\x"),
+            search: vec![String::from("\\\\server\\share"), String::from("C:\\ron")],
+        })
+    );
+
+    // without a raw string, a backslash starts an escape
+    assert_eq!(
+        ron::from_str::<String>(r#""C:\Users\x""#).unwrap_err().code,
+        Error::InvalidEscape("Unknown escape character")
+    );
+}
+
+#[test]
+fn unescaped_paths_are_serialized_as_raw_strings() {
+    let config = PrettyConfig::new().escape_strings(false);
+
+    for &(path, raw) in PATHS {
+        let ser = to_string_pretty(path, config.clone()).unwrap();
+
+        assert_eq!(ser, raw);
+        assert_eq!(ron::from_str::<String>(&ser).as_deref(), Ok(path));
+    }
+
+    // strings without backslashes or quotes stay ordinary strings
+    assert_eq!(
+        to_string_pretty("/usr/local/#bin", config.clone()).unwrap(),
+        "\"/usr/local/#bin\""
+    );
+
+    // a path next to a quote still gets the minimal number of hashes
+    assert_eq!(
+        to_string_pretty("\"C:\\x\"#", config).unwrap(),
+        "r##\"\"C:\\x\"#\"##"
+    );
+}
+
+#[test]
+fn escaped_paths_double_their_backslashes() {
+    for &(path, _) in PATHS {
+        let ser = ron::to_string(path).unwrap();
+
+        assert_eq!(ser, format!("{:?}", path));
+        assert_eq!(ron::from_str::<String>(&ser).as_deref(), Ok(path));
+    }
+}
+
+#[test]
+fn paths_in_nested_values() {
+    let config = Config {
+        path: String::from("C:\\Users\\x\\config.ron"),
+        search: vec![String::from("\\\\server\\share"), String::from("plain")],
+    };
+    let map = BTreeMap::from([(String::from("C:\\key"), config)]);
+
+    let ser = to_string_pretty(
+        &map,
+        PrettyConfig::new().new_line("\n").escape_strings(false),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ser,
+        r#"{
+    r"C:\key": (
+        path: r"C:\Users\x\config.ron",
+        search: [
+            r"\\server\share",
+            "plain",
+        ],
+    ),
+}"#
+    );
+    assert_eq!(ron::from_str(&ser), Ok(map));
+}